@@ -8,31 +8,43 @@
 mod analyze;
 mod assignment;
 mod association;
+mod binding;
 mod concurrent;
+mod configuration;
+mod control_flow;
+mod dataflow;
 mod declarative;
 mod design_unit;
 mod expression;
+mod external_name;
 mod formal_region;
+mod group;
+mod latch;
 mod literals;
 mod lock;
 mod named_entity;
 mod names;
 mod overloaded;
 mod package_instance;
+mod psl;
+mod purity;
 mod range;
 mod region;
 mod root;
 mod semantic;
 mod sequential;
 mod standard;
+mod static_expression;
 mod target;
+mod view;
 mod visibility;
+mod width;
 
 #[cfg(test)]
 mod tests;
 
 pub use self::root::{DesignRoot, EntHierarchy};
 pub use named_entity::{
-    AnyEnt, AnyEntKind, Concurrent, Design, EntRef, EntityId, HasEntityId, Object, Overloaded,
+    AnyEnt, AnyEntKind, Concurrent, Design, EntRef, EntityId, HasEntityId, Object, Overloaded, Psl,
     Related, Sequential, Type,
 };
@@ -8,8 +8,10 @@ use super::named_entity::*;
 use super::region::*;
 use super::root::*;
 use crate::ast::*;
+use crate::config::Lint;
 use crate::data::*;
-use fnv::FnvHashSet;
+use crate::standard::VHDLStandard;
+use fnv::{FnvHashMap, FnvHashSet};
 use std::cell::RefCell;
 use std::ops::Deref;
 
@@ -136,6 +138,8 @@ pub(super) struct AnalyzeContext<'a> {
     std_sym: Symbol,
     standard_sym: Symbol,
     pub(super) is_std_logic_1164: bool,
+    // The language standard of the library of the current unit
+    pub(super) standard: VHDLStandard,
 
     // Record dependencies and sensitivies when
     // analyzing design units
@@ -150,11 +154,19 @@ pub(super) struct AnalyzeContext<'a> {
     //    missing library
     //  - for example when doing 'use library.all' the file is sensitive to adding/removing
     //    anything from library
+    //  - for example when an instance is bound by default the file is sensitive to
+    //    adding/removing architectures or configurations of the entity
     current_unit: UnitId,
     pub(super) arena: &'a Arena,
     uses: RefCell<FnvHashSet<UnitId>>,
     missing_unit: RefCell<FnvHashSet<(Symbol, Symbol, Option<Symbol>)>>,
     uses_library_all: RefCell<FnvHashSet<Symbol>>,
+    uses_entity_bindings: RefCell<FnvHashSet<(Symbol, Symbol)>>,
+    // Procedures analyzed within the current design unit and whether they contain a wait statement
+    pub(super) procedure_waits: RefCell<FnvHashMap<EntityId, bool>>,
+    // The instantiated units of the instance labels within the current design unit
+    // External names may refer to an instance before its statement has been analyzed
+    pub(super) instantiated_units: RefCell<FnvHashMap<EntityId, InstantiatedUnit>>,
 }
 
 impl<'a> AnalyzeContext<'a> {
@@ -172,12 +184,16 @@ impl<'a> AnalyzeContext<'a> {
                     &root.symbol_utf8("ieee"),
                     &root.symbol_utf8("std_logic_1164"),
                 ),
+            standard: root.standard_of(current_unit.library_name()),
             root,
             current_unit: current_unit.clone(),
             arena,
             uses: RefCell::new(FnvHashSet::default()),
             missing_unit: RefCell::new(FnvHashSet::default()),
             uses_library_all: RefCell::new(FnvHashSet::default()),
+            uses_entity_bindings: RefCell::new(FnvHashSet::default()),
+            procedure_waits: RefCell::new(FnvHashMap::default()),
+            instantiated_units: RefCell::new(FnvHashMap::default()),
         }
     }
 
@@ -189,6 +205,11 @@ impl<'a> AnalyzeContext<'a> {
         self.get_library(self.current_unit.library_name()).unwrap()
     }
 
+    /// Whether a lint is reported for the current unit
+    pub fn is_lint_enabled(&self, lint: Lint) -> bool {
+        self.root.is_lint_enabled(self.work_library_name(), lint)
+    }
+
     pub fn current_unit_id(&self) -> &UnitId {
         &self.current_unit
     }
@@ -214,6 +235,18 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    fn make_use_of_entity_bindings(&self, library_name: &Symbol, entity_name: &Symbol) {
+        // Check local cache before taking lock
+        if self
+            .uses_entity_bindings
+            .borrow_mut()
+            .insert((library_name.clone(), entity_name.clone()))
+        {
+            self.root
+                .make_use_of_entity_bindings(&self.current_unit, library_name, entity_name);
+        }
+    }
+
     fn make_use_of_missing_unit(
         &self,
         library_name: &Symbol,
@@ -357,24 +390,42 @@ impl<'a> AnalyzeContext<'a> {
         entity_name: &Symbol,
         architecture_name: &Symbol,
     ) -> AnalysisResult<DesignEnt<'a>> {
+        self.get_architecture_analysis(library_name, pos, entity_name, architecture_name)
+            .map(|(design, _)| design)
+    }
+
+    /// Get the architecture together with its analyzed design unit
+    pub(super) fn get_architecture_analysis(
+        &self,
+        library_name: &Symbol,
+        pos: &SrcPos,
+        entity_name: &Symbol,
+        architecture_name: &Symbol,
+    ) -> AnalysisResult<(DesignEnt<'a>, UnitReadGuard<'a>)> {
         if let Some(unit) = self.get_secondary_unit(library_name, entity_name, architecture_name) {
             let data = self.get_analysis(Some(pos), unit)?;
-            if let AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(arch)) = data.deref() {
-                if let Some(id) = arch.ident.decl {
-                    let ent = self.arena.get(id);
-                    let design = DesignEnt::from_any(ent).ok_or_else(|| {
-                        // Almost impossible but better not fail silently
-                        Diagnostic::error(
-                            pos,
-                            format!(
-                                "Found non-design {} unit within library {}",
-                                ent.describe(),
-                                library_name
-                            ),
-                        )
-                    })?;
-                    return Ok(design);
-                }
+            let decl = if let AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(arch)) =
+                data.deref()
+            {
+                arch.ident.decl
+            } else {
+                None
+            };
+
+            if let Some(id) = decl {
+                let ent = self.arena.get(id);
+                let design = DesignEnt::from_any(ent).ok_or_else(|| {
+                    // Almost impossible but better not fail silently
+                    Diagnostic::error(
+                        pos,
+                        format!(
+                            "Found non-design {} unit within library {}",
+                            ent.describe(),
+                            library_name
+                        ),
+                    )
+                })?;
+                return Ok((design, data));
             }
         }
 
@@ -386,6 +437,103 @@ impl<'a> AnalyzeContext<'a> {
         )))
     }
 
+    /// The analysis of the design unit with the bodies of the subprograms declared by a package
+    /// or an entity, which is the package body or the entity itself
+    /// Returns None for the current design unit which is still being analyzed
+    pub(super) fn get_subprogram_bodies_analysis(
+        &self,
+        design: EntRef<'a>,
+    ) -> Option<UnitReadGuard<'a>> {
+        let library_name = design.library_name()?;
+        let name = design.designator().as_identifier()?;
+        let unit = match design.kind() {
+            AnyEntKind::Design(Design::Package(..)) => {
+                self.get_secondary_unit(library_name, name, name)?
+            }
+            AnyEntKind::Design(Design::Entity(..)) => self.get_primary_unit(library_name, name)?,
+            _ => return None,
+        };
+
+        if unit.unit_id() == &self.current_unit {
+            return None;
+        }
+
+        // Package bodies that call each others procedures depend on each other
+        // and the bodies are then not known to each other
+        self.get_analysis(None, unit).ok()
+    }
+
+    /// The architecture of an entity when no architecture is given explicitly
+    /// Returns None when it depends on the elaboration which of several architectures is used
+    pub(super) fn default_architecture(
+        &self,
+        library_name: &Symbol,
+        entity_name: &Symbol,
+    ) -> Option<Symbol> {
+        // Adding or removing an architecture may change the result
+        self.make_use_of_entity_bindings(library_name, entity_name);
+
+        let units = self.root.get_library_units(library_name)?;
+        let architectures: Vec<_> = units
+            .values()
+            .filter(|unit| unit.kind() == AnyKind::Secondary(SecondaryKind::Architecture))
+            .filter_map(|unit| match unit.key() {
+                UnitKey::Secondary(primary_name, name) if primary_name == entity_name => Some(name),
+                _ => None,
+            })
+            .collect();
+
+        if let [name] = architectures.as_slice() {
+            Some((*name).clone())
+        } else {
+            // Within one of the architectures it is the one being elaborated
+            architectures
+                .into_iter()
+                .find(|name| self.is_current_architecture(library_name, entity_name, name))
+                .cloned()
+        }
+    }
+
+    /// True when the architecture being analyzed is configured by a configuration declaration
+    /// The component instances of the architecture are then bound by the configuration
+    pub(super) fn is_configured_architecture(&self) -> bool {
+        if self.current_unit.kind() != AnyKind::Secondary(SecondaryKind::Architecture) {
+            return false;
+        }
+
+        // Adding or removing a configuration may change the result
+        let library_name = self.current_unit.library_name();
+        self.make_use_of_entity_bindings(library_name, self.current_unit.primary_name());
+
+        let units = if let Some(units) = self.root.get_library_units(library_name) {
+            units
+        } else {
+            return false;
+        };
+
+        units
+            .values()
+            .any(|unit| match unit.configured_architecture() {
+                Some((entity_name, architecture_name)) => {
+                    entity_name == self.current_unit.primary_name()
+                        && Some(architecture_name) == self.current_unit.secondary_name()
+                }
+                None => false,
+            })
+    }
+
+    pub(super) fn is_current_architecture(
+        &self,
+        library_name: &Symbol,
+        entity_name: &Symbol,
+        architecture_name: &Symbol,
+    ) -> bool {
+        self.current_unit.kind() == AnyKind::Secondary(SecondaryKind::Architecture)
+            && self.current_unit.library_name() == library_name
+            && self.current_unit.primary_name() == entity_name
+            && self.current_unit.secondary_name() == Some(architecture_name)
+    }
+
     pub fn lookup_in_library(
         &self,
         library_name: &Symbol,
@@ -54,6 +54,7 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
         }
+        self.check_expr_assignment_width(target, rhs, diagnostics);
         Ok(())
     }
 
@@ -96,6 +97,7 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
         }
+        self.check_waveform_assignment_width(target, rhs, diagnostics);
         Ok(())
     }
 
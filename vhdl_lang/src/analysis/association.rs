@@ -15,6 +15,7 @@ use super::names::ResolvedName;
 use super::region::*;
 use crate::ast::*;
 use crate::data::*;
+use crate::standard::VHDLStandard;
 
 #[derive(Copy, Clone)]
 pub enum ResolvedFormal<'a> {
@@ -44,6 +45,16 @@ impl<'a> ResolvedFormal<'a> {
         }
     }
 
+    /// The subtype of an object formal that is associated as a whole
+    fn subtype(&self) -> Option<Subtype<'a>> {
+        if let ResolvedFormal::Basic(_, ent) = self {
+            if let AnyEntKind::Object(obj) = ent.inner().kind() {
+                return Some(obj.subtype);
+            }
+        }
+        None
+    }
+
     fn select(self, suffix_type: TypeEnt<'a>) -> Option<Self> {
         match self {
             ResolvedFormal::Basic(idx, ent) => {
@@ -58,6 +69,15 @@ impl<'a> ResolvedFormal<'a> {
         }
     }
 
+    // The interface object of the formal
+    pub fn interface(&self) -> InterfaceEnt<'a> {
+        *match self {
+            ResolvedFormal::Basic(_, ent) => ent,
+            ResolvedFormal::Selected(_, ent, _) => ent,
+            ResolvedFormal::Converted(_, ent, _) => ent,
+        }
+    }
+
     // The position of the formal in the formal region
     fn idx(&self) -> usize {
         *match self {
@@ -257,9 +277,10 @@ impl<'a> AnalyzeContext<'a> {
         let mut missing = false;
         let mut associated_indexes: FnvHashSet<usize> = Default::default();
         let mut extra_associations: Vec<SrcPos> = Default::default();
+        let mut open_associations: Vec<(SrcPos, InterfaceEnt<'a>)> = Default::default();
 
         for (idx, AssociationElement { formal, actual }) in elems.iter_mut().enumerate() {
-            if let Some(ref mut formal) = formal {
+            let resolved = if let Some(ref mut formal) = formal {
                 // Call by name using formal
                 match self.resolve_formal(
                     formal_region,
@@ -271,33 +292,68 @@ impl<'a> AnalyzeContext<'a> {
                     Err(err) => {
                         missing = true;
                         diagnostics.push(err.into_non_fatal()?);
+                        None
                     }
-                    Ok(formal) => {
-                        associated_indexes.insert(formal.idx());
-                        result.push(formal);
-                    }
+                    Ok(formal) => Some(formal),
                 }
             } else if let Some(formal) = formal_region.nth(idx) {
-                associated_indexes.insert(idx);
-                result.push(ResolvedFormal::Basic(idx, formal));
+                Some(ResolvedFormal::Basic(idx, formal))
             } else {
                 extra_associations.push(actual.pos.clone());
+                None
             };
+
+            if let Some(formal) = resolved {
+                associated_indexes.insert(formal.idx());
+                if let (ResolvedFormal::Basic(_, ent), ActualPart::Open) = (formal, &actual.item) {
+                    if !may_be_left_open(formal_region, ent) {
+                        open_associations.push((actual.pos.clone(), ent));
+                    }
+                }
+                result.push(formal);
+            }
         }
 
         let mut not_associated = Vec::new();
+        let mut left_open = Vec::new();
         for (idx, formal) in formal_region.iter().enumerate() {
-            if !(associated_indexes.contains(&idx)
-                // Default may be unconnected
-                || formal.has_default()
-                // Output ports are allowed to be unconnected
-                || (formal_region.typ == InterfaceType::Port && formal.is_out_or_inout_signal()))
-            {
+            if associated_indexes.contains(&idx) {
+                continue;
+            } else if !may_be_left_open(formal_region, formal) {
                 not_associated.push(idx);
+            } else if !formal.has_default() {
+                left_open.push(formal);
+            }
+        }
+
+        for (pos, formal) in open_associations {
+            let mut diagnostic = Diagnostic::error(
+                pos,
+                format!(
+                    "{} has no default value and may not be associated with open",
+                    formal.describe()
+                ),
+            );
+            if let Some(decl_pos) = formal.decl_pos() {
+                diagnostic.add_related(decl_pos, "Defined here");
             }
+            diagnostics.push(diagnostic);
         }
 
         if not_associated.is_empty() && extra_associations.is_empty() && !missing {
+            for formal in left_open {
+                let mut diagnostic = Diagnostic::warning(
+                    error_pos,
+                    format!(
+                        "{} is left open since it has no association",
+                        formal.describe()
+                    ),
+                );
+                if let Some(decl_pos) = formal.decl_pos() {
+                    diagnostic.add_related(decl_pos, "Defined here");
+                }
+                diagnostics.push(diagnostic);
+            }
             Ok(result)
         } else {
             // Only complain if nothing else is wrong
@@ -342,14 +398,51 @@ impl<'a> AnalyzeContext<'a> {
                 .zip(elems.iter_mut().map(|assoc| &mut assoc.actual))
             {
                 match &mut actual.item {
+                    ActualPart::Expression(Expression::Name(name))
+                        if !is_read_only_formal(formal) =>
+                    {
+                        // The actual of a formal that is written is not just read
+                        if let Some(subtype) = formal.subtype() {
+                            self.name_with_subtype(
+                                scope,
+                                &subtype,
+                                &actual.pos,
+                                name,
+                                diagnostics,
+                            )?;
+                        } else {
+                            self.expression_name_with_ttyp(
+                                scope,
+                                &actual.pos,
+                                name,
+                                formal.type_mark(),
+                                diagnostics,
+                            )?;
+                        }
+                        self.check_actual_mode(formal, &actual.pos, name, diagnostics);
+                    }
                     ActualPart::Expression(expr) => {
-                        self.expr_pos_with_ttyp(
-                            scope,
-                            formal.type_mark(),
-                            &actual.pos,
-                            expr,
-                            diagnostics,
-                        )?;
+                        if let Some(subtype) = formal.subtype() {
+                            self.expr_pos_with_subtype(
+                                scope,
+                                &subtype,
+                                &actual.pos,
+                                expr,
+                                diagnostics,
+                            )?;
+                        } else {
+                            self.expr_pos_with_ttyp(
+                                scope,
+                                formal.type_mark(),
+                                &actual.pos,
+                                expr,
+                                diagnostics,
+                            )?;
+                        }
+
+                        if let Expression::Name(name) = expr {
+                            self.check_actual_mode(formal, &actual.pos, name, diagnostics);
+                        }
                     }
                     ActualPart::Open => {}
                 }
@@ -359,6 +452,102 @@ impl<'a> AnalyzeContext<'a> {
     }
 }
 
+impl<'a> AnalyzeContext<'a> {
+    /// Check that the mode of a port or signal parameter used as an actual
+    /// is compatible with the mode of a signal formal
+    fn check_actual_mode(
+        &self,
+        formal: &ResolvedFormal<'a>,
+        actual_pos: &SrcPos,
+        actual: &Name,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let formal = if let Some(formal) = ObjectEnt::from_any(formal.interface().inner()) {
+            formal
+        } else {
+            return;
+        };
+
+        let formal_mode = match formal.mode() {
+            Some(mode) if formal.class() == ObjectClass::Signal => mode,
+            _ => return,
+        };
+
+        let actual = if let Some(actual) = self.object_of_resolved_name(actual) {
+            actual
+        } else {
+            return;
+        };
+
+        // Only interface objects such as ports have a mode, other signals may be associated freely
+        if let Some(actual_mode) = actual.mode() {
+            if !is_compatible_actual_mode(formal_mode, actual_mode, self.standard) {
+                diagnostics.push(
+                    Diagnostic::error(
+                        actual_pos,
+                        format!(
+                            "{} cannot be associated with formal {}",
+                            actual.describe(),
+                            formal.describe()
+                        ),
+                    )
+                    .opt_related(formal.decl_pos(), "Defined here"),
+                );
+            }
+        }
+    }
+}
+
+/// LRM 6.5.6.3 Port clauses
+/// The modes of ports that may be associated as an actual with a formal of a given mode
+/// The same rules apply to signal parameters of subprograms
+/// The modes of the elements of a mode view are not checked
+/// Reading ports of mode out is allowed since VHDL-2008
+/// Ports of mode buffer may be associated with other modes since VHDL-2002
+pub(super) fn is_compatible_actual_mode(
+    formal: Mode,
+    actual: Mode,
+    standard: VHDLStandard,
+) -> bool {
+    if actual == Mode::View {
+        return true;
+    }
+    match formal {
+        Mode::In if standard >= VHDLStandard::VHDL2008 => {
+            matches!(actual, Mode::In | Mode::Out | Mode::InOut | Mode::Buffer)
+        }
+        Mode::In => matches!(actual, Mode::In | Mode::InOut | Mode::Buffer),
+        Mode::Out if standard >= VHDLStandard::VHDL2002 => {
+            matches!(actual, Mode::Out | Mode::InOut | Mode::Buffer)
+        }
+        Mode::Out => matches!(actual, Mode::Out | Mode::InOut),
+        Mode::InOut if standard >= VHDLStandard::VHDL2002 => {
+            matches!(actual, Mode::InOut | Mode::Buffer)
+        }
+        Mode::InOut => actual == Mode::InOut,
+        Mode::Buffer if standard >= VHDLStandard::VHDL2002 => {
+            matches!(actual, Mode::Out | Mode::InOut | Mode::Buffer)
+        }
+        Mode::Buffer => actual == Mode::Buffer,
+        Mode::Linkage | Mode::View => true,
+    }
+}
+
+/// A formal of mode in only reads the value of its actual
+fn is_read_only_formal(formal: &ResolvedFormal) -> bool {
+    match ObjectEnt::from_any(formal.interface().inner()) {
+        Some(formal) => matches!(formal.mode(), None | Some(Mode::In)),
+        None => true,
+    }
+}
+
+/// LRM 6.5.6.3 A formal without a default value may only be left unassociated or associated
+/// with open when it is a port of a mode other than in
+pub fn may_be_left_open(formal_region: &FormalRegion, formal: InterfaceEnt) -> bool {
+    formal.has_default()
+        || (formal_region.typ == InterfaceType::Port && formal.is_out_or_inout_signal())
+}
+
 fn to_formal_conversion_argument(
     parameters: &mut [AssociationElement],
 ) -> Option<(&SrcPos, &mut WithRef<Designator>)> {
@@ -0,0 +1,622 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Binding of component instances to design entities
+//!
+//! LRM 7.3.3 Default binding indication
+//! A component that is not configured is bound to the entity with the same simple name
+//! and its generics and ports are associated by name with those of the entity.
+
+use super::analyze::*;
+use super::association::{is_compatible_actual_mode, may_be_left_open};
+use super::formal_region::FormalRegion;
+use super::named_entity::*;
+use super::region::*;
+use crate::ast::*;
+use crate::data::*;
+use crate::standard::VHDLStandard;
+
+impl<'a> AnalyzeContext<'a> {
+    /// LRM 7.3 Configuration specification
+    pub fn analyze_configuration_specification(
+        &self,
+        scope: &Scope<'a>,
+        spec: &mut ConfigurationSpecification,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let component = match self.resolve_component_name(scope, &mut spec.spec.component_name) {
+            Ok(component) => component,
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                return Ok(());
+            }
+        };
+
+        self.resolve_instantiation_list(scope, &mut spec.spec.instantiation_list, diagnostics)?;
+        self.analyze_binding_indication(
+            scope,
+            component,
+            &spec.spec.component_name.pos,
+            &mut spec.bind_ind,
+            diagnostics,
+        )?;
+        Ok(())
+    }
+
+    /// LRM 7.3.2 Binding indication
+    /// Returns the entity when the entity aspect denotes one
+    pub fn analyze_binding_indication(
+        &self,
+        scope: &Scope<'a>,
+        component: EntRef<'a>,
+        pos: &SrcPos,
+        bind_ind: &mut BindingIndication,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<DesignEnt<'a>>> {
+        let entity = if let Some(ref mut entity_aspect) = bind_ind.entity_aspect {
+            self.resolve_entity_aspect(scope, entity_aspect, diagnostics)?
+        } else {
+            None
+        };
+
+        let component_region = if let AnyEntKind::Component(region) = component.kind() {
+            region
+        } else {
+            return Ok(entity);
+        };
+
+        // The actuals of the map aspects are the local generics and ports of the component
+        let local_scope = scope.nested();
+        local_scope.make_all_potentially_visible(None, component_region);
+
+        let bound = entity.and_then(|entity| {
+            let ent: EntRef<'a> = entity.into();
+            if let AnyEntKind::Design(Design::Entity(_, region)) = ent.kind() {
+                Some((ent, region))
+            } else {
+                None
+            }
+        });
+
+        let (entity_ent, entity_region) = if let Some(bound) = bound {
+            bound
+        } else {
+            if let Some(ref mut list) = bind_ind.generic_map {
+                self.analyze_assoc_elems(&local_scope, list, diagnostics)?;
+            }
+            if let Some(ref mut list) = bind_ind.port_map {
+                self.analyze_assoc_elems(&local_scope, list, diagnostics)?;
+            }
+            return Ok(entity);
+        };
+
+        let (component_generics, component_ports) = component_region.to_entity_formal();
+        let (entity_generics, entity_ports) = entity_region.to_entity_formal();
+
+        let binding = Binding {
+            component,
+            entity: entity_ent,
+            pos,
+            standard: self.standard,
+        };
+
+        // Explicit map aspects replace the association by name
+        if let Some(ref mut list) = bind_ind.generic_map {
+            self.analyze_assoc_elems_with_formal_region(
+                pos,
+                &entity_generics,
+                &local_scope,
+                list,
+                diagnostics,
+            )?;
+        } else {
+            binding.check(&component_generics, &entity_generics, diagnostics);
+        }
+
+        if let Some(ref mut list) = bind_ind.port_map {
+            self.analyze_assoc_elems_with_formal_region(
+                pos,
+                &entity_ports,
+                &local_scope,
+                list,
+                diagnostics,
+            )?;
+        } else {
+            binding.check(&component_ports, &entity_ports, diagnostics);
+        }
+
+        Ok(entity)
+    }
+
+    /// Resolve the instance labels of a component specification
+    pub fn resolve_instantiation_list(
+        &self,
+        scope: &Scope<'a>,
+        instantiation_list: &mut InstantiationList,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        if let InstantiationList::Labels(ref mut labels) = instantiation_list {
+            for label in labels.iter_mut() {
+                let designator = Designator::Identifier(label.item.item.clone());
+                match scope
+                    .lookup(&label.item.pos, &designator)
+                    .map_err(AnalysisError::NotFatal)
+                    .and_then(|entities| {
+                        self.resolve_non_overloaded_with_kind(
+                            entities,
+                            &label.item.pos,
+                            &|kind| {
+                                matches!(kind, AnyEntKind::Concurrent(Some(Concurrent::Instance)))
+                            },
+                            "instance",
+                        )
+                    }) {
+                    Ok(ent) => label.set_unique_reference(ent),
+                    Err(err) => err.add_to(diagnostics)?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub fn resolve_component_name(
+        &self,
+        scope: &Scope<'a>,
+        component_name: &mut WithPos<SelectedName>,
+    ) -> AnalysisResult<EntRef<'a>> {
+        let entities = self.resolve_selected_name(scope, component_name)?;
+        let expected = "component";
+        let ent = self.resolve_non_overloaded(entities, component_name.suffix_pos(), expected)?;
+
+        if let AnyEntKind::Component(_) = ent.kind() {
+            Ok(ent)
+        } else {
+            Err(AnalysisError::NotFatal(
+                ent.kind_error(component_name.suffix_pos(), expected),
+            ))
+        }
+    }
+
+    /// Resolve the entity aspect of a binding indication
+    /// Returns the entity when the aspect denotes one
+    pub fn resolve_entity_aspect(
+        &self,
+        scope: &Scope<'a>,
+        entity_aspect: &mut EntityAspect,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<DesignEnt<'a>>> {
+        match entity_aspect {
+            EntityAspect::Entity(ref mut entity_name, ref mut architecture_name) => {
+                let entities = match self.resolve_selected_name(scope, entity_name) {
+                    Ok(entities) => entities,
+                    Err(err) => {
+                        err.add_to(diagnostics)?;
+                        return Ok(None);
+                    }
+                };
+
+                let expected = "entity";
+                let ent =
+                    match self.resolve_non_overloaded(entities, entity_name.suffix_pos(), expected)
+                    {
+                        Ok(ent) => ent,
+                        Err(err) => {
+                            err.add_to(diagnostics)?;
+                            return Ok(None);
+                        }
+                    };
+
+                let entity = match DesignEnt::from_any(ent) {
+                    Some(design) if matches!(design.kind(), Design::Entity(..)) => design,
+                    _ => {
+                        diagnostics.push(ent.kind_error(entity_name.suffix_pos(), expected));
+                        return Ok(None);
+                    }
+                };
+
+                if let Some(ref mut architecture_name) = architecture_name {
+                    if let (Designator::Identifier(entity_ident), Some(library_name)) =
+                        (entity.designator(), entity.library_name())
+                    {
+                        match self.get_architecture(
+                            library_name,
+                            &architecture_name.item.pos,
+                            entity_ident,
+                            &architecture_name.item.item,
+                        ) {
+                            Ok(arch) => architecture_name.set_unique_reference(&arch),
+                            Err(err) => err.add_to(diagnostics)?,
+                        }
+                    }
+                }
+
+                Ok(Some(entity))
+            }
+            EntityAspect::Configuration(ref mut config_name) => {
+                if let Err(err) =
+                    self.resolve_selected_name(scope, config_name)
+                        .and_then(|entities| {
+                            self.resolve_non_overloaded_with_kind(
+                                entities,
+                                config_name.suffix_pos(),
+                                &|kind| matches!(kind, AnyEntKind::Design(Design::Configuration)),
+                                "configuration",
+                            )
+                        })
+                {
+                    err.add_to(diagnostics)?;
+                }
+                Ok(None)
+            }
+            EntityAspect::Open => Ok(None),
+        }
+    }
+
+    /// Check the default binding of the component instances among the concurrent statements
+    /// Instances that are configured by a configuration specification in the same
+    /// declarative part are not default bound
+    /// The instances of an architecture that is configured by a configuration declaration
+    /// are checked when analyzing the configuration
+    pub fn check_default_bindings(
+        &self,
+        scope: &Scope<'a>,
+        decl: &[Declaration],
+        statements: &[LabeledConcurrentStatement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        for decl in decl.iter() {
+            if let Declaration::Configuration(ref spec) = decl {
+                self.check_instantiation_list(&spec.spec, statements.iter(), diagnostics);
+            }
+        }
+
+        let has_component_instance = statements.iter().any(|statement| {
+            matches!(
+                statement.statement.item,
+                ConcurrentStatement::Instance(InstantiationStatement {
+                    unit: InstantiatedUnit::Component(..),
+                    ..
+                })
+            )
+        });
+
+        if !has_component_instance || self.is_configured_architecture() {
+            return Ok(());
+        }
+
+        for statement in statements.iter() {
+            self.check_default_binding(Some(scope), decl.iter(), statement, diagnostics)?;
+        }
+        Ok(())
+    }
+
+    /// Check the default binding of a component instance unless a configuration
+    /// specification among the declarations applies to it
+    /// The scope of the instance is not known when checking the instances of a configuration
+    pub fn check_default_binding<'d>(
+        &self,
+        scope: Option<&Scope<'a>>,
+        decl: impl IntoIterator<Item = &'d Declaration>,
+        statement: &LabeledConcurrentStatement,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let component_name =
+            if let ConcurrentStatement::Instance(ref instance) = statement.statement.item {
+                if let InstantiatedUnit::Component(ref component_name) = instance.unit {
+                    component_name
+                } else {
+                    return Ok(());
+                }
+            } else {
+                return Ok(());
+            };
+
+        let component = if let Some(id) = component_name.item.get_suffix_reference() {
+            self.arena.get(id)
+        } else {
+            return Ok(());
+        };
+
+        if configuration_of(decl, statement.label.tree.as_ref(), component).is_some() {
+            return Ok(());
+        }
+
+        if let Some(entity) = self.default_binding(
+            scope,
+            self.work_library_name(),
+            component,
+            &component_name.pos,
+        )? {
+            self.check_component_binding(component, entity, &component_name.pos, diagnostics);
+        }
+        Ok(())
+    }
+
+    /// Check that the labeled instances of a component specification instantiate the component
+    pub fn check_instantiation_list<'s>(
+        &self,
+        spec: &ComponentSpecification,
+        statements: impl Iterator<Item = &'s LabeledConcurrentStatement> + Clone,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let component = if let Some(id) = spec.component_name.item.get_suffix_reference() {
+            self.arena.get(id)
+        } else {
+            return;
+        };
+
+        let labels = if let InstantiationList::Labels(ref labels) = spec.instantiation_list {
+            labels
+        } else {
+            return;
+        };
+
+        for label in labels.iter() {
+            let (ent, statement) = if let Some(found) = label.reference.and_then(|id| {
+                statements
+                    .clone()
+                    .find(|statement| statement.label.decl == Some(id))
+                    .map(|statement| (self.arena.get(id), statement))
+            }) {
+                found
+            } else {
+                continue;
+            };
+
+            let instantiated =
+                if let ConcurrentStatement::Instance(ref instance) = statement.statement.item {
+                    if let InstantiatedUnit::Component(ref component_name) = instance.unit {
+                        component_name.item.get_suffix_reference()
+                    } else {
+                        None
+                    }
+                } else {
+                    None
+                };
+
+            if instantiated != Some(component.id()) {
+                diagnostics.push(
+                    Diagnostic::error(
+                        &label.item.pos,
+                        format!(
+                            "{} is not an instance of {}",
+                            ent.describe(),
+                            component.describe()
+                        ),
+                    )
+                    .opt_related(ent.decl_pos(), "Defined here"),
+                );
+            }
+        }
+    }
+
+    /// LRM 7.3.3 The entity of the default binding of a component instance is the entity
+    /// with the same simple name as the component that is visible at the instance,
+    /// otherwise it is the entity of that name in the library of the instantiating unit
+    pub fn default_binding(
+        &self,
+        scope: Option<&Scope<'a>>,
+        library_name: &Symbol,
+        component: EntRef<'a>,
+        pos: &SrcPos,
+    ) -> FatalResult<Option<DesignEnt<'a>>> {
+        let visible = scope
+            .into_iter()
+            .flat_map(|scope| scope.potentially_visible(component.designator()))
+            .filter_map(DesignEnt::from_any)
+            .find(|design| matches!(design.kind(), Design::Entity(..)));
+        if visible.is_some() {
+            return Ok(visible);
+        }
+
+        match self.lookup_in_library(library_name, pos, component.designator()) {
+            Ok(design) if matches!(design.kind(), Design::Entity(..)) => Ok(Some(design)),
+            Ok(_) | Err(AnalysisError::NotFatal(_)) => Ok(None),
+            Err(AnalysisError::Fatal(err)) => Err(err),
+        }
+    }
+
+    /// Check that the generics and ports of a component match those of the entity it is bound to
+    pub fn check_component_binding(
+        &self,
+        component: EntRef<'a>,
+        entity: DesignEnt<'a>,
+        pos: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let component_region = if let AnyEntKind::Component(region) = component.kind() {
+            region
+        } else {
+            return;
+        };
+
+        let entity_region = if let Design::Entity(_, region) = entity.kind() {
+            region
+        } else {
+            return;
+        };
+
+        let (component_generics, component_ports) = component_region.to_entity_formal();
+        let (entity_generics, entity_ports) = entity_region.to_entity_formal();
+
+        let binding = Binding {
+            component,
+            entity: entity.into(),
+            pos,
+            standard: self.standard,
+        };
+        binding.check(&component_generics, &entity_generics, diagnostics);
+        binding.check(&component_ports, &entity_ports, diagnostics);
+    }
+}
+
+struct Binding<'p, 'a> {
+    component: EntRef<'a>,
+    entity: EntRef<'a>,
+    pos: &'p SrcPos,
+    standard: VHDLStandard,
+}
+
+impl<'p, 'a> Binding<'p, 'a> {
+    fn check(
+        &self,
+        component_formals: &FormalRegion<'a>,
+        entity_formals: &FormalRegion<'a>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        for local in component_formals.iter() {
+            let formal =
+                if let Ok((_, formal)) = entity_formals.lookup(self.pos, local.designator()) {
+                    formal
+                } else {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            self.pos,
+                            format!(
+                                "{} of {} has no match in {}",
+                                local.describe(),
+                                self.component.describe(),
+                                self.entity.describe()
+                            ),
+                        )
+                        .opt_related(local.decl_pos(), "Defined here")
+                        .opt_related(
+                            self.entity.decl_pos(),
+                            format!("Found {}", self.entity.describe()),
+                        ),
+                    );
+                    continue;
+                };
+
+            let local_obj = ObjectEnt::from_any(local.inner());
+            let formal_obj = ObjectEnt::from_any(formal.inner());
+
+            // The local port is the actual of the port of the entity
+            if let (Some(local_obj), Some(formal_obj)) = (local_obj, formal_obj) {
+                if local_obj.class() == ObjectClass::Signal
+                    && !matches!(
+                        (formal_obj.mode(), local_obj.mode()),
+                        (Some(formal_mode), Some(local_mode))
+                            if is_compatible_actual_mode(formal_mode, local_mode, self.standard)
+                    )
+                {
+                    diagnostics.push(self.mismatch(
+                        format!(
+                            "{} of {} has a different mode than {} of {}",
+                            local.describe(),
+                            self.component.describe(),
+                            formal.describe(),
+                            self.entity.describe()
+                        ),
+                        local.inner(),
+                        formal.inner(),
+                    ));
+                    continue;
+                }
+            }
+
+            let is_interface_type =
+                |typ: TypeEnt| matches!(typ.base_type().kind(), Type::Interface);
+            if local.base() != formal.base()
+                && !is_interface_type(local.type_mark())
+                && !is_interface_type(formal.type_mark())
+            {
+                diagnostics.push(self.mismatch(
+                    format!(
+                        "{} of {} has {} but {} of {} has {}",
+                        local.describe(),
+                        self.component.describe(),
+                        local.base_type().describe(),
+                        formal.describe(),
+                        self.entity.describe(),
+                        formal.base_type().describe()
+                    ),
+                    local.inner(),
+                    formal.inner(),
+                ));
+            }
+        }
+
+        for formal in entity_formals.iter() {
+            if component_formals
+                .lookup(self.pos, formal.designator())
+                .is_ok()
+            {
+                continue;
+            }
+
+            let message = format!(
+                "{} of {} has no match in {}",
+                formal.describe(),
+                self.entity.describe(),
+                self.component.describe()
+            );
+
+            let diagnostic = if may_be_left_open(entity_formals, formal) {
+                if formal.has_default() {
+                    continue;
+                }
+                Diagnostic::warning(self.pos, format!("{message} and is left open"))
+            } else {
+                Diagnostic::error(self.pos, message)
+            };
+
+            diagnostics.push(
+                diagnostic
+                    .opt_related(formal.decl_pos(), "Defined here")
+                    .opt_related(
+                        self.component.decl_pos(),
+                        format!("Found {}", self.component.describe()),
+                    ),
+            );
+        }
+    }
+
+    fn mismatch(&self, message: String, local: EntRef<'a>, formal: EntRef<'a>) -> Diagnostic {
+        Diagnostic::error(self.pos, message)
+            .opt_related(
+                local.decl_pos(),
+                format!("Defined in {}", self.component.describe()),
+            )
+            .opt_related(
+                formal.decl_pos(),
+                format!("Defined in {}", self.entity.describe()),
+            )
+    }
+}
+
+/// The configuration specification that applies to the instance if any
+pub(super) fn configuration_of<'d>(
+    decl: impl IntoIterator<Item = &'d Declaration>,
+    label: Option<&Ident>,
+    component: EntRef,
+) -> Option<&'d ConfigurationSpecification> {
+    decl.into_iter().find_map(|decl| {
+        if let Declaration::Configuration(ref spec) = decl {
+            if is_specified(&spec.spec, label, component) {
+                return Some(spec);
+            }
+        }
+        None
+    })
+}
+
+/// Returns true if a component specification applies to the instance
+pub(super) fn is_specified(
+    spec: &ComponentSpecification,
+    label: Option<&Ident>,
+    component: EntRef,
+) -> bool {
+    if spec.component_name.item.get_suffix_reference() != Some(component.id()) {
+        return false;
+    }
+    match spec.instantiation_list {
+        InstantiationList::Labels(ref labels) => label
+            .map(|label| labels.iter().any(|ident| ident.item.item == label.item))
+            .unwrap_or(false),
+        InstantiationList::Others | InstantiationList::All => true,
+    }
+}
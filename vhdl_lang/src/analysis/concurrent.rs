@@ -53,6 +53,12 @@ impl<'a> AnalyzeContext<'a> {
                 );
                 statement.label.decl = Some(ent.id());
                 scope.add(ent, diagnostics);
+
+                if let ConcurrentStatement::Instance(ref instance) = statement.statement.item {
+                    self.instantiated_units
+                        .borrow_mut()
+                        .insert(ent.id(), instance.unit.clone());
+                }
             } else if statement.statement.item.can_have_label() {
                 // Generate an anonymous label if it is not explicitly defined
                 let ent = self.arena.alloc(
@@ -69,6 +75,32 @@ impl<'a> AnalyzeContext<'a> {
         Ok(())
     }
 
+    /// A guarded assignment is controlled by the signal GUARD which must be visible
+    fn check_guard_signal(
+        &self,
+        scope: &Scope<'a>,
+        pos: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let designator = Designator::Identifier(self.root.symbol_utf8("GUARD"));
+        let is_guard_signal = match scope.lookup(pos, &designator) {
+            Ok(NamedEntities::Single(ent)) => ObjectEnt::from_any(ent)
+                .map(|obj| {
+                    obj.class() == ObjectClass::Signal
+                        && obj.type_mark().base() == self.boolean().base()
+                })
+                .unwrap_or(false),
+            _ => false,
+        };
+
+        if !is_guard_signal {
+            diagnostics.error(
+                pos,
+                "Guarded assignment requires a boolean signal 'GUARD' to be visible",
+            );
+        }
+    }
+
     fn analyze_concurrent_statement(
         &self,
         scope: &Scope<'a>,
@@ -78,10 +110,27 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match statement.statement.item {
             ConcurrentStatement::Block(ref mut block) => {
+                let nested = scope.nested().in_concurrent_region();
                 if let Some(ref mut guard_condition) = block.guard_condition {
                     self.boolean_expr(scope, guard_condition, diagnostics)?;
+
+                    // A guarded block implicitly declares the boolean signal GUARD,
+                    // the parent is the label of the block
+                    let guard = self.arena.explicit(
+                        self.root.symbol_utf8("GUARD"),
+                        parent,
+                        AnyEntKind::Object(Object {
+                            class: ObjectClass::Signal,
+                            iface: None,
+                            subtype: Subtype::new(self.boolean()),
+                            has_default: true,
+                            signal_kind: None,
+                            static_value: None,
+                        }),
+                        Some(&guard_condition.pos),
+                    );
+                    nested.add(guard, diagnostics);
                 }
-                let nested = scope.nested();
                 if let Some(ref mut list) = block.header.generic_clause {
                     self.analyze_interface_list(&nested, parent, list, diagnostics)?;
                 }
@@ -96,13 +145,14 @@ impl<'a> AnalyzeContext<'a> {
                 }
 
                 self.define_labels_for_concurrent_part(
-                    scope,
+                    &nested,
                     parent,
                     &mut block.statements,
                     diagnostics,
                 )?;
                 self.analyze_declarative_part(&nested, parent, &mut block.decl, diagnostics)?;
                 self.analyze_concurrent_part(&nested, parent, &mut block.statements, diagnostics)?;
+                self.check_default_bindings(&nested, &block.decl, &block.statements, diagnostics)?;
             }
             ConcurrentStatement::Process(ref mut process) => {
                 let ProcessStatement {
@@ -124,6 +174,14 @@ impl<'a> AnalyzeContext<'a> {
                 self.define_labels_for_sequential_part(scope, parent, statements, diagnostics)?;
                 self.analyze_declarative_part(&nested, parent, decl, diagnostics)?;
                 self.analyze_sequential_part(&nested, parent, statements, diagnostics)?;
+                self.check_inferred_latches(parent, process, diagnostics);
+                self.check_process_dataflow(parent, process, diagnostics);
+                let pos = if let Some(ref label) = statement.label.tree {
+                    &label.pos
+                } else {
+                    &statement.statement.pos
+                };
+                self.check_process_wait_statements(pos, process, diagnostics);
             }
             ConcurrentStatement::ForGenerate(ref mut gen) => {
                 let ForGenerateStatement {
@@ -133,7 +191,7 @@ impl<'a> AnalyzeContext<'a> {
                     end_label_pos: _,
                 } = gen;
                 let typ = as_fatal(self.drange_type(scope, discrete_range, diagnostics))?;
-                let nested = scope.nested();
+                let nested = scope.nested().in_concurrent_region();
                 nested.add(
                     index_name.define(self.arena, parent, AnyEntKind::LoopParameter(typ)),
                     diagnostics,
@@ -148,17 +206,17 @@ impl<'a> AnalyzeContext<'a> {
                 for conditional in conditionals.iter_mut() {
                     let Conditional { condition, item } = conditional;
                     self.boolean_expr(scope, condition, diagnostics)?;
-                    let nested = scope.nested();
+                    let nested = scope.nested().in_concurrent_region();
                     self.analyze_generate_body(&nested, parent, item, diagnostics)?;
                 }
                 if let Some(ref mut else_item) = else_item {
-                    let nested = scope.nested();
+                    let nested = scope.nested().in_concurrent_region();
                     self.analyze_generate_body(&nested, parent, else_item, diagnostics)?;
                 }
             }
             ConcurrentStatement::CaseGenerate(ref mut gen) => {
                 for alternative in gen.sels.alternatives.iter_mut() {
-                    let nested = scope.nested();
+                    let nested = scope.nested().in_concurrent_region();
                     self.analyze_generate_body(
                         &nested,
                         parent,
@@ -172,7 +230,15 @@ impl<'a> AnalyzeContext<'a> {
             }
             ConcurrentStatement::Assignment(ref mut assign) => {
                 // @TODO more delaymechanism
-                let ConcurrentSignalAssignment { target, rhs, .. } = assign;
+                let ConcurrentSignalAssignment {
+                    guarded,
+                    target,
+                    rhs,
+                    ..
+                } = assign;
+                if *guarded {
+                    self.check_guard_signal(scope, &target.pos, diagnostics);
+                }
                 self.analyze_waveform_assignment(
                     scope,
                     target,
@@ -203,6 +269,16 @@ impl<'a> AnalyzeContext<'a> {
                     self.expr_with_ttyp(scope, self.severity_level(), expr, diagnostics)?;
                 }
             }
+            ConcurrentStatement::Psl(ref mut directive) => {
+                self.analyze_psl_directive(scope, directive, diagnostics)?;
+            }
+            ConcurrentStatement::PslProperty(ref mut property) => {
+                let ent = self.analyze_psl_property(scope, parent, property, diagnostics)?;
+                scope.add(ent, diagnostics);
+            }
+            ConcurrentStatement::PslDefaultClock(ref mut clock) => {
+                self.analyze_psl_expression(scope, clock, diagnostics)?;
+            }
         };
         Ok(())
     }
@@ -239,6 +315,12 @@ impl<'a> AnalyzeContext<'a> {
             self.analyze_declarative_part(scope, parent, decl, diagnostics)?;
         }
         self.analyze_concurrent_part(scope, inner_parent, statements, diagnostics)?;
+        self.check_default_bindings(
+            scope,
+            decl.as_deref().unwrap_or_default(),
+            statements,
+            diagnostics,
+        )?;
 
         Ok(())
     }
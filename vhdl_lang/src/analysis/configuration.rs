@@ -0,0 +1,424 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! LRM 3.4 Configuration declarations
+//!
+//! The block configurations are analyzed within the declarative region of the
+//! configured architecture so that instance labels and component names
+//! are resolved against the architecture.
+
+use super::analyze::*;
+use super::binding::is_specified;
+use super::named_entity::*;
+use super::region::*;
+use crate::ast::*;
+use crate::data::*;
+use crate::NullDiagnostics;
+use std::ops::Deref;
+
+/// The declarations and statements of a block of an analyzed architecture
+#[derive(Default)]
+pub(super) struct ConfiguredBlock<'s> {
+    pub(super) decl: Vec<&'s Declaration>,
+    pub(super) statements: Vec<&'s LabeledConcurrentStatement>,
+}
+
+impl<'s> ConfiguredBlock<'s> {
+    pub(super) fn new(
+        decl: impl IntoIterator<Item = &'s Declaration>,
+        statements: impl IntoIterator<Item = &'s LabeledConcurrentStatement>,
+    ) -> Self {
+        Self {
+            decl: decl.into_iter().collect(),
+            statements: statements.into_iter().collect(),
+        }
+    }
+
+    /// The alternatives of if and case generate statements are configured together
+    fn add_generate_body(&mut self, body: &'s GenerateBody) {
+        if let Some(ref decl) = body.decl {
+            self.decl.extend(decl.iter());
+        }
+        self.statements.extend(body.statements.iter());
+    }
+
+    pub(super) fn of_statement(statement: &'s ConcurrentStatement) -> Self {
+        let mut block = Self::default();
+        match statement {
+            ConcurrentStatement::Block(ref block_statement) => {
+                block.decl.extend(block_statement.decl.iter());
+                block.statements.extend(block_statement.statements.iter());
+            }
+            ConcurrentStatement::ForGenerate(ref gen) => block.add_generate_body(&gen.body),
+            ConcurrentStatement::IfGenerate(ref gen) => {
+                for conditional in gen.conds.conditionals.iter() {
+                    block.add_generate_body(&conditional.item);
+                }
+                if let Some(ref else_item) = gen.conds.else_item {
+                    block.add_generate_body(else_item);
+                }
+            }
+            ConcurrentStatement::CaseGenerate(ref gen) => {
+                for alternative in gen.sels.alternatives.iter() {
+                    block.add_generate_body(&alternative.item);
+                }
+            }
+            _ => {}
+        }
+        block
+    }
+
+    pub(super) fn find(&self, id: EntityId) -> Option<&'s LabeledConcurrentStatement> {
+        self.statements
+            .iter()
+            .find(|statement| statement.label.decl == Some(id))
+            .copied()
+    }
+}
+
+impl<'a> AnalyzeContext<'a> {
+    pub fn analyze_configuration_body(
+        &self,
+        scope: &Scope<'a>,
+        entity: DesignEnt<'a>,
+        unit: &mut ConfigurationDeclaration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        for item in unit.decl.iter_mut() {
+            match item {
+                ConfigurationDeclarativeItem::Use(ref mut use_clause) => {
+                    self.analyze_use_clause(scope, &mut use_clause.item, diagnostics)?;
+                }
+            }
+        }
+
+        self.analyze_architecture_configuration(scope, entity, &mut unit.block_config, diagnostics)
+    }
+
+    /// The block configuration of an architecture of the entity
+    fn analyze_architecture_configuration(
+        &self,
+        scope: &Scope<'a>,
+        entity: DesignEnt<'a>,
+        block_config: &mut BlockConfiguration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let block_spec = &mut block_config.block_spec;
+        let designator = if let Name::Designator(ref mut designator) = block_spec.item {
+            designator
+        } else {
+            diagnostics.error(
+                &block_spec.pos,
+                format!(
+                    "Expected the name of an architecture of {}",
+                    entity.describe()
+                ),
+            );
+            return Ok(());
+        };
+
+        let (library_name, entity_ident, architecture_ident) =
+            match (entity.library_name(), entity.designator(), &designator.item) {
+                (
+                    Some(library_name),
+                    Designator::Identifier(entity_ident),
+                    Designator::Identifier(architecture_ident),
+                ) => (library_name, entity_ident, architecture_ident),
+                _ => return Ok(()),
+            };
+
+        let (arch, data) = match self.get_architecture_analysis(
+            library_name,
+            &block_spec.pos,
+            entity_ident,
+            architecture_ident,
+        ) {
+            Ok(found) => found,
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                return Ok(());
+            }
+        };
+        designator.set_unique_reference(&arch);
+
+        let body = if let AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(ref body)) =
+            data.deref()
+        {
+            body
+        } else {
+            return Ok(());
+        };
+
+        let (visibility, region) =
+            if let Design::Architecture(ref visibility, ref region, _) = arch.kind() {
+                (visibility, region)
+            } else {
+                return Ok(());
+            };
+
+        let root_scope = Scope::new(Region::with_visibility(visibility.clone())).with_parent(scope);
+        let arch_scope = Scope::extend(region, Some(&root_scope));
+        let block = ConfiguredBlock::new(body.decl.iter(), body.statements.iter());
+
+        self.analyze_block_configuration_items(&arch_scope, &block, block_config, diagnostics)
+    }
+
+    fn analyze_block_configuration_items(
+        &self,
+        scope: &Scope<'a>,
+        block: &ConfiguredBlock,
+        block_config: &mut BlockConfiguration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        for use_clause in block_config.use_clauses.iter_mut() {
+            self.analyze_use_clause(scope, use_clause, diagnostics)?;
+        }
+
+        for item in block_config.items.iter_mut() {
+            match item {
+                ConfigurationItem::Block(ref mut nested) => {
+                    self.analyze_nested_block_configuration(scope, block, nested, diagnostics)?;
+                }
+                ConfigurationItem::Component(ref mut component_config) => {
+                    self.analyze_component_configuration(
+                        scope,
+                        block,
+                        component_config,
+                        diagnostics,
+                    )?;
+                }
+            }
+        }
+
+        self.check_unconfigured_instances(block, &block_config.items, diagnostics)
+    }
+
+    /// The component instances of a block that are not configured by the block configuration
+    /// are default bound
+    fn check_unconfigured_instances(
+        &self,
+        block: &ConfiguredBlock,
+        items: &[ConfigurationItem],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        for statement in block.statements.iter() {
+            match statement.statement.item {
+                ConcurrentStatement::Instance(ref instance) => {
+                    let component =
+                        if let InstantiatedUnit::Component(ref component_name) = instance.unit {
+                            component_name.item.get_suffix_reference()
+                        } else {
+                            None
+                        };
+
+                    let is_configured = items.iter().any(|item| match (item, component) {
+                        (ConfigurationItem::Component(ref config), Some(id)) => is_specified(
+                            &config.spec,
+                            statement.label.tree.as_ref(),
+                            self.arena.get(id),
+                        ),
+                        _ => false,
+                    });
+
+                    if !is_configured {
+                        self.check_default_binding(
+                            None,
+                            block.decl.iter().copied(),
+                            statement,
+                            diagnostics,
+                        )?;
+                    }
+                }
+                ConcurrentStatement::Block(_)
+                | ConcurrentStatement::ForGenerate(_)
+                | ConcurrentStatement::IfGenerate(_)
+                | ConcurrentStatement::CaseGenerate(_) => {
+                    let is_configured = items.iter().any(|item| match item {
+                        ConfigurationItem::Block(ref nested) => {
+                            statement.label.decl.is_some()
+                                && block_spec_reference(&nested.block_spec) == statement.label.decl
+                        }
+                        ConfigurationItem::Component(_) => false,
+                    });
+
+                    if !is_configured {
+                        let inner = ConfiguredBlock::of_statement(&statement.statement.item);
+                        self.check_unconfigured_instances(&inner, &[], diagnostics)?;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// The block configuration of a block statement or generate statement
+    fn analyze_nested_block_configuration(
+        &self,
+        scope: &Scope<'a>,
+        block: &ConfiguredBlock,
+        block_config: &mut BlockConfiguration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let (pos, label) = if let Some(spec) = block_spec_label(&mut block_config.block_spec) {
+            spec
+        } else {
+            diagnostics.error(
+                &block_config.block_spec.pos,
+                "Expected the label of a block or generate statement",
+            );
+            return Ok(());
+        };
+
+        let ent = match scope
+            .lookup(pos, label.designator())
+            .map_err(AnalysisError::NotFatal)
+            .and_then(|entities| {
+                self.resolve_non_overloaded_with_kind(
+                    entities,
+                    pos,
+                    &|kind| {
+                        matches!(
+                            kind,
+                            AnyEntKind::Concurrent(Some(Concurrent::Block | Concurrent::Generate))
+                        )
+                    },
+                    "block or generate statement",
+                )
+            }) {
+            Ok(ent) => ent,
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                return Ok(());
+            }
+        };
+        label.set_unique_reference(ent);
+
+        let inner = if let Some(statement) = block.find(ent.id()) {
+            ConfiguredBlock::of_statement(&statement.statement.item)
+        } else {
+            return Ok(());
+        };
+
+        // The declarations of the inner block are only known to the AST of the architecture
+        let nested = scope.nested();
+        for decl in inner.decl.iter() {
+            if let Declaration::Component(ref component) = decl {
+                if let Some(id) = component.ident.decl {
+                    declare_once(&nested, self.arena.get(id));
+                }
+            }
+        }
+        for statement in inner.statements.iter() {
+            if let (Some(_), Some(id)) = (&statement.label.tree, statement.label.decl) {
+                declare_once(&nested, self.arena.get(id));
+            }
+        }
+
+        self.analyze_block_configuration_items(&nested, &inner, block_config, diagnostics)
+    }
+
+    /// LRM 3.4.3 Component configuration
+    fn analyze_component_configuration(
+        &self,
+        scope: &Scope<'a>,
+        block: &ConfiguredBlock,
+        component_config: &mut ComponentConfiguration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let spec = &mut component_config.spec;
+        let component = match self.resolve_component_name(scope, &mut spec.component_name) {
+            Ok(component) => component,
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                return Ok(());
+            }
+        };
+
+        self.resolve_instantiation_list(scope, &mut spec.instantiation_list, diagnostics)?;
+        self.check_instantiation_list(spec, block.statements.iter().copied(), diagnostics);
+
+        let entity = if let Some(ref mut bind_ind) = component_config.bind_ind {
+            self.analyze_binding_indication(
+                scope,
+                component,
+                &spec.component_name.pos,
+                bind_ind,
+                diagnostics,
+            )?
+        } else {
+            let entity = self.default_binding(
+                None,
+                self.work_library_name(),
+                component,
+                &spec.component_name.pos,
+            )?;
+            if let Some(entity) = entity {
+                self.check_component_binding(
+                    component,
+                    entity,
+                    &spec.component_name.pos,
+                    diagnostics,
+                );
+            }
+            entity
+        };
+
+        if let Some(ref mut block_config) = component_config.block_config {
+            if let Some(entity) = entity {
+                self.analyze_architecture_configuration(scope, entity, block_config, diagnostics)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The label of a block specification, the generate parameter or alternative label
+/// of a generate statement is not part of the label
+fn block_spec_label(name: &mut WithPos<Name>) -> Option<(&SrcPos, &mut WithRef<Designator>)> {
+    match name.item {
+        Name::Designator(ref mut designator) => Some((&name.pos, designator)),
+        Name::CallOrIndexed(ref mut call) => {
+            if let Name::Designator(ref mut designator) = call.name.item {
+                Some((&call.name.pos, designator))
+            } else {
+                None
+            }
+        }
+        Name::Slice(ref mut prefix, _) => {
+            if let Name::Designator(ref mut designator) = prefix.item {
+                Some((&prefix.pos, designator))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
+}
+
+/// The statement referenced by the label of a block specification
+fn block_spec_reference(name: &WithPos<Name>) -> Option<EntityId> {
+    match name.item {
+        Name::Designator(ref designator) => designator.reference,
+        Name::CallOrIndexed(ref call) => match call.name.item {
+            Name::Designator(ref designator) => designator.reference,
+            _ => None,
+        },
+        Name::Slice(ref prefix, _) => match prefix.item {
+            Name::Designator(ref designator) => designator.reference,
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The same label may be used within several alternatives of a generate statement
+fn declare_once<'a>(scope: &Scope<'a>, ent: EntRef<'a>) {
+    if scope.lookup_immediate(ent.designator()).is_none() {
+        scope.add(ent, &mut NullDiagnostics);
+    }
+}
@@ -0,0 +1,201 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Control flow checks of sequential statements
+//!
+//! A statement list terminates when control can never continue past its end,
+//! either due to a return statement, an infinite loop or a failing assertion.
+
+use super::analyze::*;
+use super::named_entity::*;
+use crate::ast::*;
+use crate::config::Lint;
+use crate::data::*;
+
+impl<'a> AnalyzeContext<'a> {
+    /// LRM 4.3 It is an error if the execution of a function completes without a return statement
+    pub fn check_missing_return(
+        &self,
+        function: OverloadedEnt<'a>,
+        body: &SubprogramBody,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::MissingReturn)
+            || function.return_type().is_none()
+            || self.terminates(&body.statements)
+        {
+            return;
+        }
+
+        diagnostics.push(Diagnostic::warning(
+            &body.specification.subpgm_designator().pos,
+            format!(
+                "Function '{}' can reach the end of its body without returning a value",
+                function.designator()
+            ),
+        ));
+    }
+
+    /// Warn about statements that follow a return, exit or next statement
+    pub fn check_unreachable_statements(
+        &self,
+        statements: &[LabeledSequentialStatement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::UnreachableCode) {
+            return;
+        }
+
+        for (statement, next) in statements.iter().zip(statements.iter().skip(1)) {
+            let is_jump = match statement.statement.item {
+                SequentialStatement::Return(ref ret) => ret.is_unconditional(),
+                SequentialStatement::Exit(ref exit_stmt) => exit_stmt.condition.is_none(),
+                SequentialStatement::Next(ref next_stmt) => next_stmt.condition.is_none(),
+                _ => false,
+            };
+
+            if is_jump {
+                diagnostics.push(
+                    Diagnostic::warning(&next.statement.pos, "Unreachable statement").related(
+                        &statement.statement.pos,
+                        "Control does not continue after this statement",
+                    ),
+                );
+                return;
+            }
+        }
+    }
+
+    /// Returns true if control can never reach the end of the statements
+    fn terminates(&self, statements: &[LabeledSequentialStatement]) -> bool {
+        statements
+            .iter()
+            .any(|statement| self.statement_terminates(&statement.statement.item))
+    }
+
+    fn statement_terminates(&self, statement: &SequentialStatement) -> bool {
+        match statement {
+            SequentialStatement::Return(ref ret) => ret.is_unconditional(),
+            SequentialStatement::Block(ref block) => self.terminates(&block.statements),
+            SequentialStatement::If(ref ifstmt) => {
+                if let Some(ref else_item) = ifstmt.conds.else_item {
+                    self.terminates(else_item)
+                        && ifstmt
+                            .conds
+                            .conditionals
+                            .iter()
+                            .all(|cond| self.terminates(&cond.item))
+                } else {
+                    false
+                }
+            }
+            // The choices of a case statement always cover all values of the expression
+            SequentialStatement::Case(ref case) => {
+                !case.alternatives.is_empty()
+                    && case
+                        .alternatives
+                        .iter()
+                        .all(|alternative| self.terminates(&alternative.item))
+            }
+            SequentialStatement::Loop(ref loop_stmt) => {
+                loop_stmt.iteration_scheme.is_none()
+                    && !has_escaping_exit(&loop_stmt.statements, &mut Vec::new())
+            }
+            SequentialStatement::Assert(ref assert_stmt) => {
+                self.is_literal(&assert_stmt.condition, self.boolean(), "false")
+                    && assert_stmt
+                        .severity
+                        .as_ref()
+                        .map(|severity| self.is_failure(severity))
+                        .unwrap_or(false)
+            }
+            SequentialStatement::Report(ref report_stmt) => report_stmt
+                .severity
+                .as_ref()
+                .map(|severity| self.is_failure(severity))
+                .unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    fn is_failure(&self, expr: &WithPos<Expression>) -> bool {
+        self.is_literal(expr, self.severity_level(), "failure")
+    }
+
+    /// Returns true if the expression is the enumeration literal of the type
+    fn is_literal(&self, expr: &WithPos<Expression>, typ: TypeEnt<'a>, literal: &str) -> bool {
+        let designator = if let Expression::Name(ref name) = expr.item {
+            if let Name::Designator(ref designator) = name.as_ref() {
+                designator
+            } else {
+                return false;
+            }
+        } else {
+            return false;
+        };
+
+        let ent = if let Some(id) = designator.reference {
+            self.arena.get(id)
+        } else {
+            return false;
+        };
+
+        if let AnyEntKind::Overloaded(Overloaded::EnumLiteral(ref signature)) = ent.kind() {
+            signature.return_type().map(|rtyp| rtyp.base()) == Some(typ.base())
+                && matches!(ent.designator(), Designator::Identifier(sym) if sym == &self.root.symbol_utf8(literal))
+        } else {
+            false
+        }
+    }
+}
+
+/// Returns true if any exit statement leaves the loop whose body contains the statements.
+/// The ids are the labels of the loops nested within the loop.
+fn has_escaping_exit(statements: &[LabeledSequentialStatement], inner: &mut Vec<EntityId>) -> bool {
+    statements
+        .iter()
+        .any(|statement| match statement.statement.item {
+            SequentialStatement::Exit(ref exit_stmt) => {
+                if let Some(ref label) = exit_stmt.loop_label {
+                    !label
+                        .reference
+                        .map(|id| inner.contains(&id))
+                        .unwrap_or(false)
+                } else {
+                    inner.is_empty()
+                }
+            }
+            SequentialStatement::If(ref ifstmt) => {
+                ifstmt
+                    .conds
+                    .conditionals
+                    .iter()
+                    .any(|cond| has_escaping_exit(&cond.item, inner))
+                    || ifstmt
+                        .conds
+                        .else_item
+                        .as_ref()
+                        .map(|else_item| has_escaping_exit(else_item, inner))
+                        .unwrap_or(false)
+            }
+            SequentialStatement::Case(ref case) => case
+                .alternatives
+                .iter()
+                .any(|alternative| has_escaping_exit(&alternative.item, inner)),
+            SequentialStatement::Block(ref block) => has_escaping_exit(&block.statements, inner),
+            SequentialStatement::Loop(ref loop_stmt) => {
+                if let Some(id) = statement.label.decl {
+                    inner.push(id);
+                    let escapes = has_escaping_exit(&loop_stmt.statements, inner);
+                    inner.pop();
+                    escapes
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        })
+}
@@ -0,0 +1,781 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Dataflow analysis of the local variables of a process or subprogram
+//!
+//! A control flow graph is built from the sequential statements where each node
+//! holds the reads and writes of local variables in execution order.
+//! A forward analysis finds variables that may be read before any assignment
+//! and a backward liveness analysis finds assignments whose value is always
+//! overwritten before it is read.
+
+use super::analyze::*;
+use super::named_entity::*;
+use super::purity::ReferenceCollector;
+use crate::ast::search::*;
+use crate::ast::Range;
+use crate::ast::*;
+use crate::config::Lint;
+use crate::data::*;
+use fnv::{FnvHashMap, FnvHashSet};
+
+type Variables = FnvHashSet<EntityId>;
+
+enum Access {
+    Read(SrcPos, EntityId),
+    // An assignment of the whole variable
+    Write(SrcPos, EntityId),
+    // An assignment of an element, a slice or a conditional assignment keeps the old value in part
+    PartialWrite(EntityId),
+}
+
+#[derive(Default)]
+struct Node {
+    accesses: Vec<Access>,
+    successors: Vec<usize>,
+}
+
+impl<'a> AnalyzeContext<'a> {
+    pub fn check_process_dataflow(
+        &self,
+        process_ent: EntRef<'a>,
+        process: &mut ProcessStatement,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::UnassignedVariables) {
+            return;
+        }
+
+        let variables = self.local_variables(process_ent, &mut process.decl);
+        if variables.all.is_empty() {
+            return;
+        }
+
+        let mut builder = GraphBuilder::new(self, &variables.all);
+        builder.statements(&process.statements);
+        // The process resumes from the start after the last statement
+        builder.edge(builder.current, ENTRY);
+        builder.check(&variables, false, diagnostics);
+    }
+
+    pub fn check_subprogram_dataflow(
+        &self,
+        subpgm_ent: EntRef<'a>,
+        body: &mut SubprogramBody,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::UnassignedVariables) {
+            return;
+        }
+
+        let variables = self.local_variables(subpgm_ent, &mut body.declarations);
+        if variables.all.is_empty() {
+            return;
+        }
+
+        let mut builder = GraphBuilder::new(self, &variables.all);
+        builder.statements(&body.statements);
+        builder.edge(builder.current, EXIT);
+        builder.check(&variables, true, diagnostics);
+    }
+
+    /// Variables declared directly within the process or subprogram
+    /// which are not referenced by nested subprograms
+    fn local_variables(&self, parent: EntRef<'a>, decls: &mut [Declaration]) -> LocalVariables {
+        let mut collector = ReferenceCollector::default();
+        for decl in decls.iter_mut() {
+            if let Declaration::SubprogramBody(ref mut body) = decl {
+                let _ = body.declarations.search(&mut collector);
+                let _ = body.statements.search(&mut collector);
+            }
+        }
+        let nested: Variables = collector.references.into_iter().map(|(_, id)| id).collect();
+
+        let mut variables = LocalVariables::default();
+        for decl in decls.iter() {
+            if let Declaration::Object(ref object) = decl {
+                let id = if let Some(id) = object.ident.decl {
+                    id
+                } else {
+                    continue;
+                };
+
+                if object.class != ObjectClass::Variable || nested.contains(&id) {
+                    continue;
+                }
+
+                let ent = self.arena.get(id);
+                if ent.parent.map(|ent| ent.id()) != Some(parent.id()) {
+                    continue;
+                }
+
+                if let Some(obj) = ObjectEnt::from_any(ent) {
+                    if matches!(
+                        obj.type_mark().base().kind(),
+                        Type::Access(..) | Type::Protected(..)
+                    ) {
+                        continue;
+                    }
+                }
+
+                variables.all.insert(id);
+                if object.expression.is_none() {
+                    variables.uninitialized.insert(id);
+                }
+            }
+        }
+        variables
+    }
+
+    /// The mode of the formal that the actual of a procedure call is associated with
+    pub(super) fn formal_mode(
+        &self,
+        pcall: &CallOrIndexed,
+        idx: usize,
+        elem: &AssociationElement,
+    ) -> Option<Mode> {
+        let formal = if let Some(ref formal) = elem.formal {
+            self.object_of_resolved_name(&formal.item)
+                .map(|obj| obj.ent)
+        } else {
+            pcall
+                .name
+                .item
+                .get_suffix_reference()
+                .and_then(|id| OverloadedEnt::from_any(self.arena.get(id)))
+                .and_then(|proc| proc.formals().nth(idx))
+                .map(|formal| formal.inner())
+        };
+
+        formal
+            .and_then(ObjectEnt::from_any)
+            .and_then(|obj| obj.mode())
+    }
+}
+
+#[derive(Default)]
+struct LocalVariables {
+    all: Variables,
+    // Variables that get the 'left value of their type until they are assigned
+    uninitialized: Variables,
+}
+
+const ENTRY: usize = 0;
+const EXIT: usize = 1;
+
+struct GraphBuilder<'c, 'a> {
+    ctx: &'c AnalyzeContext<'a>,
+    variables: &'c Variables,
+    nodes: Vec<Node>,
+    current: usize,
+    // The label, start and successor node of the enclosing loops
+    loops: Vec<(Option<EntityId>, usize, usize)>,
+}
+
+impl<'c, 'a> GraphBuilder<'c, 'a> {
+    fn new(ctx: &'c AnalyzeContext<'a>, variables: &'c Variables) -> Self {
+        GraphBuilder {
+            ctx,
+            variables,
+            nodes: vec![Node::default(), Node::default()],
+            current: ENTRY,
+            loops: Vec::new(),
+        }
+    }
+
+    fn new_node(&mut self) -> usize {
+        self.nodes.push(Node::default());
+        self.nodes.len() - 1
+    }
+
+    fn edge(&mut self, from: usize, to: usize) {
+        self.nodes[from].successors.push(to);
+    }
+
+    /// Continue with a new node that follows the current node
+    fn branch_from(&mut self, from: usize) -> usize {
+        let node = self.new_node();
+        self.edge(from, node);
+        self.current = node;
+        node
+    }
+
+    /// Control does not continue after a jump, statements that follow are unreachable
+    fn jump_to(&mut self, target: usize) {
+        self.edge(self.current, target);
+        self.current = self.new_node();
+    }
+
+    fn access(&mut self, access: Access) {
+        self.nodes[self.current].accesses.push(access);
+    }
+
+    fn statements(&mut self, statements: &[LabeledSequentialStatement]) {
+        for statement in statements.iter() {
+            self.statement(statement);
+        }
+    }
+
+    fn statement(&mut self, statement: &LabeledSequentialStatement) {
+        match statement.statement.item {
+            SequentialStatement::VariableAssignment(ref assign) => {
+                let is_complete = self.rhs(&assign.rhs, |builder, expr| {
+                    builder.expr(expr);
+                    true
+                });
+                self.target(&assign.target, is_complete);
+            }
+            SequentialStatement::SignalAssignment(ref assign) => {
+                self.rhs(&assign.rhs, |builder, waveform| builder.waveform(waveform));
+                self.target(&assign.target, false);
+            }
+            SequentialStatement::SignalForceAssignment(ref assign) => {
+                self.rhs(&assign.rhs, |builder, expr| {
+                    builder.expr(expr);
+                    true
+                });
+                self.target(&assign.target, false);
+            }
+            SequentialStatement::SignalReleaseAssignment(ref assign) => {
+                self.target(&assign.target, false);
+            }
+            SequentialStatement::ProcedureCall(ref pcall) => {
+                self.name(&pcall.item.name.item, &pcall.item.name.pos);
+                let mut outputs = Vec::new();
+                for (idx, elem) in pcall.item.parameters.iter().enumerate() {
+                    let mode = self.ctx.formal_mode(&pcall.item, idx, elem);
+                    if let ActualPart::Expression(Expression::Name(ref name)) = elem.actual.item {
+                        match mode {
+                            Some(Mode::Out) => {
+                                self.target_indexes(name);
+                                outputs.push((name.as_ref(), &elem.actual.pos, true));
+                                continue;
+                            }
+                            Some(Mode::InOut) => {
+                                outputs.push((name.as_ref(), &elem.actual.pos, false));
+                            }
+                            _ => {}
+                        }
+                    }
+                    self.actual(&elem.actual);
+                }
+                // The actuals of out parameters are assigned when the procedure returns
+                for (name, pos, is_complete) in outputs {
+                    self.write(name, pos, is_complete);
+                }
+            }
+            SequentialStatement::If(ref ifstmt) => {
+                let mut ends = Vec::new();
+                for cond in ifstmt.conds.conditionals.iter() {
+                    self.expr(&cond.condition);
+                    let start = self.current;
+                    self.branch_from(start);
+                    self.statements(&cond.item);
+                    ends.push(self.current);
+                    self.branch_from(start);
+                }
+                if let Some(ref else_item) = ifstmt.conds.else_item {
+                    self.statements(else_item);
+                }
+                ends.push(self.current);
+
+                let after = self.new_node();
+                for end in ends {
+                    self.edge(end, after);
+                }
+                self.current = after;
+            }
+            SequentialStatement::Case(ref case) => {
+                self.expr(&case.expression);
+                let start = self.current;
+                let after = self.new_node();
+                if case.alternatives.is_empty() {
+                    self.edge(start, after);
+                }
+                for alternative in case.alternatives.iter() {
+                    self.branch_from(start);
+                    self.statements(&alternative.item);
+                    self.edge(self.current, after);
+                }
+                self.current = after;
+            }
+            SequentialStatement::Loop(ref loop_stmt) => {
+                if let Some(IterationScheme::For(_, ref drange)) = loop_stmt.iteration_scheme {
+                    self.discrete_range(drange);
+                }
+                let start = self.current;
+                let header = self.branch_from(start);
+                if let Some(IterationScheme::While(ref cond)) = loop_stmt.iteration_scheme {
+                    self.expr(cond);
+                }
+                let after = self.new_node();
+                if loop_stmt.iteration_scheme.is_some() {
+                    self.edge(header, after);
+                }
+
+                self.branch_from(header);
+                self.loops.push((statement.label.decl, header, after));
+                self.statements(&loop_stmt.statements);
+                self.loops.pop();
+                self.edge(self.current, header);
+                self.current = after;
+            }
+            SequentialStatement::Block(ref block) => self.statements(&block.statements),
+            SequentialStatement::Exit(ref exit_stmt) => {
+                let target = self
+                    .loop_target(&exit_stmt.loop_label)
+                    .map(|(_, after)| after);
+                self.conditional_jump(&exit_stmt.condition, target);
+            }
+            SequentialStatement::Next(ref next_stmt) => {
+                let target = self
+                    .loop_target(&next_stmt.loop_label)
+                    .map(|(header, _)| header);
+                self.conditional_jump(&next_stmt.condition, target);
+            }
+            SequentialStatement::Return(ref ret) => {
+                if let Some(ref expr) = ret.expression {
+                    self.expr(expr);
+                }
+                if ret.is_unconditional() {
+                    self.jump_to(EXIT);
+                } else {
+                    self.conditional_jump(&ret.condition, Some(EXIT));
+                }
+            }
+            SequentialStatement::Wait(ref wait) => {
+                if let Some(ref cond) = wait.condition_clause {
+                    self.expr(cond);
+                }
+                if let Some(ref timeout) = wait.timeout_clause {
+                    self.expr(timeout);
+                }
+            }
+            SequentialStatement::Assert(ref assert_stmt) => {
+                self.expr(&assert_stmt.condition);
+                if let Some(ref report) = assert_stmt.report {
+                    self.expr(report);
+                }
+                if let Some(ref severity) = assert_stmt.severity {
+                    self.expr(severity);
+                }
+            }
+            SequentialStatement::Report(ref report_stmt) => {
+                self.expr(&report_stmt.report);
+                if let Some(ref severity) = report_stmt.severity {
+                    self.expr(severity);
+                }
+            }
+            SequentialStatement::Null => {}
+        }
+    }
+
+    /// The start and successor node of the loop that an exit or next statement refers to
+    fn loop_target(&self, label: &Option<WithRef<Ident>>) -> Option<(usize, usize)> {
+        let (_, header, after) = if let Some(ref label) = label {
+            self.loops
+                .iter()
+                .rev()
+                .find(|(id, ..)| id.is_some() && *id == label.reference)?
+        } else {
+            self.loops.last()?
+        };
+        Some((*header, *after))
+    }
+
+    fn conditional_jump(&mut self, condition: &Option<WithPos<Expression>>, target: Option<usize>) {
+        if let Some(ref cond) = condition {
+            self.expr(cond);
+            if let Some(target) = target {
+                self.edge(self.current, target);
+            }
+            self.branch_from(self.current);
+        } else if let Some(target) = target {
+            self.jump_to(target);
+        }
+    }
+
+    /// Analyze the right hand side of an assignment and return true if it always assigns a value
+    fn rhs<T>(
+        &mut self,
+        rhs: &AssignmentRightHand<T>,
+        item: impl Fn(&mut Self, &T) -> bool,
+    ) -> bool {
+        match rhs {
+            AssignmentRightHand::Simple(value) => item(self, value),
+            AssignmentRightHand::Conditional(conditionals) => {
+                for cond in conditionals.conditionals.iter() {
+                    self.expr(&cond.condition);
+                    item(self, &cond.item);
+                }
+                if let Some(ref else_item) = conditionals.else_item {
+                    item(self, else_item);
+                }
+                false
+            }
+            AssignmentRightHand::Selected(selection) => {
+                self.expr(&selection.expression);
+                for alternative in selection.alternatives.iter() {
+                    item(self, &alternative.item);
+                }
+                false
+            }
+        }
+    }
+
+    fn waveform(&mut self, waveform: &Waveform) -> bool {
+        if let Waveform::Elements(ref elems) = waveform {
+            for elem in elems.iter() {
+                self.expr(&elem.value);
+                if let Some(ref after) = elem.after {
+                    self.expr(after);
+                }
+            }
+        }
+        true
+    }
+
+    fn target(&mut self, target: &WithPos<Target>, is_complete: bool) {
+        match target.item {
+            Target::Name(ref name) => {
+                self.target_indexes(name);
+                self.write(name, &target.pos, is_complete);
+            }
+            Target::Aggregate(ref assocs) => {
+                for assoc in assocs.iter() {
+                    let expr = match assoc {
+                        ElementAssociation::Positional(expr) => expr,
+                        ElementAssociation::Named(_, expr) => expr,
+                    };
+                    if let Expression::Name(ref name) = expr.item {
+                        self.target_indexes(name);
+                        self.write(name, &expr.pos, is_complete);
+                    }
+                }
+            }
+        }
+    }
+
+    fn write(&mut self, name: &Name, pos: &SrcPos, is_complete: bool) {
+        let id = if let Some(obj) = self.ctx.object_of_resolved_name(name) {
+            obj.id()
+        } else {
+            return;
+        };
+
+        if !self.variables.contains(&id) {
+            return;
+        }
+
+        // Assigning an element or an alias of the variable keeps the rest of the value
+        let is_whole =
+            matches!(name, Name::Designator(designator) if designator.reference == Some(id));
+        if is_complete && is_whole {
+            self.access(Access::Write(pos.clone(), id));
+        } else {
+            self.access(Access::PartialWrite(id));
+        }
+    }
+
+    /// Index expressions of a target are read
+    fn target_indexes(&mut self, name: &Name) {
+        match name {
+            Name::CallOrIndexed(fcall) => {
+                self.target_indexes(&fcall.name.item);
+                for elem in fcall.parameters.iter() {
+                    self.actual(&elem.actual);
+                }
+            }
+            Name::Slice(prefix, drange) => {
+                self.target_indexes(&prefix.item);
+                self.discrete_range(drange);
+            }
+            Name::Selected(prefix, _) => self.target_indexes(&prefix.item),
+            _ => {}
+        }
+    }
+
+    fn discrete_range(&mut self, drange: &DiscreteRange) {
+        match drange {
+            DiscreteRange::Discrete(_, Some(ref range)) | DiscreteRange::Range(ref range) => {
+                self.range(range)
+            }
+            DiscreteRange::Discrete(_, None) => {}
+        }
+    }
+
+    fn range(&mut self, range: &Range) {
+        match range {
+            Range::Range(ref constraint) => {
+                self.expr(&constraint.left_expr);
+                self.expr(&constraint.right_expr);
+            }
+            Range::Attribute(ref attr) => {
+                if let Some(ref expr) = attr.expr {
+                    self.expr(expr);
+                }
+            }
+        }
+    }
+
+    fn actual(&mut self, actual: &WithPos<ActualPart>) {
+        if let ActualPart::Expression(ref expr) = actual.item {
+            self.expr_at(expr, &actual.pos);
+        }
+    }
+
+    fn expr(&mut self, expr: &WithPos<Expression>) {
+        self.expr_at(&expr.item, &expr.pos);
+    }
+
+    fn expr_at(&mut self, expr: &Expression, pos: &SrcPos) {
+        match expr {
+            Expression::Binary(_, left, right) => {
+                self.expr(left);
+                self.expr(right);
+            }
+            Expression::Unary(_, expr) => self.expr(expr),
+            Expression::Aggregate(assocs) => {
+                for assoc in assocs.iter() {
+                    match assoc {
+                        ElementAssociation::Positional(expr) => self.expr(expr),
+                        ElementAssociation::Named(_, expr) => self.expr(expr),
+                    }
+                }
+            }
+            Expression::Qualified(qexpr) => self.expr(&qexpr.expr),
+            Expression::New(alloc) => {
+                if let Allocator::Qualified(ref qexpr) = alloc.item {
+                    self.expr(&qexpr.expr);
+                }
+            }
+            Expression::Conditional(conds) => {
+                for cond in conds.conditionals.iter() {
+                    self.expr(&cond.condition);
+                    self.expr(&cond.item);
+                }
+                if let Some(ref else_item) = conds.else_item {
+                    self.expr(else_item);
+                }
+            }
+            Expression::Name(name) => self.name(name, pos),
+            Expression::Literal(..) => {}
+        }
+    }
+
+    fn name(&mut self, name: &Name, pos: &SrcPos) {
+        match name {
+            Name::Designator(_) => {
+                if let Some(obj) = self.ctx.object_of_resolved_name(name) {
+                    if self.variables.contains(&obj.id()) {
+                        self.access(Access::Read(pos.clone(), obj.id()));
+                    }
+                }
+            }
+            Name::Selected(prefix, _) | Name::SelectedAll(prefix) => {
+                self.name(&prefix.item, &prefix.pos)
+            }
+            Name::Slice(prefix, drange) => {
+                self.name(&prefix.item, &prefix.pos);
+                self.discrete_range(drange);
+            }
+            Name::CallOrIndexed(fcall) => {
+                self.name(&fcall.name.item, &fcall.name.pos);
+                for elem in fcall.parameters.iter() {
+                    self.actual(&elem.actual);
+                }
+            }
+            // The prefix of an attribute such as 'length is not read
+            Name::Attribute(attr) => {
+                if let Some(ref expr) = attr.expr {
+                    self.expr(expr);
+                }
+            }
+            Name::External(..) => {}
+        }
+    }
+
+    fn predecessors(&self) -> Vec<Vec<usize>> {
+        let mut predecessors = vec![Vec::new(); self.nodes.len()];
+        for (idx, node) in self.nodes.iter().enumerate() {
+            for successor in node.successors.iter() {
+                predecessors[*successor].push(idx);
+            }
+        }
+        predecessors
+    }
+
+    /// Forward analysis of the variables that may not have been assigned at the start of each node
+    fn unassigned(&self, uninitialized: &Variables) -> Vec<Variables> {
+        let predecessors = self.predecessors();
+        let mut inputs = vec![Variables::default(); self.nodes.len()];
+        inputs[ENTRY] = uninitialized.clone();
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for idx in 0..self.nodes.len() {
+                let mut input = if idx == ENTRY {
+                    uninitialized.clone()
+                } else {
+                    Variables::default()
+                };
+                for pred in predecessors[idx].iter() {
+                    input.extend(self.unassigned_after(*pred, &inputs[*pred]));
+                }
+                if input != inputs[idx] {
+                    inputs[idx] = input;
+                    changed = true;
+                }
+            }
+        }
+        inputs
+    }
+
+    fn unassigned_after(&self, idx: usize, input: &Variables) -> Variables {
+        let mut unassigned = input.clone();
+        for access in self.nodes[idx].accesses.iter() {
+            match access {
+                Access::Write(_, id) | Access::PartialWrite(id) => {
+                    unassigned.remove(id);
+                }
+                Access::Read(..) => {}
+            }
+        }
+        unassigned
+    }
+
+    /// Backward analysis of the variables whose value may be read after the end of each node.
+    /// The value of variables at the end of a subprogram is lost rather than overwritten.
+    fn live(&self, exit_reads: &Variables) -> Vec<Variables> {
+        let mut outputs = vec![Variables::default(); self.nodes.len()];
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for idx in (0..self.nodes.len()).rev() {
+                let mut output = Variables::default();
+                for successor in self.nodes[idx].successors.iter() {
+                    if *successor == EXIT {
+                        output.extend(exit_reads.iter().cloned());
+                    } else {
+                        output.extend(self.live_before(*successor, &outputs[*successor]));
+                    }
+                }
+                if output != outputs[idx] {
+                    outputs[idx] = output;
+                    changed = true;
+                }
+            }
+        }
+        outputs
+    }
+
+    fn live_before(&self, idx: usize, output: &Variables) -> Variables {
+        let mut live = output.clone();
+        for access in self.nodes[idx].accesses.iter().rev() {
+            match access {
+                Access::Write(_, id) => {
+                    live.remove(id);
+                }
+                Access::Read(_, id) | Access::PartialWrite(id) => {
+                    live.insert(*id);
+                }
+            }
+        }
+        live
+    }
+
+    fn check(
+        &self,
+        variables: &LocalVariables,
+        is_subprogram: bool,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let mut read_variables = Variables::default();
+        for node in self.nodes.iter() {
+            for access in node.accesses.iter() {
+                if let Access::Read(_, id) = access {
+                    read_variables.insert(*id);
+                }
+            }
+        }
+
+        // The first read of each variable that may not have been assigned
+        let mut unassigned_reads: FnvHashMap<EntityId, &SrcPos> = FnvHashMap::default();
+        for (idx, input) in self.unassigned(&variables.uninitialized).iter().enumerate() {
+            let mut unassigned = input.clone();
+            for access in self.nodes[idx].accesses.iter() {
+                match access {
+                    Access::Read(pos, id) => {
+                        if unassigned.contains(id) {
+                            let first = unassigned_reads.entry(*id).or_insert(pos);
+                            if pos < *first {
+                                *first = pos;
+                            }
+                        }
+                    }
+                    Access::Write(_, id) | Access::PartialWrite(id) => {
+                        unassigned.remove(id);
+                    }
+                }
+            }
+        }
+
+        let exit_reads = if is_subprogram {
+            variables.all.clone()
+        } else {
+            Variables::default()
+        };
+
+        let mut dead_stores = Vec::new();
+        for (idx, output) in self.live(&exit_reads).iter().enumerate() {
+            let mut live = output.clone();
+            for access in self.nodes[idx].accesses.iter().rev() {
+                match access {
+                    Access::Write(pos, id) => {
+                        // Variables that are never read are not interesting
+                        if !live.contains(id) && read_variables.contains(id) {
+                            dead_stores.push((pos, *id));
+                        }
+                        live.remove(id);
+                    }
+                    Access::Read(_, id) | Access::PartialWrite(id) => {
+                        live.insert(*id);
+                    }
+                }
+            }
+        }
+
+        let mut unassigned_reads: Vec<_> = unassigned_reads.into_iter().collect();
+        unassigned_reads.sort_by_key(|(_, pos)| *pos);
+        for (id, pos) in unassigned_reads {
+            let ent = self.ctx.arena.get(id);
+            diagnostics.push(
+                Diagnostic::warning(
+                    pos,
+                    format!(
+                        "Variable '{}' may be read before it is assigned",
+                        ent.designator()
+                    ),
+                )
+                .opt_related(ent.decl_pos(), "Defined here"),
+            );
+        }
+
+        dead_stores.sort_by_key(|(pos, _)| *pos);
+        for (pos, id) in dead_stores {
+            let ent = self.ctx.arena.get(id);
+            diagnostics.warning(
+                pos,
+                format!(
+                    "Value assigned to variable '{}' is overwritten before it is read",
+                    ent.designator()
+                ),
+            );
+        }
+    }
+}
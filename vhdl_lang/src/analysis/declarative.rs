@@ -5,12 +5,15 @@
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
 use super::formal_region::FormalRegion;
+use super::formal_region::GpkgInterfaceEnt;
+use super::formal_region::GpkgRegion;
 use super::formal_region::RecordRegion;
 use super::named_entity::*;
 use super::names::*;
 use super::*;
 use crate::ast;
 use crate::ast::*;
+use crate::config::Lint;
 use crate::data::*;
 use analyze::*;
 use fnv::FnvHashMap;
@@ -109,9 +112,45 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
         }
+        self.check_hidden_declarations(scope, parent, diagnostics);
         Ok(())
     }
 
+    /// Warn about declarations that hide a declaration of an enclosing region
+    /// or a declaration made visible by a use clause
+    fn check_hidden_declarations(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::Shadowing) {
+            return;
+        }
+
+        let mut hidden: Vec<_> = scope
+            .hidden_declarations()
+            .into_iter()
+            .filter(|(ent, _)| ent.parent.map(|ent| ent.id()) == Some(parent.id()))
+            .filter_map(|(ent, other)| Some((ent.decl_pos()?, ent, other)))
+            .collect();
+        hidden.sort_by_key(|(pos, ..)| *pos);
+
+        for (pos, ent, other) in hidden {
+            diagnostics.push(
+                Diagnostic::warning(
+                    pos,
+                    format!(
+                        "Declaration of '{}' hides {}",
+                        ent.designator(),
+                        other.describe()
+                    ),
+                )
+                .opt_related(other.decl_pos(), "Hidden declaration"),
+            );
+        }
+    }
+
     fn analyze_alias_declaration(
         &self,
         scope: &Scope<'a>,
@@ -126,6 +165,20 @@ impl<'a> AnalyzeContext<'a> {
             signature,
         } = alias;
 
+        let is_converse = matches!(name.item, Name::Attribute(ref attr)
+            if attr.attr.item == AttributeDesignator::Converse
+                && attr.signature.is_none()
+                && attr.expr.is_none());
+
+        if is_converse {
+            // The converse of a mode view is not an expression but may be aliased as a view
+            if let Some(ref signature) = signature {
+                diagnostics.push(Diagnostic::should_not_have_signature("Alias", signature));
+            }
+            let typ = self.resolve_view_name(scope, name, diagnostics)?;
+            return Ok(designator.define(self.arena, parent, AnyEntKind::View(typ)));
+        }
+
         let resolved_name = self.name_resolve(scope, &name.pos, &mut name.item, diagnostics);
 
         if let Some(ref mut subtype_indication) = subtype_indication {
@@ -206,9 +259,17 @@ impl<'a> AnalyzeContext<'a> {
                         return Err(EvalError::Unknown);
                     }
                 }
-                ResolvedName::Final(_) => {
-                    // @TODO some of these can probably be aliased
-                    return Err(EvalError::Unknown);
+                ResolvedName::Final(ent) => {
+                    if let AnyEntKind::View(typ) = ent.kind() {
+                        if let Some(ref signature) = signature {
+                            diagnostics
+                                .push(Diagnostic::should_not_have_signature("Alias", signature));
+                        }
+                        AnyEntKind::View(*typ)
+                    } else {
+                        // @TODO some of these can probably be aliased
+                        return Err(EvalError::Unknown);
+                    }
                 }
             }
         };
@@ -216,6 +277,112 @@ impl<'a> AnalyzeContext<'a> {
         Ok(designator.define(self.arena, parent, kind))
     }
 
+    fn analyze_object_declaration(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        object_decl: &mut ObjectDeclaration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let subtype = self.resolve_subtype_indication(
+            scope,
+            &mut object_decl.subtype_indication,
+            diagnostics,
+        );
+
+        if let Some(ref mut expr) = object_decl.expression {
+            if let Ok(ref subtype) = subtype {
+                self.expr_pos_with_subtype(scope, subtype, &expr.pos, &mut expr.item, diagnostics)?;
+                self.check_initial_value_width(subtype, expr, diagnostics);
+            } else {
+                self.expr_unknown_ttyp(scope, expr, diagnostics)?;
+            }
+        }
+
+        match subtype {
+            Ok(subtype) => {
+                self.check_guarded_signal_subtype(
+                    object_decl.signal_kind,
+                    &subtype,
+                    &object_decl.subtype_indication,
+                    diagnostics,
+                );
+
+                let kind = if object_decl.class == ObjectClass::Constant
+                    && object_decl.expression.is_none()
+                {
+                    AnyEntKind::DeferredConstant(subtype)
+                } else {
+                    let static_value = if object_decl.class == ObjectClass::Constant
+                        && matches!(subtype.base_type().kind(), Type::Integer)
+                    {
+                        object_decl
+                            .expression
+                            .as_ref()
+                            .and_then(|expr| self.static_integer(&expr.item))
+                    } else {
+                        None
+                    };
+
+                    AnyEntKind::Object(Object {
+                        class: object_decl.class,
+                        iface: None,
+                        has_default: object_decl.expression.is_some(),
+                        subtype,
+                        signal_kind: object_decl.signal_kind,
+                        static_value,
+                    })
+                };
+
+                let declared_by = if object_decl.class == ObjectClass::Constant
+                    && object_decl.expression.is_some()
+                {
+                    self.find_deferred_constant_declaration(scope, &object_decl.ident.tree.item)
+                } else {
+                    None
+                };
+
+                let object_ent = self.arena.alloc(
+                    object_decl.ident.tree.item.clone().into(),
+                    Some(parent),
+                    if let Some(declared_by) = declared_by {
+                        Related::DeclaredBy(declared_by)
+                    } else {
+                        Related::None
+                    },
+                    kind,
+                    Some(object_decl.ident.tree.pos().clone()),
+                );
+                object_decl.ident.decl = Some(object_ent.id());
+
+                scope.add(object_ent, diagnostics);
+            }
+            Err(err) => err.add_to(diagnostics)?,
+        }
+        Ok(())
+    }
+
+    /// LRM 6.4.2.3 A guarded signal must be of a resolved subtype
+    fn check_guarded_signal_subtype(
+        &self,
+        signal_kind: Option<SignalKind>,
+        subtype: &Subtype<'a>,
+        subtype_indication: &SubtypeIndication,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if let Some(signal_kind) = signal_kind {
+            if !subtype.is_resolved() {
+                diagnostics.error(
+                    &subtype_indication.type_mark.pos,
+                    format!(
+                        "Signal of kind {signal_kind} must have a resolved subtype, {} is not resolved",
+                        subtype.type_mark().describe()
+                    ),
+                );
+            }
+        }
+    }
+
     pub(crate) fn analyze_declaration(
         &self,
         scope: &Scope<'a>,
@@ -252,69 +419,7 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             Declaration::Object(ref mut object_decl) => {
-                let subtype = self.resolve_subtype_indication(
-                    scope,
-                    &mut object_decl.subtype_indication,
-                    diagnostics,
-                );
-
-                if let Some(ref mut expr) = object_decl.expression {
-                    if let Ok(ref subtype) = subtype {
-                        self.expr_pos_with_ttyp(
-                            scope,
-                            subtype.type_mark(),
-                            &expr.pos,
-                            &mut expr.item,
-                            diagnostics,
-                        )?;
-                    } else {
-                        self.expr_unknown_ttyp(scope, expr, diagnostics)?;
-                    }
-                }
-
-                match subtype {
-                    Ok(subtype) => {
-                        let kind = if object_decl.class == ObjectClass::Constant
-                            && object_decl.expression.is_none()
-                        {
-                            AnyEntKind::DeferredConstant(subtype)
-                        } else {
-                            AnyEntKind::Object(Object {
-                                class: object_decl.class,
-                                iface: None,
-                                has_default: object_decl.expression.is_some(),
-                                subtype,
-                            })
-                        };
-
-                        let declared_by = if object_decl.class == ObjectClass::Constant
-                            && object_decl.expression.is_some()
-                        {
-                            self.find_deferred_constant_declaration(
-                                scope,
-                                &object_decl.ident.tree.item,
-                            )
-                        } else {
-                            None
-                        };
-
-                        let object_ent = self.arena.alloc(
-                            object_decl.ident.tree.item.clone().into(),
-                            Some(parent),
-                            if let Some(declared_by) = declared_by {
-                                Related::DeclaredBy(declared_by)
-                            } else {
-                                Related::None
-                            },
-                            kind,
-                            Some(object_decl.ident.tree.pos().clone()),
-                        );
-                        object_decl.ident.decl = Some(object_ent.id());
-
-                        scope.add(object_ent, diagnostics);
-                    }
-                    Err(err) => err.add_to(diagnostics)?,
-                }
+                self.analyze_object_declaration(scope, parent, object_decl, diagnostics)?;
             }
             Declaration::File(ref mut file) => {
                 let FileDeclaration {
@@ -391,8 +496,7 @@ impl<'a> AnalyzeContext<'a> {
                     let AttributeSpecification {
                         ident,
                         entity_name,
-                        // @TODO also check the entity class
-                        entity_class: _,
+                        entity_class,
                         expr,
                     } = attr_spec;
 
@@ -442,6 +546,12 @@ impl<'a> AnalyzeContext<'a> {
                                         "Attribute specification",
                                         &signature.pos,
                                     ));
+                                } else if ent.entity_class() != Some(*entity_class) {
+                                    diagnostics.push(Diagnostic::entity_class_mismatch(
+                                        &designator.pos,
+                                        ent,
+                                        *entity_class,
+                                    ));
                                 }
                             }
                             Ok(NamedEntities::Overloaded(overloaded)) => {
@@ -450,6 +560,15 @@ impl<'a> AnalyzeContext<'a> {
                                         Ok(signature_key) => {
                                             if let Some(ent) = overloaded.get(&signature_key) {
                                                 designator.set_unique_reference(&ent);
+                                                if ent.entity_class() != Some(*entity_class) {
+                                                    diagnostics.push(
+                                                        Diagnostic::entity_class_mismatch(
+                                                            &designator.pos,
+                                                            &ent,
+                                                            *entity_class,
+                                                        ),
+                                                    );
+                                                }
                                             } else {
                                                 diagnostics.push(
                                                     Diagnostic::no_overloaded_with_signature(
@@ -464,10 +583,22 @@ impl<'a> AnalyzeContext<'a> {
                                             err.add_to(diagnostics)?;
                                         }
                                     }
-                                } else if let Some(ent) = overloaded.as_unique() {
-                                    designator.set_unique_reference(ent);
                                 } else {
-                                    diagnostics.push(Diagnostic::signature_required(designator));
+                                    let candidates: Vec<_> = overloaded
+                                        .entities()
+                                        .filter(|ent| ent.entity_class() == Some(*entity_class))
+                                        .collect();
+
+                                    match candidates.as_slice() {
+                                        [ent] => designator.set_unique_reference(ent),
+                                        [] => diagnostics.push(Diagnostic::entity_class_mismatch(
+                                            &designator.pos,
+                                            &overloaded.first(),
+                                            *entity_class,
+                                        )),
+                                        _ => diagnostics
+                                            .push(Diagnostic::signature_required(designator)),
+                                    }
                                 }
                             }
                             Err(err) => {
@@ -513,6 +644,12 @@ impl<'a> AnalyzeContext<'a> {
                     &mut body.statements,
                     diagnostics,
                 )?;
+                self.check_function_purity(subpgm_ent, body, diagnostics);
+                self.check_missing_return(subpgm_ent, body, diagnostics);
+                self.check_subprogram_dataflow(subpgm_ent.into(), body, diagnostics);
+                if subpgm_ent.is_procedure() {
+                    self.mark_analyzed_procedure(subpgm_ent.into());
+                }
             }
             Declaration::SubprogramDeclaration(ref mut subdecl) => {
                 match self.subprogram_declaration(
@@ -532,6 +669,48 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
 
+            Declaration::SubprogramInstantiation(ref mut instance) => {
+                if let Some(ent) =
+                    as_fatal(self.subprogram_instance(scope, parent, instance, diagnostics))?
+                {
+                    scope.add(ent.into(), diagnostics);
+                }
+            }
+
+            Declaration::View(ref mut view) => {
+                if let Some(typ) =
+                    as_fatal(self.analyze_view_declaration(scope, view, diagnostics))?
+                {
+                    let ent = self
+                        .arena
+                        .define(&mut view.ident, parent, AnyEntKind::View(typ));
+                    scope.add(ent, diagnostics);
+                }
+            }
+
+            Declaration::PslProperty(ref mut property) => {
+                let ent = self.analyze_psl_property(scope, parent, property, diagnostics)?;
+                scope.add(ent, diagnostics);
+            }
+
+            Declaration::PslDefaultClock(ref mut clock) => {
+                self.analyze_psl_expression(scope, clock, diagnostics)?;
+            }
+
+            Declaration::GroupTemplate(ref mut template) => {
+                let ent = self.arena.define(
+                    &mut template.ident,
+                    parent,
+                    AnyEntKind::GroupTemplate(template.entity_classes.clone()),
+                );
+                scope.add(ent, diagnostics);
+            }
+
+            Declaration::Group(ref mut group) => {
+                let ent = self.analyze_group_declaration(scope, parent, group, diagnostics)?;
+                scope.add(ent, diagnostics);
+            }
+
             Declaration::Use(ref mut use_clause) => {
                 self.analyze_use_clause(scope, &mut use_clause.item, diagnostics)?;
             }
@@ -553,13 +732,73 @@ impl<'a> AnalyzeContext<'a> {
                     scope.add(ent, diagnostics);
                 }
             }
-            Declaration::Configuration(..) => {}
+            Declaration::Configuration(ref mut spec) => {
+                self.analyze_configuration_specification(scope, spec, diagnostics)?;
+            }
+            Declaration::Disconnection(ref mut spec) => {
+                self.analyze_disconnection_specification(scope, spec, diagnostics)?;
+            }
             Declaration::Type(..) => unreachable!("Handled elsewhere"),
         };
 
         Ok(())
     }
 
+    /// LRM 7.4 Disconnection specification
+    fn analyze_disconnection_specification(
+        &self,
+        scope: &Scope<'a>,
+        spec: &mut DisconnectionSpecification,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let DisconnectionSpecification {
+            signal_list,
+            type_mark,
+            after,
+        } = spec;
+
+        let typ = match self.resolve_type_mark(scope, type_mark) {
+            Ok(typ) => Some(typ),
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                None
+            }
+        };
+
+        if let GuardedSignalList::Names(ref mut names) = signal_list {
+            for name in names.iter_mut() {
+                if let Some(object_name) = as_fatal(self.resolve_object_name(
+                    scope,
+                    &name.pos,
+                    &mut name.item,
+                    "is not a guarded signal",
+                    diagnostics,
+                ))? {
+                    if !object_name.base.is_guarded_signal() {
+                        diagnostics.error(
+                            &name.pos,
+                            format!("{} is not a guarded signal", object_name.base.describe()),
+                        );
+                    } else if let Some(typ) = typ {
+                        if object_name.type_mark().base() != typ.base() {
+                            diagnostics.error(
+                                &name.pos,
+                                format!(
+                                    "{} does not match {}",
+                                    typ.describe(),
+                                    object_name.describe_type()
+                                ),
+                            );
+                        }
+                    }
+                }
+            }
+        }
+
+        self.expr_with_ttyp(scope, self.time(), after, diagnostics)?;
+        Ok(())
+    }
+
     fn find_subpgm_declaration(
         &self,
         scope: &Scope<'a>,
@@ -578,6 +817,54 @@ impl<'a> AnalyzeContext<'a> {
         None
     }
 
+    /// Find the declaration of a generic subprogram body
+    /// The generic types of the body are mapped to those of the declaration
+    fn find_uninst_subpgm_declaration(
+        &self,
+        scope: &Scope<'a>,
+        decl: &SubprogramDeclaration,
+        signature: &Signature<'a>,
+        generics: &GpkgRegion<'a>,
+    ) -> Option<OverloadedEnt<'a>> {
+        let des = decl.subpgm_designator().item.clone().into_designator();
+
+        if let Some(NamedEntities::Overloaded(overloaded)) = scope.lookup_immediate(&des) {
+            for ent in overloaded.entities() {
+                let decl_generics =
+                    if let Overloaded::UninstSubprogramDecl(_, decl_generics) = ent.kind() {
+                        decl_generics
+                    } else {
+                        continue;
+                    };
+
+                if decl_generics.iter().len() != generics.iter().len() {
+                    continue;
+                }
+
+                let mut mapping = FnvHashMap::default();
+                for (generic, decl_generic) in generics.iter().zip(decl_generics.iter()) {
+                    if let (GpkgInterfaceEnt::Type(typ), GpkgInterfaceEnt::Type(decl_typ)) =
+                        (generic, decl_generic)
+                    {
+                        mapping.insert(typ.id(), decl_typ);
+                    }
+                }
+
+                let key = signature.key().map(|base_type| {
+                    mapping
+                        .get(&base_type.id())
+                        .map(|typ| typ.base())
+                        .unwrap_or(base_type)
+                });
+
+                if key == ent.signature().key() {
+                    return Some(ent);
+                }
+            }
+        }
+        None
+    }
+
     fn find_deferred_constant_declaration(
         &self,
         scope: &Scope<'a>,
@@ -747,6 +1034,16 @@ impl<'a> AnalyzeContext<'a> {
                                 }
                             }
                         }
+                        ProtectedTypeDeclarativeItem::PrivateVariable(ref mut object_decl) => {
+                            // Private variables are visible within the protected type body
+                            // but cannot be selected from outside of it
+                            self.analyze_object_declaration(
+                                &region,
+                                ptype,
+                                object_decl,
+                                diagnostics,
+                            )?;
+                        }
                     }
                 }
 
@@ -856,6 +1153,13 @@ impl<'a> AnalyzeContext<'a> {
                         }
                     };
 
+                let static_range = array_indexes.first().and_then(|index| match index {
+                    ArrayIndex::IndexSubtypeDefintion(type_mark) => {
+                        self.static_type_mark(type_mark)
+                    }
+                    ArrayIndex::Discrete(drange) => self.static_drange(drange),
+                });
+
                 let is_1d = indexes.len() == 1;
                 let array_ent = TypeEnt::define_with_opt_id(
                     self.arena,
@@ -863,7 +1167,11 @@ impl<'a> AnalyzeContext<'a> {
                     &mut type_decl.ident,
                     parent,
                     None,
-                    Type::Array { indexes, elem_type },
+                    Type::Array {
+                        indexes,
+                        elem_type,
+                        static_range,
+                    },
                 );
 
                 scope.add(array_ent.into(), diagnostics);
@@ -963,19 +1271,10 @@ impl<'a> AnalyzeContext<'a> {
             }
 
             TypeDefinition::Numeric(ref mut range) => {
-                self.range_unknown_typ(scope, range, diagnostics)?;
-
-                let universal_type = if let Some(range_typ) =
-                    as_fatal(self.range_type(scope, range, diagnostics))?
+                let universal_type = if let Some(universal_type) =
+                    as_fatal(self.numeric_range_type(scope, range, diagnostics))?
                 {
-                    if range_typ.is_any_integer() {
-                        UniversalType::Integer
-                    } else if range_typ.is_any_real() {
-                        UniversalType::Real
-                    } else {
-                        diagnostics.error(&range.pos(), "Expected real or integer range");
-                        return Ok(());
-                    }
+                    universal_type
                 } else {
                     return Ok(());
                 };
@@ -1099,12 +1398,15 @@ impl<'a> AnalyzeContext<'a> {
         parent: EntRef<'a>,
         decl: &mut InterfaceDeclaration,
         diagnostics: &mut dyn DiagnosticHandler,
-    ) -> AnalysisResult<EntRef<'a>> {
+    ) -> EvalResult<EntRef<'a>> {
         let ent = match decl {
             InterfaceDeclaration::File(ref mut file_decl) => {
-                let file_type = self.resolve_subtype_indication(
-                    scope,
-                    &mut file_decl.subtype_indication,
+                let file_type = catch_analysis_err(
+                    self.resolve_subtype_indication(
+                        scope,
+                        &mut file_decl.subtype_indication,
+                        diagnostics,
+                    ),
                     diagnostics,
                 )?;
                 self.arena.define(
@@ -1114,17 +1416,36 @@ impl<'a> AnalyzeContext<'a> {
                 )
             }
             InterfaceDeclaration::Object(ref mut object_decl) => {
-                let subtype = self.resolve_subtype_indication(
-                    scope,
-                    &mut object_decl.subtype_indication,
-                    diagnostics,
-                );
+                let view_typ = if let Some(ref mut view) = object_decl.view {
+                    Some(self.resolve_view_name(scope, &mut view.name, diagnostics))
+                } else {
+                    None
+                };
+
+                let subtype =
+                    if let Some(ref mut subtype_indication) = object_decl.subtype_indication {
+                        let subtype =
+                            self.resolve_subtype_indication(scope, subtype_indication, diagnostics);
+                        if let (Some(view), Some(Ok(view_typ)), Ok(subtype)) =
+                            (&object_decl.view, view_typ, &subtype)
+                        {
+                            self.check_view_type(view, view_typ, subtype.type_mark(), diagnostics);
+                        }
+                        subtype
+                    } else if let Some(view_typ) = view_typ {
+                        // A record mode view without a subtype gives the type of the object
+                        Ok(Subtype::new(view_typ?))
+                    } else {
+                        // Only a record mode view may omit the subtype indication
+                        diagnostics.error(&object_decl.ident.tree.pos, "Expected a mode view");
+                        return Err(EvalError::Unknown);
+                    };
 
                 if let Some(ref mut expression) = object_decl.expression {
                     if let Ok(ref subtype) = subtype {
-                        self.expr_pos_with_ttyp(
+                        self.expr_pos_with_subtype(
                             scope,
-                            subtype.type_mark(),
+                            subtype,
                             &expression.pos,
                             &mut expression.item,
                             diagnostics,
@@ -1134,7 +1455,15 @@ impl<'a> AnalyzeContext<'a> {
                     }
                 }
 
-                let subtype = subtype?;
+                let subtype = catch_analysis_err(subtype, diagnostics)?;
+                if let Some(ref subtype_indication) = object_decl.subtype_indication {
+                    self.check_guarded_signal_subtype(
+                        object_decl.signal_kind,
+                        &subtype,
+                        subtype_indication,
+                        diagnostics,
+                    );
+                }
                 self.arena.define(
                     &mut object_decl.ident,
                     parent,
@@ -1146,6 +1475,8 @@ impl<'a> AnalyzeContext<'a> {
                         )),
                         subtype,
                         has_default: object_decl.expression.is_some(),
+                        signal_kind: object_decl.signal_kind,
+                        static_value: None,
                     }),
                 )
             }
@@ -1172,19 +1503,25 @@ impl<'a> AnalyzeContext<'a> {
 
                 typ.into()
             }
-            InterfaceDeclaration::Subprogram(ref mut subpgm, ..) => {
-                let (_, ent) = self.subprogram_declaration(
-                    scope,
-                    parent,
-                    subpgm,
-                    Overloaded::InterfaceSubprogram,
+            InterfaceDeclaration::Subprogram(ref mut subpgm, ref default) => {
+                let has_default = default.is_some();
+                let (_, ent) = catch_analysis_err(
+                    self.subprogram_declaration(
+                        scope,
+                        parent,
+                        subpgm,
+                        |signature| Overloaded::InterfaceSubprogram(signature, has_default),
+                        diagnostics,
+                    ),
                     diagnostics,
                 )?;
                 ent.into()
             }
             InterfaceDeclaration::Package(ref mut instance) => {
-                let package_region =
-                    self.analyze_package_instance_name(scope, &mut instance.package_name)?;
+                let package_region = catch_analysis_err(
+                    self.analyze_package_instance_name(scope, &mut instance.package_name),
+                    diagnostics,
+                )?;
 
                 self.arena.define(
                     &mut instance.ident,
@@ -1204,13 +1541,10 @@ impl<'a> AnalyzeContext<'a> {
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         for decl in declarations.iter_mut() {
-            match self.analyze_interface_declaration(scope, parent, decl, diagnostics) {
-                Ok(ent) => {
-                    scope.add(ent, diagnostics);
-                }
-                Err(err) => {
-                    err.add_to(diagnostics)?;
-                }
+            if let Some(ent) =
+                as_fatal(self.analyze_interface_declaration(scope, parent, decl, diagnostics))?
+            {
+                scope.add(ent, diagnostics);
             }
         }
         Ok(())
@@ -1226,19 +1560,38 @@ impl<'a> AnalyzeContext<'a> {
         let mut params = FormalRegion::new(InterfaceType::Parameter);
 
         for decl in declarations.iter_mut() {
-            match self.analyze_interface_declaration(scope, parent, decl, diagnostics) {
-                Ok(ent) => {
-                    scope.add(ent, diagnostics);
-                    params.add(ent);
-                }
-                Err(err) => {
-                    err.add_to(diagnostics)?;
-                }
+            if let Some(ent) =
+                as_fatal(self.analyze_interface_declaration(scope, parent, decl, diagnostics))?
+            {
+                scope.add(ent, diagnostics);
+                params.add(ent);
             }
         }
         Ok(params)
     }
 
+    pub fn analyze_generic_list(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        declarations: &mut [InterfaceDeclaration],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<GpkgRegion<'a>> {
+        let mut generics = Vec::with_capacity(declarations.len());
+
+        for decl in declarations.iter_mut() {
+            if let Some(ent) =
+                as_fatal(self.analyze_interface_declaration(scope, parent, decl, diagnostics))?
+            {
+                scope.add(ent, diagnostics);
+                if let Some(generic) = GpkgInterfaceEnt::from_any(ent) {
+                    generics.push(generic);
+                }
+            }
+        }
+        Ok(GpkgRegion::new(generics))
+    }
+
     fn analyze_array_index(
         &self,
         scope: &Scope<'a>,
@@ -1269,7 +1622,10 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match constraint {
             SubtypeConstraint::Array(ref mut dranges, ref mut constraint) => {
-                if let Type::Array { indexes, elem_type } = base_type.kind() {
+                if let Type::Array {
+                    indexes, elem_type, ..
+                } = base_type.kind()
+                {
                     for (idx, drange) in dranges.iter_mut().enumerate() {
                         if let Some(index_typ) = indexes.get(idx) {
                             if let Some(index_typ) = index_typ {
@@ -1376,13 +1732,15 @@ impl<'a> AnalyzeContext<'a> {
     ) -> AnalysisResult<Subtype<'a>> {
         // @TODO more
         let SubtypeIndication {
+            resolution,
             type_mark,
             constraint,
-            ..
         } = subtype_indication;
 
         let base_type = self.resolve_type_mark(scope, type_mark)?;
 
+        let mut static_length = None;
+        let mut static_range = None;
         if let Some(constraint) = constraint {
             self.analyze_subtype_constraint(
                 scope,
@@ -1391,9 +1749,23 @@ impl<'a> AnalyzeContext<'a> {
                 &mut constraint.item,
                 diagnostics,
             )?;
+
+            match constraint.item {
+                SubtypeConstraint::Array(ref ranges, _) => {
+                    static_length = ranges.first().and_then(|drange| self.static_length(drange));
+                    static_range = ranges.first().and_then(|drange| self.static_drange(drange));
+                }
+                SubtypeConstraint::Range(ref range) => {
+                    static_range = self.static_range(range);
+                }
+                SubtypeConstraint::Record(..) => {}
+            }
         }
 
-        Ok(Subtype::new(base_type))
+        Ok(Subtype::new(base_type)
+            .with_static_length(static_length)
+            .with_static_range(static_range)
+            .with_resolution(!matches!(resolution, ResolutionIndication::Unresolved)))
     }
 
     pub fn analyze_subtype_indication(
@@ -1428,37 +1800,75 @@ impl<'a> AnalyzeContext<'a> {
             Some(&subprogram.subpgm_designator().pos),
         );
 
-        let signature = match subprogram {
-            SubprogramDeclaration::Function(fun) => {
-                let params = self.analyze_parameter_list(
+        // The generics of a subprogram that must be instantiated before it can be called
+        let mut generics = None;
+        // The actuals of a generic map in the subprogram header
+        let mut mapping = FnvHashMap::default();
+
+        if let Some(header) = subprogram.header_mut() {
+            let generic_list = self.analyze_generic_list(
+                &subpgm_region,
+                ent,
+                &mut header.generic_list,
+                diagnostics,
+            )?;
+
+            if let Some(ref mut generic_map) = header.generic_map {
+                if let Some(generic_map) = as_fatal(self.analyze_generic_map(
                     &subpgm_region,
-                    ent,
-                    &mut fun.parameter_list,
+                    generic_list,
+                    generic_map,
                     diagnostics,
-                );
-                let return_type = self.resolve_type_mark(scope, &mut fun.return_type);
-                Signature::new(params?, Some(return_type?))
+                ))? {
+                    mapping = generic_map;
+                }
+            } else {
+                generics = Some(generic_list);
             }
-            SubprogramDeclaration::Procedure(procedure) => {
-                let params = self.analyze_parameter_list(
-                    &subpgm_region,
-                    ent,
-                    &mut procedure.parameter_list,
-                    diagnostics,
+        }
+
+        let signature =
+            self.subprogram_signature(scope, &subpgm_region, ent, subprogram, diagnostics)?;
+
+        let signature = if mapping.is_empty() {
+            signature
+        } else {
+            self.map_signature(Some(ent), &mapping, &signature)
+                .map_err(|err| {
+                    AnalysisError::not_fatal_error(subprogram.subpgm_designator(), err)
+                })?
+        };
+
+        let kind = match (to_kind(signature), generics) {
+            (kind, None) => kind,
+            (Overloaded::SubprogramDecl(signature), Some(generics)) => {
+                Overloaded::UninstSubprogramDecl(signature, generics)
+            }
+            (Overloaded::Subprogram(signature), Some(generics)) => {
+                Overloaded::UninstSubprogram(signature, generics)
+            }
+            (kind, Some(_)) => {
+                diagnostics.error(
+                    subprogram.subpgm_designator(),
+                    format!("Interface {} may not have generics", kind.describe()),
                 );
-                Signature::new(params?, None)
+                kind
             }
         };
 
-        let kind = to_kind(signature);
-
-        if matches!(kind, Overloaded::Subprogram(_)) {
-            let declared_by = self.find_subpgm_declaration(scope, subprogram, kind.signature());
+        let declared_by = match kind {
+            Overloaded::Subprogram(ref signature) => {
+                self.find_subpgm_declaration(scope, subprogram, signature)
+            }
+            Overloaded::UninstSubprogram(ref signature, ref generics) => {
+                self.find_uninst_subpgm_declaration(scope, subprogram, signature, generics)
+            }
+            _ => None,
+        };
 
-            if let Some(declared_by) = declared_by {
-                unsafe {
-                    ent.set_declared_by(declared_by.into());
-                }
+        if let Some(declared_by) = declared_by {
+            unsafe {
+                ent.set_declared_by(declared_by.into());
             }
         }
 
@@ -1468,6 +1878,48 @@ impl<'a> AnalyzeContext<'a> {
         subprogram.set_decl_id(ent.id());
         Ok((subpgm_region, OverloadedEnt::from_any(ent).unwrap()))
     }
+
+    fn subprogram_signature(
+        &self,
+        scope: &Scope<'a>,
+        subpgm_region: &Scope<'a>,
+        ent: EntRef<'a>,
+        subprogram: &mut SubprogramDeclaration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> AnalysisResult<Signature<'a>> {
+        Ok(match subprogram {
+            SubprogramDeclaration::Function(fun) => {
+                let params = self.analyze_parameter_list(
+                    subpgm_region,
+                    ent,
+                    &mut fun.parameter_list,
+                    diagnostics,
+                );
+                // The generic types of the subprogram header are visible in the return type
+                let return_scope = if fun.header.is_some() {
+                    subpgm_region
+                } else {
+                    scope
+                };
+                let return_type = self.resolve_type_mark(return_scope, &mut fun.return_type);
+                let signature = Signature::new(params?, Some(return_type?));
+                if fun.pure {
+                    signature
+                } else {
+                    signature.impure()
+                }
+            }
+            SubprogramDeclaration::Procedure(procedure) => {
+                let params = self.analyze_parameter_list(
+                    subpgm_region,
+                    ent,
+                    &mut procedure.parameter_list,
+                    diagnostics,
+                );
+                Signature::new(params?, None)
+            }
+        })
+    }
 }
 
 fn find_full_type_definition<'a>(
@@ -1515,6 +1967,21 @@ impl Diagnostic {
         )
     }
 
+    pub(super) fn entity_class_mismatch(
+        pos: &SrcPos,
+        ent: &AnyEnt,
+        entity_class: EntityClass,
+    ) -> Diagnostic {
+        Diagnostic::error(
+            pos,
+            format!(
+                "{} is not of entity class '{}'",
+                ent.describe(),
+                entity_class
+            ),
+        )
+    }
+
     fn signature_required(pos: impl AsRef<SrcPos>) -> Diagnostic {
         Diagnostic::error(
             pos,
@@ -26,6 +26,9 @@ impl<'a> AnalyzeContext<'a> {
                 self.analyze_package_instance(unit, diagnostics)
             }
             AnyPrimaryUnit::Context(unit) => self.analyze_context(unit, diagnostics),
+            AnyPrimaryUnit::VerificationUnit(unit) => {
+                self.analyze_verification_unit(unit, diagnostics)
+            }
         }
     }
 
@@ -58,7 +61,7 @@ impl<'a> AnalyzeContext<'a> {
         self.add_implicit_context_clause(&root_scope)?;
         self.analyze_context_clause(&root_scope, &mut unit.context_clause, diagnostics)?;
 
-        let primary_scope = root_scope.nested();
+        let primary_scope = root_scope.nested().in_concurrent_region();
 
         // Entity name is visible
         primary_scope.make_potentially_visible(Some(unit.pos()), ent);
@@ -96,7 +99,7 @@ impl<'a> AnalyzeContext<'a> {
         self.add_implicit_context_clause(&root_region)?;
         self.analyze_context_clause(&root_region, &mut unit.context_clause, diagnostics)?;
 
-        match self.lookup_entity_for_configuration(&root_region, unit) {
+        let entity = match self.lookup_entity_for_configuration(&root_region, unit) {
             Ok(named_entity) => {
                 if let Some(primary_pos) = named_entity.decl_pos() {
                     let secondary_pos = unit.pos();
@@ -113,9 +116,11 @@ impl<'a> AnalyzeContext<'a> {
                         ));
                     }
                 }
+                Some(named_entity)
             }
             Err(err) => {
                 err.add_to(diagnostics)?;
+                None
             }
         };
 
@@ -125,6 +130,11 @@ impl<'a> AnalyzeContext<'a> {
             AnyEntKind::Design(Design::Configuration),
         );
 
+        if let Some(entity) = entity {
+            let scope = root_region.nested();
+            self.analyze_configuration_body(&scope, entity, unit, diagnostics)?;
+        }
+
         Ok(())
     }
 
@@ -260,12 +270,16 @@ impl<'a> AnalyzeContext<'a> {
 
         let root_scope = Scope::new(Region::with_visibility(visibility.clone()));
         self.analyze_context_clause(&root_scope, &mut unit.context_clause, diagnostics)?;
-        let scope = Scope::extend(region, Some(&root_scope));
+        let scope = Scope::extend(region, Some(&root_scope)).in_concurrent_region();
 
         let arch = self.arena.define(
             &mut unit.ident,
             primary.into(),
-            AnyEntKind::Design(Design::Architecture(primary)),
+            AnyEntKind::Design(Design::Architecture(
+                Visibility::default(),
+                Region::default(),
+                primary,
+            )),
         );
 
         // Architecture name is visible
@@ -274,7 +288,16 @@ impl<'a> AnalyzeContext<'a> {
         self.define_labels_for_concurrent_part(&scope, arch, &mut unit.statements, diagnostics)?;
         self.analyze_declarative_part(&scope, arch, &mut unit.decl, diagnostics)?;
         self.analyze_concurrent_part(&scope, arch, &mut unit.statements, diagnostics)?;
+        self.check_default_bindings(&scope, &unit.decl, &unit.statements, diagnostics)?;
         scope.close(diagnostics);
+
+        // The region is kept for configurations of the architecture
+        let region = scope.into_region();
+        let visibility = root_scope.into_visibility();
+
+        let kind = AnyEntKind::Design(Design::Architecture(visibility, region, primary));
+        unsafe { arch.set_kind(kind) }
+
         Ok(())
     }
 
@@ -358,7 +381,7 @@ impl<'a> AnalyzeContext<'a> {
         &self,
         scope: &Scope<'a>,
         config: &mut ConfigurationDeclaration,
-    ) -> AnalysisResult<DesignEnt> {
+    ) -> AnalysisResult<DesignEnt<'a>> {
         let ent_name = &mut config.entity_name;
 
         match ent_name.item {
@@ -17,6 +17,7 @@ use super::overloaded::ResolvedCall;
 use super::region::*;
 use crate::ast::*;
 use crate::data::*;
+use crate::standard::VHDLStandard;
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum ExpressionType<'a> {
@@ -269,9 +270,11 @@ impl<'a> AnalyzeContext<'a> {
                     .collect();
 
                 if op_candidates.is_empty() {
-                    Err(Diagnostic::error(
+                    Err(Diagnostic::no_operator_with_arity(
                         op_pos,
-                        format!("Found no match for {}", designator.describe()),
+                        &designator,
+                        arity,
+                        overloaded.entities(),
                     )
                     .into())
                 } else {
@@ -397,10 +400,14 @@ impl<'a> AnalyzeContext<'a> {
         }
 
         if candidates.is_empty() {
-            diagnostics.error(
+            diagnostics.push(self.no_match_for_operator(
                 &op.pos,
-                format!("Found no match for {}", designator.describe()),
-            );
+                &designator,
+                ttyp,
+                overloaded,
+                exprs,
+                &operand_types,
+            ));
 
             Err(EvalError::Unknown)
         } else if candidates.len() == 1 {
@@ -448,6 +455,34 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// LRM 6.5.2 Before VHDL-2008 the value of an object of mode out may not be read,
+    /// only its attributes
+    /// The value of an object of mode linkage may only be read through association
+    pub fn check_read_of_name(
+        &self,
+        expr_pos: &SrcPos,
+        name: &Name,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if let Some(object) = self.object_of_resolved_name(name) {
+            match object.mode() {
+                Some(Mode::Out) if self.standard < VHDLStandard::VHDL2008 => {
+                    diagnostics.error(
+                        expr_pos,
+                        format!("{} cannot be read before VHDL-2008", object.describe()),
+                    );
+                }
+                Some(Mode::Linkage) => {
+                    diagnostics.error(
+                        expr_pos,
+                        format!("{} may only be read through association", object.describe()),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
     pub fn expr_type(
         &self,
         scope: &Scope<'a>,
@@ -471,14 +506,37 @@ impl<'a> AnalyzeContext<'a> {
             Expression::Unary(ref mut op, ref mut inner) => {
                 self.operator_type(scope, op, &mut [inner.as_mut()], diagnostics)
             }
-            Expression::Name(ref mut name) => self
-                .expression_name_types(scope, expr_pos, name.as_mut(), diagnostics)
-                .map(ExpressionType::from),
+            Expression::Name(ref mut name) => {
+                let typ =
+                    self.expression_name_types(scope, expr_pos, name.as_mut(), diagnostics)?;
+                self.check_read_of_name(expr_pos, name, diagnostics);
+                Ok(ExpressionType::from(typ))
+            }
             Expression::Aggregate(_) => Ok(ExpressionType::Aggregate),
             Expression::Qualified(ref mut qexpr) => {
                 let typ = self.analyze_qualified_expression(scope, qexpr, diagnostics)?;
                 Ok(ExpressionType::Unambiguous(typ))
             }
+            Expression::Conditional(ref mut conds) => {
+                for cond in conds.conditionals.iter_mut() {
+                    self.boolean_expr(scope, &mut cond.condition, diagnostics)?;
+                }
+
+                // The type of a conditional expression is given by its first value
+                let mut items = conds
+                    .conditionals
+                    .iter_mut()
+                    .map(|cond| &mut cond.item)
+                    .chain(conds.else_item.iter_mut());
+                let first = items.next().unwrap();
+                let typ = self.expr_type(scope, first, diagnostics)?;
+                if let ExpressionType::Unambiguous(ttyp) = typ {
+                    for item in items {
+                        self.expr_with_ttyp(scope, ttyp, item, diagnostics)?;
+                    }
+                }
+                Ok(typ)
+            }
             Expression::New(ref mut alloc) => match &mut alloc.item {
                 Allocator::Qualified(ref mut qexpr) => {
                     let typ = self.analyze_qualified_expression(scope, qexpr, diagnostics)?;
@@ -628,6 +686,11 @@ impl<'a> AnalyzeContext<'a> {
     }
 
     fn implicit_bool_types(&self, scope: &Scope<'a>, pos: &SrcPos) -> FnvHashSet<BaseType<'a>> {
+        // The condition operator is applied implicitly since VHDL-2008
+        if self.standard < VHDLStandard::VHDL2008 {
+            return FnvHashSet::default();
+        }
+
         if let Ok(NamedEntities::Overloaded(overloaded)) =
             scope.lookup(pos, &Designator::OperatorSymbol(Operator::QueQue))
         {
@@ -687,10 +750,15 @@ impl<'a> AnalyzeContext<'a> {
                     if typ.base() != self.boolean().base() {
                         let implicit_bools = self.implicit_bool_types(scope, &expr.pos);
                         if !implicit_bools.contains(&typ.base()) {
+                            let reason = if self.standard < VHDLStandard::VHDL2008 {
+                                "Implicit conversion requires VHDL-2008 or later."
+                            } else {
+                                "Operator ?? is not defined for this type."
+                            };
                             diagnostics.error(
                                 &expr.pos,
                                 format!(
-                                    "{} cannot be implictly converted to {}. Operator ?? is not defined for this type.",
+                                    "{} cannot be implictly converted to {}. {reason}",
                                     typ.describe(),
                                     self.boolean().describe()
                                 ),
@@ -763,17 +831,21 @@ impl<'a> AnalyzeContext<'a> {
             Expression::Literal(ref mut lit) => self.analyze_literal_with_target_type(
                 scope,
                 target_type,
+                target_type.static_length(),
                 expr_pos,
                 lit,
                 diagnostics,
             )?,
-            Expression::Name(ref mut name) => self.expression_name_with_ttyp(
-                scope,
-                expr_pos,
-                name.as_mut(),
-                target_type,
-                diagnostics,
-            )?,
+            Expression::Name(ref mut name) => {
+                self.expression_name_with_ttyp(
+                    scope,
+                    expr_pos,
+                    name.as_mut(),
+                    target_type,
+                    diagnostics,
+                )?;
+                self.check_read_of_name(expr_pos, name, diagnostics);
+            }
             Expression::Qualified(ref mut qexpr) => {
                 if let Some(type_mark) =
                     as_fatal(self.analyze_qualified_expression(scope, qexpr, diagnostics))?
@@ -863,45 +935,203 @@ impl<'a> AnalyzeContext<'a> {
                     None => {}
                 }
             }
-            Expression::Aggregate(assocs) => match target_base.kind() {
-                Type::Array {
-                    elem_type, indexes, ..
-                } => {
-                    for assoc in assocs.iter_mut() {
-                        as_fatal(self.array_assoc_elem(
-                            scope,
-                            target_base,
-                            indexes,
-                            *elem_type,
-                            assoc,
-                            diagnostics,
-                        ))?;
-                    }
+            Expression::Aggregate(assocs) => {
+                self.aggregate_with_ttyp(
+                    scope,
+                    target_type,
+                    target_type.static_length(),
+                    expr_pos,
+                    assocs,
+                    diagnostics,
+                )?;
+            }
+            Expression::New(ref mut alloc) => {
+                self.analyze_allocation(scope, alloc, diagnostics)?;
+            }
+            Expression::Conditional(ref mut conds) => {
+                for cond in conds.conditionals.iter_mut() {
+                    self.boolean_expr(scope, &mut cond.condition, diagnostics)?;
+                    self.expr_with_ttyp(scope, target_type, &mut cond.item, diagnostics)?;
+                }
+                if let Some(ref mut else_item) = conds.else_item {
+                    self.expr_with_ttyp(scope, target_type, else_item, diagnostics)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Analyze an expression where the index or range constraint of the target subtype
+    /// is not part of its type mark
+    pub fn expr_pos_with_subtype(
+        &self,
+        scope: &Scope<'a>,
+        subtype: &Subtype<'a>,
+        expr_pos: &SrcPos,
+        expr: &mut Expression,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let result = match expr {
+            Expression::Aggregate(ref mut assocs) => self.aggregate_with_ttyp(
+                scope,
+                subtype.type_mark(),
+                subtype.static_length(),
+                expr_pos,
+                assocs,
+                diagnostics,
+            ),
+            Expression::Literal(ref mut lit) => self.analyze_literal_with_target_type(
+                scope,
+                subtype.type_mark(),
+                subtype.static_length(),
+                expr_pos,
+                lit,
+                diagnostics,
+            ),
+            Expression::Name(ref mut name) => {
+                self.name_with_subtype(scope, subtype, expr_pos, name, diagnostics)?;
+                self.check_read_of_name(expr_pos, name, diagnostics);
+                Ok(())
+            }
+            Expression::Conditional(ref mut conds) => {
+                for cond in conds.conditionals.iter_mut() {
+                    self.boolean_expr(scope, &mut cond.condition, diagnostics)?;
+                    self.expr_pos_with_subtype(
+                        scope,
+                        subtype,
+                        &cond.item.pos,
+                        &mut cond.item.item,
+                        diagnostics,
+                    )?;
                 }
-                Type::Record(record_scope) => {
-                    self.analyze_record_aggregate(
+                if let Some(ref mut else_item) = conds.else_item {
+                    self.expr_pos_with_subtype(
                         scope,
-                        target_base,
-                        record_scope,
-                        expr_pos,
-                        assocs,
+                        subtype,
+                        &else_item.pos,
+                        &mut else_item.item,
                         diagnostics,
                     )?;
                 }
-                _ => {
-                    self.analyze_aggregate(scope, assocs, diagnostics)?;
+                Ok(())
+            }
+            _ => self.expr_pos_with_ttyp(scope, subtype.type_mark(), expr_pos, expr, diagnostics),
+        };
+
+        // Names of constants are only known after the expression has been analyzed
+        if matches!(subtype.base_type().kind(), Type::Integer) {
+            if let (Some(range), Some(value)) = (subtype.static_range(), self.static_integer(expr))
+            {
+                if let Some(message) = range.check_value(value, "the target subtype") {
+                    diagnostics.error(expr_pos, message);
+                }
+            }
+        }
 
+        result
+    }
+
+    /// Analyze a name with the checks of an expression of the subtype except for reading
+    /// its value, the name of an actual of a formal of mode out is written rather than read
+    pub fn name_with_subtype(
+        &self,
+        scope: &Scope<'a>,
+        subtype: &Subtype<'a>,
+        expr_pos: &SrcPos,
+        name: &mut Name,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        self.expression_name_with_ttyp(scope, expr_pos, name, subtype.type_mark(), diagnostics)?;
+
+        // Objects of a constrained array subtype must have the length of the target subtype
+        if let (Some(expected), Name::Designator(designator)) = (subtype.static_length(), name) {
+            let length = designator
+                .reference
+                .and_then(|id| match self.arena.get(id).kind() {
+                    AnyEntKind::Object(object) => object.subtype.static_length(),
+                    _ => None,
+                });
+
+            if let Some(length) = length {
+                if expected != length {
                     diagnostics.error(
                         expr_pos,
-                        format!("composite does not match {}", target_type.describe()),
+                        format!(
+                            "Object of length {length} does not match target subtype of length {expected}"
+                        ),
                     );
                 }
-            },
-            Expression::New(ref mut alloc) => {
-                self.analyze_allocation(scope, alloc, diagnostics)?;
             }
         }
+        Ok(())
+    }
+
+    /// Analyze an aggregate where the target subtype may have a statically known length
+    pub fn aggregate_with_ttyp(
+        &self,
+        scope: &Scope<'a>,
+        target_type: TypeEnt<'a>,
+        static_length: Option<u64>,
+        expr_pos: &SrcPos,
+        assocs: &mut [ElementAssociation],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let target_base = target_type.base_type();
+        match target_base.kind() {
+            Type::Array {
+                elem_type, indexes, ..
+            } => {
+                let is_ordered = check_positional_before_named(assocs, diagnostics);
+
+                // The number of elements of the aggregate if all associations are statically known
+                let mut length: Option<u64> = Some(0);
+                for assoc in assocs.iter_mut() {
+                    let elem_length = as_fatal(self.array_assoc_elem(
+                        scope,
+                        target_base,
+                        indexes,
+                        *elem_type,
+                        assoc,
+                        diagnostics,
+                    ))?
+                    .flatten();
+
+                    length = length
+                        .zip(elem_length)
+                        .and_then(|(length, elem_length)| length.checked_add(elem_length));
+                }
+
+                if let (true, Some(expected), Some(length)) = (is_ordered, static_length, length) {
+                    if expected != length {
+                        diagnostics.error(
+                            expr_pos,
+                            format!(
+                                "Aggregate of length {length} does not match target subtype of length {expected}"
+                            ),
+                        );
+                    }
+                }
+            }
+            Type::Record(record_scope) => {
+                self.analyze_record_aggregate(
+                    scope,
+                    target_base,
+                    record_scope,
+                    expr_pos,
+                    assocs,
+                    diagnostics,
+                )?;
+            }
+            _ => {
+                self.analyze_aggregate(scope, assocs, diagnostics)?;
 
+                diagnostics.error(
+                    expr_pos,
+                    format!("composite does not match {}", target_type.describe()),
+                );
+            }
+        }
         Ok(())
     }
 
@@ -945,7 +1175,8 @@ impl<'a> AnalyzeContext<'a> {
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         let mut associated = RecordAssociations::default();
-        let mut is_ok_so_far = true;
+        let is_ordered = check_positional_before_named(assocs, diagnostics);
+        let mut is_ok_so_far = is_ordered;
 
         for (idx, assoc) in assocs.iter_mut().enumerate() {
             match assoc {
@@ -1072,7 +1303,10 @@ impl<'a> AnalyzeContext<'a> {
                     }
                 }
                 ElementAssociation::Positional(ref mut expr) => {
-                    if let Some(elem) = elems.nth(idx) {
+                    if !is_ordered {
+                        // The element cannot be known when following a named association
+                        self.expr_unknown_ttyp(scope, expr, diagnostics)?;
+                    } else if let Some(elem) = elems.nth(idx) {
                         self.expr_with_ttyp(scope, elem.type_mark(), expr, diagnostics)?;
                         associated.associate(elem, &expr.pos, diagnostics);
                     } else {
@@ -1128,11 +1362,13 @@ impl<'a> AnalyzeContext<'a> {
         elem_type: TypeEnt<'a>,
         assoc: &mut ElementAssociation,
         diagnostics: &mut dyn DiagnosticHandler,
-    ) -> EvalResult {
+    ) -> EvalResult<Option<u64>> {
         let index_type = index_types.first().and_then(|x| *x);
         let mut can_be_array = true;
+        // The number of elements associated if it is statically known
+        let mut length = Some(0);
 
-        let expr = match assoc {
+        let (expr, is_positional) = match assoc {
             ElementAssociation::Named(ref mut choices, ref mut expr) => {
                 for choice in choices.iter_mut() {
                     match &mut choice.item {
@@ -1155,6 +1391,7 @@ impl<'a> AnalyzeContext<'a> {
                                     }
 
                                     can_be_array = true;
+                                    length = None;
                                 }
                                 Ok(None) => {
                                     if let Some(index_type) = index_type {
@@ -1167,10 +1404,11 @@ impl<'a> AnalyzeContext<'a> {
                                         )?;
                                     }
                                     can_be_array = false;
+                                    length = length.map(|length| length + 1);
                                 }
                                 Err(err) => {
                                     diagnostics.push(err.into_non_fatal()?);
-                                    return Ok(());
+                                    return Ok(None);
                                 }
                             }
                         }
@@ -1185,16 +1423,23 @@ impl<'a> AnalyzeContext<'a> {
                             } else {
                                 self.drange_unknown_type(scope, drange, diagnostics)?;
                             }
+                            length = length
+                                .zip(self.static_length(drange))
+                                .map(|(length, range_length)| length + range_length);
                         }
                         Choice::Others => {
                             // @TODO choice must be alone so cannot appear here
                             can_be_array = false;
+                            length = None;
                         }
                     }
                 }
-                expr
+                (expr, false)
+            }
+            ElementAssociation::Positional(ref mut expr) => {
+                length = Some(1);
+                (expr, true)
             }
-            ElementAssociation::Positional(ref mut expr) => expr,
         };
 
         if index_types.len() > 1 {
@@ -1229,12 +1474,16 @@ impl<'a> AnalyzeContext<'a> {
                 self.expr_pos_with_ttyp(scope, elem_type, &expr.pos, &mut expr.item, diagnostics)?;
             } else if is_array {
                 self.expr_pos_with_ttyp(scope, array_type, &expr.pos, &mut expr.item, diagnostics)?;
+                if is_positional {
+                    // The length of an array expression is not known
+                    length = None;
+                }
             }
         } else {
             self.expr_pos_with_ttyp(scope, elem_type, &expr.pos, &mut expr.item, diagnostics)?;
         }
 
-        Ok(())
+        Ok(length)
     }
 }
 
@@ -1256,6 +1505,31 @@ impl Diagnostic {
     }
 }
 
+/// LRM 9.3.3.1 Positional associations must precede named associations.
+/// Returns false if a positional association follows a named association
+fn check_positional_before_named(
+    assocs: &[ElementAssociation],
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> bool {
+    let mut is_named = false;
+    let mut is_ordered = true;
+    for assoc in assocs.iter() {
+        match assoc {
+            ElementAssociation::Named(..) => is_named = true,
+            ElementAssociation::Positional(ref expr) => {
+                if is_named {
+                    is_ordered = false;
+                    diagnostics.error(
+                        &expr.pos,
+                        "Positional association cannot follow named association",
+                    );
+                }
+            }
+        }
+    }
+    is_ordered
+}
+
 #[derive(Default)]
 struct RecordAssociations<'a>(FnvHashMap<EntityId, &'a SrcPos>);
 
@@ -0,0 +1,645 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! LRM 8.7 External names
+//!
+//! The pathname of an external name is resolved by walking the instance, block and generate
+//! labels of the design hierarchy. Which design entity is the root of the hierarchy is only
+//! known during elaboration, thus a pathname is only followed into the design units that are
+//! instantiated below the current design unit. A pathname that leaves the current design unit
+//! or passes through an entity with several architectures is not resolved any further.
+
+use super::analyze::*;
+use super::binding::configuration_of;
+use super::configuration::ConfiguredBlock;
+use super::named_entity::*;
+use super::names::{ObjectBase, ObjectName};
+use super::region::*;
+use crate::ast::*;
+use crate::data::*;
+use std::ops::Deref;
+
+/// An element of the pathname of an external name
+struct PathElement<'n> {
+    pos: &'n SrcPos,
+    designator: &'n mut WithRef<Designator>,
+    /// The index of a generate statement label
+    index: Option<&'n mut Vec<AssociationElement>>,
+}
+
+/// Split a pathname into its elements, returns false if it is not a valid pathname
+fn path_elements<'n>(name: &'n mut WithPos<Name>, elements: &mut Vec<PathElement<'n>>) -> bool {
+    let WithPos { item, pos } = name;
+    match item {
+        Name::Designator(designator) => {
+            elements.push(PathElement {
+                pos,
+                designator,
+                index: None,
+            });
+            true
+        }
+        Name::Selected(prefix, suffix) => {
+            if !path_elements(prefix, elements) {
+                return false;
+            }
+            let WithPos { item, pos } = suffix;
+            elements.push(PathElement {
+                pos,
+                designator: item,
+                index: None,
+            });
+            true
+        }
+        Name::CallOrIndexed(call) => {
+            let CallOrIndexed { name, parameters } = call.as_mut();
+            if !path_elements(name, elements) {
+                return false;
+            }
+            if let Some(last) = elements.last_mut() {
+                last.index = Some(parameters);
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+/// The entity and the explicit architecture that an instance is bound to
+type BoundEntity<'a> = (DesignEnt<'a>, Option<Symbol>);
+
+/// The region of the design hierarchy that a pathname element is declared within
+enum HierarchyRegion<'a, 's> {
+    /// A concurrent region of the current design unit enclosing the external name
+    /// The statements of the current design unit are not available, only its declarations
+    Current(Scope<'a>),
+    /// A block of an architecture of another design unit
+    /// The region of the architecture is only available at the outermost block
+    Block {
+        prefix: EntRef<'a>,
+        region: Option<&'a Region<'a>>,
+        block: ConfiguredBlock<'s>,
+    },
+    /// A package or a package instance
+    Package {
+        prefix: EntRef<'a>,
+        region: &'a Region<'a>,
+    },
+}
+
+impl<'a> AnalyzeContext<'a> {
+    /// Resolve the pathname of an external name and check that it denotes an object of the
+    /// class and type of the external name
+    pub fn resolve_external_path(
+        &self,
+        scope: &Scope<'a>,
+        class: ExternalObjectClass,
+        path: &mut WithPos<ExternalPath>,
+        subtype: TypeEnt<'a>,
+        subtype_pos: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let (name, is_absolute, is_package, up_levels) = match path.item {
+            ExternalPath::Absolute(ref mut name) => (name, true, false, 0),
+            ExternalPath::Package(ref mut name) => (name, false, true, 0),
+            ExternalPath::Relative(ref mut name, up_levels) => (name, false, false, up_levels),
+        };
+
+        let mut elements = Vec::new();
+        if !path_elements(name, &mut elements) {
+            diagnostics.error(&name.pos, "Invalid pathname of external name");
+            return Ok(());
+        }
+
+        // The generate indexes are static expressions evaluated where the external name is
+        for element in elements.iter_mut() {
+            if let Some(ref mut index) = element.index {
+                self.analyze_assoc_elems(scope, index, diagnostics)?;
+            }
+        }
+
+        let ent = if is_package {
+            self.resolve_package_path(scope, &mut elements, diagnostics)?
+        } else if is_absolute {
+            self.resolve_absolute_path(scope, &mut elements, diagnostics)?
+        } else if let Some(region) = scope.concurrent_regions().into_iter().nth(up_levels) {
+            // Each ^ moves up to the concurrent region enclosing the current one
+            let region = HierarchyRegion::Current(region);
+            self.resolve_path(scope, &region, &mut elements, diagnostics)?
+        } else {
+            // The pathname goes above the current design unit
+            None
+        };
+
+        let (ent, pos) = if let (Some(ent), Some(last)) = (ent, elements.last()) {
+            (ent, last.pos)
+        } else {
+            return Ok(());
+        };
+
+        let object = ObjectEnt::from_any(ent).filter(|object| match class {
+            ExternalObjectClass::Variable => matches!(
+                object.class(),
+                ObjectClass::Variable | ObjectClass::SharedVariable
+            ),
+            _ => object.class() == ObjectClass::from(class),
+        });
+
+        if let Some(object) = object {
+            if object.type_mark().base() != subtype.base() {
+                let object_name = ObjectName {
+                    base: ObjectBase::Object(object),
+                    type_mark: None,
+                };
+                diagnostics.error(
+                    subtype_pos,
+                    format!(
+                        "{} does not match {}",
+                        subtype.describe(),
+                        object_name.describe_type()
+                    ),
+                );
+            }
+        } else {
+            diagnostics.push(ent.kind_error(pos, &class.to_string()));
+        }
+
+        Ok(())
+    }
+
+    /// The first element of an absolute pathname is the entity at the root of the design hierarchy
+    fn resolve_absolute_path(
+        &self,
+        scope: &Scope<'a>,
+        elements: &mut [PathElement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<EntRef<'a>>> {
+        let (root, elements) = if let Some(split) = elements.split_first_mut() {
+            split
+        } else {
+            return Ok(None);
+        };
+
+        // The root design entity may be analyzed into another library
+        let entity =
+            match self.lookup_in_library(self.work_library_name(), root.pos, &root.designator.item)
+            {
+                Ok(design) => design,
+                Err(AnalysisError::NotFatal(_)) => return Ok(None),
+                Err(AnalysisError::Fatal(err)) => return Err(err),
+            };
+        root.designator.set_unique_reference(&entity);
+
+        if !matches!(entity.kind(), Design::Entity(..)) {
+            diagnostics.push(entity.kind_error(root.pos, "entity"));
+            return Ok(None);
+        }
+
+        let (library_name, entity_name, architecture) =
+            if let Some(unit) = self.entity_architecture(entity, None) {
+                unit
+            } else {
+                return Ok(None);
+            };
+
+        // Only the hierarchy below the current design unit is known
+        if self.is_current_architecture(library_name, entity_name, &architecture) {
+            self.resolve_path_in_current_unit(scope, elements, diagnostics)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// The elements of a package pathname are a library, a package and nested packages
+    fn resolve_package_path(
+        &self,
+        scope: &Scope<'a>,
+        elements: &mut [PathElement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<EntRef<'a>>> {
+        let (library, package, elements) = match elements {
+            [library, package, elements @ ..] => (library, package, elements),
+            _ => return Ok(None),
+        };
+
+        let library_name = match library.designator.item {
+            Designator::Identifier(ref name) if name == &self.work_sym => {
+                self.work_library_name().clone()
+            }
+            Designator::Identifier(ref name) => name.clone(),
+            _ => return Ok(None),
+        };
+
+        if let Some(library_ent) = self.get_library(&library_name) {
+            library.designator.set_unique_reference(library_ent);
+        } else {
+            diagnostics.error(library.pos, format!("No such library '{library_name}'"));
+            return Ok(None);
+        }
+
+        let design =
+            match self.lookup_in_library(&library_name, package.pos, &package.designator.item) {
+                Ok(design) => design,
+                Err(err) => {
+                    err.add_to(diagnostics)?;
+                    return Ok(None);
+                }
+            };
+        package.designator.set_unique_reference(&design);
+
+        match design.0.kind() {
+            AnyEntKind::Design(Design::Package(_, ref region))
+            | AnyEntKind::Design(Design::PackageInstance(ref region)) => {
+                let region = HierarchyRegion::Package {
+                    prefix: design.into(),
+                    region,
+                };
+                self.resolve_path(scope, &region, elements, diagnostics)
+            }
+            _ => {
+                diagnostics.push(design.kind_error(package.pos, "package"));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Resolve the remaining elements of a pathname within a region of the design hierarchy
+    /// Returns the named entity denoted by the last element
+    fn resolve_path(
+        &self,
+        scope: &Scope<'a>,
+        region: &HierarchyRegion<'a, '_>,
+        elements: &mut [PathElement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<EntRef<'a>>> {
+        let (element, elements) = if let Some(split) = elements.split_first_mut() {
+            split
+        } else {
+            return Ok(None);
+        };
+
+        let ent = match region {
+            // Declarations made visible by use clauses are not part of the hierarchy
+            HierarchyRegion::Current(region) => {
+                if let Some(visible) = region.lookup_immediate(&element.designator.item) {
+                    visible.first()
+                } else {
+                    diagnostics.error(
+                        element.pos,
+                        format!("No declaration of '{}'", element.designator.item),
+                    );
+                    return Ok(None);
+                }
+            }
+            HierarchyRegion::Block {
+                prefix,
+                region,
+                block,
+            } => {
+                let ent = region
+                    .and_then(|region| region.lookup_immediate(&element.designator.item))
+                    .map(|visible| visible.first())
+                    .or_else(|| self.lookup_in_block(block, &element.designator.item));
+
+                if let Some(ent) = ent {
+                    ent
+                } else {
+                    diagnostics.push(Diagnostic::no_declaration_within(
+                        prefix,
+                        element.pos,
+                        &element.designator.item,
+                    ));
+                    return Ok(None);
+                }
+            }
+            HierarchyRegion::Package { prefix, region } => {
+                if let Some(visible) = region.lookup_immediate(&element.designator.item) {
+                    visible.first()
+                } else {
+                    diagnostics.push(Diagnostic::no_declaration_within(
+                        prefix,
+                        element.pos,
+                        &element.designator.item,
+                    ));
+                    return Ok(None);
+                }
+            }
+        };
+        element.designator.set_unique_reference(ent);
+
+        if elements.is_empty() {
+            return Ok(Some(ent));
+        }
+
+        match ent.kind() {
+            AnyEntKind::Concurrent(Some(Concurrent::Instance)) => {
+                let bound = match region {
+                    HierarchyRegion::Current(_) => {
+                        let unit = self.instantiated_units.borrow().get(&ent.id()).cloned();
+                        if let Some(mut unit) = unit {
+                            self.resolve_instantiated_entity(scope, &mut unit, element.pos)?
+                        } else {
+                            None
+                        }
+                    }
+                    HierarchyRegion::Block { prefix, block, .. } => {
+                        if let Some(statement) = block.find(ent.id()) {
+                            if let ConcurrentStatement::Instance(ref instance) =
+                                statement.statement.item
+                            {
+                                if let Some(bound) =
+                                    self.configured_entity(block, statement, &instance.unit)
+                                {
+                                    bound
+                                } else {
+                                    let library_name = prefix
+                                        .library_name()
+                                        .unwrap_or_else(|| self.work_library_name());
+                                    self.instantiated_entity(
+                                        None,
+                                        library_name,
+                                        &instance.unit,
+                                        element.pos,
+                                    )?
+                                }
+                            } else {
+                                None
+                            }
+                        } else {
+                            None
+                        }
+                    }
+                    HierarchyRegion::Package { .. } => None,
+                };
+
+                if let Some(bound) = bound {
+                    self.resolve_path_in_entity(
+                        scope,
+                        ent,
+                        element.pos,
+                        bound,
+                        elements,
+                        diagnostics,
+                    )
+                } else {
+                    Ok(None)
+                }
+            }
+            AnyEntKind::Concurrent(Some(Concurrent::Block | Concurrent::Generate)) => {
+                // The statements of the current design unit are not available
+                if let HierarchyRegion::Block { block, .. } = region {
+                    if let Some(statement) = block.find(ent.id()) {
+                        let region = HierarchyRegion::Block {
+                            prefix: ent,
+                            region: None,
+                            block: ConfiguredBlock::of_statement(&statement.statement.item),
+                        };
+                        return self.resolve_path(scope, &region, elements, diagnostics);
+                    }
+                }
+                Ok(None)
+            }
+            AnyEntKind::Design(Design::PackageInstance(ref package_region)) => {
+                let region = HierarchyRegion::Package {
+                    prefix: ent,
+                    region: package_region,
+                };
+                self.resolve_path(scope, &region, elements, diagnostics)
+            }
+            _ => {
+                diagnostics.push(Diagnostic::invalid_selected_name_prefix(ent, element.pos));
+                Ok(None)
+            }
+        }
+    }
+
+    /// Continue a pathname within the architecture that an instance is bound to
+    fn resolve_path_in_entity(
+        &self,
+        scope: &Scope<'a>,
+        instance: EntRef<'a>,
+        instance_pos: &SrcPos,
+        (entity, architecture): BoundEntity<'a>,
+        elements: &mut [PathElement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<EntRef<'a>>> {
+        let (library_name, entity_name, architecture) =
+            if let Some(unit) = self.entity_architecture(entity, architecture) {
+                unit
+            } else {
+                return Ok(None);
+            };
+
+        // A recursive instance of the current design unit
+        if self.is_current_architecture(library_name, entity_name, &architecture) {
+            return self.resolve_path_in_current_unit(scope, elements, diagnostics);
+        }
+
+        let (arch, data) = match self.get_architecture_analysis(
+            library_name,
+            instance_pos,
+            entity_name,
+            &architecture,
+        ) {
+            Ok(found) => found,
+            // A missing architecture is reported by the instance
+            Err(AnalysisError::NotFatal(_)) => return Ok(None),
+            Err(AnalysisError::Fatal(err)) => return Err(err),
+        };
+
+        let (body, region) = match (data.deref(), arch.0.kind()) {
+            (
+                AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(ref body)),
+                AnyEntKind::Design(Design::Architecture(_, ref region, _)),
+            ) => (body, region),
+            _ => return Ok(None),
+        };
+
+        let region = HierarchyRegion::Block {
+            prefix: instance,
+            region: Some(region),
+            block: ConfiguredBlock::new(body.decl.iter(), body.statements.iter()),
+        };
+        self.resolve_path(scope, &region, elements, diagnostics)
+    }
+
+    /// Resolve a pathname from the outermost concurrent region of the current design unit
+    fn resolve_path_in_current_unit(
+        &self,
+        scope: &Scope<'a>,
+        elements: &mut [PathElement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<EntRef<'a>>> {
+        if let Some(region) = scope.concurrent_regions().pop() {
+            let region = HierarchyRegion::Current(region);
+            self.resolve_path(scope, &region, elements, diagnostics)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// The library, entity and architecture name of an entity
+    /// The default architecture is used when the architecture is not given explicitly
+    fn entity_architecture(
+        &self,
+        entity: DesignEnt<'a>,
+        architecture: Option<Symbol>,
+    ) -> Option<(&'a Symbol, &'a Symbol, Symbol)> {
+        let entity = entity.0;
+        let library_name = entity.library_name()?;
+        let entity_name = if let Designator::Identifier(name) = entity.designator() {
+            name
+        } else {
+            return None;
+        };
+
+        let architecture = if architecture.is_some() {
+            architecture
+        } else {
+            self.default_architecture(library_name, entity_name)
+        }?;
+        Some((library_name, entity_name, architecture))
+    }
+
+    /// The declarations and statement labels of a block of another design unit
+    fn lookup_in_block(
+        &self,
+        block: &ConfiguredBlock,
+        designator: &Designator,
+    ) -> Option<EntRef<'a>> {
+        let name = if let Designator::Identifier(name) = designator {
+            name
+        } else {
+            return None;
+        };
+
+        let label = block.statements.iter().find_map(|statement| {
+            let label = statement.label.tree.as_ref()?;
+            if &label.item == name {
+                statement.label.decl
+            } else {
+                None
+            }
+        });
+
+        let id = label.or_else(|| {
+            block.decl.iter().find_map(|decl| match decl {
+                Declaration::Object(object) if &object.ident.tree.item == name => object.ident.decl,
+                Declaration::Package(instance) if &instance.ident.tree.item == name => {
+                    instance.ident.decl
+                }
+                _ => None,
+            })
+        })?;
+
+        Some(self.arena.get(id))
+    }
+
+    /// The entity and architecture of a component instance that is bound by a configuration
+    /// specification of the block
+    fn configured_entity(
+        &self,
+        block: &ConfiguredBlock,
+        statement: &LabeledConcurrentStatement,
+        unit: &InstantiatedUnit,
+    ) -> Option<Option<BoundEntity<'a>>> {
+        let component = if let InstantiatedUnit::Component(ref component_name) = unit {
+            self.arena.get(component_name.item.get_suffix_reference()?)
+        } else {
+            return None;
+        };
+
+        let spec = configuration_of(
+            block.decl.iter().copied(),
+            statement.label.tree.as_ref(),
+            component,
+        )?;
+
+        let bound = match spec.bind_ind.entity_aspect {
+            Some(EntityAspect::Entity(ref entity_name, ref architecture_name)) => entity_name
+                .item
+                .get_suffix_reference()
+                .and_then(|id| DesignEnt::from_any(self.arena.get(id)))
+                .map(|entity| {
+                    let architecture = architecture_name
+                        .as_ref()
+                        .map(|name| name.item.item.clone());
+                    (entity, architecture)
+                }),
+            _ => None,
+        };
+        Some(bound)
+    }
+
+    /// The entity and architecture of an instance within another design unit that has been analyzed
+    /// The library is that of the design unit which contains the instance
+    fn instantiated_entity(
+        &self,
+        scope: Option<&Scope<'a>>,
+        library_name: &Symbol,
+        unit: &InstantiatedUnit,
+        pos: &SrcPos,
+    ) -> FatalResult<Option<BoundEntity<'a>>> {
+        match unit {
+            InstantiatedUnit::Entity(ref entity_name, ref architecture_name) => {
+                let entity = entity_name
+                    .item
+                    .get_suffix_reference()
+                    .and_then(|id| DesignEnt::from_any(self.arena.get(id)));
+                Ok(entity.map(|entity| {
+                    let architecture = architecture_name
+                        .as_ref()
+                        .map(|name| name.item.item.clone());
+                    (entity, architecture)
+                }))
+            }
+            InstantiatedUnit::Component(ref component_name) => {
+                if let Some(id) = component_name.item.get_suffix_reference() {
+                    let component = self.arena.get(id);
+                    Ok(self
+                        .default_binding(scope, library_name, component, pos)?
+                        .map(|entity| (entity, None)))
+                } else {
+                    Ok(None)
+                }
+            }
+            InstantiatedUnit::Configuration(..) => Ok(None),
+        }
+    }
+
+    /// The entity and architecture of an instance within the current design unit
+    /// The instance statement may not have been analyzed yet so its names are resolved here
+    fn resolve_instantiated_entity(
+        &self,
+        scope: &Scope<'a>,
+        unit: &mut InstantiatedUnit,
+        pos: &SrcPos,
+    ) -> FatalResult<Option<BoundEntity<'a>>> {
+        match unit {
+            InstantiatedUnit::Entity(ref mut entity_name, _) => {
+                match self.resolve_selected_name(scope, entity_name) {
+                    Ok(visible) => {
+                        if let Ok(ent) = visible.into_non_overloaded() {
+                            entity_name.set_unique_reference(ent);
+                        }
+                    }
+                    // Errors are reported when analyzing the instance
+                    Err(AnalysisError::NotFatal(_)) => return Ok(None),
+                    Err(AnalysisError::Fatal(err)) => return Err(err),
+                }
+            }
+            InstantiatedUnit::Component(ref mut component_name) => {
+                match self.resolve_component_name(scope, component_name) {
+                    Ok(component) => component_name.set_unique_reference(component),
+                    Err(AnalysisError::NotFatal(_)) => return Ok(None),
+                    Err(AnalysisError::Fatal(err)) => return Err(err),
+                }
+            }
+            InstantiatedUnit::Configuration(..) => return Ok(None),
+        }
+        self.instantiated_entity(Some(scope), self.work_library_name(), unit, pos)
+    }
+}
@@ -211,8 +211,12 @@ impl<'a> RecordElement<'a> {
     }
 
     pub fn type_mark(&self) -> TypeEnt<'a> {
+        self.subtype().type_mark()
+    }
+
+    pub fn subtype(&self) -> Subtype<'a> {
         match self.ent.kind() {
-            AnyEntKind::ElementDeclaration(subtype) => subtype.type_mark(),
+            AnyEntKind::ElementDeclaration(subtype) => *subtype,
             _ => {
                 unreachable!();
             }
@@ -250,13 +254,35 @@ impl<'a> GpkgInterfaceEnt<'a> {
             AnyEntKind::Object(obj) if obj.is_generic() => Some(GpkgInterfaceEnt::Constant(
                 ObjectEnt::from_any(ent).unwrap(),
             )),
-            AnyEntKind::Overloaded(Overloaded::InterfaceSubprogram(_)) => Some(
+            AnyEntKind::Overloaded(Overloaded::InterfaceSubprogram(..)) => Some(
                 GpkgInterfaceEnt::Subprogram(OverloadedEnt::from_any(ent).unwrap()),
             ),
             AnyEntKind::Design(Design::PackageInstance(_)) => Some(GpkgInterfaceEnt::Package(ent)),
             _ => None,
         }
     }
+
+    /// True if the generic may be left out of a generic map
+    pub fn has_default(&self) -> bool {
+        match self {
+            GpkgInterfaceEnt::Type(_) | GpkgInterfaceEnt::Package(_) => false,
+            GpkgInterfaceEnt::Constant(obj) => obj.object().has_default,
+            GpkgInterfaceEnt::Subprogram(subp) => {
+                matches!(subp.kind(), Overloaded::InterfaceSubprogram(_, true))
+            }
+        }
+    }
+}
+
+impl<'a> From<GpkgInterfaceEnt<'a>> for EntRef<'a> {
+    fn from(value: GpkgInterfaceEnt<'a>) -> Self {
+        match value {
+            GpkgInterfaceEnt::Type(typ) => typ.into(),
+            GpkgInterfaceEnt::Constant(obj) => obj.ent,
+            GpkgInterfaceEnt::Subprogram(subp) => subp.into(),
+            GpkgInterfaceEnt::Package(ent) => ent,
+        }
+    }
 }
 
 impl<'a> std::ops::Deref for GpkgInterfaceEnt<'a> {
@@ -300,4 +326,8 @@ impl<'a> GpkgRegion<'a> {
     pub fn nth(&self, idx: usize) -> Option<GpkgInterfaceEnt<'a>> {
         self.entities.get(idx).cloned()
     }
+
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = GpkgInterfaceEnt<'a>> + '_ {
+        self.entities.iter().cloned()
+    }
 }
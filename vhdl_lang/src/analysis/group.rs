@@ -0,0 +1,156 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::analyze::*;
+use super::named_entity::*;
+use super::names::plural;
+use super::region::*;
+use crate::ast::*;
+use crate::data::*;
+
+impl<'a> AnalyzeContext<'a> {
+    pub fn analyze_group_declaration(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        group: &mut GroupDeclaration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<EntRef<'a>> {
+        let template_pos = group.group_template_name.pos.clone();
+        let entity_classes = match self.resolve_selected_name(scope, &mut group.group_template_name)
+        {
+            Ok(visible) => match visible.into_non_overloaded() {
+                Ok(ent) => {
+                    if let AnyEntKind::GroupTemplate(ref entity_classes) = ent.kind() {
+                        Some(entity_classes.as_slice())
+                    } else {
+                        diagnostics.error(
+                            &template_pos,
+                            format!("Expected group template, got {}", ent.describe()),
+                        );
+                        None
+                    }
+                }
+                Err(overloaded) => {
+                    diagnostics.error(
+                        &template_pos,
+                        format!(
+                            "Expected group template, got {}",
+                            overloaded.first().describe()
+                        ),
+                    );
+                    None
+                }
+            },
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                None
+            }
+        };
+
+        let ent = self
+            .arena
+            .define(&mut group.ident, parent, AnyEntKind::Group);
+
+        for (idx, constituent) in group.constituents.iter_mut().enumerate() {
+            let entity_class = entity_classes.and_then(|entries| constituent_class(entries, idx));
+            self.analyze_group_constituent(scope, constituent, entity_class, diagnostics)?;
+        }
+
+        if let Some(entries) = entity_classes {
+            let has_box = entries.last().map(|entry| entry.has_box).unwrap_or(false);
+            let num_constituents = group.constituents.len();
+
+            if has_box && num_constituents < entries.len() - 1 {
+                diagnostics.error(
+                    &template_pos,
+                    format!(
+                        "Group template '{}' expects at least {} {}, got {}",
+                        group.group_template_name,
+                        entries.len() - 1,
+                        plural("constituent", "constituents", entries.len() - 1),
+                        num_constituents
+                    ),
+                );
+            } else if !has_box && num_constituents != entries.len() {
+                diagnostics.error(
+                    &template_pos,
+                    format!(
+                        "Group template '{}' expects {} {}, got {}",
+                        group.group_template_name,
+                        entries.len(),
+                        plural("constituent", "constituents", entries.len()),
+                        num_constituents
+                    ),
+                );
+            }
+        }
+
+        Ok(ent)
+    }
+
+    /// Resolve a group constituent and check it against the entity class of the template
+    fn analyze_group_constituent(
+        &self,
+        scope: &Scope<'a>,
+        constituent: &mut WithPos<SelectedName>,
+        entity_class: Option<EntityClass>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let visible = match self.resolve_selected_name(scope, constituent) {
+            Ok(visible) => visible,
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                return Ok(());
+            }
+        };
+
+        if let Some(entity_class) = entity_class {
+            match visible {
+                NamedEntities::Single(ent) => {
+                    if ent.entity_class() != Some(entity_class) {
+                        diagnostics.push(Diagnostic::entity_class_mismatch(
+                            &constituent.pos,
+                            ent,
+                            entity_class,
+                        ));
+                    }
+                }
+                NamedEntities::Overloaded(overloaded) => {
+                    let candidates: Vec<_> = overloaded
+                        .entities()
+                        .filter(|ent| ent.entity_class() == Some(entity_class))
+                        .collect();
+
+                    match candidates.as_slice() {
+                        [ent] => constituent.set_unique_reference(ent),
+                        [] => diagnostics.push(Diagnostic::entity_class_mismatch(
+                            &constituent.pos,
+                            &overloaded.first(),
+                            entity_class,
+                        )),
+                        // A group constituent has no signature to disambiguate with
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The entity class of the constituent at the index, the last entry is repeated if it has a box
+fn constituent_class(entries: &[EntityClassEntry], idx: usize) -> Option<EntityClass> {
+    if let Some(entry) = entries.get(idx) {
+        Some(entry.entity_class)
+    } else {
+        entries
+            .last()
+            .filter(|entry| entry.has_box)
+            .map(|entry| entry.entity_class)
+    }
+}
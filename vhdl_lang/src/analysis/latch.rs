@@ -0,0 +1,930 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Detection of latches inferred by combinational processes
+//!
+//! A process is considered combinational when it has a sensitivity list and no wait statements,
+//! or when its only wait statement is a trailing 'wait on' without condition or timeout.
+//! Processes that test for a clock edge are considered to be clocked and are not checked.
+//! The check is only made when the inferred_latches lint is enabled.
+
+use super::analyze::*;
+use super::named_entity::*;
+use super::purity::collect_waits;
+use crate::ast::Range;
+use crate::ast::*;
+use crate::data::*;
+use crate::Lint;
+use fnv::{FnvHashMap, FnvHashSet};
+
+/// The lowest and highest index of consecutive array elements
+type Interval = (i64, i64);
+
+/// The objects that are definitely assigned at a point of the process
+#[derive(Clone, Default)]
+struct Assigned {
+    objects: FnvHashSet<EntityId>,
+    // The statically known index ranges assigned of arrays that are only assigned in part
+    elements: FnvHashMap<EntityId, Vec<Interval>>,
+}
+
+impl Assigned {
+    fn contains(&self, id: &EntityId) -> bool {
+        self.objects.contains(id)
+    }
+
+    fn insert(&mut self, id: EntityId) {
+        self.elements.remove(&id);
+        self.objects.insert(id);
+    }
+
+    /// Add the elements from low to high of an array with the index range, the array is
+    /// assigned once every element of the range is
+    fn insert_elements(&mut self, id: EntityId, elements: Interval, range: Interval) {
+        if self.objects.contains(&id) {
+            return;
+        }
+
+        let intervals = self.elements.entry(id).or_default();
+        intervals.push(elements);
+        intervals.sort_unstable();
+
+        let mut next = range.0;
+        for (low, high) in intervals.iter() {
+            if *low > next {
+                break;
+            }
+            next = next.max(high.saturating_add(1));
+        }
+
+        if next > range.1 {
+            self.insert(id);
+        }
+    }
+
+    fn intervals(&self, id: &EntityId) -> Option<Vec<Interval>> {
+        if self.objects.contains(id) {
+            Some(vec![(i64::MIN, i64::MAX)])
+        } else {
+            self.elements.get(id).cloned()
+        }
+    }
+
+    /// The objects and elements that are assigned in both
+    fn intersection(&self, other: &Assigned) -> Assigned {
+        let mut result = Assigned {
+            objects: self.objects.intersection(&other.objects).cloned().collect(),
+            elements: FnvHashMap::default(),
+        };
+
+        for id in self.elements.keys().chain(other.elements.keys()) {
+            if let (Some(lhs), Some(rhs)) = (self.intervals(id), other.intervals(id)) {
+                let mut intervals = Vec::new();
+                for (llow, lhigh) in lhs.iter() {
+                    for (rlow, rhigh) in rhs.iter() {
+                        let (low, high) = (*llow.max(rlow), *lhigh.min(rhigh));
+                        if low <= high {
+                            intervals.push((low, high));
+                        }
+                    }
+                }
+                if !intervals.is_empty() {
+                    result.elements.insert(*id, intervals);
+                }
+            }
+        }
+        result
+    }
+}
+
+/// The values a for loop without exit or next statements iterates over
+#[derive(Clone, Copy)]
+enum LoopRange {
+    // The 'range or 'reverse_range of an object
+    RangeOf(EntityId),
+    Static(i64, i64),
+}
+
+impl<'a> AnalyzeContext<'a> {
+    pub fn check_inferred_latches(
+        &self,
+        process_ent: EntRef<'a>,
+        process: &ProcessStatement,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::InferredLatches) {
+            return;
+        }
+
+        let statements = if let Some(statements) = combinational_statements(process) {
+            statements
+        } else {
+            return;
+        };
+
+        let mut checker = LatchChecker::new(self, process_ent);
+
+        let definite = checker.statements(statements, Assigned::default());
+        if checker.is_clocked {
+            return;
+        }
+
+        let mut reported = FnvHashSet::default();
+        for (pos, id) in checker.reads.iter() {
+            if !reported.insert(*id) || !checker.first_assignment.contains_key(id) {
+                continue;
+            }
+            let ent = self.arena.get(*id);
+            if let Some(obj) = ObjectEnt::from_any(ent) {
+                diagnostics.push(
+                    Diagnostic::warning(
+                        pos,
+                        format!(
+                            "Latch inferred for {} which is read before it is assigned",
+                            obj.describe_name()
+                        ),
+                    )
+                    .opt_related(ent.decl_pos(), "Defined here"),
+                );
+            }
+        }
+
+        let mut latches: Vec<_> = checker
+            .first_assignment
+            .iter()
+            .filter(|(id, _)| !definite.contains(id))
+            .filter_map(|(id, pos)| {
+                let ent = self.arena.get(*id);
+                let obj = ObjectEnt::from_any(ent)?;
+                if obj.class() == ObjectClass::Signal {
+                    Some((pos, ent, obj))
+                } else {
+                    None
+                }
+            })
+            .collect();
+        latches.sort_by_key(|(pos, ..)| (*pos).clone());
+
+        for (pos, ent, obj) in latches {
+            let mut diagnostic = Diagnostic::warning(
+                pos,
+                format!(
+                    "Latch inferred for {} which is not assigned on every path through the process",
+                    obj.describe_name()
+                ),
+            )
+            .opt_related(ent.decl_pos(), "Defined here");
+
+            if let Some((pos, msg)) = checker.find_uncovered(statements, ent.id()) {
+                diagnostic.add_related(pos, msg);
+            }
+            diagnostics.push(diagnostic);
+        }
+    }
+}
+
+/// Returns the statements executed by a combinational process or None if the process is not combinational
+fn combinational_statements(process: &ProcessStatement) -> Option<&[LabeledSequentialStatement]> {
+    let statements = process.statements.as_slice();
+
+    if process.sensitivity_list.is_some() {
+        if contains_wait(statements) {
+            return None;
+        }
+        return Some(statements);
+    }
+
+    let (last, body) = statements.split_last()?;
+    if let SequentialStatement::Wait(ref wait) = last.statement.item {
+        if !wait.sensitivity_clause.is_empty()
+            && wait.condition_clause.is_none()
+            && wait.timeout_clause.is_none()
+            && !contains_wait(body)
+        {
+            return Some(body);
+        }
+    }
+    None
+}
+
+fn contains_wait(statements: &[LabeledSequentialStatement]) -> bool {
+    let mut waits = Vec::new();
+    let mut calls = Vec::new();
+    collect_waits(statements, &mut waits, &mut calls);
+    !waits.is_empty()
+}
+
+fn contains_exit_or_next(statements: &[LabeledSequentialStatement]) -> bool {
+    statements.iter().any(|stmt| match stmt.statement.item {
+        SequentialStatement::Exit(..) | SequentialStatement::Next(..) => true,
+        SequentialStatement::If(ref ifstmt) => {
+            ifstmt
+                .conds
+                .conditionals
+                .iter()
+                .any(|cond| contains_exit_or_next(&cond.item))
+                || ifstmt
+                    .conds
+                    .else_item
+                    .as_ref()
+                    .map(|stmts| contains_exit_or_next(stmts))
+                    .unwrap_or(false)
+        }
+        SequentialStatement::Case(ref case) => case
+            .alternatives
+            .iter()
+            .any(|alt| contains_exit_or_next(&alt.item)),
+        SequentialStatement::Loop(ref lstmt) => contains_exit_or_next(&lstmt.statements),
+        SequentialStatement::Block(ref block) => contains_exit_or_next(&block.statements),
+        _ => false,
+    })
+}
+
+/// An indexed or sliced target only assigns part of the object
+fn is_partial_target(name: &Name) -> bool {
+    match name {
+        Name::CallOrIndexed(..) | Name::Slice(..) => true,
+        Name::Selected(prefix, _) => is_partial_target(&prefix.item),
+        _ => false,
+    }
+}
+
+struct LatchChecker<'c, 'a> {
+    ctx: &'c AnalyzeContext<'a>,
+    process: EntRef<'a>,
+    rising_edge: Symbol,
+    falling_edge: Symbol,
+    is_clocked: bool,
+    // The first target position where an object is assigned within the process
+    first_assignment: FnvHashMap<EntityId, SrcPos>,
+    // Reads of process variables that are not definitely assigned at the point of the read
+    reads: Vec<(SrcPos, EntityId)>,
+    // The parameters of the enclosing for loops and the range they iterate over
+    loops: Vec<(EntityId, LoopRange)>,
+}
+
+impl<'c, 'a> LatchChecker<'c, 'a> {
+    fn new(ctx: &'c AnalyzeContext<'a>, process: EntRef<'a>) -> Self {
+        LatchChecker {
+            ctx,
+            process,
+            rising_edge: ctx.root.symbol_utf8("rising_edge"),
+            falling_edge: ctx.root.symbol_utf8("falling_edge"),
+            is_clocked: false,
+            first_assignment: FnvHashMap::default(),
+            reads: Vec::new(),
+            loops: Vec::new(),
+        }
+    }
+
+    /// A for loop over a static range that is not null executes its body at least once
+    /// unless the body may leave an iteration early
+    fn is_always_executed(&self, lstmt: &LoopStatement) -> bool {
+        if let Some(IterationScheme::For(_, ref drange)) = lstmt.iteration_scheme {
+            self.ctx
+                .static_length(drange)
+                .map(|length| length > 0)
+                .unwrap_or(false)
+                && !contains_exit_or_next(&lstmt.statements)
+        } else {
+            false
+        }
+    }
+
+    /// Walk the statements and return the set of objects that are definitely assigned afterwards
+    fn statements(
+        &mut self,
+        statements: &[LabeledSequentialStatement],
+        mut assigned: Assigned,
+    ) -> Assigned {
+        for stmt in statements.iter() {
+            assigned = self.statement(&stmt.statement, assigned);
+        }
+        assigned
+    }
+
+    fn statement(&mut self, stmt: &WithPos<SequentialStatement>, assigned: Assigned) -> Assigned {
+        match stmt.item {
+            SequentialStatement::VariableAssignment(ref assign) => {
+                let is_definite = self.rhs(&assign.rhs, &assigned, |checker, expr, assigned| {
+                    checker.expr(expr, assigned);
+                    true
+                });
+                self.assign(&assign.target, is_definite, assigned)
+            }
+            SequentialStatement::SignalAssignment(ref assign) => {
+                let is_definite = self.rhs(&assign.rhs, &assigned, |checker, wavf, assigned| {
+                    checker.waveform(wavf, assigned)
+                });
+                self.assign(&assign.target, is_definite, assigned)
+            }
+            SequentialStatement::ProcedureCall(ref pcall) => {
+                let mut assigned = assigned;
+                for (idx, elem) in pcall.item.parameters.iter().enumerate() {
+                    if let ActualPart::Expression(Expression::Name(ref name)) = elem.actual.item {
+                        if let Some(id) = self.base_object(name) {
+                            if self.is_output_formal(&pcall.item, idx, elem) {
+                                self.add_assignment(id, &elem.actual.pos);
+                                assigned.insert(id);
+                                continue;
+                            }
+                        }
+                    }
+                    self.actual(&elem.actual, &assigned);
+                }
+                assigned
+            }
+            SequentialStatement::If(ref ifstmt) => {
+                let mut result: Option<Assigned> = None;
+                for cond in ifstmt.conds.conditionals.iter() {
+                    self.condition(&cond.condition, &assigned);
+                    let branch = self.statements(&cond.item, assigned.clone());
+                    result = Some(intersect(result, branch));
+                }
+
+                let else_branch = if let Some(ref stmts) = ifstmt.conds.else_item {
+                    self.statements(stmts, assigned.clone())
+                } else {
+                    assigned.clone()
+                };
+                intersect(result, else_branch)
+            }
+            SequentialStatement::Case(ref case) => {
+                self.expr(&case.expression, &assigned);
+                let mut result: Option<Assigned> = None;
+                for alternative in case.alternatives.iter() {
+                    let branch = self.statements(&alternative.item, assigned.clone());
+                    result = Some(intersect(result, branch));
+                }
+                result.unwrap_or(assigned)
+            }
+            SequentialStatement::Loop(ref lstmt) => {
+                if let Some(IterationScheme::While(ref cond)) = lstmt.iteration_scheme {
+                    self.condition(cond, &assigned);
+                }
+                let loop_range = self.loop_range(lstmt);
+                if let Some(loop_range) = loop_range {
+                    self.loops.push(loop_range);
+                }
+                let body = self.statements(&lstmt.statements, assigned.clone());
+                if loop_range.is_some() {
+                    self.loops.pop();
+                }
+
+                if self.is_always_executed(lstmt) {
+                    body
+                } else if let Some((_, LoopRange::RangeOf(id))) = loop_range {
+                    // The loop body is only skipped when the object has no elements to assign
+                    let mut assigned = assigned;
+                    if body.contains(&id) {
+                        assigned.insert(id);
+                    }
+                    assigned
+                } else {
+                    // The loop body might not be executed at all
+                    assigned
+                }
+            }
+            SequentialStatement::Block(ref block) => self.statements(&block.statements, assigned),
+            SequentialStatement::Assert(ref assert) => {
+                self.expr(&assert.condition, &assigned);
+                if let Some(ref report) = assert.report {
+                    self.expr(report, &assigned);
+                }
+                assigned
+            }
+            SequentialStatement::Report(ref report) => {
+                self.expr(&report.report, &assigned);
+                assigned
+            }
+            SequentialStatement::Exit(ExitStatement {
+                condition: Some(ref cond),
+                ..
+            })
+            | SequentialStatement::Next(NextStatement {
+                condition: Some(ref cond),
+                ..
+            }) => {
+                self.condition(cond, &assigned);
+                assigned
+            }
+            SequentialStatement::Wait(..)
+            | SequentialStatement::SignalForceAssignment(..)
+            | SequentialStatement::SignalReleaseAssignment(..)
+            | SequentialStatement::Exit(..)
+            | SequentialStatement::Next(..)
+            | SequentialStatement::Return(..)
+            | SequentialStatement::Null => assigned,
+        }
+    }
+
+    /// Analyze the right hand side of an assignment and return true if it always assigns a value
+    fn rhs<T>(
+        &mut self,
+        rhs: &AssignmentRightHand<T>,
+        assigned: &Assigned,
+        item: impl Fn(&mut Self, &T, &Assigned) -> bool,
+    ) -> bool {
+        match rhs {
+            AssignmentRightHand::Simple(value) => item(self, value, assigned),
+            AssignmentRightHand::Conditional(conditionals) => {
+                let mut is_definite = true;
+                for cond in conditionals.conditionals.iter() {
+                    self.condition(&cond.condition, assigned);
+                    is_definite &= item(self, &cond.item, assigned);
+                }
+                if let Some(ref else_item) = conditionals.else_item {
+                    is_definite &= item(self, else_item, assigned);
+                } else {
+                    is_definite = false;
+                }
+                is_definite
+            }
+            AssignmentRightHand::Selected(selection) => {
+                self.expr(&selection.expression, assigned);
+                let mut is_definite = true;
+                for alternative in selection.alternatives.iter() {
+                    is_definite &= item(self, &alternative.item, assigned);
+                }
+                is_definite
+            }
+        }
+    }
+
+    /// Returns false for 'unaffected'
+    fn waveform(&mut self, waveform: &Waveform, assigned: &Assigned) -> bool {
+        match waveform {
+            Waveform::Elements(elems) => {
+                for elem in elems.iter() {
+                    self.expr(&elem.value, assigned);
+                }
+                true
+            }
+            Waveform::Unaffected => false,
+        }
+    }
+
+    fn assign(
+        &mut self,
+        target: &WithPos<Target>,
+        mut is_definite: bool,
+        mut assigned: Assigned,
+    ) -> Assigned {
+        let mut targets = Vec::new();
+        let mut elements = None;
+        match target.item {
+            Target::Name(ref name) => {
+                targets.extend(self.base_object(name));
+                self.target_indexes(name, &assigned);
+                if is_partial_target(name) && !self.is_covered_by_loop(name) {
+                    if is_definite {
+                        elements = self.static_elements(name);
+                    }
+                    is_definite = false;
+                }
+            }
+            Target::Aggregate(ref assocs) => {
+                for assoc in assocs.iter() {
+                    let expr = match assoc {
+                        ElementAssociation::Positional(expr) => expr,
+                        ElementAssociation::Named(_, expr) => expr,
+                    };
+                    if let Expression::Name(ref name) = expr.item {
+                        targets.extend(self.base_object(name));
+                    }
+                }
+            }
+        }
+
+        for id in targets {
+            self.add_assignment(id, &target.pos);
+            if is_definite {
+                assigned.insert(id);
+            }
+        }
+
+        if let Some((id, elements, range)) = elements {
+            assigned.insert_elements(id, elements, range);
+        }
+        assigned
+    }
+
+    /// The parameter of a for loop without exit or next statements and the range it
+    /// iterates over when it is the range of an object or a static range
+    fn loop_range(&self, lstmt: &LoopStatement) -> Option<(EntityId, LoopRange)> {
+        let (param, drange) = match lstmt.iteration_scheme {
+            Some(IterationScheme::For(ref param, ref drange)) => (param.decl?, drange),
+            _ => return None,
+        };
+
+        if contains_exit_or_next(&lstmt.statements) {
+            return None;
+        }
+
+        if let DiscreteRange::Range(Range::Attribute(ref attr)) = drange {
+            if matches!(attr.attr.item, AttributeDesignator::Range(_))
+                && attr.expr.is_none()
+                && !is_partial_target(&attr.name.item)
+            {
+                let id = self.base_object(&attr.name.item)?;
+                return Some((param, LoopRange::RangeOf(id)));
+            }
+        }
+
+        let range = self.ctx.static_drange(drange)?;
+        match (range.low, range.high) {
+            (Some(low), Some(high)) if low <= high => Some((param, LoopRange::Static(low, high))),
+            _ => None,
+        }
+    }
+
+    /// The range of an enclosing loop when the expression is its parameter
+    fn loop_of_parameter(&self, expr: &Expression) -> Option<LoopRange> {
+        if let Expression::Name(ref name) = expr {
+            if let Name::Designator(ref designator) = name.as_ref() {
+                let id = designator.reference?;
+                return self
+                    .loops
+                    .iter()
+                    .rev()
+                    .find(|(param, _)| *param == id)
+                    .map(|(_, range)| *range);
+            }
+        }
+        None
+    }
+
+    /// An object indexed by the parameter of an enclosing loop over the range of the object
+    fn is_covered_by_loop(&self, name: &Name) -> bool {
+        if let Name::CallOrIndexed(ref fcall) = name {
+            if let [AssociationElement {
+                formal: None,
+                actual,
+            }] = fcall.parameters.as_slice()
+            {
+                if let ActualPart::Expression(ref expr) = actual.item {
+                    if let Some(LoopRange::RangeOf(id)) = self.loop_of_parameter(expr) {
+                        return !is_partial_target(&fcall.name.item)
+                            && self.base_object(&fcall.name.item) == Some(id);
+                    }
+                }
+            }
+        }
+        false
+    }
+
+    /// The object, the elements and the index range of the object of an indexed or sliced
+    /// target when they are statically known
+    fn static_elements(&self, name: &Name) -> Option<(EntityId, Interval, Interval)> {
+        let (prefix, elements) = match name {
+            Name::CallOrIndexed(ref fcall) => {
+                let expr = match fcall.parameters.as_slice() {
+                    [AssociationElement {
+                        formal: None,
+                        actual,
+                    }] => match actual.item {
+                        ActualPart::Expression(ref expr) => expr,
+                        ActualPart::Open => return None,
+                    },
+                    _ => return None,
+                };
+
+                let elements = match self.loop_of_parameter(expr) {
+                    Some(LoopRange::Static(low, high)) => (low, high),
+                    Some(LoopRange::RangeOf(_)) => return None,
+                    None => {
+                        let value = self.ctx.static_integer(expr)?;
+                        (value, value)
+                    }
+                };
+                (&fcall.name.item, elements)
+            }
+            Name::Slice(ref prefix, ref drange) => {
+                let range = self.ctx.static_drange(drange)?;
+                (&prefix.item, (range.low?, range.high?))
+            }
+            _ => return None,
+        };
+
+        if is_partial_target(prefix) {
+            return None;
+        }
+
+        let id = self.base_object(prefix)?;
+        let obj = ObjectEnt::from_any(self.ctx.arena.get(id))?;
+        let range = obj.object().subtype.static_range()?;
+        match (range.low, range.high) {
+            (Some(low), Some(high)) if low <= high => Some((id, elements, (low, high))),
+            _ => None,
+        }
+    }
+
+    fn add_assignment(&mut self, id: EntityId, pos: &SrcPos) {
+        self.first_assignment
+            .entry(id)
+            .or_insert_with(|| pos.clone());
+    }
+
+    /// Index expressions of a target are read
+    fn target_indexes(&mut self, name: &Name, assigned: &Assigned) {
+        match name {
+            Name::CallOrIndexed(fcall) => {
+                self.target_indexes(&fcall.name.item, assigned);
+                for elem in fcall.parameters.iter() {
+                    self.actual(&elem.actual, assigned);
+                }
+            }
+            Name::Slice(prefix, _) | Name::Selected(prefix, _) => {
+                self.target_indexes(&prefix.item, assigned)
+            }
+            _ => {}
+        }
+    }
+
+    /// The object that is assigned or read by a name
+    fn base_object(&self, name: &Name) -> Option<EntityId> {
+        self.ctx.object_of_resolved_name(name).map(|obj| obj.id())
+    }
+
+    /// Returns true if the actual is associated with a formal of mode out or inout
+    fn is_output_formal(
+        &self,
+        pcall: &CallOrIndexed,
+        idx: usize,
+        elem: &AssociationElement,
+    ) -> bool {
+        self.ctx
+            .formal_mode(pcall, idx, elem)
+            .map(|mode| matches!(mode, Mode::Out | Mode::InOut))
+            .unwrap_or(false)
+    }
+
+    fn is_process_variable(&self, id: EntityId) -> bool {
+        let ent = self.ctx.arena.get(id);
+        if let Some(obj) = ObjectEnt::from_any(ent) {
+            obj.class() == ObjectClass::Variable
+                && ent.parent.map(|parent| parent.id()) == Some(self.process.id())
+        } else {
+            false
+        }
+    }
+
+    fn condition(&mut self, cond: &WithPos<Expression>, assigned: &Assigned) {
+        if self.is_clock_edge(&cond.item) {
+            self.is_clocked = true;
+        }
+        self.expr(cond, assigned);
+    }
+
+    fn is_clock_edge(&self, expr: &Expression) -> bool {
+        match expr {
+            Expression::Binary(_, left, right) => {
+                self.is_clock_edge(&left.item) || self.is_clock_edge(&right.item)
+            }
+            Expression::Unary(_, expr) => self.is_clock_edge(&expr.item),
+            Expression::Name(name) => match name.as_ref() {
+                Name::CallOrIndexed(fcall) => match fcall.name.item {
+                    Name::Designator(ref designator)
+                    | Name::Selected(
+                        _,
+                        WithPos {
+                            item: ref designator,
+                            ..
+                        },
+                    ) => {
+                        matches!(designator.item, Designator::Identifier(ref sym) if sym == &self.rising_edge || sym == &self.falling_edge)
+                    }
+                    _ => false,
+                },
+                Name::Attribute(attr) => matches!(
+                    attr.attr.item,
+                    AttributeDesignator::Signal(SignalAttribute::Event)
+                        | AttributeDesignator::Signal(SignalAttribute::Stable)
+                ),
+                _ => false,
+            },
+            _ => false,
+        }
+    }
+
+    /// Record reads of process variables that are not definitely assigned
+    fn expr(&mut self, expr: &WithPos<Expression>, assigned: &Assigned) {
+        self.expr_at(&expr.item, &expr.pos, assigned);
+    }
+
+    fn actual(&mut self, actual: &WithPos<ActualPart>, assigned: &Assigned) {
+        if let ActualPart::Expression(ref expr) = actual.item {
+            self.expr_at(expr, &actual.pos, assigned);
+        }
+    }
+
+    fn expr_at(&mut self, expr: &Expression, pos: &SrcPos, assigned: &Assigned) {
+        match expr {
+            Expression::Binary(_, left, right) => {
+                self.expr(left, assigned);
+                self.expr(right, assigned);
+            }
+            Expression::Unary(_, expr) => self.expr(expr, assigned),
+            Expression::Aggregate(assocs) => {
+                for assoc in assocs.iter() {
+                    match assoc {
+                        ElementAssociation::Positional(expr) => self.expr(expr, assigned),
+                        ElementAssociation::Named(_, expr) => self.expr(expr, assigned),
+                    }
+                }
+            }
+            Expression::Qualified(qexpr) => self.expr(&qexpr.expr, assigned),
+            Expression::Conditional(conds) => {
+                for cond in conds.conditionals.iter() {
+                    self.expr(&cond.condition, assigned);
+                    self.expr(&cond.item, assigned);
+                }
+                if let Some(ref else_item) = conds.else_item {
+                    self.expr(else_item, assigned);
+                }
+            }
+            Expression::Name(name) => self.name(name, pos, assigned),
+            Expression::Literal(..) | Expression::New(..) => {}
+        }
+    }
+
+    fn name(&mut self, name: &Name, pos: &SrcPos, assigned: &Assigned) {
+        match name {
+            Name::Designator(_) => {
+                if let Some(id) = self.base_object(name) {
+                    // Only the first read is interesting
+                    if self.is_process_variable(id)
+                        && !assigned.contains(&id)
+                        && !self.reads.iter().any(|(_, read)| *read == id)
+                    {
+                        self.reads.push((pos.clone(), id));
+                    }
+                }
+            }
+            Name::Selected(prefix, _) | Name::Slice(prefix, _) | Name::SelectedAll(prefix) => {
+                self.name(&prefix.item, &prefix.pos, assigned)
+            }
+            Name::CallOrIndexed(fcall) => {
+                self.name(&fcall.name.item, &fcall.name.pos, assigned);
+                for elem in fcall.parameters.iter() {
+                    self.actual(&elem.actual, assigned);
+                }
+            }
+            Name::Attribute(attr) => {
+                if let Some(ref expr) = attr.expr {
+                    self.expr(expr, assigned);
+                }
+            }
+            Name::External(..) => {}
+        }
+    }
+
+    /// Find a branch where the object is not assigned to explain why a latch is inferred
+    fn find_uncovered(
+        &self,
+        statements: &[LabeledSequentialStatement],
+        id: EntityId,
+    ) -> Option<(SrcPos, &'static str)> {
+        let mut found = None;
+        for stmt in statements.iter() {
+            if let Some(uncovered) = self.find_uncovered_stmt(&stmt.statement, id) {
+                found = Some(uncovered);
+            }
+            if self.definitely_assigns(&stmt.statement, id) {
+                return None;
+            }
+        }
+        found
+    }
+
+    fn find_uncovered_stmt(
+        &self,
+        stmt: &WithPos<SequentialStatement>,
+        id: EntityId,
+    ) -> Option<(SrcPos, &'static str)> {
+        match stmt.item {
+            SequentialStatement::If(ref ifstmt) => {
+                let mut assigns_any = false;
+                let mut uncovered = None;
+                for cond in ifstmt.conds.conditionals.iter() {
+                    if self.assigns(&cond.item, id) {
+                        assigns_any = true;
+                        if let Some(found) = self.find_uncovered(&cond.item, id) {
+                            uncovered.get_or_insert(found);
+                        }
+                    } else {
+                        uncovered.get_or_insert((
+                            cond.condition.pos.clone(),
+                            "Not assigned when this condition is true",
+                        ));
+                    }
+                }
+                if let Some(ref stmts) = ifstmt.conds.else_item {
+                    if self.assigns(stmts, id) {
+                        assigns_any = true;
+                        if let Some(found) = self.find_uncovered(stmts, id) {
+                            uncovered.get_or_insert(found);
+                        }
+                    } else {
+                        uncovered
+                            .get_or_insert((stmt.pos.clone(), "Not assigned in the else branch"));
+                    }
+                } else if assigns_any {
+                    return Some((stmt.pos.clone(), "Not assigned when no condition is true"));
+                }
+                if assigns_any {
+                    uncovered
+                } else {
+                    None
+                }
+            }
+            SequentialStatement::Case(ref case) => {
+                let mut assigns_any = false;
+                let mut uncovered = None;
+                for alternative in case.alternatives.iter() {
+                    if self.assigns(&alternative.item, id) {
+                        assigns_any = true;
+                        if let Some(found) = self.find_uncovered(&alternative.item, id) {
+                            uncovered.get_or_insert(found);
+                        }
+                    } else if let Some(choice) = alternative.choices.first() {
+                        uncovered
+                            .get_or_insert((choice.pos.clone(), "Not assigned for this choice"));
+                    }
+                }
+                if assigns_any {
+                    uncovered
+                } else {
+                    None
+                }
+            }
+            SequentialStatement::Block(ref block) => self.find_uncovered(&block.statements, id),
+            SequentialStatement::Loop(ref lstmt) => {
+                // A loop over the range of the object is only skipped when it has no elements
+                let is_range_of_object = matches!(
+                    self.loop_range(lstmt),
+                    Some((_, LoopRange::RangeOf(obj))) if obj == id
+                );
+                if self.is_always_executed(lstmt) || is_range_of_object {
+                    self.find_uncovered(&lstmt.statements, id)
+                } else if self.assigns(&lstmt.statements, id) {
+                    Some((
+                        stmt.pos.clone(),
+                        "Not assigned when the loop body is not executed",
+                    ))
+                } else {
+                    None
+                }
+            }
+            SequentialStatement::SignalAssignment(SignalAssignment {
+                ref target,
+                rhs: AssignmentRightHand::Conditional(ref conds),
+                ..
+            }) if conds.else_item.is_none() && self.targets(target, id) => {
+                Some((stmt.pos.clone(), "Not assigned when no condition is true"))
+            }
+            SequentialStatement::SignalAssignment(SignalAssignment { ref target, .. })
+            | SequentialStatement::VariableAssignment(VariableAssignment { ref target, .. }) => {
+                match target.item {
+                    Target::Name(ref name)
+                        if is_partial_target(name) && self.base_object(name) == Some(id) =>
+                    {
+                        Some((target.pos.clone(), "Only part of the object is assigned"))
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn definitely_assigns(&self, stmt: &WithPos<SequentialStatement>, id: EntityId) -> bool {
+        let mut checker = LatchChecker::new(self.ctx, self.process);
+        checker.statement(stmt, Assigned::default()).contains(&id)
+    }
+
+    /// Returns true if any statement assigns the object on some path
+    fn assigns(&self, statements: &[LabeledSequentialStatement], id: EntityId) -> bool {
+        let mut checker = LatchChecker::new(self.ctx, self.process);
+        checker.statements(statements, Assigned::default());
+        checker.first_assignment.contains_key(&id)
+    }
+
+    fn targets(&self, target: &WithPos<Target>, id: EntityId) -> bool {
+        match target.item {
+            Target::Name(ref name) => self.base_object(name) == Some(id),
+            Target::Aggregate(..) => false,
+        }
+    }
+}
+
+fn intersect(result: Option<Assigned>, branch: Assigned) -> Assigned {
+    if let Some(result) = result {
+        result.intersection(&branch)
+    } else {
+        branch
+    }
+}
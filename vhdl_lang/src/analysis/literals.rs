@@ -18,11 +18,13 @@ impl<'a> AnalyzeContext<'a> {
         &self,
         scope: &Scope<'a>,
         target_type: TypeEnt<'a>,
+        static_length: Option<u64>,
         pos: &SrcPos,
         literal: &mut Literal,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
         let target_base = target_type.base_type();
+        check_literal_length(literal, static_length, pos, diagnostics);
 
         match literal {
             Literal::AbstractLiteral(abst) => match abst {
@@ -183,6 +185,31 @@ impl<'a> AnalyzeContext<'a> {
     }
 }
 
+/// String and bit string literals must have the same length as a constrained target subtype
+fn check_literal_length(
+    literal: &Literal,
+    static_length: Option<u64>,
+    pos: &SrcPos,
+    diagnostics: &mut dyn DiagnosticHandler,
+) {
+    let (kind, length) = match literal {
+        Literal::String(string_lit) => ("String", string_lit.len() as u64),
+        Literal::BitString(bitstring) => ("Bit string", bitstring.expanded_length()),
+        _ => return,
+    };
+
+    if let Some(expected) = static_length {
+        if expected != length {
+            diagnostics.error(
+                pos,
+                format!(
+                    "{kind} literal of length {length} does not match target subtype of length {expected}"
+                ),
+            );
+        }
+    }
+}
+
 /// Must be an array type with a single index of enum type
 fn as_single_index_enum_array(typ: TypeEnt) -> Option<(TypeEnt, &FnvHashSet<Designator>)> {
     if let Type::Array {
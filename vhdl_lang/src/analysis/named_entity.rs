@@ -8,7 +8,8 @@ use super::formal_region::FormalRegion;
 use super::region::Region;
 use crate::ast::ExternalObjectClass;
 use crate::ast::{
-    AnyPrimaryUnit, Designator, HasIdent, Ident, ObjectClass, SubprogramDeclaration, WithDecl,
+    AnyPrimaryUnit, Designator, EntityClass, EntityClassEntry, HasIdent, Ident, ObjectClass,
+    SubprogramDeclaration, WithDecl,
 };
 use crate::data::*;
 
@@ -49,6 +50,12 @@ pub enum AnyEntKind<'a> {
     LoopParameter(Option<BaseType<'a>>),
     PhysicalLiteral(TypeEnt<'a>),
     DeferredConstant(Subtype<'a>),
+    /// A mode view of a record type
+    View(TypeEnt<'a>),
+    Psl(Psl),
+    /// The entity classes of the constituents of a group
+    GroupTemplate(Vec<EntityClassEntry>),
+    Group,
     Library,
     Design(Design<'a>),
 }
@@ -110,6 +117,10 @@ impl<'a> AnyEntKind<'a> {
             Object(object) => object.class.describe(),
             PhysicalLiteral(..) => "physical literal",
             DeferredConstant(..) => "deferred constant",
+            View(..) => "view",
+            Psl(psl) => psl.describe(),
+            GroupTemplate(..) => "group template",
+            Group => "group",
             Library => "library",
             Design(design) => design.describe(),
             Type(typ) => typ.describe(),
@@ -250,14 +261,16 @@ impl<'a> AnyEnt<'a> {
     pub fn is_subprogram(&self) -> bool {
         matches!(
             self.kind,
-            AnyEntKind::Overloaded(Overloaded::Subprogram(..))
+            AnyEntKind::Overloaded(Overloaded::Subprogram(..) | Overloaded::UninstSubprogram(..))
         )
     }
 
     pub fn is_subprogram_decl(&self) -> bool {
         matches!(
             self.kind,
-            AnyEntKind::Overloaded(Overloaded::SubprogramDecl(..))
+            AnyEntKind::Overloaded(
+                Overloaded::SubprogramDecl(..) | Overloaded::UninstSubprogramDecl(..)
+            )
         )
     }
 
@@ -374,6 +387,59 @@ impl<'a> AnyEnt<'a> {
         self.as_actual().kind()
     }
 
+    /// The entity class used to denote the entity in attribute specifications and groups
+    pub fn entity_class(&self) -> Option<EntityClass> {
+        match self.actual_kind() {
+            AnyEntKind::ObjectAlias { base_object, .. } => base_object.entity_class(),
+            AnyEntKind::ExternalAlias { class, .. } => Some(match class {
+                ExternalObjectClass::Constant => EntityClass::Constant,
+                ExternalObjectClass::Signal => EntityClass::Signal,
+                ExternalObjectClass::Variable => EntityClass::Variable,
+            }),
+            AnyEntKind::Object(object) => Some(match object.class {
+                ObjectClass::Signal => EntityClass::Signal,
+                ObjectClass::Constant => EntityClass::Constant,
+                ObjectClass::Variable | ObjectClass::SharedVariable => EntityClass::Variable,
+            }),
+            AnyEntKind::DeferredConstant(..) | AnyEntKind::LoopParameter(..) => {
+                Some(EntityClass::Constant)
+            }
+            AnyEntKind::File(..) | AnyEntKind::InterfaceFile(..) => Some(EntityClass::File),
+            AnyEntKind::Component(..) => Some(EntityClass::Component),
+            AnyEntKind::Overloaded(Overloaded::EnumLiteral(..)) => Some(EntityClass::Literal),
+            AnyEntKind::Overloaded(overloaded) => {
+                if overloaded.signature().return_type().is_some() {
+                    Some(EntityClass::Function)
+                } else {
+                    Some(EntityClass::Procedure)
+                }
+            }
+            AnyEntKind::Type(Type::Subtype(..)) => Some(EntityClass::Subtype),
+            AnyEntKind::Type(..) => Some(EntityClass::Type),
+            AnyEntKind::Concurrent(..) | AnyEntKind::Sequential(..) => Some(EntityClass::Label),
+            AnyEntKind::PhysicalLiteral(..) => Some(EntityClass::Units),
+            AnyEntKind::View(..) => Some(EntityClass::View),
+            AnyEntKind::Psl(Psl::Property) => Some(EntityClass::Property),
+            AnyEntKind::Psl(Psl::Sequence) => Some(EntityClass::Sequence),
+            AnyEntKind::Group => Some(EntityClass::Group),
+            AnyEntKind::Design(design) => match design {
+                Design::Entity(..) => Some(EntityClass::Entity),
+                Design::Architecture(..) => Some(EntityClass::Architecture),
+                Design::Configuration => Some(EntityClass::Configuration),
+                Design::Package(..)
+                | Design::PackageBody
+                | Design::UninstPackage(..)
+                | Design::PackageInstance(..) => Some(EntityClass::Package),
+                Design::Context(..) | Design::VerificationUnit(..) => None,
+            },
+            AnyEntKind::Psl(Psl::Parameter)
+            | AnyEntKind::GroupTemplate(..)
+            | AnyEntKind::Attribute(..)
+            | AnyEntKind::ElementDeclaration(..)
+            | AnyEntKind::Library => None,
+        }
+    }
+
     /// Returns true if self is alias of other
     pub fn is_alias_of(&self, other: &AnyEnt) -> bool {
         match self.kind() {
@@ -517,6 +583,7 @@ pub enum Sequential {
     Loop,
     If,
     Case,
+    Block,
 }
 
 impl Sequential {
@@ -525,6 +592,24 @@ impl Sequential {
             Sequential::Case => "case",
             Sequential::If => "if",
             Sequential::Loop => "loop",
+            Sequential::Block => "block",
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum Psl {
+    Property,
+    Sequence,
+    Parameter,
+}
+
+impl Psl {
+    fn describe(&self) -> &'static str {
+        match self {
+            Psl::Property => "property",
+            Psl::Sequence => "sequence",
+            Psl::Parameter => "PSL parameter",
         }
     }
 }
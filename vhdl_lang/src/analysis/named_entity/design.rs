@@ -21,13 +21,14 @@ use crate::SrcPos;
 
 pub enum Design<'a> {
     Entity(Visibility<'a>, Region<'a>),
-    Architecture(DesignEnt<'a>),
+    Architecture(Visibility<'a>, Region<'a>, DesignEnt<'a>),
     Configuration,
     Package(Visibility<'a>, Region<'a>),
     PackageBody,
     UninstPackage(Visibility<'a>, Region<'a>),
     PackageInstance(Region<'a>),
     Context(Region<'a>),
+    VerificationUnit(Region<'a>),
 }
 
 impl<'a> Design<'a> {
@@ -42,6 +43,7 @@ impl<'a> Design<'a> {
             UninstPackage(..) => "uninstantiated package",
             PackageInstance(..) => "package instance",
             Context(..) => "context",
+            VerificationUnit(..) => "verification unit",
         }
     }
 }
@@ -10,6 +10,7 @@ use crate::analysis::AnyEntKind;
 use crate::ast::InterfaceType;
 use crate::ast::Mode;
 use crate::ast::ObjectClass;
+use crate::ast::SignalKind;
 
 use super::AnyEnt;
 use super::EntRef;
@@ -124,6 +125,10 @@ pub struct Object<'a> {
     pub iface: Option<ObjectInterface>,
     pub subtype: Subtype<'a>,
     pub has_default: bool,
+    /// A guarded signal has a signal kind
+    pub signal_kind: Option<SignalKind>,
+    /// The value of a constant of an integer type when it is statically known
+    pub static_value: Option<i64>,
 }
 
 impl<'a> Object<'a> {
@@ -133,6 +138,8 @@ impl<'a> Object<'a> {
             iface: Some(ObjectInterface::Parameter(Mode::In)),
             subtype,
             has_default: false,
+            signal_kind: None,
+            static_value: None,
         }
     }
 
@@ -156,6 +163,10 @@ impl<'a> Object<'a> {
     pub fn mode(&self) -> Option<Mode> {
         self.iface.map(|i| i.mode())
     }
+
+    pub fn is_guarded_signal(&self) -> bool {
+        self.class == ObjectClass::Signal && self.signal_kind.is_some()
+    }
 }
 
 impl ObjectClass {
@@ -9,13 +9,18 @@ use super::BaseType;
 use super::EntRef;
 use super::TypeEnt;
 use crate::analysis::formal_region::FormalRegion;
+use crate::analysis::formal_region::GpkgRegion;
 use crate::analysis::formal_region::InterfaceEnt;
 use crate::ast::Designator;
 
 pub enum Overloaded<'a> {
     SubprogramDecl(Signature<'a>),
     Subprogram(Signature<'a>),
-    InterfaceSubprogram(Signature<'a>),
+    /// A generic subprogram that must be instantiated before it can be called
+    UninstSubprogramDecl(Signature<'a>, GpkgRegion<'a>),
+    UninstSubprogram(Signature<'a>, GpkgRegion<'a>),
+    /// An interface subprogram and whether it has a default
+    InterfaceSubprogram(Signature<'a>, bool),
     EnumLiteral(Signature<'a>),
     Alias(OverloadedEnt<'a>),
 }
@@ -24,7 +29,11 @@ impl<'a> Overloaded<'a> {
     pub fn describe(&self) -> &'static str {
         use Overloaded::*;
         match self {
-            SubprogramDecl(signature) | Subprogram(signature) | InterfaceSubprogram(signature) => {
+            SubprogramDecl(signature)
+            | Subprogram(signature)
+            | UninstSubprogramDecl(signature, _)
+            | UninstSubprogram(signature, _)
+            | InterfaceSubprogram(signature, _) => {
                 if signature.return_type().is_some() {
                     "function"
                 } else {
@@ -38,13 +47,25 @@ impl<'a> Overloaded<'a> {
 
     pub fn signature(&'a self) -> &'a Signature<'a> {
         match self {
-            Overloaded::InterfaceSubprogram(ref signature)
+            Overloaded::InterfaceSubprogram(ref signature, _)
             | Overloaded::Subprogram(ref signature)
             | Overloaded::SubprogramDecl(ref signature)
+            | Overloaded::UninstSubprogramDecl(ref signature, _)
+            | Overloaded::UninstSubprogram(ref signature, _)
             | Overloaded::EnumLiteral(ref signature) => signature,
             Overloaded::Alias(ref overloaded) => overloaded.signature(),
         }
     }
+
+    /// The generics of a subprogram that has not been instantiated
+    pub fn uninstantiated_generics(&'a self) -> Option<&'a GpkgRegion<'a>> {
+        match self {
+            Overloaded::UninstSubprogramDecl(_, ref generics)
+            | Overloaded::UninstSubprogram(_, ref generics) => Some(generics),
+            Overloaded::Alias(ref overloaded) => overloaded.kind().uninstantiated_generics(),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Clone)]
@@ -52,6 +73,8 @@ pub struct Signature<'a> {
     /// Vector of InterfaceObject or InterfaceFile
     pub(crate) formals: FormalRegion<'a>,
     pub(crate) return_type: Option<TypeEnt<'a>>,
+    /// True for functions declared as impure
+    pub(crate) impure: bool,
 }
 
 impl<'a> Signature<'a> {
@@ -59,9 +82,20 @@ impl<'a> Signature<'a> {
         Signature {
             formals,
             return_type: return_type.as_ref().map(TypeEnt::to_owned),
+            impure: false,
         }
     }
 
+    pub fn impure(mut self) -> Signature<'a> {
+        self.impure = true;
+        self
+    }
+
+    /// Returns true if the signature is that of a pure function
+    pub fn is_pure_function(&self) -> bool {
+        self.return_type.is_some() && !self.impure
+    }
+
     pub fn key(&self) -> SignatureKey<'a> {
         let formals = self.formals.iter().map(|formal| formal.base()).collect();
         let return_type = self.return_type.as_ref().map(|ent| ent.base());
@@ -215,6 +249,10 @@ impl<'a> OverloadedEnt<'a> {
         self.return_type().is_some()
     }
 
+    pub fn is_uninstantiated(&self) -> bool {
+        self.kind().uninstantiated_generics().is_some()
+    }
+
     pub fn formals(&self) -> &'a FormalRegion<'a> {
         &self.signature().formals
     }
@@ -229,7 +267,9 @@ impl<'a> OverloadedEnt<'a> {
         let prefix = match self.kind() {
             Overloaded::SubprogramDecl(_)
             | Overloaded::Subprogram(_)
-            | Overloaded::InterfaceSubprogram(_) => {
+            | Overloaded::UninstSubprogramDecl(..)
+            | Overloaded::UninstSubprogram(..)
+            | Overloaded::InterfaceSubprogram(..) => {
                 if matches!(self.designator(), Designator::OperatorSymbol(_)) {
                     "operator "
                 } else if self.is_function() {
@@ -10,6 +10,7 @@ use crate::analysis::named_entity::{AnyEnt, AnyEntKind, EntityId};
 
 use crate::analysis::formal_region::{RecordElement, RecordRegion};
 use crate::analysis::region::{NamedEntities, OverloadedName, Region};
+use crate::analysis::static_expression::StaticRange;
 use crate::ast::WithDecl;
 use crate::ast::{Designator, WithRef};
 use crate::ast::{HasDesignator, Ident};
@@ -27,6 +28,9 @@ pub enum Type<'a> {
         // Indexes are Option<> to handle unknown types
         indexes: Vec<Option<BaseType<'a>>>,
         elem_type: TypeEnt<'a>,
+        // The range of the first index of a constrained array type or of its index subtype
+        // when at least one bound is statically known
+        static_range: Option<StaticRange>,
     },
     Enum(FnvHashSet<Designator>),
     Integer,
@@ -153,6 +157,39 @@ impl<'a> TypeEnt<'a> {
         BaseType::from(*self)
     }
 
+    /// The statically known length of a constrained array subtype
+    pub fn static_length(&self) -> Option<u64> {
+        match self.kind() {
+            Type::Alias(alias) => alias.static_length(),
+            Type::Subtype(subtype) => subtype.static_length(),
+            _ => None,
+        }
+    }
+
+    /// True if the type is a resolved subtype
+    /// Composite types with resolved elements are also considered to be resolved
+    pub fn is_resolved(&self) -> bool {
+        match self.kind() {
+            Type::Alias(alias) => alias.is_resolved(),
+            Type::Subtype(subtype) => subtype.is_resolved(),
+            Type::Array { elem_type, .. } => elem_type.is_resolved(),
+            Type::Record(elems) => {
+                !elems.is_empty() && elems.iter().all(|elem| elem.subtype().is_resolved())
+            }
+            _ => false,
+        }
+    }
+
+    /// The statically known range of a scalar subtype or of the first index of an array subtype
+    pub fn static_range(&self) -> Option<StaticRange> {
+        match self.kind() {
+            Type::Alias(alias) => alias.static_range(),
+            Type::Subtype(subtype) => subtype.static_range(),
+            Type::Array { static_range, .. } => *static_range,
+            _ => None,
+        }
+    }
+
     pub fn accessed_type(&self) -> Option<TypeEnt<'a>> {
         self.base().accessed_type()
     }
@@ -393,11 +430,13 @@ impl<'a> BaseType<'a> {
         if let Type::Array {
             indexes: my_indexes,
             elem_type: my_elem_type,
+            ..
         } = self.kind()
         {
             if let Type::Array {
                 indexes: other_indexes,
                 elem_type: other_elem_type,
+                ..
             } = other.kind()
             {
                 return my_indexes.len() == other_indexes.len()
@@ -433,11 +472,54 @@ impl<'a> Deref for BaseType<'a> {
 #[derive(Clone, Copy)]
 pub struct Subtype<'a> {
     pub(crate) type_mark: TypeEnt<'a>,
+    // The length of the first index range of an array subtype when it is statically known
+    pub(crate) static_length: Option<u64>,
+    // The range of a scalar subtype or the first index range of an array subtype
+    // when at least one bound is statically known
+    pub(crate) static_range: Option<StaticRange>,
+    // The subtype indication has a resolution function or an element resolution
+    pub(crate) resolved: bool,
 }
 
 impl<'a> Subtype<'a> {
     pub fn new(type_mark: TypeEnt<'a>) -> Subtype<'a> {
-        Subtype { type_mark }
+        Subtype {
+            type_mark,
+            static_length: None,
+            static_range: None,
+            resolved: false,
+        }
+    }
+
+    pub fn with_static_length(mut self, static_length: Option<u64>) -> Subtype<'a> {
+        self.static_length = static_length;
+        self
+    }
+
+    pub fn with_static_range(mut self, static_range: Option<StaticRange>) -> Subtype<'a> {
+        self.static_range = static_range;
+        self
+    }
+
+    pub fn with_resolution(mut self, resolved: bool) -> Subtype<'a> {
+        self.resolved = resolved;
+        self
+    }
+
+    /// True if the subtype or the subtype it is declared from is a resolved subtype
+    pub fn is_resolved(&self) -> bool {
+        self.resolved || self.type_mark.is_resolved()
+    }
+
+    /// The statically known length of the subtype or of the subtype it is declared from
+    pub fn static_length(&self) -> Option<u64> {
+        self.static_length
+            .or_else(|| self.type_mark.static_length())
+    }
+
+    /// The statically known range of the subtype or of the subtype it is declared from
+    pub fn static_range(&self) -> Option<StaticRange> {
+        self.static_range.or_else(|| self.type_mark.static_range())
     }
 
     pub fn type_mark(&self) -> TypeEnt<'a> {
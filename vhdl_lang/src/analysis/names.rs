@@ -43,6 +43,15 @@ impl<'a> ObjectBase<'a> {
         }
     }
 
+    pub fn is_guarded_signal(&self) -> bool {
+        match self {
+            ObjectBase::Object(object) | ObjectBase::ObjectAlias(object, _) => {
+                object.object().is_guarded_signal()
+            }
+            ObjectBase::DeferredConstant(..) | ObjectBase::ExternalName(_) => false,
+        }
+    }
+
     // Use whenever the class and mode is relevant to the error
     pub fn describe_class(&self) -> String {
         if let Some(mode) = self.mode() {
@@ -164,6 +173,10 @@ impl<'a> ResolvedName<'a> {
             AnyEntKind::File(_)
             | AnyEntKind::InterfaceFile(_)
             | AnyEntKind::Component(_)
+            | AnyEntKind::View(_)
+            | AnyEntKind::Psl(_)
+            | AnyEntKind::GroupTemplate(_)
+            | AnyEntKind::Group
             | AnyEntKind::PhysicalLiteral(_) => ResolvedName::Final(ent),
             AnyEntKind::Design(_)
             | AnyEntKind::Library
@@ -223,6 +236,10 @@ impl<'a> ResolvedName<'a> {
             | AnyEntKind::Concurrent(_)
             | AnyEntKind::Sequential(_)
             | AnyEntKind::LoopParameter(_)
+            | AnyEntKind::View(_)
+            | AnyEntKind::Psl(_)
+            | AnyEntKind::GroupTemplate(_)
+            | AnyEntKind::Group
             | AnyEntKind::PhysicalLiteral(_) => ResolvedName::Final(ent),
             AnyEntKind::Attribute(_) | AnyEntKind::ElementDeclaration(_) => {
                 return Err(format!(
@@ -469,9 +486,10 @@ impl<'a> AnalyzeContext<'a> {
                             }
                             Ok(Some(ent.return_type().unwrap()))
                         }
-                        Disambiguated::Ambiguous(overloaded) => {
-                            Err(Diagnostic::ambiguous_call(des, overloaded))
-                        }
+                        Disambiguated::Ambiguous(overloaded) => Err(Diagnostic::ambiguous_call(
+                            des,
+                            overloaded.into_iter().map(|ent| (ent, Vec::new())),
+                        )),
                     }
                 } else {
                     Ok(None)
@@ -559,6 +577,53 @@ impl<'a> AnalyzeContext<'a> {
 
     // Apply suffix when prefix is known to have a type
     // The prefix may be an object or a function return value
+    /// An index of an object with a statically known index range must be within the range
+    fn check_static_index(
+        &self,
+        oname: &ObjectName,
+        assocs: &[AssociationElement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let obj = if let (ObjectBase::Object(obj), None) = (&oname.base, oname.type_mark) {
+            obj
+        } else {
+            return;
+        };
+
+        let range = if let Some(range) = obj.object().subtype.static_range() {
+            range
+        } else {
+            return;
+        };
+
+        // Only single integer indexes are evaluated statically
+        let is_integer_index = match obj.type_mark().array_type() {
+            Some((_, indexes)) => matches!(
+                indexes.as_slice(),
+                [Some(index_type)] if index_type.is_any_integer()
+            ),
+            None => false,
+        };
+
+        if !is_integer_index {
+            return;
+        }
+
+        if let [AssociationElement {
+            formal: None,
+            actual,
+        }] = assocs
+        {
+            if let ActualPart::Expression(ref expr) = actual.item {
+                if let Some(value) = self.static_integer(expr) {
+                    if let Some(message) = range.check_value(value, "the index range") {
+                        diagnostics.error(&actual.pos, message);
+                    }
+                }
+            }
+        }
+    }
+
     fn resolve_typed_suffix(
         &self,
         scope: &Scope<'a>,
@@ -901,6 +966,18 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
 
+            AttributeDesignator::Converse => {
+                if matches!(prefix, ResolvedName::Final(ent) if matches!(ent.kind(), AnyEntKind::View(_)))
+                {
+                    diagnostics.error(name_pos, "Mode view cannot be used as an expression");
+                } else {
+                    diagnostics.push(Diagnostic::cannot_be_prefix_of_attribute(
+                        name_pos, prefix, attr,
+                    ));
+                }
+                Err(EvalError::Unknown)
+            }
+
             AttributeDesignator::Ident(_) => {
                 diagnostics.error(
                     &attr.attr.pos,
@@ -961,11 +1038,24 @@ impl<'a> AnalyzeContext<'a> {
                 });
             }
             SplitName::External(ename) => {
-                let ExternalName { subtype, class, .. } = ename;
+                let ExternalName {
+                    subtype,
+                    class,
+                    path,
+                } = ename;
+                let subtype_pos = subtype.type_mark.pos.clone();
                 let subtype = catch_analysis_err(
                     self.resolve_subtype_indication(scope, subtype, diagnostics),
                     diagnostics,
                 )?;
+                self.resolve_external_path(
+                    scope,
+                    *class,
+                    path,
+                    subtype.type_mark(),
+                    &subtype_pos,
+                    diagnostics,
+                )?;
                 return Ok(ResolvedName::ObjectName(ObjectName {
                     base: ObjectBase::ExternalName(*class),
                     type_mark: Some(subtype.type_mark().to_owned()),
@@ -1006,7 +1096,10 @@ impl<'a> AnalyzeContext<'a> {
                         Disambiguated::Ambiguous(ents) => {
                             if let Some(types) = ambiguous_functions_to_types(&ents) {
                                 if has_suffix || ttyp.is_some() {
-                                    diagnostics.push(Diagnostic::ambiguous_call(des, ents));
+                                    diagnostics.push(Diagnostic::ambiguous_call(
+                                        des,
+                                        ents.into_iter().map(|ent| (ent, Vec::new())),
+                                    ));
                                 }
                                 resolved =
                                     ResolvedName::Expression(DisambiguatedType::Ambiguous(types));
@@ -1075,7 +1168,9 @@ impl<'a> AnalyzeContext<'a> {
                         Some(Disambiguated::Ambiguous(ents)) => {
                             if let Some(types) = ambiguous_functions_to_types(&ents) {
                                 if has_suffix || ttyp.is_some() {
-                                    diagnostics.push(Diagnostic::ambiguous_call(des, ents));
+                                    diagnostics.push(
+                                        self.ambiguous_call(scope, name_pos, des, ents, assocs)?,
+                                    );
                                 }
 
                                 resolved =
@@ -1115,7 +1210,7 @@ impl<'a> AnalyzeContext<'a> {
                 }
             }
             ResolvedName::ObjectName(oname) => {
-                match catch_analysis_err(
+                let typ_or_method = catch_analysis_err(
                     self.resolve_typed_suffix(
                         scope,
                         &prefix.pos,
@@ -1125,7 +1220,14 @@ impl<'a> AnalyzeContext<'a> {
                         diagnostics,
                     ),
                     diagnostics,
-                )? {
+                )?;
+
+                // The indexes refer to constants only after they have been analyzed
+                if let Suffix::CallOrIndexed(ref assocs) = suffix {
+                    self.check_static_index(&oname, assocs, diagnostics);
+                }
+
+                match typ_or_method {
                     Some(TypeOrMethod::Type(typ)) => {
                         resolved = ResolvedName::ObjectName(oname.with_suffix(typ));
                     }
@@ -1277,6 +1379,33 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// The object denoted by a name where the references have already been resolved
+    /// Returns None when the name does not denote an object such as for function calls
+    pub fn object_of_resolved_name(&self, name: &Name) -> Option<ObjectEnt<'a>> {
+        let id = match name {
+            Name::Designator(designator) => designator.reference?,
+            Name::Selected(prefix, suffix) => {
+                if let Some(obj) = suffix.item.reference.and_then(|id| self.object_of_id(id)) {
+                    return Some(obj);
+                }
+                return self.object_of_resolved_name(&prefix.item);
+            }
+            Name::Slice(prefix, _) => return self.object_of_resolved_name(&prefix.item),
+            Name::CallOrIndexed(fcall) => return self.object_of_resolved_name(&fcall.name.item),
+            Name::SelectedAll(..) | Name::Attribute(..) | Name::External(..) => return None,
+        };
+        self.object_of_id(id)
+    }
+
+    fn object_of_id(&self, id: EntityId) -> Option<ObjectEnt<'a>> {
+        let ent = self.arena.get(id);
+        match ent.kind() {
+            AnyEntKind::Object(..) => ObjectEnt::from_any(ent),
+            AnyEntKind::ObjectAlias { base_object, .. } => Some(*base_object),
+            _ => None,
+        }
+    }
+
     pub fn type_name(
         &self,
         scope: &Scope<'a>,
@@ -1529,7 +1658,7 @@ impl<'a> AnalyzeContext<'a> {
     }
 }
 
-fn plural(singular: &'static str, plural: &'static str, count: usize) -> &'static str {
+pub(super) fn plural(singular: &'static str, plural: &'static str, count: usize) -> &'static str {
     if count == 1 {
         singular
     } else {
@@ -1609,15 +1738,42 @@ impl Diagnostic {
         Diagnostic::warning(pos, format!("Internal error, unreachable code {expected}"))
     }
 
+    /// Explain per candidate of an ambiguous call which actuals and return type it could match
     pub fn ambiguous_call<'a>(
         call_name: &WithPos<Designator>,
-        candidates: impl IntoIterator<Item = OverloadedEnt<'a>>,
+        candidates: impl IntoIterator<Item = (OverloadedEnt<'a>, Vec<(SrcPos, String)>)>,
     ) -> Diagnostic {
         let mut diag = Diagnostic::error(
             &call_name.pos,
             format!("Ambiguous call to {}", call_name.item.describe()),
         );
-        diag.add_subprogram_candidates("Migth be", candidates);
+
+        let mut candidates: Vec<_> = candidates.into_iter().collect();
+        candidates.sort_by(|(x, _), (y, _)| x.decl_pos().cmp(&y.decl_pos()));
+
+        // The return type only explains the ambiguity when it differs between candidates
+        let return_types: FnvHashSet<_> = candidates
+            .iter()
+            .filter_map(|(ent, _)| ent.return_type())
+            .map(|typ| typ.base().id())
+            .collect();
+
+        for (ent, reasons) in candidates {
+            if let Some(decl_pos) = ent.decl_pos() {
+                diag.add_related(decl_pos, format!("Might be {}", ent.describe()));
+                for (pos, reason) in reasons {
+                    diag.add_related(pos, reason);
+                }
+                if return_types.len() > 1 {
+                    if let Some(return_type) = ent.return_type() {
+                        diag.add_related(
+                            &call_name.pos,
+                            format!("Could return {}", return_type.describe()),
+                        );
+                    }
+                }
+            }
+        }
         diag
     }
 }
@@ -2836,11 +2992,19 @@ type enum_t is (alpha, beta);
                 Diagnostic::error(code.s1("myfun"), "Ambiguous call to 'myfun'")
                     .related(
                         decl.s("myfun", 1),
-                        "Migth be function myfun[INTEGER return INTEGER]",
+                        "Might be function myfun[INTEGER return INTEGER]",
+                    )
+                    .related(
+                        code.s1("f1"),
+                        "Could be integer type 'INTEGER' of parameter 'arg'",
                     )
                     .related(
                         decl.s("myfun", 2),
-                        "Migth be function myfun[CHARACTER return INTEGER]",
+                        "Might be function myfun[CHARACTER return INTEGER]",
+                    )
+                    .related(
+                        code.s1("f1"),
+                        "Could be type 'CHARACTER' of parameter 'arg'",
                     ),
             ],
         )
@@ -2875,12 +3039,22 @@ type enum_t is (alpha, beta);
                 Diagnostic::error(code.s1("myfun"), "Ambiguous call to 'myfun'")
                     .related(
                         decl.s("myfun", 1),
-                        "Migth be function myfun[INTEGER return rec1_t]",
+                        "Might be function myfun[INTEGER return rec1_t]",
+                    )
+                    .related(
+                        code.s1("0"),
+                        "Could be integer type 'INTEGER' of parameter 'arg'",
                     )
+                    .related(code.s1("myfun"), "Could return record type 'rec1_t'")
                     .related(
                         decl.s("myfun", 2),
-                        "Migth be function myfun[INTEGER return rec2_t]",
-                    ),
+                        "Might be function myfun[INTEGER return rec2_t]",
+                    )
+                    .related(
+                        code.s1("0"),
+                        "Could be integer type 'INTEGER' of parameter 'arg'",
+                    )
+                    .related(code.s1("myfun"), "Could return record type 'rec2_t'"),
             ],
         )
     }
@@ -11,6 +11,7 @@ use super::association::ResolvedFormal;
 use super::expression::ExpressionType;
 use super::formal_region::InterfaceEnt;
 use super::named_entity::*;
+use super::names::plural;
 use super::region::*;
 use crate::ast::search::clear_references;
 use crate::ast::*;
@@ -44,6 +45,9 @@ enum Rejection<'a> {
 
     // The amount of actuals or named actuals do not match formals
     MissingFormals(Vec<InterfaceEnt<'a>>),
+
+    // The subprogram is generic and must be instantiated before it is called
+    Uninstantiated,
 }
 
 struct Candidate<'a> {
@@ -133,6 +137,10 @@ impl<'a> Candidates<'a> {
                                 cand.ent.describe()
                             ),
                         ),
+                        Rejection::Uninstantiated => diag.add_related(
+                            decl_pos,
+                            format!("Uninstantiated {} cannot be called", cand.ent.describe()),
+                        ),
                         Rejection::MissingFormals(missing) => {
                             for formal in missing.iter() {
                                 diag.add_related(
@@ -165,6 +173,13 @@ impl<'a> Disambiguated<'a> {
     }
 }
 
+// A candidate of a call that was rejected during disambiguation
+// The reasons explain which formals or actuals did not match
+struct RejectedCall<'a> {
+    ent: OverloadedEnt<'a>,
+    reasons: Vec<(SrcPos, String)>,
+}
+
 #[derive(Clone)]
 pub(super) struct ResolvedCall<'a> {
     pub subpgm: OverloadedEnt<'a>,
@@ -216,20 +231,31 @@ impl<'a> AnalyzeContext<'a> {
         call_pos: &SrcPos,
         candidates: &[OverloadedEnt<'a>],
         assocs: &mut [AssociationElement],
+        rejected: &mut Vec<RejectedCall<'a>>,
     ) -> EvalResult<Vec<ResolvedCall<'a>>> {
         let mut result = Vec::with_capacity(candidates.len());
         for ent in candidates.iter() {
+            let mut formal_diagnostics = Vec::new();
             if let Some(resolved) = as_fatal(self.resolve_association_formals(
                 call_pos,
                 ent.formals(),
                 scope,
                 assocs,
-                &mut NullDiagnostics,
+                &mut formal_diagnostics,
             ))? {
                 result.push(ResolvedCall {
                     subpgm: *ent,
                     formals: resolved,
                 });
+            } else {
+                rejected.push(RejectedCall {
+                    ent: *ent,
+                    reasons: formal_diagnostics
+                        .into_iter()
+                        .filter(|diag| diag.severity == Severity::Error)
+                        .map(|diag| (diag.pos, diag.message))
+                        .collect(),
+                });
             }
 
             for elem in assocs.iter_mut() {
@@ -264,6 +290,38 @@ impl<'a> AnalyzeContext<'a> {
         Ok(actual_types)
     }
 
+    // The actuals whose type does not match the type of the associated formal
+    fn assoc_type_mismatches(
+        &self,
+        resolved: &ResolvedCall<'a>,
+        assocs: &[AssociationElement],
+        actual_types: &[Option<ExpressionType<'a>>],
+    ) -> Vec<(SrcPos, String)> {
+        let matcher = self.implicit_matcher();
+        actual_types
+            .iter()
+            .zip(assocs.iter())
+            .zip(resolved.formals.iter())
+            .filter_map(|((actual_type, assoc), formal)| {
+                let actual_type = actual_type.as_ref()?;
+                let formal_type = formal.type_mark();
+                if matcher.is_possible(actual_type, formal_type.base()) {
+                    None
+                } else {
+                    Some((
+                        assoc.actual.pos.clone(),
+                        format!(
+                            "{} does not match {} of {}",
+                            actual_type.describe(),
+                            formal_type.describe(),
+                            formal.interface().describe()
+                        ),
+                    ))
+                }
+            })
+            .collect()
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn disambiguate(
         &self,
@@ -275,6 +333,25 @@ impl<'a> AnalyzeContext<'a> {
         all_overloaded: Vec<OverloadedEnt<'a>>,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> EvalResult<Disambiguated<'a>> {
+        if all_overloaded.iter().all(|ent| ent.is_uninstantiated()) {
+            diagnostics.push(Diagnostic::could_not_resolve(
+                call_name,
+                all_overloaded.into_iter().map(|ent| RejectedCall {
+                    ent,
+                    reasons: vec![(
+                        call_name.pos.clone(),
+                        "Uninstantiated subprogram cannot be called".to_owned(),
+                    )],
+                }),
+            ));
+            return Err(EvalError::Unknown);
+        }
+
+        let all_overloaded: Vec<_> = all_overloaded
+            .into_iter()
+            .filter(|ent| !ent.is_uninstantiated())
+            .collect();
+
         if all_overloaded.len() == 1 {
             let ent = all_overloaded[0];
             self.check_call(scope, call_pos, ent, assocs, diagnostics)?;
@@ -290,11 +367,23 @@ impl<'a> AnalyzeContext<'a> {
             self.check_call(scope, call_pos, ent, assocs, diagnostics)?;
             return Ok(Disambiguated::Unambiguous(ent));
         } else if ok_kind.is_empty() {
-            diagnostics.push(Diagnostic::could_not_resolve(call_name, all_overloaded));
+            let reason = match kind {
+                SubprogramKind::Function(_) => "Procedure cannot be called as a function",
+                SubprogramKind::Procedure => "Function cannot be called as a procedure",
+            };
+            diagnostics.push(Diagnostic::could_not_resolve(
+                call_name,
+                all_overloaded.into_iter().map(|ent| RejectedCall {
+                    ent,
+                    reasons: vec![(call_name.pos.clone(), reason.to_owned())],
+                }),
+            ));
             return Err(EvalError::Unknown);
         }
 
-        let ok_formals = self.disambiguate_by_assoc_formals(scope, call_pos, &ok_kind, assocs)?;
+        let mut rejected = Vec::new();
+        let ok_formals =
+            self.disambiguate_by_assoc_formals(scope, call_pos, &ok_kind, assocs, &mut rejected)?;
 
         // Only one candidate matched actual/formal profile
         if ok_formals.len() == 1 {
@@ -303,7 +392,7 @@ impl<'a> AnalyzeContext<'a> {
             return Ok(Disambiguated::Unambiguous(ent));
         } else if ok_formals.is_empty() {
             // No candidate matched actual/formal profile
-            diagnostics.push(Diagnostic::could_not_resolve(call_name, ok_kind));
+            diagnostics.push(Diagnostic::could_not_resolve(call_name, rejected));
             return Err(EvalError::Unknown);
         }
 
@@ -320,7 +409,10 @@ impl<'a> AnalyzeContext<'a> {
         } else if ok_assoc_types.is_empty() {
             diagnostics.push(Diagnostic::could_not_resolve(
                 call_name,
-                ok_formals.into_iter().map(|resolved| resolved.subpgm),
+                ok_formals.into_iter().map(|resolved| RejectedCall {
+                    ent: resolved.subpgm,
+                    reasons: self.assoc_type_mismatches(&resolved, assocs, &actual_types),
+                }),
             ));
             return Err(EvalError::Unknown);
         }
@@ -336,9 +428,17 @@ impl<'a> AnalyzeContext<'a> {
                 self.check_call(scope, call_pos, ent, assocs, diagnostics)?;
                 return Ok(Disambiguated::Unambiguous(ent));
             } else if ok_return_type.is_empty() {
+                let reason = if let Some(rtyp) = rtyp {
+                    format!("Return type does not match {}", rtyp.describe())
+                } else {
+                    "Return type does not match".to_owned()
+                };
                 diagnostics.push(Diagnostic::could_not_resolve(
                     call_name,
-                    ok_assoc_types.into_iter().map(|resolved| resolved.subpgm),
+                    ok_assoc_types.into_iter().map(|resolved| RejectedCall {
+                        ent: resolved.subpgm,
+                        reasons: vec![(call_name.pos.clone(), reason.clone())],
+                    }),
                 ));
                 return Err(EvalError::Unknown);
             }
@@ -368,6 +468,99 @@ impl<'a> AnalyzeContext<'a> {
         ))
     }
 
+    /// Explain per candidate of an ambiguous call which formal each actual could be associated with
+    pub fn ambiguous_call(
+        &self,
+        scope: &Scope<'a>,
+        call_pos: &SrcPos,
+        call_name: &WithPos<Designator>,
+        candidates: Vec<OverloadedEnt<'a>>,
+        assocs: &mut [AssociationElement],
+    ) -> FatalResult<Diagnostic> {
+        let mut possible = Vec::with_capacity(candidates.len());
+        for ent in candidates {
+            let mut reasons = Vec::new();
+            if let Some(formals) = as_fatal(self.resolve_association_formals(
+                call_pos,
+                ent.formals(),
+                scope,
+                assocs,
+                &mut NullDiagnostics,
+            ))? {
+                for (assoc, formal) in assocs.iter().zip(formals.iter()) {
+                    if let ActualPart::Expression(_) = assoc.actual.item {
+                        reasons.push((
+                            assoc.actual.pos.clone(),
+                            format!(
+                                "Could be {} of {}",
+                                formal.type_mark().describe(),
+                                formal.interface().describe()
+                            ),
+                        ));
+                    }
+                }
+            }
+
+            for elem in assocs.iter_mut() {
+                clear_references(elem);
+            }
+            possible.push((ent, reasons));
+        }
+
+        Ok(Diagnostic::ambiguous_call(call_name, possible))
+    }
+
+    /// Explain per candidate of an operator which operands did not match the type of their formal
+    #[allow(clippy::too_many_arguments)]
+    pub fn no_match_for_operator(
+        &self,
+        op_pos: &SrcPos,
+        designator: &Designator,
+        ttyp: Option<TypeEnt<'a>>,
+        candidates: Vec<OverloadedEnt<'a>>,
+        operands: &[&mut WithPos<Expression>],
+        operand_types: &[ExpressionType<'a>],
+    ) -> Diagnostic {
+        let matcher = self.implicit_matcher();
+        let rejected = candidates.into_iter().map(|ent| {
+            let mut reasons: Vec<_> = operand_types
+                .iter()
+                .zip(operands.iter())
+                .zip(ent.formals().iter())
+                .filter(|((operand_type, _), formal)| {
+                    !matcher.is_possible(operand_type, formal.type_mark().base())
+                })
+                .map(|((operand_type, operand), formal)| {
+                    (
+                        operand.pos.clone(),
+                        format!(
+                            "{} does not match {}",
+                            operand_type.describe(),
+                            formal.type_mark().describe()
+                        ),
+                    )
+                })
+                .collect();
+
+            if reasons.is_empty() {
+                if let Some(ttyp) = ttyp {
+                    reasons.push((
+                        op_pos.clone(),
+                        format!("Return type does not match {}", ttyp.describe()),
+                    ));
+                }
+            }
+            RejectedCall { ent, reasons }
+        });
+
+        let mut diag = Diagnostic::error(
+            op_pos,
+            format!("Found no match for {}", designator.describe()),
+        );
+        diag.add_rejected_calls(rejected);
+        diag
+    }
+
     pub fn disambiguate_no_actuals(
         &self,
         name: &WithPos<Designator>,
@@ -379,7 +572,9 @@ impl<'a> AnalyzeContext<'a> {
         let tbase = ttyp.map(|ttyp| ttyp.base());
 
         for cand in candidates.remaining() {
-            if !cand.ent.signature().can_be_called_without_actuals() {
+            if cand.ent.is_uninstantiated() {
+                cand.rejection = Some(Rejection::Uninstantiated);
+            } else if !cand.ent.signature().can_be_called_without_actuals() {
                 cand.rejection = Some(Rejection::MissingFormals(
                     cand.ent.signature().formals_without_defaults().collect(),
                 ));
@@ -401,15 +596,61 @@ impl<'a> AnalyzeContext<'a> {
 impl Diagnostic {
     fn could_not_resolve<'a>(
         name: &WithPos<Designator>,
-        rejected: impl IntoIterator<Item = OverloadedEnt<'a>>,
+        rejected: impl IntoIterator<Item = RejectedCall<'a>>,
     ) -> Self {
         let mut diag = Diagnostic::error(
             &name.pos,
             format!("Could not resolve call to '{}'", name.designator()),
         );
-        diag.add_subprogram_candidates("Does not match", rejected);
+        diag.add_rejected_calls(rejected);
+        diag
+    }
+
+    /// Explain per operator of the wrong arity how many operands it takes
+    pub(super) fn no_operator_with_arity<'a>(
+        op_pos: &SrcPos,
+        designator: &Designator,
+        arity: usize,
+        candidates: impl IntoIterator<Item = OverloadedEnt<'a>>,
+    ) -> Diagnostic {
+        let rejected = candidates.into_iter().map(|ent| {
+            let count = ent.formals().len();
+            RejectedCall {
+                ent,
+                reasons: vec![(
+                    op_pos.clone(),
+                    format!(
+                        "Takes {} {} but {} {} given",
+                        count,
+                        plural("operand", "operands", count),
+                        arity,
+                        plural("is", "are", arity)
+                    ),
+                )],
+            }
+        });
+
+        let mut diag = Diagnostic::error(
+            op_pos,
+            format!("Found no match for {}", designator.describe()),
+        );
+        diag.add_rejected_calls(rejected);
         diag
     }
+
+    fn add_rejected_calls<'a>(&mut self, rejected: impl IntoIterator<Item = RejectedCall<'a>>) {
+        let mut rejected: Vec<_> = rejected.into_iter().collect();
+        rejected.sort_by(|x, y| x.ent.decl_pos().cmp(&y.ent.decl_pos()));
+
+        for cand in rejected {
+            if let Some(decl_pos) = cand.ent.decl_pos() {
+                self.add_related(decl_pos, format!("Does not match {}", cand.ent.describe()));
+                for (pos, reason) in cand.reasons {
+                    self.add_related(pos, reason);
+                }
+            }
+        }
+    }
 }
 
 #[cfg(test)]
@@ -567,10 +808,44 @@ function myfun(arg2 : integer) return character;
                         decl.s1("myfun"),
                         "Does not match function myfun[INTEGER return INTEGER]",
                     )
+                    .related(fcall.pos(), "No association of parameter 'arg1'")
                     .related(
                         decl.s("myfun", 2),
                         "Does not match function myfun[INTEGER return CHARACTER]",
-                    ),
+                    )
+                    .related(fcall.pos(), "No association of parameter 'arg2'"),
+            ],
+        );
+    }
+
+    #[test]
+    fn disambiguate_fcall_explains_arity_and_named_formals() {
+        let test = TestSetup::new();
+        let decl = test.declarative_part(
+            "
+function myfun(arg1 : integer) return integer;
+function myfun(arg1 : integer; arg2 : integer) return integer;
+        ",
+        );
+
+        let fcall = test.snippet("myfun(0, 1, arg3 => 2)");
+        let mut diagnostics = Vec::new();
+        assert_eq!(test.disambiguate(&fcall, None, &mut diagnostics), None);
+        check_diagnostics(
+            diagnostics,
+            vec![
+                Diagnostic::error(fcall.s1("myfun"), "Could not resolve call to 'myfun'")
+                    .related(
+                        decl.s1("myfun"),
+                        "Does not match function myfun[INTEGER return INTEGER]",
+                    )
+                    .related(fcall.s1("arg3"), "No declaration of 'arg3'")
+                    .related(fcall.s1("1"), "Unexpected extra argument")
+                    .related(
+                        decl.s("myfun", 2),
+                        "Does not match function myfun[INTEGER, INTEGER return INTEGER]",
+                    )
+                    .related(fcall.s1("arg3"), "No declaration of 'arg3'"),
             ],
         );
     }
@@ -640,9 +915,17 @@ function myfun(arg1 : character) return character;
                         decl.s1("myfun"),
                         "Does not match function myfun[CHARACTER return INTEGER]",
                     )
+                    .related(
+                        fcall.s1("0"),
+                        "expression with type universal_integer does not match type 'CHARACTER' of parameter 'arg1'",
+                    )
                     .related(
                         decl.s("myfun", 2),
                         "Does not match function myfun[CHARACTER return CHARACTER]",
+                    )
+                    .related(
+                        fcall.s1("0"),
+                        "expression with type universal_integer does not match type 'CHARACTER' of parameter 'arg1'",
                     ),
             ],
         );
@@ -695,9 +978,17 @@ function myfun(arg1 : integer) return character;
                         decl.s1("myfun"),
                         "Does not match function myfun[INTEGER return INTEGER]",
                     )
+                    .related(
+                        fcall.s1("myfun"),
+                        "Return type does not match type 'BOOLEAN'",
+                    )
                     .related(
                         decl.s("myfun", 2),
                         "Does not match function myfun[INTEGER return CHARACTER]",
+                    )
+                    .related(
+                        fcall.s1("myfun"),
+                        "Return type does not match type 'BOOLEAN'",
                     ),
             ],
         )
@@ -5,6 +5,7 @@
 //! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 
 use fnv::FnvHashMap;
+use fnv::FnvHashSet;
 
 use super::analyze::*;
 use super::formal_region::FormalRegion;
@@ -35,12 +36,15 @@ use crate::ast::Literal;
 use crate::ast::Name;
 use crate::ast::Operator;
 use crate::ast::PackageInstantiation;
+use crate::ast::SubprogramInstantiation;
+use crate::ast::SubprogramKind;
 use crate::data::DiagnosticHandler;
 use crate::Diagnostic;
 use crate::NullDiagnostics;
+use crate::SrcPos;
 
 impl<'a> AnalyzeContext<'a> {
-    fn package_generic_map(
+    pub fn analyze_generic_map(
         &self,
         scope: &Scope<'a>,
         generics: GpkgRegion<'a>,
@@ -64,10 +68,7 @@ impl<'a> AnalyzeContext<'a> {
                         }
                     }
                 } else {
-                    diagnostics.error(
-                        &formal.pos,
-                        "Expected simple name for package generic formal",
-                    );
+                    diagnostics.error(&formal.pos, "Expected simple name for generic formal");
                     continue;
                 }
             } else if let Some(ent) = generics.nth(idx) {
@@ -225,7 +226,7 @@ impl<'a> AnalyzeContext<'a> {
                 let (generics, other) = package_region.to_package_generic();
 
                 let mapping = if let Some(generic_map) = generic_map {
-                    self.package_generic_map(&nested, generics, generic_map, diagnostics)?
+                    self.analyze_generic_map(&nested, generics, generic_map, diagnostics)?
                 } else {
                     FnvHashMap::default()
                 };
@@ -256,6 +257,187 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// Instantiate an uninstantiated subprogram with the actuals of the generic map
+    pub fn subprogram_instance(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        instance: &mut SubprogramInstantiation,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<OverloadedEnt<'a>> {
+        let uninst = self.analyze_subprogram_instance_name(scope, instance, diagnostics)?;
+
+        let mapping = match (
+            uninst.kind().uninstantiated_generics(),
+            &mut instance.generic_map,
+        ) {
+            (Some(generics), Some(generic_map)) => {
+                let mapping =
+                    self.analyze_generic_map(scope, generics.clone(), generic_map, diagnostics)?;
+                self.check_missing_generics(
+                    &instance.ident.tree.pos,
+                    generics,
+                    generic_map,
+                    diagnostics,
+                );
+                mapping
+            }
+            (Some(generics), None) => {
+                if generics.iter().any(|generic| !generic.has_default()) {
+                    let mut diag = Diagnostic::error(
+                        &instance.ident.tree.pos,
+                        format!("Missing generic map for {}", uninst.describe()),
+                    );
+                    if let Some(pos) = uninst.decl_pos() {
+                        diag.add_related(pos, "Defined here");
+                    }
+                    diagnostics.push(diag);
+                }
+                FnvHashMap::default()
+            }
+            _ => FnvHashMap::default(),
+        };
+
+        let ent = self.arena.define(
+            &mut instance.ident,
+            parent,
+            AnyEntKind::Library, // Will be immediately overwritten below
+        );
+
+        match self.map_signature(Some(ent), &mapping, uninst.signature()) {
+            Ok(signature) => {
+                unsafe {
+                    ent.set_kind(AnyEntKind::Overloaded(Overloaded::Subprogram(signature)));
+                }
+                Ok(OverloadedEnt::from_any(ent).unwrap())
+            }
+            Err(err) => {
+                let mut diag = Diagnostic::error(&instance.ident.tree.pos, err);
+                if let Some(pos) = uninst.decl_pos() {
+                    diag.add_related(pos, "When instantiating this declaration");
+                }
+                diagnostics.push(diag);
+                Err(EvalError::Unknown)
+            }
+        }
+    }
+
+    /// Report generics without a default that are not associated by the generic map
+    fn check_missing_generics(
+        &self,
+        pos: &SrcPos,
+        generics: &GpkgRegion<'a>,
+        generic_map: &[AssociationElement],
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let mut associated = FnvHashSet::default();
+        for (idx, assoc) in generic_map.iter().enumerate() {
+            if let Some(formal) = &assoc.formal {
+                if let Name::Designator(des) = &formal.item {
+                    if let Some(id) = des.reference {
+                        associated.insert(id);
+                    }
+                }
+            } else if let Some(ent) = generics.nth(idx) {
+                associated.insert(ent.id());
+            }
+        }
+
+        for generic in generics.iter() {
+            if !generic.has_default() && !associated.contains(&generic.id()) {
+                let mut diag =
+                    Diagnostic::error(pos, format!("No association of {}", generic.describe()));
+                if let Some(decl_pos) = generic.decl_pos() {
+                    diag.add_related(decl_pos, "Defined here");
+                }
+                diagnostics.push(diag);
+            }
+        }
+    }
+
+    fn analyze_subprogram_instance_name(
+        &self,
+        scope: &Scope<'a>,
+        instance: &mut SubprogramInstantiation,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<OverloadedEnt<'a>> {
+        let SubprogramInstantiation {
+            kind,
+            subprogram_name,
+            signature,
+            ..
+        } = instance;
+
+        let overloaded = match self.resolve_selected_name(scope, subprogram_name) {
+            Ok(NamedEntities::Overloaded(overloaded)) => overloaded,
+            Ok(NamedEntities::Single(ent)) => {
+                diagnostics.error(
+                    &subprogram_name.pos,
+                    format!("{} is not an uninstantiated subprogram", ent.describe()),
+                );
+                return Err(EvalError::Unknown);
+            }
+            Err(err) => {
+                diagnostics.push(err.into_non_fatal()?);
+                return Err(EvalError::Unknown);
+            }
+        };
+
+        let mut candidates: Vec<_> = overloaded
+            .entities()
+            .filter(|ent| {
+                ent.is_uninstantiated()
+                    && match kind {
+                        SubprogramKind::Function => ent.is_function(),
+                        SubprogramKind::Procedure => ent.is_procedure(),
+                    }
+            })
+            .collect();
+
+        if let Some(signature) = signature {
+            match self.resolve_signature(scope, signature) {
+                Ok(key) => candidates.retain(|ent| ent.signature().key() == key),
+                Err(err) => {
+                    diagnostics.push(err.into_non_fatal()?);
+                    return Err(EvalError::Unknown);
+                }
+            }
+        }
+
+        if let [uninst] = candidates.as_slice() {
+            subprogram_name.set_unique_reference(uninst);
+            return Ok(*uninst);
+        }
+
+        let kind_desc = match kind {
+            SubprogramKind::Function => "function",
+            SubprogramKind::Procedure => "procedure",
+        };
+
+        let mut diag = if candidates.is_empty() {
+            let mut diag = Diagnostic::error(
+                &subprogram_name.pos,
+                format!("'{subprogram_name}' does not denote an uninstantiated {kind_desc}"),
+            );
+            diag.add_subprogram_candidates("Found", overloaded.entities());
+            diag
+        } else {
+            let mut diag = Diagnostic::error(
+                &subprogram_name.pos,
+                format!(
+                    "Signature required to instantiate ambiguous {kind_desc} '{subprogram_name}'"
+                ),
+            );
+            diag.add_subprogram_candidates("Might be", candidates);
+            diag
+        };
+        if let Some(signature) = signature {
+            diag.add_related(&signature.pos, "With this signature");
+        }
+        diagnostics.push(diag);
+        Err(EvalError::Unknown)
+    }
+
     fn instantiate(
         &self,
         parent: Option<EntRef<'a>>,
@@ -343,6 +525,10 @@ impl<'a> AnalyzeContext<'a> {
             AnyEntKind::DeferredConstant(subtype) => {
                 AnyEntKind::DeferredConstant(self.map_subtype(mapping, *subtype)?)
             }
+            AnyEntKind::View(typ) => AnyEntKind::View(self.map_type_ent(mapping, *typ)),
+            AnyEntKind::Psl(psl) => AnyEntKind::Psl(*psl),
+            AnyEntKind::GroupTemplate(entries) => AnyEntKind::GroupTemplate(entries.clone()),
+            AnyEntKind::Group => AnyEntKind::Group,
             AnyEntKind::Library => AnyEntKind::Library,
             AnyEntKind::Design(design) => match design {
                 Design::PackageInstance(region) => AnyEntKind::Design(Design::PackageInstance(
@@ -371,8 +557,21 @@ impl<'a> AnalyzeContext<'a> {
             Overloaded::Subprogram(signature) => {
                 Overloaded::Subprogram(self.map_signature(parent, mapping, signature)?)
             }
-            Overloaded::InterfaceSubprogram(signature) => {
-                Overloaded::InterfaceSubprogram(self.map_signature(parent, mapping, signature)?)
+            Overloaded::UninstSubprogramDecl(signature, generics) => {
+                Overloaded::UninstSubprogramDecl(
+                    self.map_signature(parent, mapping, signature)?,
+                    generics.clone(),
+                )
+            }
+            Overloaded::UninstSubprogram(signature, generics) => Overloaded::UninstSubprogram(
+                self.map_signature(parent, mapping, signature)?,
+                generics.clone(),
+            ),
+            Overloaded::InterfaceSubprogram(signature, has_default) => {
+                Overloaded::InterfaceSubprogram(
+                    self.map_signature(parent, mapping, signature)?,
+                    *has_default,
+                )
             }
             Overloaded::EnumLiteral(signature) => {
                 Overloaded::EnumLiteral(self.map_signature(parent, mapping, signature)?)
@@ -392,15 +591,16 @@ impl<'a> AnalyzeContext<'a> {
         })
     }
 
-    fn map_signature(
+    pub fn map_signature(
         &self,
         parent: Option<EntRef<'a>>,
         mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
-        signature: &'a Signature<'a>,
+        signature: &Signature<'a>,
     ) -> Result<Signature<'a>, String> {
         let Signature {
             formals,
             return_type,
+            impure,
         } = signature;
 
         let FormalRegion {
@@ -410,7 +610,7 @@ impl<'a> AnalyzeContext<'a> {
 
         let mut inst_entities = Vec::with_capacity(uninst_entities.len());
         for uninst in uninst_entities {
-            let inst = self.instantiate(parent, mapping, uninst)?;
+            let inst = self.instantiate(parent, mapping, uninst.inner())?;
 
             if let Some(inst) = InterfaceEnt::from_any(inst) {
                 inst_entities.push(inst);
@@ -427,6 +627,7 @@ impl<'a> AnalyzeContext<'a> {
                 entities: inst_entities,
             },
             return_type: return_type.map(|typ| self.map_type_ent(mapping, typ)),
+            impure: *impure,
         })
     }
 
@@ -470,7 +671,11 @@ impl<'a> AnalyzeContext<'a> {
         typ: &'a Type<'a>,
     ) -> Result<Type<'a>, String> {
         Ok(match typ {
-            Type::Array { indexes, elem_type } => {
+            Type::Array {
+                indexes,
+                elem_type,
+                static_range,
+            } => {
                 let mut mapped_indexes = Vec::with_capacity(indexes.len());
                 for index_typ in indexes.iter() {
                     mapped_indexes.push(
@@ -482,6 +687,7 @@ impl<'a> AnalyzeContext<'a> {
                 Type::Array {
                     indexes: mapped_indexes,
                     elem_type: self.map_type_ent(mapping, *elem_type),
+                    static_range: *static_range,
                 }
             }
             Type::Enum(symbols) => Type::Enum(symbols.clone()),
@@ -524,6 +730,8 @@ impl<'a> AnalyzeContext<'a> {
             iface,
             subtype,
             has_default,
+            signal_kind,
+            static_value,
         } = obj;
 
         Ok(Object {
@@ -531,6 +739,8 @@ impl<'a> AnalyzeContext<'a> {
             iface: *iface,
             subtype: self.map_subtype(mapping, *subtype)?,
             has_default: *has_default,
+            signal_kind: *signal_kind,
+            static_value: *static_value,
         })
     }
 
@@ -547,10 +757,18 @@ impl<'a> AnalyzeContext<'a> {
         mapping: &FnvHashMap<EntityId, TypeEnt<'a>>,
         subtype: Subtype<'a>,
     ) -> Result<Subtype<'a>, String> {
-        let Subtype { type_mark } = subtype;
+        let Subtype {
+            type_mark,
+            static_length,
+            static_range,
+            resolved,
+        } = subtype;
 
         Ok(Subtype {
             type_mark: self.map_type_ent(mapping, type_mark),
+            static_length,
+            static_range,
+            resolved,
         })
     }
 }
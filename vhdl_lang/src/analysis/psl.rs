@@ -0,0 +1,331 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::analyze::*;
+use super::named_entity::*;
+use super::region::*;
+use crate::ast::*;
+use crate::data::*;
+
+/// PSL built-in functions which are not declared by any VHDL package
+const PSL_BUILTIN_FUNCTIONS: &[&str] = &[
+    "countones",
+    "ended",
+    "fell",
+    "isunknown",
+    "nondet",
+    "nondet_vector",
+    "onehot",
+    "onehot0",
+    "prev",
+    "rose",
+    "stable",
+];
+
+fn is_psl_builtin_function(designator: &Designator) -> bool {
+    if let Designator::Identifier(sym) = designator {
+        let name = sym.name_utf8().to_lowercase();
+        PSL_BUILTIN_FUNCTIONS.contains(&name.as_str())
+    } else {
+        false
+    }
+}
+
+impl<'a> AnalyzeContext<'a> {
+    /// Analyze a PSL property, sequence or boolean
+    /// The VHDL sub-expressions are type checked as booleans
+    pub fn analyze_psl_expression(
+        &self,
+        scope: &Scope<'a>,
+        expr: &mut WithPos<PslExpression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        match expr.item {
+            PslExpression::Hdl(ref mut expr) => self.analyze_psl_boolean(scope, expr, diagnostics),
+            PslExpression::Unary(ref mut op, ref mut operand) => {
+                match op.item {
+                    PslUnaryOperator::Next { ref mut count, .. } => {
+                        if let Some(ref mut count) = count {
+                            self.analyze_psl_count(scope, count, diagnostics)?;
+                        }
+                    }
+                    PslUnaryOperator::NextEvent {
+                        ref mut event,
+                        ref mut count,
+                        ..
+                    } => {
+                        self.analyze_psl_expression(scope, event, diagnostics)?;
+                        if let Some(ref mut count) = count {
+                            self.analyze_psl_count(scope, count, diagnostics)?;
+                        }
+                    }
+                    PslUnaryOperator::Always
+                    | PslUnaryOperator::Never
+                    | PslUnaryOperator::Eventually
+                    | PslUnaryOperator::Not => {}
+                }
+                self.analyze_psl_expression(scope, operand, diagnostics)
+            }
+            PslExpression::Binary(_, ref mut lhs, ref mut rhs) => {
+                self.analyze_psl_expression(scope, lhs, diagnostics)?;
+                self.analyze_psl_expression(scope, rhs, diagnostics)
+            }
+            PslExpression::Sere(ref mut sere) => {
+                self.analyze_psl_expression(scope, sere, diagnostics)
+            }
+            PslExpression::Repetition(ref mut operand, _, ref mut count) => {
+                if let Some(ref mut operand) = operand {
+                    self.analyze_psl_expression(scope, operand, diagnostics)?;
+                }
+                if let Some(ref mut count) = count {
+                    self.analyze_psl_count(scope, count, diagnostics)?;
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// A boolean or an instance of a named property or sequence
+    fn analyze_psl_boolean(
+        &self,
+        scope: &Scope<'a>,
+        expr: &mut WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        if self.analyze_psl_reference(scope, &expr.pos, &mut expr.item, diagnostics)? {
+            Ok(())
+        } else {
+            self.boolean_expr(scope, expr, diagnostics)
+        }
+    }
+
+    fn analyze_psl_count(
+        &self,
+        scope: &Scope<'a>,
+        count: &mut PslCount,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let PslCount { low, high } = count;
+        let high = if let Some(PslCountBound::Expression(ref mut high)) = high {
+            Some(high)
+        } else {
+            None
+        };
+        for expr in std::iter::once(low).chain(high) {
+            if !self.analyze_psl_reference(scope, &expr.pos, &mut expr.item, diagnostics)? {
+                self.integer_expr(scope, expr, diagnostics)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Resolve a name of a PSL declaration or a call of a PSL built-in function
+    /// Returns false when the expression is an ordinary VHDL expression
+    fn analyze_psl_reference(
+        &self,
+        scope: &Scope<'a>,
+        pos: &SrcPos,
+        expr: &mut Expression,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<bool> {
+        let name = if let Expression::Name(ref mut name) = expr {
+            name
+        } else {
+            return Ok(false);
+        };
+
+        let (designator, pos, parameters) = match name.as_mut() {
+            Name::Designator(ref mut designator) => (designator, pos, None),
+            Name::CallOrIndexed(ref mut call) => {
+                let CallOrIndexed { name, parameters } = call.as_mut();
+                if let Name::Designator(ref mut designator) = name.item {
+                    (designator, &name.pos, Some(parameters))
+                } else {
+                    return Ok(false);
+                }
+            }
+            _ => return Ok(false),
+        };
+
+        match scope.lookup(pos, &designator.item) {
+            Ok(NamedEntities::Single(ent)) if matches!(ent.kind(), AnyEntKind::Psl(_)) => {
+                designator.set_unique_reference(ent);
+            }
+            Err(_) if parameters.is_some() && is_psl_builtin_function(&designator.item) => {}
+            _ => return Ok(false),
+        }
+
+        for actual in parameters.into_iter().flatten() {
+            if let ActualPart::Expression(ref mut expr) = actual.actual.item {
+                if !self.analyze_psl_reference(scope, &actual.actual.pos, expr, diagnostics)? {
+                    self.expr_pos_unknown_ttyp(scope, &actual.actual.pos, expr, diagnostics)?;
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    pub fn analyze_psl_property(
+        &self,
+        scope: &Scope<'a>,
+        parent: EntRef<'a>,
+        property: &mut PslPropertyDeclaration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<EntRef<'a>> {
+        let kind = match property.kind {
+            PslPropertyKind::Property => Psl::Property,
+            PslPropertyKind::Sequence => Psl::Sequence,
+        };
+        let ent = self
+            .arena
+            .define(&mut property.ident, parent, AnyEntKind::Psl(kind));
+
+        let nested = scope.nested();
+        for parameter in property.parameters.iter_mut() {
+            let param = self
+                .arena
+                .define(parameter, ent, AnyEntKind::Psl(Psl::Parameter));
+            nested.add(param, diagnostics);
+        }
+        self.analyze_psl_expression(&nested, &mut property.body, diagnostics)?;
+        Ok(ent)
+    }
+
+    pub fn analyze_psl_directive(
+        &self,
+        scope: &Scope<'a>,
+        directive: &mut PslDirective,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        if let Some(ref mut property) = directive.property {
+            self.analyze_psl_expression(scope, property, diagnostics)?;
+        }
+        if let Some(ref mut expr) = directive.report {
+            self.expr_with_ttyp(scope, self.string(), expr, diagnostics)?;
+        }
+        if let Some(ref mut expr) = directive.severity {
+            self.expr_with_ttyp(scope, self.severity_level(), expr, diagnostics)?;
+        }
+        Ok(())
+    }
+
+    pub fn analyze_verification_unit(
+        &self,
+        unit: &mut VerificationUnit,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult {
+        let ent = self.arena.explicit(
+            unit.name().clone(),
+            self.work_library(),
+            AnyEntKind::Design(Design::VerificationUnit(Region::default())),
+            Some(unit.pos()),
+        );
+        unit.ident.decl = Some(ent.id());
+
+        let root_scope = Scope::default();
+        self.add_implicit_context_clause(&root_scope)?;
+        self.analyze_context_clause(&root_scope, &mut unit.context_clause, diagnostics)?;
+
+        let scope = if let Some(ref mut binding) = unit.binding {
+            self.verification_unit_binding_scope(&root_scope, binding, diagnostics)?
+        } else {
+            None
+        }
+        .unwrap_or_else(|| root_scope.nested());
+
+        for inherit in unit.inherits.iter_mut() {
+            let inherited = match self.lookup_in_library(
+                self.work_library_name(),
+                &inherit.item.pos,
+                &Designator::Identifier(inherit.item.item.clone()),
+            ) {
+                Ok(inherited) => inherited,
+                Err(err) => {
+                    err.add_to(diagnostics)?;
+                    continue;
+                }
+            };
+            inherit.set_unique_reference(inherited.into());
+
+            let inherited: EntRef<'a> = inherited.into();
+            if let AnyEntKind::Design(Design::VerificationUnit(ref region)) = inherited.kind() {
+                scope.make_all_potentially_visible(Some(&inherit.item.pos), region);
+            } else {
+                diagnostics.error(
+                    &inherit.item.pos,
+                    format!("Expected verification unit, got {}", inherited.describe()),
+                );
+            }
+        }
+
+        self.define_labels_for_concurrent_part(&scope, ent, &mut unit.statements, diagnostics)?;
+        self.analyze_declarative_part(&scope, ent, &mut unit.decl, diagnostics)?;
+        self.analyze_concurrent_part(&scope, ent, &mut unit.statements, diagnostics)?;
+
+        let kind = AnyEntKind::Design(Design::VerificationUnit(scope.into_region()));
+        unsafe { ent.set_kind(kind) }
+
+        Ok(())
+    }
+
+    /// The scope of the entity or architecture that the verification unit is bound to
+    fn verification_unit_binding_scope(
+        &self,
+        scope: &Scope<'a>,
+        binding: &mut VerificationUnitBinding,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> FatalResult<Option<Scope<'a>>> {
+        let entity_pos = binding.entity_name.item.pos.clone();
+        let entity = match self.lookup_in_library(
+            self.work_library_name(),
+            &entity_pos,
+            &Designator::Identifier(binding.entity_name.item.item.clone()),
+        ) {
+            Ok(entity) => entity,
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                return Ok(None);
+            }
+        };
+        binding.entity_name.set_unique_reference(entity.into());
+
+        let design = if let Some(ref mut architecture_name) = binding.architecture_name {
+            match self.get_architecture(
+                self.work_library_name(),
+                &architecture_name.item.pos,
+                &binding.entity_name.item.item,
+                &architecture_name.item.item,
+            ) {
+                Ok(arch) => {
+                    architecture_name.set_unique_reference(arch.into());
+                    arch
+                }
+                Err(err) => {
+                    err.add_to(diagnostics)?;
+                    return Ok(None);
+                }
+            }
+        } else {
+            entity
+        };
+
+        match design.kind() {
+            Design::Entity(ref visibility, ref region)
+            | Design::Architecture(ref visibility, ref region, _) => {
+                let root_scope =
+                    Scope::new(Region::with_visibility(visibility.clone())).with_parent(scope);
+                Ok(Some(Scope::extend(region, Some(&root_scope))))
+            }
+            _ => {
+                diagnostics.error(
+                    &entity_pos,
+                    format!("Expected entity, got {}", entity.describe()),
+                );
+                Ok(None)
+            }
+        }
+    }
+}
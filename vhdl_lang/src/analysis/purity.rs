@@ -0,0 +1,311 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! LRM 4.2.1 Pure functions and LRM 10.2 Wait statements
+//!
+//! A pure function may not reference signals, variables or files declared outside of it
+//! and may not call impure functions. Functions may not contain wait statements
+//! and neither may procedures called from a function or from a process with a sensitivity list.
+
+use super::analyze::*;
+use super::named_entity::*;
+use crate::ast::search::*;
+use crate::ast::*;
+use crate::config::Lint;
+use crate::data::*;
+use fnv::FnvHashSet;
+
+/// Collect all references within the body of a subprogram
+#[derive(Default)]
+pub(super) struct ReferenceCollector {
+    pub references: Vec<(SrcPos, EntityId)>,
+}
+
+impl Searcher for ReferenceCollector {
+    fn search_pos_with_ref(&mut self, pos: &SrcPos, reference: &mut Reference) -> SearchState {
+        if let Some(id) = reference {
+            self.references.push((pos.clone(), *id));
+        }
+        NotFinished
+    }
+}
+
+impl<'a> AnalyzeContext<'a> {
+    pub fn check_function_purity(
+        &self,
+        function: OverloadedEnt<'a>,
+        body: &mut SubprogramBody,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !function.signature().is_pure_function() {
+            return;
+        }
+
+        let mut collector = ReferenceCollector::default();
+        for decl in body.declarations.iter_mut() {
+            // Nested functions are checked by themselves or when they are called
+            if let Declaration::SubprogramBody(ref nested) = decl {
+                if matches!(nested.specification, SubprogramDeclaration::Function(_)) {
+                    continue;
+                }
+            }
+            let _ = decl.search(&mut collector);
+        }
+        let _ = body.statements.search(&mut collector);
+
+        let function: EntRef<'a> = function.into();
+        for (pos, id) in collector.references {
+            let ent = self.arena.get(id);
+
+            match ent.actual_kind() {
+                AnyEntKind::Overloaded(overloaded) => {
+                    let signature = overloaded.signature();
+                    if signature.return_type().is_some()
+                        && signature.impure
+                        && !self.is_standard_now(ent)
+                    {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                &pos,
+                                format!("Pure function cannot call impure {}", ent.describe()),
+                            )
+                            .opt_related(ent.decl_pos(), "Defined here"),
+                        );
+                    }
+                }
+                AnyEntKind::Object(Object {
+                    class: ObjectClass::Signal | ObjectClass::Variable | ObjectClass::SharedVariable,
+                    ..
+                })
+                | AnyEntKind::ObjectAlias { .. }
+                | AnyEntKind::File(_)
+                | AnyEntKind::InterfaceFile(_) => {
+                    if let AnyEntKind::ObjectAlias { base_object, .. } = ent.actual_kind() {
+                        if base_object.class() == ObjectClass::Constant {
+                            continue;
+                        }
+                    }
+                    if !is_declared_within(ent, function) && !is_other_formal(ent, function) {
+                        diagnostics.push(
+                            Diagnostic::error(
+                                &pos,
+                                format!(
+                                    "Pure function cannot reference {} declared outside of the function",
+                                    ent.describe()
+                                ),
+                            )
+                            .opt_related(ent.decl_pos(), "Defined here"),
+                        );
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// NOW was declared pure in VHDL-2002 and libraries such as VITAL call it from pure functions
+    fn is_standard_now(&self, ent: EntRef) -> bool {
+        matches!(ent.designator(), Designator::Identifier(sym) if sym == &self.root.symbol_utf8("now"))
+            && ent.parent.map(|parent| parent.id()) == self.root.standard_pkg_id
+    }
+
+    /// Remember that the subprogram enclosing a wait statement contains a wait statement
+    pub fn mark_waiting_subprogram(&self, parent: EntRef<'a>) {
+        if let Some(subprogram) = enclosing_subprogram(parent) {
+            self.set_procedure_waits(subprogram, true);
+        }
+    }
+
+    /// Remember that the body of the procedure has been analyzed
+    pub fn mark_analyzed_procedure(&self, procedure: EntRef<'a>) {
+        self.set_procedure_waits(procedure, false);
+    }
+
+    fn set_procedure_waits(&self, procedure: EntRef<'a>, waits: bool) {
+        let mut procedure_waits = self.procedure_waits.borrow_mut();
+        let mut ids = vec![procedure.id()];
+        if let Related::DeclaredBy(decl) = procedure.related {
+            ids.push(decl.id());
+        }
+        for id in ids {
+            let entry = procedure_waits.entry(id).or_insert(waits);
+            *entry = *entry || waits;
+        }
+    }
+
+    /// The procedures and their declarations within the current design unit that contain
+    /// a wait statement, these are known to other design units through its analysis
+    pub fn waiting_procedures(&self) -> FnvHashSet<EntityId> {
+        self.procedure_waits
+            .borrow()
+            .iter()
+            .filter_map(|(id, waits)| if *waits { Some(*id) } else { None })
+            .collect()
+    }
+
+    /// Returns true if the procedure has been found to contain a wait statement
+    /// directly or through other procedure calls
+    pub fn is_waiting_procedure(&self, id: EntityId) -> bool {
+        self.analyzed_procedure_waits(id).unwrap_or(false)
+    }
+
+    /// Procedures whose body has not been analyzed, as in a package without a body, may wait
+    fn may_wait(&self, id: EntityId) -> bool {
+        self.analyzed_procedure_waits(id).unwrap_or(true)
+    }
+
+    /// Whether the body of a procedure that has been analyzed, possibly within another
+    /// design unit, waits
+    fn analyzed_procedure_waits(&self, id: EntityId) -> Option<bool> {
+        let waits = self.procedure_waits.borrow().get(&id).copied();
+        if waits.is_some() {
+            return waits;
+        }
+
+        let ent = self.arena.get(id);
+        match ent.kind() {
+            AnyEntKind::Overloaded(Overloaded::Alias(ref overloaded)) => {
+                self.analyzed_procedure_waits(overloaded.id())
+            }
+            AnyEntKind::Overloaded(Overloaded::SubprogramDecl(_) | Overloaded::Subprogram(_)) => {
+                let data = self.get_subprogram_bodies_analysis(ent.parent?)?;
+                Some(data.result().waiting_procedures.contains(&id))
+            }
+            _ => None,
+        }
+    }
+
+    /// LRM 11.3 A process with a sensitivity list may not contain a wait statement
+    /// and a process without one should contain a wait statement
+    pub fn check_process_wait_statements(
+        &self,
+        pos: &SrcPos,
+        process: &ProcessStatement,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let mut waits = Vec::new();
+        let mut calls = Vec::new();
+        collect_waits(&process.statements, &mut waits, &mut calls);
+
+        if process.sensitivity_list.is_some() {
+            for wait_pos in waits {
+                diagnostics.error(
+                    wait_pos,
+                    "Process with sensitivity list cannot contain wait statements",
+                );
+            }
+
+            for (call_pos, id) in calls {
+                if let Some(id) = id {
+                    if self.is_waiting_procedure(id) {
+                        let ent = self.arena.get(id);
+                        diagnostics.push(
+                            Diagnostic::error(
+                                call_pos,
+                                format!(
+                                    "Process with sensitivity list cannot call {} which contains a wait statement",
+                                    ent.describe()
+                                ),
+                            )
+                            .opt_related(ent.decl_pos(), "Defined here"),
+                        );
+                    }
+                }
+            }
+        } else if self.is_lint_enabled(Lint::MissingWait)
+            && waits.is_empty()
+            && !calls
+                .iter()
+                .any(|(_, id)| id.map(|id| self.may_wait(id)).unwrap_or(true))
+        {
+            diagnostics.warning(
+                pos,
+                "Process without sensitivity list does not contain a wait statement and will loop forever",
+            );
+        }
+    }
+}
+
+/// Collect the wait statements and procedure calls of a process
+pub(super) fn collect_waits<'s>(
+    statements: &'s [LabeledSequentialStatement],
+    waits: &mut Vec<&'s SrcPos>,
+    calls: &mut Vec<(&'s SrcPos, Option<EntityId>)>,
+) {
+    for statement in statements.iter() {
+        match statement.statement.item {
+            SequentialStatement::Wait(..) => waits.push(&statement.statement.pos),
+            SequentialStatement::ProcedureCall(ref pcall) => {
+                let name = &pcall.item.name;
+                calls.push((&name.pos, name.item.get_suffix_reference()));
+            }
+            SequentialStatement::If(ref ifstmt) => {
+                for cond in ifstmt.conds.conditionals.iter() {
+                    collect_waits(&cond.item, waits, calls);
+                }
+                if let Some(ref else_item) = ifstmt.conds.else_item {
+                    collect_waits(else_item, waits, calls);
+                }
+            }
+            SequentialStatement::Case(ref case) => {
+                for alternative in case.alternatives.iter() {
+                    collect_waits(&alternative.item, waits, calls);
+                }
+            }
+            SequentialStatement::Loop(ref loop_stmt) => {
+                collect_waits(&loop_stmt.statements, waits, calls);
+            }
+            SequentialStatement::Block(ref block) => {
+                collect_waits(&block.statements, waits, calls);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The subprogram that a sequential statement belongs to if any
+fn enclosing_subprogram(ent: EntRef) -> Option<EntRef> {
+    match ent.kind() {
+        AnyEntKind::Overloaded(_) => Some(ent),
+        AnyEntKind::Sequential(_) => ent.parent.and_then(enclosing_subprogram),
+        _ => None,
+    }
+}
+
+/// Objects declared within a protected type body are accessible to its methods
+fn is_declared_within(ent: EntRef, function: EntRef) -> bool {
+    let mut parent = ent.parent;
+    while let Some(region) = parent {
+        if region.id() == function.id() || region.is_protected_type_body() {
+            return true;
+        }
+        parent = region.parent;
+    }
+    false
+}
+
+/// The formal designator of a named association refers to a formal of the called subprogram
+fn is_other_formal(ent: EntRef, function: EntRef) -> bool {
+    if let AnyEntKind::Object(Object { iface: Some(_), .. }) = ent.kind() {
+        if let Some(subprogram) = ent.parent {
+            if subprogram.is_overloaded() {
+                return !is_within(function, subprogram);
+            }
+        }
+    }
+    false
+}
+
+fn is_within(ent: EntRef, region: EntRef) -> bool {
+    let mut parent = Some(ent);
+    while let Some(ent) = parent {
+        if ent.id() == region.id() {
+            return true;
+        }
+        parent = ent.parent;
+    }
+    false
+}
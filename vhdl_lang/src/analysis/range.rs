@@ -17,16 +17,6 @@ use crate::ast::*;
 use crate::data::*;
 
 impl<'a> AnalyzeContext<'a> {
-    pub fn range_unknown_typ(
-        &self,
-        scope: &Scope<'a>,
-        range: &mut Range,
-        diagnostics: &mut dyn DiagnosticHandler,
-    ) -> FatalResult {
-        as_fatal(self.range_type(scope, range, diagnostics))?;
-        Ok(())
-    }
-
     pub fn drange_unknown_type(
         &self,
         scope: &Scope<'a>,
@@ -212,6 +202,81 @@ impl<'a> AnalyzeContext<'a> {
         }
     }
 
+    /// LRM 5.2.3 Integer types and 5.2.5 Floating-point types
+    /// The bounds of the range of a numeric type definition need not have the same type,
+    /// both must be of some integer type or both of some floating-point type
+    pub fn numeric_range_type(
+        &self,
+        scope: &Scope<'a>,
+        range: &mut Range,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<UniversalType> {
+        let constraint = match range {
+            Range::Range(ref mut constraint) => constraint,
+            Range::Attribute(..) => {
+                let typ = self.range_type(scope, range, diagnostics)?;
+                return if let Some(universal_type) = numeric_universal_type(typ) {
+                    Ok(universal_type)
+                } else {
+                    diagnostics.error(range.pos(), "Expected real or integer range");
+                    Err(EvalError::Unknown)
+                };
+            }
+        };
+
+        let left = self.numeric_bound_type(scope, &mut constraint.left_expr, diagnostics)?;
+        let right = self.numeric_bound_type(scope, &mut constraint.right_expr, diagnostics)?;
+
+        match (left, right) {
+            (Some((left_kind, _)), Some((right_kind, _))) if left_kind == right_kind => {
+                Ok(left_kind)
+            }
+            (Some((_, left_typ)), Some((_, right_typ))) => {
+                diagnostics.error(
+                    constraint.pos(),
+                    format!(
+                        "Type definition cannot mix integer and real bounds, left is {}, right is {}",
+                        left_typ.describe(),
+                        right_typ.describe()
+                    ),
+                );
+                Err(EvalError::Unknown)
+            }
+            _ => {
+                diagnostics.error(constraint.pos(), "Expected real or integer range");
+                Err(EvalError::Unknown)
+            }
+        }
+    }
+
+    /// The type of a bound of a numeric type definition
+    /// Returns None if the bound is not of an integer or floating-point type
+    fn numeric_bound_type(
+        &self,
+        scope: &Scope<'a>,
+        expr: &mut WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<Option<(UniversalType, BaseType<'a>)>> {
+        let typ = match self.range_expr_type(scope, expr, diagnostics)? {
+            DisambiguatedType::Unambiguous(typ) => typ.base(),
+            DisambiguatedType::Ambiguous(types) => {
+                let mut types = types
+                    .into_iter()
+                    .filter(|typ| numeric_universal_type(*typ).is_some());
+
+                if let (Some(typ), None) = (types.next(), types.next()) {
+                    self.expr_with_ttyp(scope, typ.into(), expr, diagnostics)?;
+                    typ
+                } else {
+                    diagnostics.error(&expr.pos, "Ambiguous bound of numeric type definition");
+                    return Err(EvalError::Unknown);
+                }
+            }
+        };
+
+        Ok(numeric_universal_type(typ).map(|universal_type| (universal_type, typ)))
+    }
+
     pub fn drange_type(
         &self,
         scope: &Scope<'a>,
@@ -352,6 +417,17 @@ impl<'a> AnalyzeContext<'a> {
     }
 }
 
+/// The universal type of an integer or floating-point type
+fn numeric_universal_type(typ: BaseType) -> Option<UniversalType> {
+    if typ.is_any_integer() {
+        Some(UniversalType::Integer)
+    } else if typ.is_any_real() {
+        Some(UniversalType::Real)
+    } else {
+        None
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::analysis::analyze::EvalError;
@@ -72,6 +72,20 @@ impl<'a> OverloadedName<'a> {
 
     #[allow(clippy::if_same_then_else)]
     fn insert(&mut self, ent: OverloadedEnt<'a>) -> Result<(), Diagnostic> {
+        if ent.kind().uninstantiated_generics().is_some() {
+            // The body of a generic subprogram has its own generic types
+            // and thus another signature key than its declaration
+            let decl_key = self
+                .entities
+                .iter()
+                .find(|(_, old_ent)| ent.is_declared_by(old_ent))
+                .map(|(key, _)| key.clone());
+
+            if let Some(key) = decl_key {
+                self.entities.remove(&key);
+            }
+        }
+
         match self.entities.entry(ent.signature().key()) {
             Entry::Occupied(mut entry) => {
                 let old_ent = entry.get();
@@ -210,6 +224,8 @@ impl<'a> NamedEntities<'a> {
 pub(crate) enum RegionKind {
     PackageDeclaration,
     PackageBody,
+    /// LRM 8.7 The concurrent region of a design entity, block or generate statement
+    Concurrent,
     Other,
 }
 
@@ -316,6 +332,41 @@ impl<'a> ScopeInner<'a> {
         }
     }
 
+    /// Non-overloaded declarations of this region that hide a non-overloaded declaration
+    /// of an enclosing region or a declaration made visible by a use clause
+    fn hidden_declarations(&self) -> Vec<(EntRef<'a>, EntRef<'a>)> {
+        let mut hidden = Vec::new();
+        for (designator, names) in self.region.entities.iter() {
+            let ent = if let NamedEntities::Single(ent) = names {
+                *ent
+            } else {
+                continue;
+            };
+
+            let enclosing = self
+                .parent
+                .as_ref()
+                .and_then(|parent| parent.0.borrow().lookup_enclosing(designator));
+
+            let other = match enclosing {
+                Some(NamedEntities::Single(other)) => Some(other),
+                Some(NamedEntities::Overloaded(_)) => None,
+                None => {
+                    let mut visible = Visible::default();
+                    self.lookup_visiblity_into(designator, &mut visible);
+                    visible.single()
+                }
+            };
+
+            if let Some(other) = other {
+                if other.id() != ent.id() {
+                    hidden.push((ent, other));
+                }
+            }
+        }
+        hidden
+    }
+
     fn lookup_visiblity_into(&self, designator: &Designator, visible: &mut Visible<'a>) {
         self.region.visibility.lookup_into(designator, visible);
         if let Some(ref parent) = self.parent {
@@ -447,6 +498,33 @@ impl<'a> Scope<'a> {
         }
     }
 
+    pub fn in_concurrent_region(self) -> Scope<'a> {
+        let inner = self.into_inner();
+
+        Self(Rc::new(RefCell::new(ScopeInner {
+            parent: inner.parent,
+            region: inner.region.in_concurrent_region(),
+            cache: inner.cache,
+            anon_idx: inner.anon_idx,
+        })))
+    }
+
+    /// The scopes of the concurrent regions enclosing this scope, innermost first
+    pub fn concurrent_regions(&self) -> Vec<Scope<'a>> {
+        let mut regions = Vec::new();
+        let mut scope = Some(self.clone());
+        while let Some(current) = scope {
+            let inner = current.0.borrow();
+            if inner.region.kind == RegionKind::Concurrent {
+                regions.push(current.clone());
+            }
+            let parent = inner.parent.clone();
+            drop(inner);
+            scope = parent;
+        }
+        regions
+    }
+
     pub fn in_package_declaration(self) -> Scope<'a> {
         let inner = self.into_inner();
 
@@ -528,6 +606,22 @@ impl<'a> Scope<'a> {
         self.0.as_ref().borrow_mut().lookup(pos, designator)
     }
 
+    /// The named entities that are made potentially visible by use clauses,
+    /// including those that are hidden by a declaration
+    pub fn potentially_visible(&self, designator: &Designator) -> Vec<EntRef<'a>> {
+        let mut visible = Visible::default();
+        self.0
+            .as_ref()
+            .borrow()
+            .lookup_visiblity_into(designator, &mut visible);
+        visible.entities().collect()
+    }
+
+    /// Pairs of a declaration of the immediate region and the declaration it hides
+    pub fn hidden_declarations(&self) -> Vec<(EntRef<'a>, EntRef<'a>)> {
+        self.0.as_ref().borrow().hidden_declarations()
+    }
+
     /// Used when using context clauses
     pub fn add_context_visibility(&self, visible_pos: Option<&SrcPos>, region: &Region<'a>) {
         self.0
@@ -569,6 +663,11 @@ impl<'a> Region<'a> {
         }
     }
 
+    fn in_concurrent_region(mut self) -> Region<'a> {
+        self.kind = RegionKind::Concurrent;
+        self
+    }
+
     fn in_package_declaration(mut self) -> Region<'a> {
         self.kind = RegionKind::PackageDeclaration;
         self
@@ -641,7 +740,7 @@ impl<'a> Region<'a> {
                     }
                 }
             }
-            RegionKind::Other => {}
+            RegionKind::Concurrent | RegionKind::Other => {}
         }
     }
 
@@ -796,6 +895,15 @@ impl SetReference for Reference {
     }
 }
 
+impl SetReference for SelectedName {
+    fn set_unique_reference(&mut self, ent: &AnyEnt) {
+        match self {
+            SelectedName::Designator(designator) => designator.set_unique_reference(ent),
+            SelectedName::Selected(_, suffix) => suffix.set_unique_reference(ent),
+        }
+    }
+}
+
 impl SetReference for Name {
     fn set_unique_reference(&mut self, ent: &AnyEnt) {
         if let Some(r) = self.suffix_reference_mut() {
@@ -16,7 +16,9 @@ use super::visibility::Visibility;
 
 use crate::ast::search::*;
 use crate::ast::*;
+use crate::config::{Lint, LintConfig};
 use crate::data::*;
+use crate::standard::VHDLStandard;
 use crate::syntax::Symbols;
 use fnv::{FnvHashMap, FnvHashSet};
 use parking_lot::RwLock;
@@ -29,6 +31,8 @@ pub(super) struct AnalysisData {
     pub diagnostics: Vec<Diagnostic>,
     pub has_circular_dependency: bool,
     pub arena: FinalArena,
+    /// The procedures of the design unit and their declarations that contain a wait statement
+    pub waiting_procedures: FnvHashSet<EntityId>,
 }
 
 pub(super) type UnitReadGuard<'a> = ReadGuard<'a, AnyDesignUnit, AnalysisData>;
@@ -40,6 +44,8 @@ pub(super) struct LockedUnit {
     ident: Ident,
     arena_id: ArenaId,
     unit_id: UnitId,
+    /// The entity and architecture names of the block configuration of a configuration
+    configured_architecture: Option<(Symbol, Symbol)>,
     pub unit: AnalysisLock<AnyDesignUnit, AnalysisData>,
 }
 
@@ -67,9 +73,53 @@ impl LockedUnit {
             ident: unit.ident().clone(),
             arena_id: ArenaId::default(),
             unit_id,
+            configured_architecture: configured_architecture(&unit),
             unit: AnalysisLock::new(unit),
         }
     }
+
+    /// The configuration is known without analyzing it so that an architecture can
+    /// depend on it without a circular dependency
+    pub(super) fn configured_architecture(&self) -> Option<(&Symbol, &Symbol)> {
+        self.configured_architecture
+            .as_ref()
+            .map(|(entity_name, architecture_name)| (entity_name, architecture_name))
+    }
+
+    /// The name of the entity when the unit is one of its architectures or configurations
+    fn bound_entity_name(&self) -> Option<&Symbol> {
+        if self.unit_id.kind() == AnyKind::Secondary(SecondaryKind::Architecture) {
+            Some(self.unit_id.primary_name())
+        } else {
+            self.configured_architecture
+                .as_ref()
+                .map(|(entity_name, _)| entity_name)
+        }
+    }
+}
+
+fn configured_architecture(unit: &AnyDesignUnit) -> Option<(Symbol, Symbol)> {
+    let config = if let AnyDesignUnit::Primary(AnyPrimaryUnit::Configuration(ref config)) = unit {
+        config
+    } else {
+        return None;
+    };
+
+    let entity_name = match config.entity_name.item {
+        SelectedName::Designator(ref designator) => &designator.item,
+        SelectedName::Selected(_, ref suffix) => &suffix.item.item,
+    };
+
+    match (entity_name, &config.block_config.block_spec.item) {
+        (Designator::Identifier(entity_name), Name::Designator(architecture_name)) => {
+            if let Designator::Identifier(ref architecture_name) = architecture_name.item {
+                Some((entity_name.clone(), architecture_name.clone()))
+            } else {
+                None
+            }
+        }
+        _ => None,
+    }
 }
 
 impl HasIdent for LockedUnit {
@@ -96,6 +146,8 @@ struct Library {
     removed: FnvHashSet<UnitId>,
     /// Units added since last analysis.
     added: FnvHashSet<UnitId>,
+    /// Entities with architectures or configurations added or removed since last analysis.
+    rebound_entities: FnvHashSet<Symbol>,
 
     /// Design units which were not added since they were duplicates.
     /// They need to be kept for later refresh which might make them not duplicates.
@@ -122,6 +174,7 @@ impl Library {
             units_by_source: FnvHashMap::default(),
             added: FnvHashSet::default(),
             removed: FnvHashSet::default(),
+            rebound_entities: FnvHashSet::default(),
             duplicates: Vec::new(),
         }
     }
@@ -139,6 +192,9 @@ impl Library {
             }
             Entry::Vacant(entry) => {
                 self.added.insert(unit_id);
+                if let Some(entity_name) = unit.bound_entity_name() {
+                    self.rebound_entities.insert(entity_name.clone());
+                }
                 match self.units_by_source.entry(unit.source().clone()) {
                     Entry::Occupied(mut entry) => {
                         entry.get_mut().insert(unit.unit_id().clone());
@@ -199,11 +255,15 @@ impl Library {
     /// This is used for incremental analysis where only a single source file is updated.
     fn remove_source(&mut self, source: &Source) {
         let removed = &mut self.removed;
+        let rebound_entities = &mut self.rebound_entities;
         self.units.retain(|_, value| {
             if value.source() != source {
                 true
             } else {
                 removed.insert(value.unit_id().clone());
+                if let Some(entity_name) = value.bound_entity_name() {
+                    rebound_entities.insert(entity_name.clone());
+                }
                 false
             }
         });
@@ -278,6 +338,20 @@ pub struct DesignRoot {
     // Tracks which units have a "use library.all;" clause.
     // library name  =>  set(affected)
     users_of_library_all: RwLock<FnvHashMap<Symbol, FnvHashSet<UnitId>>>,
+
+    // Tracks which units depend on which architectures and configurations an entity has.
+    // (library name, entity name)  =>  set(affected)
+    #[allow(clippy::type_complexity)]
+    users_of_entity_bindings: RwLock<FnvHashMap<(Symbol, Symbol), FnvHashSet<UnitId>>>,
+
+    // Lints that are not enabled by default
+    lint_config: LintConfig,
+    // Libraries such as std and ieee that lints are never reported for
+    third_party_libraries: FnvHashSet<Symbol>,
+
+    // Language standard of libraries that do not set their own
+    standard: VHDLStandard,
+    library_standards: FnvHashMap<Symbol, VHDLStandard>,
 }
 
 impl DesignRoot {
@@ -294,9 +368,52 @@ impl DesignRoot {
             users_of: RwLock::new(FnvHashMap::default()),
             missing_unit: RwLock::new(FnvHashMap::default()),
             users_of_library_all: RwLock::new(FnvHashMap::default()),
+            users_of_entity_bindings: RwLock::new(FnvHashMap::default()),
+            lint_config: LintConfig::default(),
+            third_party_libraries: FnvHashSet::default(),
+            standard: VHDLStandard::default(),
+            library_standards: FnvHashMap::default(),
         }
     }
 
+    /// Must be set before any design units are analyzed
+    pub fn set_lint_config(&mut self, lint_config: LintConfig) {
+        self.lint_config = lint_config;
+    }
+
+    /// Must be set before any design units are analyzed
+    pub fn set_third_party_library(&mut self, library_name: Symbol) {
+        self.third_party_libraries.insert(library_name);
+    }
+
+    /// Lints are only reported for the design units of libraries that are not third-party
+    pub fn is_lint_enabled(&self, library_name: &Symbol, lint: Lint) -> bool {
+        self.lint_config.is_enabled(lint) && !self.third_party_libraries.contains(library_name)
+    }
+
+    /// Must be set before any design units are analyzed
+    pub fn set_standard(&mut self, standard: VHDLStandard) {
+        self.standard = standard;
+    }
+
+    /// Must be set before any design units are analyzed
+    pub fn set_library_standard(&mut self, library_name: Symbol, standard: VHDLStandard) {
+        self.library_standards.insert(library_name, standard);
+    }
+
+    /// The language standard of libraries that do not set their own
+    pub fn standard(&self) -> VHDLStandard {
+        self.standard
+    }
+
+    /// The language standard used to analyze the design units of a library
+    pub fn standard_of(&self, library_name: &Symbol) -> VHDLStandard {
+        self.library_standards
+            .get(library_name)
+            .copied()
+            .unwrap_or(self.standard)
+    }
+
     /// Create library if it does not exist or return existing
     fn get_or_create_library(&mut self, name: Symbol) -> &mut Library {
         match self.libraries.entry(name) {
@@ -612,6 +729,7 @@ impl DesignRoot {
                 };
 
                 AnalysisData {
+                    waiting_procedures: context.waiting_procedures(),
                     arena: arena.finalize(),
                     diagnostics,
                     has_circular_dependency,
@@ -627,6 +745,7 @@ impl DesignRoot {
                 };
 
                 AnalysisData {
+                    waiting_procedures: context.waiting_procedures(),
                     arena: arena.finalize(),
                     diagnostics,
                     has_circular_dependency,
@@ -710,6 +829,29 @@ impl DesignRoot {
         }
     }
 
+    /// Register a dependency on the set of architectures and configurations of an entity
+    pub(super) fn make_use_of_entity_bindings(
+        &self,
+        user: &UnitId,
+        library_name: &Symbol,
+        entity_name: &Symbol,
+    ) {
+        match self
+            .users_of_entity_bindings
+            .write()
+            .entry((library_name.clone(), entity_name.clone()))
+        {
+            Entry::Occupied(mut entry) => {
+                entry.get_mut().insert(user.clone());
+            }
+            Entry::Vacant(entry) => {
+                let mut set = FnvHashSet::default();
+                set.insert(user.clone());
+                entry.insert(set);
+            }
+        }
+    }
+
     /// Make use of a missing unit name. The library unit will be sensitive to adding such a unit in the future.
     pub(super) fn make_use_of_missing_unit(
         &self,
@@ -741,6 +883,7 @@ impl DesignRoot {
     fn reset(&mut self) {
         let mut removed = FnvHashSet::default();
         let mut added = FnvHashSet::default();
+        let mut rebound_entities = Vec::new();
 
         for library in self.libraries.values_mut() {
             for unit_id in library.added.drain() {
@@ -749,6 +892,9 @@ impl DesignRoot {
             for unit_id in library.removed.drain() {
                 removed.insert(unit_id);
             }
+            for entity_name in library.rebound_entities.drain() {
+                rebound_entities.push((library.name.clone(), entity_name));
+            }
         }
 
         let mut affected: FnvHashSet<_> = added.union(&removed).cloned().collect();
@@ -767,6 +913,15 @@ impl DesignRoot {
                 }
             }
         }
+        // Add affected users of the architectures and configurations of an entity
+        let users_of_entity_bindings = self.users_of_entity_bindings.read();
+        for key in rebound_entities.iter() {
+            if let Some(entity_bindings_affected) = users_of_entity_bindings.get(key) {
+                affected.extend(entity_bindings_affected.iter().cloned());
+            }
+        }
+        drop(users_of_entity_bindings);
+
         let missing_unit = self.missing_unit.read();
         for ((library_name, primary_name, secondary_name), unit_ids) in missing_unit.iter() {
             let was_added = added.iter().any(|added_id| {
@@ -801,9 +956,14 @@ impl DesignRoot {
         let mut users_of = self.users_of.write();
         let mut users_of_library_all = self.users_of_library_all.write();
         let mut missing_unit = self.missing_unit.write();
+        let mut users_of_entity_bindings = self.users_of_entity_bindings.write();
 
         // Clean-up after removed units
         for removed_unit in removed.iter() {
+            users_of_entity_bindings.retain(|_, unit_ids| {
+                unit_ids.remove(removed_unit);
+                !unit_ids.is_empty()
+            });
             users_of.remove(removed_unit);
             if let Some(library_all_affected) =
                 users_of_library_all.get_mut(removed_unit.library_name())
@@ -941,6 +1101,7 @@ impl DesignRoot {
                         arena,
                         diagnostics,
                         has_circular_dependency: false,
+                        waiting_procedures: FnvHashSet::default(),
                     };
 
                     unit.finish(result);
@@ -177,9 +177,8 @@ impl<'a> AnalyzeContext<'a> {
                     names.entities().collect(),
                     diagnostics,
                 ))? {
-                    Some(Disambiguated::Ambiguous(candidates)) => {
-                        diagnostics.push(Diagnostic::ambiguous_call(des, candidates))
-                    }
+                    Some(Disambiguated::Ambiguous(candidates)) => diagnostics
+                        .push(self.ambiguous_call(scope, &fcall.pos, des, candidates, parameters)?),
                     Some(Disambiguated::Unambiguous(ent)) => {
                         name.set_unique_reference(&ent);
 
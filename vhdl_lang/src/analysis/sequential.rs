@@ -92,6 +92,14 @@ impl<'a> AnalyzeContext<'a> {
                         diagnostics,
                     )?;
                 }
+                SequentialStatement::Block(ref mut block) => {
+                    self.define_labels_for_sequential_part(
+                        scope,
+                        parent,
+                        &mut block.statements,
+                        diagnostics,
+                    )?;
+                }
                 _ => {
                     // Does not have sequential part
                 }
@@ -110,7 +118,14 @@ impl<'a> AnalyzeContext<'a> {
     ) -> FatalResult {
         match statement.statement.item {
             SequentialStatement::Return(ref mut ret) => {
-                let ReturnStatement { ref mut expression } = ret;
+                let ReturnStatement {
+                    ref mut expression,
+                    ref mut condition,
+                } = ret;
+
+                if let Some(ref mut condition) = condition {
+                    self.boolean_expr(scope, condition, diagnostics)?;
+                }
 
                 match SequentialRoot::from(parent) {
                     SequentialRoot::Function(ttyp) => {
@@ -142,6 +157,14 @@ impl<'a> AnalyzeContext<'a> {
                     condition_clause,
                     timeout_clause,
                 } = wait_stmt;
+                if let SequentialRoot::Function(_) = SequentialRoot::from(parent) {
+                    diagnostics.error(
+                        &statement.statement.pos,
+                        "Functions cannot contain wait statements",
+                    );
+                } else {
+                    self.mark_waiting_subprogram(parent);
+                }
                 self.sensitivity_list_check(scope, sensitivity_clause, diagnostics)?;
                 if let Some(expr) = condition_clause {
                     self.boolean_expr(scope, expr, diagnostics)?;
@@ -239,6 +262,11 @@ impl<'a> AnalyzeContext<'a> {
                     self.analyze_sequential_part(scope, parent, item, diagnostics)?;
                 }
             }
+            SequentialStatement::Block(ref mut block) => {
+                let region = scope.nested();
+                self.analyze_declarative_part(&region, parent, &mut block.decl, diagnostics)?;
+                self.analyze_sequential_part(&region, parent, &mut block.statements, diagnostics)?;
+            }
             SequentialStatement::Loop(ref mut loop_stmt) => {
                 let LoopStatement {
                     iteration_scheme,
@@ -267,6 +295,26 @@ impl<'a> AnalyzeContext<'a> {
             }
             SequentialStatement::ProcedureCall(ref mut pcall) => {
                 self.analyze_procedure_call(scope, pcall, diagnostics)?;
+                let name = &pcall.item.name;
+                if let Some(id) = name.item.get_suffix_reference() {
+                    if self.is_waiting_procedure(id) {
+                        if let SequentialRoot::Function(_) = SequentialRoot::from(parent) {
+                            let ent = self.arena.get(id);
+                            diagnostics.push(
+                                Diagnostic::error(
+                                    &name.pos,
+                                    format!(
+                                        "Functions cannot call {} which contains a wait statement",
+                                        ent.describe()
+                                    ),
+                                )
+                                .opt_related(ent.decl_pos(), "Defined here"),
+                            );
+                        } else {
+                            self.mark_waiting_subprogram(parent);
+                        }
+                    }
+                }
             }
             SequentialStatement::SignalAssignment(ref mut assign) => {
                 // @TODO more
@@ -362,6 +410,8 @@ impl<'a> AnalyzeContext<'a> {
         statements: &mut [LabeledSequentialStatement],
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> FatalResult {
+        self.check_unreachable_statements(statements, diagnostics);
+
         for statement in statements.iter_mut() {
             let parent = if let Some(id) = statement.label.decl {
                 self.arena.get(id)
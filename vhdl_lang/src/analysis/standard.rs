@@ -453,6 +453,8 @@ impl<'a> AnalyzeContext<'a> {
                             iface: Some(ObjectInterface::Parameter(Mode::Out)),
                             subtype: Subtype::new(type_mark),
                             has_default: false,
+                            signal_kind: None,
+                            static_value: None,
                         }),
                     ),
                 ],
@@ -537,6 +539,8 @@ impl<'a> AnalyzeContext<'a> {
                     iface: Some(ObjectInterface::Parameter(Mode::InOut)),
                     subtype: Subtype::new(type_ent.to_owned()),
                     has_default: false,
+                    signal_kind: None,
+                    static_value: None,
                 }),
             )],
             None,
@@ -0,0 +1,171 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Evaluation of locally static integer expressions
+//!
+//! Only expressions built from integer literals, constants with a statically known value
+//! and arithmetic operators are evaluated, anything else is considered unknown.
+//! Names must already have been analyzed to refer to a constant.
+
+use super::analyze::*;
+use super::named_entity::*;
+use crate::ast::Range;
+use crate::ast::*;
+use crate::data::*;
+
+/// The bounds of an integer range where each bound may be statically known
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct StaticRange {
+    pub low: Option<i64>,
+    pub high: Option<i64>,
+}
+
+impl StaticRange {
+    /// An error message if the value is known to be outside of the range
+    pub fn check_value(&self, value: i64, range_desc: &str) -> Option<String> {
+        if let (Some(low), Some(high)) = (self.low, self.high) {
+            if high < low {
+                // A null range contains no values but is not worth reporting here
+                return None;
+            }
+        }
+
+        match (self.low, self.high) {
+            (Some(low), _) if value < low => Some(format!(
+                "Value {value} is less than the lower bound {low} of {range_desc}"
+            )),
+            (_, Some(high)) if value > high => Some(format!(
+                "Value {value} is greater than the upper bound {high} of {range_desc}"
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl<'a> AnalyzeContext<'a> {
+    /// The value of an integer expression when it can be evaluated statically
+    pub fn static_integer(&self, expr: &Expression) -> Option<i64> {
+        match expr {
+            Expression::Literal(Literal::AbstractLiteral(AbstractLiteral::Integer(value))) => {
+                i64::try_from(*value).ok()
+            }
+            Expression::Name(name) => self.static_name(name),
+            Expression::Unary(op, operand) => {
+                let operand = self.static_integer(&operand.item)?;
+                match op.item.item {
+                    Operator::Plus => Some(operand),
+                    Operator::Minus => operand.checked_neg(),
+                    Operator::Abs => operand.checked_abs(),
+                    _ => None,
+                }
+            }
+            Expression::Binary(op, left, right) => {
+                let left = self.static_integer(&left.item)?;
+                let right = self.static_integer(&right.item)?;
+                match op.item.item {
+                    Operator::Plus => left.checked_add(right),
+                    Operator::Minus => left.checked_sub(right),
+                    Operator::Times => left.checked_mul(right),
+                    Operator::Div => left.checked_div(right),
+                    Operator::Rem => left.checked_rem(right),
+                    Operator::Mod => left.checked_rem_euclid(right).map(|rem| {
+                        if rem != 0 && right < 0 {
+                            rem + right
+                        } else {
+                            rem
+                        }
+                    }),
+                    Operator::Pow => u32::try_from(right)
+                        .ok()
+                        .and_then(|exp| left.checked_pow(exp)),
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// The value of a name that denotes a constant with a statically known value
+    fn static_name(&self, name: &Name) -> Option<i64> {
+        if !matches!(name, Name::Designator(_) | Name::Selected(..)) {
+            return None;
+        }
+
+        let ent = self.arena.get(name.get_suffix_reference()?);
+        if let AnyEntKind::Object(obj) = ent.actual_kind() {
+            obj.static_value
+        } else {
+            None
+        }
+    }
+
+    /// The bounds of a range that can be evaluated statically
+    pub fn static_range(&self, range: &Range) -> Option<StaticRange> {
+        if let Range::Range(constraint) = range {
+            let left = self.static_integer(&constraint.left_expr.item);
+            let right = self.static_integer(&constraint.right_expr.item);
+            let (low, high) = match constraint.direction {
+                Direction::Ascending => (left, right),
+                Direction::Descending => (right, left),
+            };
+
+            if low.is_some() || high.is_some() {
+                Some(StaticRange { low, high })
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    }
+
+    /// The range of a discrete range when it is given explicitly or by a subtype with a
+    /// statically known range
+    pub fn static_drange(&self, drange: &DiscreteRange) -> Option<StaticRange> {
+        match drange {
+            DiscreteRange::Discrete(_, Some(range)) | DiscreteRange::Range(range) => {
+                self.static_range(range)
+            }
+            DiscreteRange::Discrete(type_mark, None) => self.static_type_mark(type_mark),
+        }
+    }
+
+    /// The range of the subtype denoted by a type mark when it is statically known
+    pub fn static_type_mark(&self, type_mark: &WithPos<TypeMark>) -> Option<StaticRange> {
+        if type_mark.item.attr.is_some() {
+            return None;
+        }
+        let ent = self
+            .arena
+            .get(type_mark.item.name.item.get_suffix_reference()?);
+        TypeEnt::from_any(ent)?.static_range()
+    }
+
+    /// The number of values in a discrete range when it can be evaluated statically
+    pub fn static_length(&self, drange: &DiscreteRange) -> Option<u64> {
+        let range = match drange {
+            DiscreteRange::Discrete(_, Some(range)) | DiscreteRange::Range(range) => range,
+            DiscreteRange::Discrete(_, None) => return None,
+        };
+
+        if let Range::Range(constraint) = range {
+            let left = self.static_integer(&constraint.left_expr.item)?;
+            let right = self.static_integer(&constraint.right_expr.item)?;
+            let (low, high) = match constraint.direction {
+                Direction::Ascending => (left, right),
+                Direction::Descending => (right, left),
+            };
+
+            if high < low {
+                Some(0)
+            } else {
+                u64::try_from(high.checked_sub(low)?.checked_add(1)?).ok()
+            }
+        } else {
+            None
+        }
+    }
+}
@@ -85,8 +85,10 @@ impl AssignmentType {
 }
 
 /// Check that the assignment target is a writable object and not constant or input only
+/// Linkage ports may only be read or updated through association with another port
 fn is_valid_assignment_target(base: &ObjectBase) -> bool {
-    base.class() != ObjectClass::Constant && !matches!(base.mode(), Some(Mode::In))
+    base.class() != ObjectClass::Constant
+        && !matches!(base.mode(), Some(Mode::In) | Some(Mode::Linkage))
 }
 
 // Check that a signal is not the target of a variable assignment and vice-versa
@@ -304,6 +304,44 @@ end architecture;
     check_diagnostics(diagnostics, expected);
 }
 
+#[test]
+fn input_and_linkage_ports_may_not_be_assignment_target() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (
+    inport : in natural;
+    linkport : linkage natural;
+    outport : out natural
+  );
+end entity;
+
+architecture a of ent is
+begin
+    inport <= 1;
+    linkport <= 1;
+    outport <= 1;
+end architecture;
+",
+    );
+
+    let expected = vec![
+        Diagnostic::error(
+            code.s("inport", 2),
+            "interface signal 'inport' of mode in may not be the target of an assignment",
+        ),
+        Diagnostic::error(
+            code.s("linkport", 2),
+            "interface signal 'linkport' of mode linkage may not be the target of an assignment",
+        ),
+    ];
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(diagnostics, expected);
+}
+
 #[test]
 fn checks_signal_vs_variable_assignment_target() {
     let mut builder = LibraryBuilder::new();
@@ -449,9 +449,235 @@ end architecture;
     );
 
     let (root, diagnostics) = builder.get_analyzed_root();
-    check_no_diagnostics(&diagnostics);
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("entity work.ent2").s1("work.ent2"),
+            "port 'outport' : out is left open since it has no association",
+        )
+        .related(code.s1("outport"), "Defined here")],
+    );
     // Still resolves references when missing output port
     assert!(root
         .search_reference(code.source(), code.s1("inport => sig").s1("sig").start())
         .is_some())
 }
+
+#[test]
+fn actual_port_mode_must_be_compatible_with_formal_mode() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent2 is
+port (
+    signal inport: in natural;
+    signal outport: out natural;
+    signal ioport: inout natural);
+end entity;
+
+architecture a of ent2 is
+begin
+end architecture;
+
+entity ent is
+port (
+    signal i: in natural;
+    signal o: out natural;
+    signal io: inout natural);
+end entity;
+
+architecture a of ent is
+begin
+    good: entity work.ent2
+        port map (
+        inport => o,
+        outport => io,
+        ioport => io
+        );
+
+    bad: entity work.ent2
+        port map (
+        inport => i,
+        outport => i,
+        ioport => o
+        );
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("outport => i,").s1("=> i").s1("i"),
+                "port 'i' : in cannot be associated with formal port 'outport' : out",
+            )
+            .related(code.s1("outport: out").s1("outport"), "Defined here"),
+            Diagnostic::error(
+                code.s1("ioport => o").s1("=> o").s1("o"),
+                "port 'o' : out cannot be associated with formal port 'ioport' : inout",
+            )
+            .related(code.s1("ioport: inout").s1("ioport"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn actual_port_mode_must_be_compatible_with_signal_parameter_mode() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+port (
+    signal i: in natural;
+    signal o: out natural);
+end entity;
+
+architecture a of ent is
+    procedure drive(signal s : out natural) is
+    begin
+        s <= 0;
+    end;
+begin
+    process
+    begin
+        drive(o);
+        drive(i);
+        wait;
+    end process;
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("drive(i)").s1("(i").s1("i"),
+            "port 'i' : in cannot be associated with formal signal 's' : out",
+        )
+        .related(code.s1("signal s :").s1("s :").s1("s"), "Defined here")],
+    );
+}
+
+#[test]
+fn linkage_ports_may_only_be_read_through_association() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent2 is
+port (
+    signal inport: in natural;
+    signal linkport: linkage natural);
+end entity;
+
+architecture a of ent2 is
+begin
+end architecture;
+
+entity ent is
+port (
+    signal l: linkage natural);
+end entity;
+
+architecture a of ent is
+    signal s : natural;
+begin
+    s <= l + 1;
+
+    good: entity work.ent2
+        port map (
+        inport => s,
+        linkport => l
+        );
+
+    bad: entity work.ent2
+        port map (
+        inport => l,
+        linkport => s
+        );
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("s <= l").s1("l"),
+                "port 'l' : linkage may only be read through association",
+            ),
+            Diagnostic::error(
+                code.s1("inport => l").s1("=> l").s1("l"),
+                "port 'l' : linkage may only be read through association",
+            ),
+            Diagnostic::error(
+                code.s1("inport => l").s1("=> l").s1("l"),
+                "port 'l' : linkage cannot be associated with formal port 'inport' : in",
+            )
+            .related(code.s1("inport: in").s1("inport"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn input_ports_and_generics_without_default_may_not_be_open() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent2 is
+generic (
+    thegeneric : natural;
+    defgeneric : natural := 0);
+port (
+    signal inport: in natural;
+    signal definport: in natural := 0;
+    signal outport: out natural);
+end entity;
+
+architecture a of ent2 is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+    inst: entity work.ent2
+        generic map (
+        thegeneric => open,
+        defgeneric => open
+        )
+        port map (
+        inport => open,
+        definport => open,
+        outport => open
+        );
+end architecture;
+    ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("thegeneric => open").s1("open"),
+                "generic 'thegeneric' has no default value and may not be associated with open",
+            )
+            .related(code.s1("thegeneric"), "Defined here"),
+            Diagnostic::error(
+                code.s1("inport => open").s1("open"),
+                "port 'inport' : in has no default value and may not be associated with open",
+            )
+            .related(code.s1("inport"), "Defined here"),
+        ],
+    );
+}
@@ -0,0 +1,388 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn matching_default_binding() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity comp is
+  generic (width : natural := 8);
+  port (
+    clk : in bit;
+    q : out bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  component comp is
+    generic (width : natural := 8);
+    port (
+      clk : in bit;
+      q : out bit);
+  end component;
+
+  signal clk, q : bit;
+begin
+  inst: comp port map (clk => clk, q => q);
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn default_binding_with_mismatched_ports() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity comp is
+  port (
+    clk : in bit;
+    d : in natural;
+    q : out bit;
+    en : in bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  component comp is
+    port (
+      clk : in bit;
+      d : in bit;
+      q : in bit;
+      rst : in bit);
+  end component;
+
+  signal sig : bit;
+begin
+  inst: comp port map (clk => sig, d => sig, q => sig, rst => sig);
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    let inst = code.s1("inst: comp").s1("comp");
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                &inst,
+                "port 'd' : in of component 'comp' has type 'BIT' but port 'd' : in of entity 'comp' has integer type 'INTEGER'",
+            )
+            .related(code.s("d :", 2).s1("d"), "Defined in component 'comp'")
+            .related(code.s1("d :").s1("d"), "Defined in entity 'comp'"),
+            Diagnostic::error(
+                &inst,
+                "port 'q' : in of component 'comp' has a different mode than port 'q' : out of entity 'comp'",
+            )
+            .related(code.s("q :", 2).s1("q"), "Defined in component 'comp'")
+            .related(code.s1("q :").s1("q"), "Defined in entity 'comp'"),
+            Diagnostic::error(
+                &inst,
+                "port 'rst' : in of component 'comp' has no match in entity 'comp'",
+            )
+            .related(code.s1("rst"), "Defined here")
+            .related(code.s1("comp"), "Found entity 'comp'"),
+            Diagnostic::error(
+                &inst,
+                "port 'en' : in of entity 'comp' has no match in component 'comp'",
+            )
+            .related(code.s1("en :").s1("en"), "Defined here")
+            .related(code.s("comp", 4), "Found component 'comp'"),
+        ],
+    );
+}
+
+#[test]
+fn configured_instance_is_not_default_bound() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity comp is
+  port (a : in bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+entity impl is
+  port (b : in bit);
+end entity;
+
+architecture a of impl is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  component comp is
+    port (b : in bit);
+  end component;
+
+  for inst : comp use entity work.impl(a);
+  for others : comp use entity work.comp;
+
+  signal sig : bit;
+begin
+  inst: comp port map (b => sig);
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    let spec = code.s1("others : comp").s1("comp");
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                &spec,
+                "port 'b' : in of component 'comp' has no match in entity 'comp'",
+            )
+            .related(code.s("b : in", 2).s1("b"), "Defined here")
+            .related(code.s1("comp"), "Found entity 'comp'"),
+            Diagnostic::error(
+                &spec,
+                "port 'a' : in of entity 'comp' has no match in component 'comp'",
+            )
+            .related(code.s1("a : in").s1("a"), "Defined here")
+            .related(code.s("comp", 4), "Found component 'comp'"),
+        ],
+    );
+}
+
+#[test]
+fn default_binding_with_compatible_port_modes() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity comp is
+  port (
+    x : in bit;
+    y : out bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  component comp is
+    port (
+      x : inout bit;
+      y : buffer bit);
+  end component;
+
+  signal x, y : bit;
+begin
+  inst: comp port map (x => x, y => y);
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn instances_of_configured_architecture_are_bound_by_the_configuration() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity comp is
+  port (a : in bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+entity impl is
+  port (b : in bit);
+end entity;
+
+architecture a of impl is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  component comp is
+    port (b : in bit);
+  end component;
+
+  signal sig : bit;
+begin
+  inst: comp port map (b => sig);
+
+  blk: block
+  begin
+    other: comp port map (b => sig);
+  end block;
+end architecture;
+
+configuration cfg of ent is
+  for a
+    for inst : comp
+      use entity work.impl(a);
+    end for;
+  end for;
+end configuration;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    let other = code.s1("other: comp").s1("comp");
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                &other,
+                "port 'b' : in of component 'comp' has no match in entity 'comp'",
+            )
+            .related(code.s("b : in", 2).s1("b"), "Defined here")
+            .related(code.s1("comp"), "Found entity 'comp'"),
+            Diagnostic::error(
+                &other,
+                "port 'a' : in of entity 'comp' has no match in component 'comp'",
+            )
+            .related(code.s1("a : in").s1("a"), "Defined here")
+            .related(code.s("comp", 4), "Found component 'comp'"),
+        ],
+    );
+}
+
+#[test]
+fn component_of_other_library_is_bound_to_entity_of_instantiating_library() {
+    let mut builder = LibraryBuilder::new();
+    let pkg = builder.code(
+        "pkglib",
+        "
+package comps is
+  component comp is
+    port (b : in bit);
+  end component;
+end package;
+        ",
+    );
+    let code = builder.code(
+        "libname",
+        "
+entity comp is
+  port (a : in bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+library pkglib;
+use pkglib.comps.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  inst: comp port map (b => sig);
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    let inst = code.s1("inst: comp").s1("comp");
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                &inst,
+                "port 'b' : in of component 'comp' has no match in entity 'comp'",
+            )
+            .related(pkg.s1("b : in").s1("b"), "Defined here")
+            .related(code.s1("comp"), "Found entity 'comp'"),
+            Diagnostic::error(
+                &inst,
+                "port 'a' : in of entity 'comp' has no match in component 'comp'",
+            )
+            .related(code.s1("a : in").s1("a"), "Defined here")
+            .related(
+                pkg.s1("component comp").s("comp", 2),
+                "Found component 'comp'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn entity_visible_at_the_instance_is_bound_by_default() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "otherlib",
+        "
+entity comp is
+  port (b : in bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+        ",
+    );
+    builder.code(
+        "libname",
+        "
+entity comp is
+  port (a : in bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+library otherlib;
+use otherlib.comp;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  component comp is
+    port (b : in bit);
+  end component;
+
+  signal sig : bit;
+begin
+  inst: comp port map (b => sig);
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
@@ -0,0 +1,192 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+const DESIGN: &str = "
+entity leaf is
+  port (a : in bit);
+end entity;
+
+architecture rtl of leaf is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture struct of ent is
+  component c_a is
+    port (a : in bit);
+  end component;
+
+  signal sig : bit;
+begin
+  u1: c_a port map (a => sig);
+
+  g1: for i in 0 to 1 generate
+    component c_b is
+      port (a : in bit);
+    end component;
+  begin
+    u2: c_b port map (a => sig);
+  end generate;
+end architecture;
+";
+
+#[test]
+fn resolves_references_within_configuration() {
+    for name in ["struct", "u1", "c_a", "rtl", "g1", "u2", "c_b", "leaf"] {
+        check_search_reference_with_name(
+            name,
+            &format!(
+                "{DESIGN}
+configuration cfg of ent is
+  for struct
+    for u1 : c_a
+      use entity work.leaf(rtl);
+    end for;
+    for g1(0)
+      for u2 : c_b
+        use entity work.leaf(rtl);
+      end for;
+    end for;
+  end for;
+end configuration;
+"
+            ),
+        );
+    }
+}
+
+#[test]
+fn error_on_missing_architecture_and_labels() {
+    let mut builder = LibraryBuilder::new();
+    let design = builder.code("libname", DESIGN);
+    let code = builder.code(
+        "libname",
+        "
+configuration cfg1 of ent is
+  for missing_arch
+  end for;
+end configuration;
+
+configuration cfg2 of ent is
+  for struct
+    for missing_inst : c_a
+    end for;
+    for sig
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("missing_arch"),
+                "No architecture 'missing_arch' for entity 'libname.ent'",
+            ),
+            Diagnostic::error(code.s1("missing_inst"), "No declaration of 'missing_inst'"),
+            Diagnostic::error(
+                code.s1("for sig").s1("sig"),
+                "Expected block or generate statement, got signal 'sig'",
+            )
+            .related(design.s1("sig :").s1("sig"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn error_on_label_of_other_component() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture struct of ent is
+  component comp1 is
+  end component;
+
+  component comp2 is
+  end component;
+begin
+  u1: component comp1;
+end architecture;
+
+configuration cfg of ent is
+  for struct
+    for u1 : comp2
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s("u1", 2),
+            "instance 'u1' is not an instance of component 'comp2'",
+        )
+        .related(code.s1("u1"), "Defined here")],
+    );
+}
+
+#[test]
+fn checks_binding_indication_of_component_configuration() {
+    let mut builder = LibraryBuilder::new();
+    let design = builder.code("libname", DESIGN);
+    let code = builder.code(
+        "libname",
+        "
+entity other is
+  port (b : in bit);
+end entity;
+
+configuration cfg of ent is
+  for struct
+    for u1 : c_a
+      use entity work.other;
+    end for;
+    for g1(0)
+      for u2 : c_b
+        use entity work.other port map (b => a, c => a);
+      end for;
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    let diagnostics = builder.analyze();
+    let spec = code.s1("u1 : c_a").s1("c_a");
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                &spec,
+                "port 'a' : in of component 'c_a' has no match in entity 'other'",
+            )
+            .related(design.s("a : in", 2).s1("a"), "Defined here")
+            .related(code.s1("other"), "Found entity 'other'"),
+            Diagnostic::error(
+                &spec,
+                "port 'b' : in of entity 'other' has no match in component 'c_a'",
+            )
+            .related(code.s1("b : in").s1("b"), "Defined here")
+            .related(
+                design.s1("component c_a").s1("c_a"),
+                "Found component 'c_a'",
+            ),
+            Diagnostic::error(code.s1("c => a").s1("c"), "No declaration of 'c'"),
+        ],
+    );
+}
@@ -0,0 +1,197 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::config::Lint;
+
+fn builder_with_lints() -> LibraryBuilder {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::MissingReturn);
+    builder.enable_lint(Lint::UnreachableCode);
+    builder
+}
+
+#[test]
+fn lints_are_disabled_by_default() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+function fun(arg : natural) return natural is
+  variable count : natural := 0;
+begin
+  if arg = 0 then
+    return count;
+    count := 1;
+  end if;
+end function;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn function_without_return_on_every_path() {
+    let mut builder = builder_with_lints();
+    let code = builder.in_declarative_region(
+        "
+function fun1(arg : natural) return natural is
+begin
+end function;
+
+function fun2(arg : natural) return natural is
+begin
+  if arg = 0 then
+    return 0;
+  elsif arg = 1 then
+    return 1;
+  end if;
+end function;
+
+function fun3(arg : natural) return natural is
+begin
+  case arg is
+    when 0 => return 0;
+    when others => null;
+  end case;
+end function;
+
+function fun4(arg : natural) return natural is
+begin
+  for i in 0 to arg loop
+    return i;
+  end loop;
+end function;
+
+function fun5(arg : natural) return natural is
+begin
+  loop
+    exit when arg = 0;
+  end loop;
+end function;
+
+function fun6(arg : natural) return natural is
+begin
+  outer: loop
+    loop
+      exit outer when arg = 0;
+    end loop;
+  end loop;
+end function;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        (1..=6)
+            .map(|idx| {
+                let name = format!("fun{idx}");
+                Diagnostic::warning(
+                    code.s1(&name),
+                    format!(
+                        "Function '{name}' can reach the end of its body without returning a value"
+                    ),
+                )
+            })
+            .collect(),
+    );
+}
+
+#[test]
+fn function_with_return_on_every_path() {
+    let mut builder = builder_with_lints();
+    builder.in_declarative_region(
+        "
+function fun1(arg : natural) return natural is
+begin
+  if arg = 0 then
+    return 0;
+  elsif arg = 1 then
+    return 1;
+  else
+    return 2;
+  end if;
+end function;
+
+function fun2(arg : natural) return natural is
+begin
+  case arg is
+    when 0 => return 0;
+    when others => return 1;
+  end case;
+end function;
+
+function fun3(arg : natural) return natural is
+  variable count : natural := 0;
+begin
+  loop
+    for i in 0 to arg loop
+      exit when i = 1;
+    end loop;
+
+    inner: loop
+      exit inner when count = arg;
+      count := count + 1;
+    end loop;
+  end loop;
+end function;
+
+function fun4(arg : natural) return natural is
+begin
+  assert false report \"unreachable\" severity failure;
+end function;
+
+function fun5(arg : natural) return natural is
+begin
+  report \"unreachable\" severity failure;
+end function;
+
+procedure proc is
+begin
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn unreachable_statements() {
+    let mut builder = builder_with_lints();
+    let code = builder.in_declarative_region(
+        "
+function fun(arg : natural) return natural is
+  variable count : natural := 0;
+begin
+  for i in 0 to arg loop
+    next when i = 0;
+    count := count + 1;
+    exit;
+    count := count + 2;
+  end loop;
+  return count;
+  count := 0;
+end function;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(code.s1("count := count + 2;"), "Unreachable statement").related(
+                code.s1("exit;"),
+                "Control does not continue after this statement",
+            ),
+            Diagnostic::warning(code.s1("count := 0;"), "Unreachable statement").related(
+                code.s1("return count;"),
+                "Control does not continue after this statement",
+            ),
+        ],
+    );
+}
@@ -0,0 +1,246 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::config::Lint;
+
+fn builder_with_lint() -> LibraryBuilder {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::UnassignedVariables);
+    builder
+}
+
+#[test]
+fn lint_is_disabled_by_default() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+function fun(arg : natural) return natural is
+  variable result : natural;
+begin
+  return result + arg;
+end function;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn variable_read_before_assignment() {
+    let mut builder = builder_with_lint();
+    let code = builder.in_declarative_region(
+        "
+function fun1(arg : natural) return natural is
+  variable result : natural;
+begin
+  if arg = 0 then
+    result := 1;
+  end if;
+  return result;
+end function;
+
+function fun2(arg : natural) return natural is
+  variable result : natural;
+begin
+  for i in 0 to arg loop
+    result := i;
+  end loop;
+  return result + result;
+end function;
+
+function fun3(arg : natural) return natural is
+  variable result : natural := 0;
+  variable other : natural;
+begin
+  if arg = 0 then
+    other := 1;
+  else
+    other := 2;
+  end if;
+  return result + other;
+end function;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("return result;").s1("result"),
+                "Variable 'result' may be read before it is assigned",
+            )
+            .related(code.s1("result"), "Defined here"),
+            Diagnostic::warning(
+                code.s1("return result + result;").s1("result"),
+                "Variable 'result' may be read before it is assigned",
+            )
+            .related(code.s("result", 4), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn variable_read_before_assignment_in_process() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk : bit;
+  signal output : natural;
+begin
+  process
+    variable count : natural;
+    variable prev : natural;
+  begin
+    wait until clk = '1';
+    output <= count;
+    count := prev + 1;
+    prev := count;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("<= count").s1("count"),
+                "Variable 'count' may be read before it is assigned",
+            )
+            .related(code.s1("count"), "Defined here"),
+            Diagnostic::warning(
+                code.s1("prev + 1").s1("prev"),
+                "Variable 'prev' may be read before it is assigned",
+            )
+            .related(code.s1("prev"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn out_parameters_and_attributes_do_not_read() {
+    let mut builder = builder_with_lint();
+    builder.in_declarative_region(
+        "
+procedure get(value : out natural) is
+begin
+  value := 0;
+end procedure;
+
+function fun return natural is
+  variable value : natural;
+  variable vec : bit_vector(0 to 3);
+begin
+  get(value);
+  return value + vec'length;
+end function;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn variables_referenced_by_nested_subprograms_are_not_checked() {
+    let mut builder = builder_with_lint();
+    builder.in_declarative_region(
+        "
+function fun return natural is
+  variable value : natural;
+
+  procedure set is
+  begin
+    value := 1;
+  end procedure;
+begin
+  set;
+  return value;
+end function;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn overwritten_assignments() {
+    let mut builder = builder_with_lint();
+    let code = builder.in_declarative_region(
+        "
+function fun(arg : natural) return natural is
+  variable value : natural := 0;
+  variable unused : natural;
+  variable vec : bit_vector(0 to 1);
+begin
+  value := 1;
+  value := 2;
+  if arg = 0 then
+    value := 3;
+  end if;
+  unused := 4;
+  vec := \"00\";
+  vec(0) := '1';
+  vec := \"11\";
+  if vec(0) = '1' then
+    return value;
+  end if;
+  return 0;
+end function;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("value := 1").s1("value"),
+            "Value assigned to variable 'value' is overwritten before it is read",
+        )],
+    );
+}
+
+#[test]
+fn loops_and_jumps() {
+    let mut builder = builder_with_lint();
+    let code = builder.in_declarative_region(
+        "
+function fun1(arg : natural) return natural is
+  variable value : natural;
+begin
+  value := arg;
+  loop
+    value := 1;
+    exit when value > arg;
+  end loop;
+  return value;
+end function;
+
+function fun2(arg : natural) return natural is
+  variable value : natural;
+begin
+  value := arg;
+  for i in 0 to arg loop
+    next when i = 0;
+    value := value + i;
+  end loop;
+  return value;
+end function;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("value := arg").s1("value"),
+            "Value assigned to variable 'value' is overwritten before it is read",
+        )],
+    );
+}
@@ -0,0 +1,389 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+fn add_core(builder: &mut LibraryBuilder) -> Code {
+    builder.code(
+        "libname",
+        "
+package types_pkg is
+  type state_t is (idle, busy);
+  signal global_sig : bit;
+end package;
+
+use work.types_pkg.all;
+
+entity core is
+end entity;
+
+architecture rtl of core is
+  signal state : state_t;
+  constant width : natural := 8;
+begin
+  gen: for i in 0 to 3 generate
+    signal lane : bit;
+  begin
+  end generate;
+
+  blk: block
+    signal inner : bit;
+  begin
+  end block;
+end architecture;
+
+entity dut is
+end entity;
+
+architecture rtl of dut is
+  component core is
+  end component;
+begin
+  u_core: component core;
+end architecture;
+        ",
+    )
+}
+
+#[test]
+fn resolves_relative_path_into_instances() {
+    let mut builder = LibraryBuilder::new();
+    let core = add_core(&mut builder);
+    let code = builder.code(
+        "libname",
+        "
+use work.types_pkg.all;
+
+entity tb is
+end entity;
+
+architecture a of tb is
+  alias state is <<signal dut_inst.u_core.state : state_t>>;
+  alias width is <<constant dut_inst.u_core.width : natural>>;
+  alias lane is <<signal dut_inst.u_core.gen(0).lane : bit>>;
+  alias inner is <<signal dut_inst.u_core.blk.inner : bit>>;
+begin
+  dut_inst: entity work.dut;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("dut_inst.u_core").start()),
+        Some(code.s1("dut_inst: entity").s1("dut_inst").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("u_core.state").start()),
+        Some(core.s1("u_core: component").s1("u_core").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(".state").s1("state").start()),
+        Some(core.s1("signal state").s1("state").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(".width").s1("width").start()),
+        Some(core.s1("width").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("gen(0)").start()),
+        Some(core.s1("gen").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(".lane").s1("lane").start()),
+        Some(core.s1("lane").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(".inner").s1("inner").start()),
+        Some(core.s1("inner").pos())
+    );
+}
+
+#[test]
+fn resolves_absolute_path_from_current_entity() {
+    let mut builder = LibraryBuilder::new();
+    let core = add_core(&mut builder);
+    let code = builder.code(
+        "libname",
+        "
+use work.types_pkg.all;
+
+entity tb is
+end entity;
+
+architecture a of tb is
+begin
+  dut_inst: entity work.dut;
+
+  process
+  begin
+    wait until <<signal .tb.dut_inst.u_core.state : state_t>> = busy;
+  end process;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(".tb").s1("tb").start()),
+        Some(code.s1("tb").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(".state").s1("state").start()),
+        Some(core.s1("signal state").s1("state").pos())
+    );
+}
+
+#[test]
+fn resolves_package_path() {
+    let mut builder = LibraryBuilder::new();
+    let core = add_core(&mut builder);
+    let code = builder.code(
+        "libname",
+        "
+entity tb is
+end entity;
+
+architecture a of tb is
+  alias sig is <<signal @libname.types_pkg.global_sig : bit>>;
+  alias work_sig is <<signal @work.types_pkg.global_sig : bit>>;
+begin
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("types_pkg").start()),
+        Some(core.s1("types_pkg").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("global_sig").start()),
+        Some(core.s1("global_sig").pos())
+    );
+}
+
+#[test]
+fn path_that_leaves_current_design_unit_is_not_resolved() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  alias up is <<signal ^.sibling.sig : bit>>;
+  alias top is <<signal .top.dut.sig : bit>>;
+begin
+end architecture;
+        ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn resolves_path_from_enclosing_concurrent_regions() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal s : bit;
+begin
+  outer: block
+    signal s : integer;
+  begin
+    inner: block
+      signal s : boolean;
+      alias up is <<signal ^.s : integer>>;
+      alias up_up is <<signal ^.^.s : bit>>;
+    begin
+      process
+        alias here is <<signal s : boolean>>;
+        alias above is <<signal ^.s : integer>>;
+      begin
+        wait;
+      end process;
+    end block;
+  end block;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("^.s").s1("s").start()),
+        Some(code.s1("s : integer").s1("s").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("^.^.s").s1("s").start()),
+        Some(code.s1("s : bit").s1("s").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(
+            code.source(),
+            code.s1("signal s : boolean>>").s1("s ").start()
+        ),
+        Some(code.s1("s : boolean").s1("s").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(
+            code.source(),
+            code.s1("above is <<signal ^.s").s1("^.s").s1("s").start()
+        ),
+        Some(code.s1("s : integer").s1("s").pos())
+    );
+}
+
+#[test]
+fn use_visible_declarations_are_not_part_of_the_hierarchy() {
+    let mut builder = LibraryBuilder::new();
+    add_core(&mut builder);
+    let code = builder.code(
+        "libname",
+        "
+use work.types_pkg.all;
+
+entity tb is
+end entity;
+
+architecture a of tb is
+  alias sig is <<signal global_sig : bit>>;
+begin
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("global_sig"),
+            "No declaration of 'global_sig'",
+        )],
+    );
+}
+
+#[test]
+fn error_on_missing_path_element() {
+    let mut builder = LibraryBuilder::new();
+    add_core(&mut builder);
+    let code = builder.code(
+        "libname",
+        "
+use work.types_pkg.all;
+
+entity tb is
+end entity;
+
+architecture a of tb is
+  alias missing_inst is <<signal missing.sig : bit>>;
+  alias missing_sig is <<signal dut_inst.u_core.missing : bit>>;
+  alias missing_pkg_sig is <<signal @work.types_pkg.missing : bit>>;
+  alias missing_lib is <<signal @missing_lib.pkg.sig : bit>>;
+begin
+  dut_inst: entity work.dut;
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("missing.sig").s1("missing"),
+                "No declaration of 'missing'",
+            ),
+            Diagnostic::error(
+                code.s1("u_core.missing").s1("missing"),
+                "No declaration of 'missing' within instance 'u_core'",
+            ),
+            Diagnostic::error(
+                code.s1("types_pkg.missing").s1("missing"),
+                "No declaration of 'missing' within package 'types_pkg'",
+            ),
+            Diagnostic::error(
+                code.s1("@missing_lib").s1("missing_lib"),
+                "No such library 'missing_lib'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn error_on_object_class_mismatch() {
+    let mut builder = LibraryBuilder::new();
+    let core = add_core(&mut builder);
+    let code = builder.code(
+        "libname",
+        "
+entity tb is
+end entity;
+
+architecture a of tb is
+  alias width is <<signal dut_inst.u_core.width : natural>>;
+  alias inst is <<signal dut_inst.u_core : bit>>;
+begin
+  dut_inst: entity work.dut;
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("u_core.width").s1("width"),
+                "Expected signal, got constant 'width'",
+            )
+            .related(core.s1("width"), "Defined here"),
+            Diagnostic::error(
+                code.s1("u_core : bit").s1("u_core"),
+                "Expected signal, got instance 'u_core'",
+            )
+            .related(core.s1("u_core"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn error_on_type_mismatch() {
+    let mut builder = LibraryBuilder::new();
+    add_core(&mut builder);
+    let code = builder.code(
+        "libname",
+        "
+entity tb is
+end entity;
+
+architecture a of tb is
+  alias state is <<signal dut_inst.u_core.state : bit>>;
+begin
+  dut_inst: entity work.dut;
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1(": bit").s1("bit"),
+            "type 'BIT' does not match signal 'state' of type 'state_t'",
+        )],
+    );
+}
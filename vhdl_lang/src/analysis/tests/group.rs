@@ -0,0 +1,148 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn group_constituents_are_resolved() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, data : bit;
+  group pin2pin is (signal, signal);
+  group path is (label, label <>);
+  group timing : pin2pin (clk, data);
+  group chain : path (first, second, third);
+
+  attribute delay : time;
+  attribute delay of timing : group is 1 ns;
+begin
+  first: data <= clk;
+  second: data <= not clk;
+  third: data <= clk;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1(": pin2pin").s1("pin2pin").start()),
+        Some(code.s1("pin2pin").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("(clk, data)").s1("data").start()),
+        Some(code.s1("data").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("(first").s1("first").start()),
+        Some(code.s1("first:").s1("first").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("of timing").s1("timing").start()),
+        Some(code.s1("timing").pos())
+    );
+}
+
+#[test]
+fn group_constituent_must_match_entity_class() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+constant c0 : natural := 0;
+signal sig : bit;
+group pin2pin is (signal, signal);
+group bad : pin2pin (sig, c0);
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("sig, c0").s1("c0"),
+            "constant 'c0' is not of entity class 'signal'",
+        )],
+    );
+}
+
+#[test]
+fn group_constituent_literal_is_disambiguated_by_entity_class() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type state_t is (idle, busy);
+function idle return boolean;
+group states is (literal <>);
+group g0 : states (idle, busy);
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("(idle").s1("idle").start()),
+        Some(code.s1("idle").pos())
+    );
+}
+
+#[test]
+fn group_must_have_template_number_of_constituents() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+signal s0, s1, s2 : bit;
+group pin2pin is (signal, signal);
+group bus_t is (signal, signal, signal <>);
+group too_many : pin2pin (s0, s1, s2);
+group too_few : bus_t (s0);
+group with_box : bus_t (s0, s1, s2, s0);
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1(": pin2pin").s1("pin2pin"),
+                "Group template 'pin2pin' expects 2 constituents, got 3",
+            ),
+            Diagnostic::error(
+                code.s1(": bus_t").s1("bus_t"),
+                "Group template 'bus_t' expects at least 2 constituents, got 1",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn group_template_name_must_be_group_template() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+signal sig : bit;
+group bad : sig (sig);
+group missing : missing_t (sig);
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1(": sig").s1("sig"),
+                "Expected group template, got signal 'sig'",
+            ),
+            Diagnostic::error(code.s1("missing_t"), "No declaration of 'missing_t'"),
+        ],
+    );
+}
@@ -0,0 +1,328 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn guarded_block_declares_implicit_guard_signal() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, d : std_logic;
+  signal q : std_logic register;
+  signal b : std_logic bus;
+begin
+  latch: block (clk = '1' and not clk'stable)
+  begin
+    q <= guarded d;
+    b <= guarded d after 1 ns;
+    with d select
+      b <= guarded '1' when '0', '0' when others;
+  end block;
+
+  monitor: block (clk = '1')
+    signal enabled : boolean;
+  begin
+    enabled <= GUARD;
+  end block;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("<= GUARD").s1("GUARD").start()),
+        Some(code.s1("clk = '1')").s1("clk = '1'").pos())
+    );
+}
+
+#[test]
+fn guard_condition_is_searched() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal enable : boolean;
+begin
+  blk: block (enable)
+  begin
+  end block;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("(enable)").s1("enable").start()),
+        Some(code.s1("enable").pos())
+    );
+}
+
+#[test]
+fn guarded_assignment_requires_guard_signal() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal d, q : bit;
+begin
+  q <= guarded d;
+
+  blk: block
+    signal GUARD : bit;
+  begin
+    q <= guarded d;
+  end block;
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s(" q <=", 1).s1("q"),
+                "Guarded assignment requires a boolean signal 'GUARD' to be visible",
+            ),
+            Diagnostic::error(
+                code.s(" q <=", 2).s1("q"),
+                "Guarded assignment requires a boolean signal 'GUARD' to be visible",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn explicit_guard_signal_may_be_used() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal GUARD : boolean;
+  signal d, q : bit;
+begin
+  q <= guarded d;
+end architecture;
+        ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn disconnection_specification() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant delay : time := 2 ns;
+  signal b0, b1 : std_logic bus;
+  signal r : std_logic_vector(7 downto 0) register;
+  disconnect b0, b1 : std_logic after delay;
+  disconnect others : std_logic_vector after 1 ns;
+  disconnect all : std_ulogic after 0 ns;
+begin
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("b0, b1 :").s1("b1").start()),
+        Some(code.s1("b1").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("after delay").s1("delay").start()),
+        Some(code.s1("delay").pos())
+    );
+}
+
+#[test]
+fn disconnection_specification_requires_guarded_signal_of_type() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal unguarded : std_logic;
+  signal guarded_sig : std_logic bus;
+  disconnect unguarded : std_logic after 1 ns;
+  disconnect guarded_sig : bit after 1 ns;
+  disconnect guarded_sig : std_logic after 1;
+begin
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("disconnect unguarded").s1("unguarded"),
+                "signal 'unguarded' is not a guarded signal",
+            ),
+            Diagnostic::error(
+                code.s1("disconnect guarded_sig").s1("guarded_sig"),
+                "type 'BIT' does not match signal 'guarded_sig' of subtype 'STD_LOGIC'",
+            ),
+            Diagnostic::error(
+                code.s1("after 1;").s1("1"),
+                "integer literal does not match physical type 'TIME'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn guard_signal_is_declared_within_the_guarded_block() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal enable : boolean;
+begin
+  blk: block (enable)
+    signal enabled : boolean;
+  begin
+    enabled <= GUARD;
+  end block;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let guard = root
+        .search_reference(code.source(), code.s1("GUARD").start())
+        .unwrap();
+    assert_eq!(
+        guard.parent.map(|parent| parent.decl_pos().cloned()),
+        Some(Some(code.s1("blk").pos()))
+    );
+}
+
+#[test]
+fn interface_signals_may_be_of_kind_bus() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+  port (
+    b : inout std_logic bus
+  );
+  disconnect b : std_logic after 1 ns;
+end entity;
+
+architecture a of ent is
+begin
+  blk: block (true)
+  begin
+    b <= guarded '1';
+  end block;
+end architecture;
+        ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn guarded_signals_must_have_a_resolved_subtype() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+  port (
+    p : inout bit bus
+  );
+end entity;
+
+architecture a of ent is
+  type logic_vector is array (natural range <>) of std_logic;
+  signal s0 : std_ulogic bus;
+  signal s1 : resolved std_ulogic register;
+  signal s2 : logic_vector(0 to 1) bus;
+  signal s3 : integer register;
+begin
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("bit bus").s1("bit"),
+                "Signal of kind bus must have a resolved subtype, type 'BIT' is not resolved",
+            ),
+            Diagnostic::error(
+                code.s1("std_ulogic bus").s1("std_ulogic"),
+                "Signal of kind bus must have a resolved subtype, type 'STD_ULOGIC' is not resolved",
+            ),
+            Diagnostic::error(
+                code.s1("integer register").s1("integer"),
+                "Signal of kind register must have a resolved subtype, integer type 'INTEGER' is not resolved",
+            ),
+        ],
+    );
+}
@@ -158,7 +158,7 @@ end architecture;
         "libname",
         "
 configuration cfg of ent is
-for rtl
+for a
 end for;
 end configuration;
 ",
@@ -180,6 +180,96 @@ end architecture;
     check_incremental_analysis(builder, vec![]);
 }
 
+#[test]
+fn incremental_analysis_of_configured_default_binding() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity comp is
+  port (a : in bit);
+end entity;
+
+architecture a of comp is
+begin
+end architecture;
+
+entity impl is
+  port (b : in bit);
+end entity;
+
+architecture a of impl is
+begin
+end architecture;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  component comp is
+    port (b : in bit);
+  end component;
+
+  signal sig : bit;
+begin
+  inst: comp port map (b => sig);
+end architecture;
+",
+    );
+
+    builder.code(
+        "libname",
+        "
+configuration cfg of ent is
+  for a
+    for inst : comp
+      use entity work.impl(a);
+    end for;
+  end for;
+end configuration;
+",
+    );
+
+    check_incremental_analysis(builder, vec![]);
+}
+
+#[test]
+fn incremental_analysis_of_default_architecture() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity core is
+end entity;
+
+architecture rtl of core is
+  signal state : bit;
+begin
+end architecture;
+
+entity tb is
+end entity;
+
+architecture a of tb is
+  alias state is <<signal inst.state : bit>>;
+begin
+  inst: entity work.core;
+end architecture;
+",
+    );
+
+    builder.code(
+        "libname",
+        "
+architecture other of core is
+begin
+end architecture;
+",
+    );
+
+    check_incremental_analysis(builder, vec![]);
+}
+
 #[test]
 fn incremental_analysis_library_all_collision() {
     let mut builder = LibraryBuilder::new();
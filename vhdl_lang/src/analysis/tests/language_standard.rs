@@ -0,0 +1,220 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::standard::VHDLStandard;
+
+fn read_out_port_code(builder: &mut LibraryBuilder) -> Code {
+    builder.code(
+        "libname",
+        "
+entity ent2 is
+  port (
+    inport : in bit;
+    outport : out bit);
+end entity;
+
+entity ent is
+  port (
+    o : out bit;
+    v : out bit_vector(0 to 1));
+end entity;
+
+architecture a of ent is
+  signal s : bit;
+  signal n : natural;
+begin
+  s <= o;
+  s <= v(0);
+  n <= v'length;
+
+  inst: entity work.ent2
+    port map (
+      inport => o,
+      outport => o);
+end architecture;
+        ",
+    )
+}
+
+#[test]
+fn out_ports_cannot_be_read_before_vhdl2008() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL1993);
+    let code = read_out_port_code(&mut builder);
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("s <= o").s1("o"),
+                "port 'o' : out cannot be read before VHDL-2008",
+            ),
+            Diagnostic::error(
+                code.s1("v(0)"),
+                "port 'v' : out cannot be read before VHDL-2008",
+            ),
+            Diagnostic::error(
+                code.s1("inport => o").s("o", 2),
+                "port 'o' : out cannot be read before VHDL-2008",
+            ),
+            Diagnostic::error(
+                code.s1("inport => o").s("o", 2),
+                "port 'o' : out cannot be associated with formal port 'inport' : in",
+            )
+            .related(code.s1("inport"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn out_ports_can_be_read_in_vhdl2008() {
+    let mut builder = LibraryBuilder::new();
+    read_out_port_code(&mut builder);
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn actuals_of_out_ports_are_checked_against_the_formal_subtype() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL1993);
+    let code = builder.code(
+        "libname",
+        "
+entity ent2 is
+  port (
+    inport : in bit_vector(0 to 1);
+    outport : out bit_vector(0 to 1));
+end entity;
+
+entity ent is
+  port (o : out bit_vector(0 to 1));
+end entity;
+
+architecture a of ent is
+  signal wide : bit_vector(0 to 2);
+  signal n : natural;
+begin
+  inst: entity work.ent2
+    port map (
+      inport => wide,
+      outport => o);
+
+  inst2: entity work.ent2
+    port map (
+      inport => wide(0 to 1),
+      outport => wide);
+
+  inst3: entity work.ent2
+    port map (
+      inport => wide(0 to 1),
+      outport => n);
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("inport => wide").s1("wide"),
+                "Object of length 3 does not match target subtype of length 2",
+            ),
+            Diagnostic::error(
+                code.s1("outport => wide").s1("wide"),
+                "Object of length 3 does not match target subtype of length 2",
+            ),
+            Diagnostic::error(
+                code.s1("outport => n").s1("n"),
+                "signal 'n' of subtype 'NATURAL' does not match array type 'BIT_VECTOR'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn buffer_ports_associate_with_other_modes_since_vhdl2002() {
+    let code = "
+entity ent2 is
+  port (
+    outport : out bit;
+    bufport : buffer bit);
+end entity;
+
+entity ent is
+  port (
+    b : buffer bit;
+    o : out bit);
+end entity;
+
+architecture a of ent is
+begin
+  inst: entity work.ent2
+    port map (
+      outport => b,
+      bufport => o);
+end architecture;
+        ";
+
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2002);
+    builder.code("libname", code);
+    check_no_diagnostics(&builder.analyze());
+
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL1993);
+    let code = builder.code("libname", code);
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("outport => b").s1("b"),
+                "port 'b' : buffer cannot be associated with formal port 'outport' : out",
+            )
+            .related(code.s1("outport"), "Defined here"),
+            Diagnostic::error(
+                code.s1("bufport => o").s("o", 2),
+                "port 'o' : out cannot be associated with formal port 'bufport' : buffer",
+            )
+            .related(code.s1("bufport"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn no_implicit_condition_operator_before_vhdl2008() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2002);
+    builder.add_std_logic_1164();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk : std_logic;
+  signal context : boolean;
+begin
+  process (clk)
+  begin
+    if clk then
+    end if;
+    if context and clk = '1' then
+    end if;
+  end process;
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("if clk").s1("clk"),
+            "subtype 'STD_LOGIC' cannot be implictly converted to type 'BOOLEAN'. Implicit conversion requires VHDL-2008 or later.",
+        )],
+    );
+}
@@ -0,0 +1,378 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::config::Lint;
+
+fn builder_with_lint() -> LibraryBuilder {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::InferredLatches);
+    builder
+}
+
+#[test]
+fn if_without_else_infers_latch() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal en, d, q : bit;
+begin
+  process (en, d)
+  begin
+    if en = '1' then
+      q <= d;
+    end if;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("q <= d").s1("q"),
+            "Latch inferred for signal 'q' which is not assigned on every path through the process",
+        )
+        .related(code.s1("en, d, q").s1("q"), "Defined here")
+        .related(
+            code.s1("if en = '1' then
+      q <= d;
+    end if;"),
+            "Not assigned when no condition is true",
+        )],
+    );
+}
+
+#[test]
+fn incomplete_case_infers_latch() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sel : natural;
+  signal d, q : bit;
+begin
+  process (all)
+  begin
+    case sel is
+      when 0 =>
+        q <= d;
+      when others =>
+        null;
+    end case;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("q <= d").s1("q"),
+            "Latch inferred for signal 'q' which is not assigned on every path through the process",
+        )
+        .related(code.s1("d, q").s1("q"), "Defined here")
+        .related(code.s1("others"), "Not assigned for this choice")],
+    );
+}
+
+#[test]
+fn default_assignment_prevents_latch() {
+    let mut builder = builder_with_lint();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal en, d, q, q2 : bit;
+begin
+  process (en, d)
+  begin
+    q <= '0';
+    if en = '1' then
+      q <= d;
+    end if;
+  end process;
+
+  process
+  begin
+    if en = '1' then
+      q2 <= d;
+    else
+      q2 <= '0';
+    end if;
+    wait on en, d;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn clocked_process_does_not_infer_latch() {
+    let mut builder = builder_with_lint();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, d, q : bit;
+begin
+  process (clk)
+  begin
+    if clk'event and clk = '1' then
+      q <= d;
+    end if;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn variable_read_before_assignment_infers_latch() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal d, q : bit;
+begin
+  process (d)
+    variable v : bit;
+  begin
+    q <= v;
+    v := d;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("q <= v").s1("v"),
+            "Latch inferred for variable 'v' which is read before it is assigned",
+        )
+        .related(
+            code.s1("variable v : bit").s1("v :").s1("v"),
+            "Defined here",
+        )],
+    );
+}
+
+#[test]
+fn latches_are_not_reported_unless_enabled() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal en, d, q : bit;
+begin
+  process (en, d)
+  begin
+    if en = '1' then
+      q <= d;
+    end if;
+  end process;
+end architecture;
+        ",
+    );
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn for_loop_over_static_range_is_always_executed() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal d : bit_vector(0 to 3);
+  signal q, r : bit;
+begin
+  process (all)
+    variable acc : bit;
+  begin
+    for i in 0 to 3 loop
+      acc := d(i);
+      q <= acc;
+    end loop;
+
+    for i in 3 downto 4 loop
+      r <= d(i);
+    end loop;
+  end process;
+end architecture;
+        ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::warning(
+            code.s1("r <= d(i)").s1("r"),
+            "Latch inferred for signal 'r' which is not assigned on every path through the process",
+        )
+        .related(code.s1("q, r").s1("r"), "Defined here")
+        .related(
+            code.s1("for i in 3 downto 4 loop
+      r <= d(i);
+    end loop;"),
+            "Not assigned when the loop body is not executed",
+        )],
+    );
+}
+
+#[test]
+fn indexed_assignment_is_partial() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal d : bit;
+  signal q : bit_vector(0 to 1);
+begin
+  process (all)
+  begin
+    q(0) <= d;
+  end process;
+end architecture;
+        ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::warning(
+            code.s1("q(0)"),
+            "Latch inferred for signal 'q' which is not assigned on every path through the process",
+        )
+        .related(code.s1("q :").s1("q"), "Defined here")
+        .related(code.s1("q(0)"), "Only part of the object is assigned")],
+    );
+}
+
+#[test]
+fn assignments_covering_every_element_are_complete() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (
+    d : in bit_vector;
+    q : out bit_vector);
+end entity;
+
+architecture a of ent is
+  signal r, s : bit_vector(0 to 3);
+  signal t : bit_vector(0 to 2);
+  signal u : bit_vector(0 to 3);
+begin
+  process (all)
+  begin
+    for i in q'range loop
+      q(i) <= d(i);
+    end loop;
+
+    for i in 0 to 3 loop
+      r(i) <= d(i);
+    end loop;
+
+    s(0 to 1) <= \"00\";
+    for i in 2 to 3 loop
+      s(i) <= '1';
+    end loop;
+
+    t(0) <= '0';
+    t(2) <= '0';
+    t(1) <= '0';
+
+    for i in 0 to 2 loop
+      u(i) <= d(i);
+    end loop;
+  end process;
+end architecture;
+        ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::warning(
+            code.s1("u(i)"),
+            "Latch inferred for signal 'u' which is not assigned on every path through the process",
+        )
+        .related(code.s1("u :").s1("u"), "Defined here")
+        .related(code.s1("u(i)"), "Only part of the object is assigned")],
+    );
+}
+
+#[test]
+fn conditional_element_assignments_are_partial() {
+    let mut builder = builder_with_lint();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal en : bit;
+  signal d, q : bit_vector(0 to 1);
+begin
+  process (all)
+  begin
+    for i in q'range loop
+      if en = '1' then
+        q(i) <= d(i);
+      end if;
+    end loop;
+  end process;
+end architecture;
+        ",
+    );
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::warning(
+            code.s1("q(i)"),
+            "Latch inferred for signal 'q' which is not assigned on every path through the process",
+        )
+        .related(code.s1("d, q").s1("q"), "Defined here")
+        .related(
+            code.s1("if en = '1' then
+        q(i) <= d(i);
+      end if;"),
+            "Not assigned when no condition is true",
+        )],
+    );
+}
@@ -7,23 +7,41 @@
 mod assignment_typecheck;
 mod association_formal;
 mod circular_dependencies;
+mod component_binding;
+mod configuration_declaration;
 mod context_clause;
+mod control_flow;
+mod dataflow;
 mod deferred_constant;
+mod external_names;
+mod group;
+mod guarded_signals;
 mod hierarchy;
 mod homographs;
 mod implicit;
 mod incomplete_type;
 mod incremental_analysis;
+mod language_standard;
+mod latch_inference;
+mod mode_view;
+mod numeric_types;
+mod out_of_range;
 mod package_instance;
 mod protected_type;
+mod psl;
+mod purity;
 mod resolves_design_units;
 mod resolves_names;
 mod resolves_type_mark;
 mod sensitivity_list;
+mod shadowing;
 mod subprogram_arguments;
+mod subprogram_instance;
 mod typecheck_expression;
 mod util;
+mod vhdl2019;
 mod visibility;
+mod width_mismatch;
 
 use std::cell::RefCell;
 
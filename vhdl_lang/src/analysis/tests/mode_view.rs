@@ -0,0 +1,292 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::standard::VHDLStandard;
+
+#[test]
+fn mode_view_ports() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type bus_t is record
+    addr, wdata : natural;
+    rdata : natural;
+  end record;
+  type bus_vec_t is array (natural range <>) of bus_t;
+
+  view master of bus_t is
+    addr, wdata : out;
+    rdata : in;
+  end view;
+end package;
+
+use work.pkg.all;
+
+entity ent is
+  port (
+    m : view master;
+    s : view master'converse of bus_t;
+    ms : view (master) of bus_vec_t(0 to 1)
+  );
+end entity;
+
+architecture a of ent is
+begin
+  m.addr <= s.addr;
+  ms(0).wdata <= 0;
+end architecture;
+
+use work.pkg.all;
+
+entity top is
+end entity;
+
+architecture a of top is
+  signal b : bus_t;
+  signal bs : bus_vec_t(0 to 1);
+begin
+  inst : entity work.ent
+    port map (m => b, s => b, ms => bs);
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("addr", 2).start()),
+        Some(code.s1("addr").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(
+            code.source(),
+            code.s1("m : view master").s1("master").start()
+        ),
+        Some(code.s1("master").pos())
+    );
+}
+
+#[test]
+fn mode_views_may_be_aliased() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type bus_t is record
+    addr : natural;
+    rdata : natural;
+  end record;
+
+  view master of bus_t is
+    addr : out;
+    rdata : in;
+  end view;
+
+  alias manager is master;
+  alias slave is master'converse;
+end package;
+
+use work.pkg.all;
+
+entity ent is
+  port (
+    m : view manager;
+    s : view slave of bus_t;
+    s2 : view slave'converse
+  );
+end entity;
+
+architecture a of ent is
+begin
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("master", 3).start()),
+        Some(code.s1("master").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("slave", 2).start()),
+        Some(code.s1("slave").pos())
+    );
+}
+
+#[test]
+fn mode_view_elements_must_match_record_elements() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type bus_t is record
+    addr : natural;
+    data : natural;
+    resp : natural;
+  end record;
+
+  view master of bus_t is
+    addr : out;
+    missing : in;
+    addr : in;
+  end view;
+
+  view bad of natural is
+  end view;
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("missing"),
+                "No declaration of 'missing' within record type 'bus_t'",
+            ),
+            Diagnostic::error(code.s("addr", 3), "Duplicate mode of record element 'addr'")
+                .related(code.s("addr", 2), "Previously defined here"),
+            Diagnostic::error(
+                code.s1("master"),
+                "Missing mode of record elements 'data', 'resp' in mode view 'master'",
+            ),
+            Diagnostic::error(
+                code.s("natural", 4),
+                "The type of a mode view must be a record type, got subtype 'NATURAL'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn mode_view_of_element_must_match_element_type() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type sub_t is record
+    valid : boolean;
+  end record;
+  type sub_vec_t is array (natural range <>) of sub_t;
+
+  type bus_t is record
+    sub : sub_t;
+    subs : sub_vec_t(0 to 1);
+    other : natural;
+  end record;
+
+  view sub_master of sub_t is
+    valid : out;
+  end view;
+
+  view master of bus_t is
+    sub : view sub_master;
+    subs : view (sub_master'converse);
+    other : view sub_master;
+  end view;
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("sub_master", 4),
+            "Mode view of record type 'sub_t' does not match subtype 'NATURAL'",
+        )],
+    );
+}
+
+#[test]
+fn port_mode_view_must_denote_view_of_matching_type() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type bus_t is record
+    addr : natural;
+  end record;
+  type bus_vec_t is array (natural range <>) of bus_t;
+
+  view master of bus_t is
+    addr : out;
+  end view;
+
+  constant c0 : natural := 0;
+end package;
+
+use work.pkg.all;
+
+entity ent is
+  port (
+    p0 : view c0;
+    p1 : view master of natural;
+    p2 : view (master) of bus_t
+  );
+end entity;
+
+architecture a of ent is
+  constant c1 : bus_t := master'converse;
+begin
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(code.s("c0", 2), "Expected mode view, got constant 'c0'"),
+            Diagnostic::error(
+                code.s("master", 2),
+                "Mode view of record type 'bus_t' does not match subtype 'NATURAL'",
+            ),
+            Diagnostic::error(
+                code.s("master", 3),
+                "Mode view of record type 'bus_t' does not match the element type of record type 'bus_t'",
+            ),
+            Diagnostic::error(
+                code.s1("master'converse"),
+                "Mode view cannot be used as an expression",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn view_is_not_reserved_before_vhdl2019() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2008);
+    builder.code(
+        "libname",
+        "
+entity ent is
+  port (
+    view : in bit
+  );
+end entity;
+
+architecture a of ent is
+  signal private : bit;
+begin
+  private <= view;
+end architecture;
+        ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn floating_point_type_has_real_operators() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type my_real is range -1.0e6 to 1.0e6;
+subtype my_unit is my_real range 0.0 to 1.0;
+constant c0 : my_real := 1.0;
+constant c1 : my_real := abs (c0 * 2.0 + my_real'high) / 2.0 - c0 ** 2;
+constant c2 : boolean := c0 < 1.0 and maximum(c0, 0.0) = c0;
+constant c3 : real := real(c0);
+constant c4 : string := to_string(c1);
+constant c5 : my_real := c0 mod c0;
+        ",
+    );
+
+    check_diagnostics(
+        without_releated(&builder.analyze()),
+        vec![Diagnostic::error(
+            code.s1("mod"),
+            "Found no match for operator \"mod\"",
+        )],
+    );
+}
+
+#[test]
+fn floating_point_type_is_not_integer_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type my_real is range 0.0 to 1.0;
+type my_int is range 0 to 10;
+constant c0 : my_real := 1;
+constant c1 : my_int := 1.0;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1(":= 1;").s1("1"),
+                "integer literal does not match real type 'my_real'",
+            ),
+            Diagnostic::error(
+                code.s1(":= 1.0;").s1("1.0"),
+                "real literal does not match integer type 'my_int'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn numeric_type_bounds_may_have_different_types() {
+    let mut builder = LibraryBuilder::new();
+    builder.in_declarative_region(
+        "
+type my_int is range 0 to 10;
+type wide_int is range my_int'low to integer'high;
+type my_real is range 0.0 to 1.0;
+type wide_real is range my_real'low to real'high;
+constant c0 : wide_int := 1;
+constant c1 : wide_real := 1.0;
+        ",
+    );
+
+    check_no_diagnostics(&builder.analyze());
+}
+
+#[test]
+fn error_on_mixed_integer_and_real_bounds() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+constant r0 : real := 1.0;
+type bad0 is range 0 to 1.0;
+type bad1 is range 0 to r0;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("0 to 1.0"),
+                "Type definition cannot mix integer and real bounds, left is type universal_integer, right is type universal_real",
+            ),
+            Diagnostic::error(
+                code.s1("0 to r0"),
+                "Type definition cannot mix integer and real bounds, left is type universal_integer, right is real type 'REAL'",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn error_on_non_numeric_bounds() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type bad is range 'a' to 'z';
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("'a' to 'z'"),
+            "Expected real or integer range",
+        )],
+    );
+}
@@ -0,0 +1,186 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn object_initial_value_out_of_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype small_t is integer range 7 downto 0;
+
+constant good1 : natural := 0;
+constant good2 : integer range 0 to 7 := 2 + 5;
+constant good3 : small_t := 7;
+constant bad1 : natural := -1;
+signal bad2 : integer range 0 to 7 := 9;
+shared variable bad3 : small_t := 4 * 2;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("-1"),
+                "Value -1 is less than the lower bound 0 of the target subtype",
+            ),
+            Diagnostic::error(
+                code.s1(":= 9").s1("9"),
+                "Value 9 is greater than the upper bound 7 of the target subtype",
+            ),
+            Diagnostic::error(
+                code.s1("4 * 2"),
+                "Value 8 is greater than the upper bound 7 of the target subtype",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn generic_default_and_actual_out_of_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  generic (
+    width : integer range 1 to 64 := 0
+  );
+end entity;
+
+architecture a of ent is
+  procedure proc(arg : natural) is
+  begin
+  end procedure;
+begin
+  inst : entity work.ent
+    generic map (width => 65);
+
+  process
+  begin
+    proc(-3);
+    proc(3);
+    wait;
+  end process;
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1(":= 0").s1("0"),
+                "Value 0 is less than the lower bound 1 of the target subtype",
+            ),
+            Diagnostic::error(
+                code.s1("65"),
+                "Value 65 is greater than the upper bound 64 of the target subtype",
+            ),
+            Diagnostic::error(
+                code.s1("-3"),
+                "Value -3 is less than the lower bound 0 of the target subtype",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn index_out_of_range() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(15 downto 0);
+signal vec : bit_vector(0 to 7);
+signal word : word_t;
+constant good1 : bit := vec(7);
+constant good2 : bit := word(0);
+constant bad1 : bit := vec(8);
+constant bad2 : bit := word(-1);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("vec(8)").s1("8"),
+                "Value 8 is greater than the upper bound 7 of the index range",
+            ),
+            Diagnostic::error(
+                code.s1("word(-1)").s1("-1"),
+                "Value -1 is less than the lower bound 0 of the index range",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn index_out_of_range_of_array_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype idx_t is integer range 0 to 3;
+type arr_t is array (0 to 3) of bit;
+type idx_arr_t is array (idx_t) of bit;
+signal arr : arr_t;
+signal idx_arr : idx_arr_t;
+constant good1 : bit := arr(3);
+constant good2 : bit := idx_arr(0);
+constant bad1 : bit := arr(4);
+constant bad2 : bit := idx_arr(5);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("arr(4)").s1("4"),
+                "Value 4 is greater than the upper bound 3 of the index range",
+            ),
+            Diagnostic::error(
+                code.s1("idx_arr(5)").s1("5"),
+                "Value 5 is greater than the upper bound 3 of the index range",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn values_of_constants_are_evaluated() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+constant k : natural := 3;
+constant neg : natural := k - 5;
+signal vec : bit_vector(0 to k);
+constant good : bit := vec(k);
+constant bad : bit := vec(k + 1);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("k - 5"),
+                "Value -2 is less than the lower bound 0 of the target subtype",
+            ),
+            Diagnostic::error(
+                code.s1("k + 1"),
+                "Value 4 is greater than the upper bound 3 of the index range",
+            ),
+        ],
+    );
+}
@@ -0,0 +1,215 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn psl_names_resolve_within_architecture() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal clk, req, ack : bit;
+
+  default clock is clk'event and clk = '1';
+  property handshake(boolean r, a) is always r -> eventually! a;
+  sequence transfer is {req; ack};
+begin
+  assert always (req -> next ack) report \"no ack\";
+  cover transfer;
+  -- psl assume never (req and ack);
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    let references: Vec<_> = (1..=4).map(|idx| code.s("req", idx).pos()).collect();
+    assert_eq!(
+        root.find_all_references_pos(&code.s1("req").pos()),
+        references
+    );
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("transfer", 2).start()),
+        Some(code.s1("transfer").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("! a;").s1("a").start()),
+        Some(code.s1("r, a").s1("a").pos())
+    );
+}
+
+#[test]
+fn psl_comments_may_declare_and_assert_properties_among_statements() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture rtl of ent is
+  signal clk, a, b, req, ack : bit;
+begin
+  -- psl default clock is clk'event and clk = '1';
+  -- psl property p_handshake is always req -> next ack;
+  -- psl assert never (a and b);
+  -- psl assert p_handshake;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("p_handshake", 2).start()),
+        Some(code.s1("p_handshake").pos())
+    );
+}
+
+#[test]
+fn psl_names_must_be_declared() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal req : bit;
+begin
+  cover {req; missing};
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("missing"),
+            "No declaration of 'missing'",
+        )],
+    );
+}
+
+#[test]
+fn verification_unit_is_bound_to_architecture() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  port (clk : in bit);
+end entity;
+
+architecture rtl of ent is
+  signal req, ack : bit;
+begin
+end architecture;
+
+vunit common {
+  constant max_latency : natural := 3;
+}
+
+vunit checker(ent(rtl)) {
+  inherit common;
+  default clock is clk'event and clk = '1';
+  assert always req -> next_e[1 to max_latency] (ack);
+}
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("req", 2).start()),
+        Some(code.s1("req").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("clk", 2).start()),
+        Some(code.s1("clk").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("max_latency", 2).start()),
+        Some(code.s1("max_latency").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s("common", 2).start()),
+        Some(code.s1("common").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("ent(rtl)").s1("rtl").start()),
+        Some(code.s1("rtl").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("(ent(").s1("ent").start()),
+        Some(code.s1("entity ent").s1(" ent").s1("ent").pos())
+    );
+}
+
+#[test]
+fn verification_unit_binding_must_exist() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+vunit checker(ent(missing)) {
+}
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![Diagnostic::error(
+            code.s1("missing"),
+            "No architecture 'missing' for entity 'libname.ent'",
+        )],
+    );
+}
+
+#[test]
+fn psl_booleans_are_type_checked() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal req, ack : bit;
+  signal count : integer;
+begin
+  assert req -> next ack;
+  assert always rose(req) |=> {ack[*2]; not ack} until count = 0;
+  assert always count -> next ack;
+  cover {req[*count]; fell(missing)};
+end architecture;
+        ",
+    );
+
+    check_diagnostics(
+        builder.analyze(),
+        vec![
+            Diagnostic::error(
+                code.s1("always count").s1("count"),
+                "integer type 'INTEGER' cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(code.s1("missing"), "No declaration of 'missing'"),
+        ],
+    );
+}
@@ -0,0 +1,498 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::config::Lint;
+
+#[test]
+fn pure_function_cannot_reference_outer_objects() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : natural;
+  shared variable shvar : natural;
+  constant const : natural := 0;
+
+  function fun1(arg : natural) return natural is
+    variable local : natural;
+  begin
+    local := arg + const;
+    return local + sig + shvar;
+  end function;
+
+  function fun2 return boolean is
+  begin
+    return sig'event;
+  end function;
+
+  impure function fun3 return natural is
+  begin
+    return sig + shvar;
+  end function;
+begin
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("local + sig").s1("sig"),
+                "Pure function cannot reference signal 'sig' declared outside of the function",
+            )
+            .related(code.s1("sig :").s1("sig"), "Defined here"),
+            Diagnostic::error(
+                code.s1("sig + shvar;").s1("shvar"),
+                "Pure function cannot reference shared variable 'shvar' declared outside of the function",
+            )
+            .related(code.s1("shvar"), "Defined here"),
+            Diagnostic::error(
+                code.s1("sig'event").s1("sig"),
+                "Pure function cannot reference signal 'sig' declared outside of the function",
+            )
+            .related(code.s1("sig :").s1("sig"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn pure_function_cannot_call_impure_function() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  impure function get return natural;
+  function fun return natural;
+end package;
+
+package body pkg is
+  impure function get return natural is
+  begin
+    return 0;
+  end function;
+
+  function fun return natural is
+  begin
+    return get;
+  end function;
+end package body;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("return get").s1("get"),
+            "Pure function cannot call impure function get[return NATURAL]",
+        )
+        .related(code.s("get", 2), "Defined here")],
+    );
+}
+
+#[test]
+fn pure_function_may_use_formals_of_called_subprograms() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+end package;
+
+package body pkg is
+  procedure split(value : natural; variable high, low : out natural) is
+  begin
+    high := value / 2;
+    low := value mod 2;
+  end procedure;
+
+  function fun(value : natural) return natural is
+    variable h, l : natural;
+  begin
+    split(value => value, high => h, low => l);
+    return h + l;
+  end function;
+
+  function stamp return time is
+  begin
+    return now;
+  end function;
+end package body;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn protected_type_methods_may_reference_private_variables() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+  type prot_t is protected
+    function get return natural;
+  end protected;
+end package;
+
+package body pkg is
+  type prot_t is protected body
+    variable count : natural;
+
+    function get return natural is
+    begin
+      return count;
+    end function;
+  end protected body;
+end package body;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn functions_cannot_wait() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+end package;
+
+package body pkg is
+  procedure waiting is
+  begin
+    wait for 1 ns;
+  end procedure;
+
+  procedure indirect is
+  begin
+    waiting;
+  end procedure;
+
+  function fun1 return natural is
+  begin
+    wait for 1 ns;
+    return 0;
+  end function;
+
+  function fun2 return natural is
+  begin
+    indirect;
+    return 0;
+  end function;
+end package body;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("wait for 1 ns;", 2),
+                "Functions cannot contain wait statements",
+            ),
+            Diagnostic::error(
+                code.s("indirect", 2),
+                "Functions cannot call procedure indirect[] which contains a wait statement",
+            )
+            .related(code.s1("indirect"), "Defined here"),
+        ],
+    );
+}
+
+#[test]
+fn sensitized_process_cannot_call_waiting_procedure() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+
+  procedure waiting;
+
+  procedure waiting is
+  begin
+    wait until sig = '1';
+  end procedure;
+begin
+  process (sig)
+  begin
+    if sig = '1' then
+      waiting;
+    end if;
+  end process;
+
+  process
+  begin
+    waiting;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("waiting", 3),
+            "Process with sensitivity list cannot call procedure waiting[] which contains a wait statement",
+        )
+        .related(code.s("waiting", 2), "Defined here")],
+    );
+}
+
+#[test]
+fn sensitized_process_cannot_contain_wait() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  process (sig)
+  begin
+    if sig = '1' then
+      wait for 1 ns;
+    end if;
+  end process;
+
+  process (all)
+  begin
+    wait on sig;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("wait for 1 ns;"),
+                "Process with sensitivity list cannot contain wait statements",
+            ),
+            Diagnostic::error(
+                code.s1("wait on sig;"),
+                "Process with sensitivity list cannot contain wait statements",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn process_without_wait_loops_forever() {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::MissingWait);
+    builder.code(
+        "libname",
+        "
+package pkg is
+  procedure external_proc;
+end package;
+        ",
+    );
+    let code = builder.code(
+        "libname",
+        "
+use work.pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+
+  procedure local_proc is
+  begin
+  end procedure;
+begin
+  forever : process
+  begin
+    sig <= not sig;
+    local_proc;
+  end process;
+
+  calls_external : process
+  begin
+    external_proc;
+  end process;
+
+  waits : process
+  begin
+    sig <= not sig;
+    wait for 1 ns;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s1("forever"),
+            "Process without sensitivity list does not contain a wait statement and will loop forever",
+        )],
+    );
+}
+
+#[test]
+fn missing_wait_lint_is_disabled_by_default() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  forever : process
+  begin
+    sig <= not sig;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn waiting_procedures_are_known_to_other_design_units() {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::MissingWait);
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+  procedure waiting is
+  begin
+    wait for 1 ns;
+  end procedure;
+
+  procedure not_waiting is
+  begin
+  end procedure;
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  process (sig)
+  begin
+    waiting;
+  end process;
+
+  calls_waiting : process
+  begin
+    waiting;
+  end process;
+
+  forever : process
+  begin
+    not_waiting;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("waiting;").s1("waiting"),
+                "Process with sensitivity list cannot call procedure waiting[] which contains a wait statement",
+            )
+            .related(code.s1("waiting"), "Defined here"),
+            Diagnostic::warning(
+                code.s1("forever"),
+                "Process without sensitivity list does not contain a wait statement and will loop forever",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn waiting_package_procedures_are_known_to_other_design_units() {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::MissingWait);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  procedure waiting;
+  procedure not_waiting;
+end package;
+
+package body pkg is
+  procedure waiting is
+  begin
+    wait for 1 ns;
+  end procedure;
+
+  procedure not_waiting is
+  begin
+  end procedure;
+end package body;
+
+use work.pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+begin
+  process (sig)
+  begin
+    waiting;
+  end process;
+
+  calls_waiting : process
+  begin
+    waiting;
+  end process;
+
+  forever : process
+  begin
+    not_waiting;
+  end process;
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("waiting;", 3).s1("waiting"),
+                "Process with sensitivity list cannot call procedure waiting[] which contains a wait statement",
+            )
+            .related(code.s1("waiting"), "Defined here"),
+            Diagnostic::warning(
+                code.s1("forever"),
+                "Process without sensitivity list does not contain a wait statement and will loop forever",
+            ),
+        ],
+    );
+}
@@ -21,6 +21,10 @@ end configuration;
 
 entity ent is
 end entity;
+
+architecture rtl of ent is
+begin
+end architecture;
 ",
     );
 
@@ -64,6 +68,10 @@ fn good_configurations() {
 entity ent is
 end entity;
 
+architecture rtl of ent is
+begin
+end architecture;
+
 configuration cfg_good1 of ent is
 for rtl
 end for;
@@ -122,6 +130,10 @@ fn search_reference_from_configuration_to_entity() {
 entity decl is
 end entity;
 
+architecture rtl of decl is
+begin
+end architecture;
+
 configuration cfg_good1 of decl is
 for rtl
 end for;
@@ -362,6 +374,13 @@ configuration decl of ent is
 end configuration;
 
 architecture a of ent is
+begin
+end architecture;
+
+entity ent2 is
+end entity;
+
+architecture a of ent2 is
 begin
   inst : configuration work.decl;
 end architecture;
@@ -624,7 +624,7 @@ end package body;
 
 #[test]
 fn check_missing_in_process_statements() {
-    check_missing(
+    check_missing_with_diagnostics(
         "
 entity ent is
 end entity;
@@ -644,12 +644,19 @@ begin
   end process;
 end architecture;
 ",
+        |code| {
+            vec![Diagnostic::error(
+                code.s1("wait on missing until missing = 0 ns for missing;"),
+                "Process with sensitivity list cannot contain wait statements",
+            )]
+        },
     );
 }
 
 #[test]
 fn search_in_process_statements() {
-    check_search_reference(
+    check_search_reference_with_diagnostics(
+        "decl",
         "
 entity ent is
 end entity;
@@ -669,6 +676,12 @@ begin
   end process;
 end architecture;
 ",
+        |code| {
+            vec![Diagnostic::error(
+                code.s1("wait on decl until decl = 0 ns for decl;"),
+                "Process with sensitivity list cannot contain wait statements",
+            )]
+        },
     );
 }
 
@@ -1714,6 +1727,88 @@ attribute bad of ram : signal is 0;
     );
 }
 
+#[test]
+fn attribute_spec_entity_classes() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+  attribute info : string;
+  attribute info of pkg : package is \"pkg\";
+
+  type enum_t is (alpha, beta, 'c');
+  subtype sub_t is enum_t range alpha to beta;
+  type time_t is range 0 to 1000
+    units
+      ps;
+      ns = 1000 ps;
+    end units;
+  constant c0 : natural := 0;
+  signal sig : bit;
+  shared variable var : integer;
+  file fh : std.textio.text;
+  component comp is
+  end component;
+  function fun return natural;
+  procedure proc;
+  group pair is (signal, signal);
+  group g0 : pair (sig, sig);
+
+  attribute info of enum_t : type is \"enum_t\";
+  attribute info of sub_t : subtype is \"sub_t\";
+  attribute info of alpha : literal is \"alpha\";
+  attribute info of 'c'[return enum_t] : literal is \"c\";
+  attribute info of ns : units is \"ns\";
+  attribute info of c0 : constant is \"c0\";
+  attribute info of sig : signal is \"sig\";
+  attribute info of var : variable is \"var\";
+  attribute info of fh : file is \"fh\";
+  attribute info of comp : component is \"comp\";
+  attribute info of fun : function is \"fun\";
+  attribute info of proc : procedure is \"proc\";
+  attribute info of g0 : group is \"g0\";
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn attribute_spec_entity_class_mismatch() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+attribute info : natural;
+signal sig : bit;
+function fun return natural;
+function fun return boolean;
+procedure fun(arg : natural);
+
+attribute info of sig : constant is 0;
+attribute info of fun : procedure is 0;
+attribute info of fun[return boolean] : procedure is 0;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("sig : constant").s1("sig"),
+                "signal 'sig' is not of entity class 'constant'",
+            ),
+            Diagnostic::error(
+                code.s1("fun[return boolean] : procedure").s1("fun"),
+                "function fun[return BOOLEAN] is not of entity class 'procedure'",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn selected_function_is_resolved() {
     // This test case exists because a bug was found
@@ -1953,7 +2048,7 @@ package body pkg is
 end package body pkg;
 
 configuration cfg1 of ent1 is
-  for rtl(0)
+  for a1
   end for;
 end configuration cfg1;
 
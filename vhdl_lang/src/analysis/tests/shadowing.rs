@@ -0,0 +1,138 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::config::Lint;
+
+const CODE: &str = "
+entity ent is
+  port (clk : in bit);
+end entity;
+
+architecture a of ent is
+  signal sig : bit;
+
+  function fun(sig : bit) return bit is
+  begin
+    return sig;
+  end function;
+begin
+  blk : block
+    signal clk : bit;
+  begin
+  end block;
+
+  process
+    variable sig : bit;
+  begin
+    wait;
+  end process;
+end architecture;
+";
+
+#[test]
+fn shadowing_is_disabled_by_default() {
+    let mut builder = LibraryBuilder::new();
+    builder.code("libname", CODE);
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn declarations_hiding_enclosing_declarations() {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::Shadowing);
+    let code = builder.code("libname", CODE);
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s("sig :", 2).s1("sig"),
+                "Declaration of 'sig' hides signal 'sig'",
+            )
+            .related(code.s1("sig :").s1("sig"), "Hidden declaration"),
+            Diagnostic::warning(
+                code.s("clk", 2),
+                "Declaration of 'clk' hides port 'clk' : in",
+            )
+            .related(code.s1("clk"), "Hidden declaration"),
+            Diagnostic::warning(
+                code.s("sig :", 3).s1("sig"),
+                "Declaration of 'sig' hides signal 'sig'",
+            )
+            .related(code.s1("sig :").s1("sig"), "Hidden declaration"),
+        ],
+    );
+}
+
+#[test]
+fn declarations_hiding_use_visible_declarations() {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::Shadowing);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  constant const : natural := 0;
+  function fun return natural;
+end package;
+
+use work.pkg.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  constant const : natural := 1;
+  constant fun : natural := 2;
+begin
+end architecture;
+        ",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s("const :", 2).s1("const"),
+            "Declaration of 'const' hides constant 'const'",
+        )
+        .related(code.s1("const :").s1("const"), "Hidden declaration")],
+    );
+}
+
+#[test]
+fn lints_are_not_reported_for_third_party_libraries() {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::Shadowing);
+    builder.set_third_party_library("vendor");
+    builder.code("vendor", CODE);
+    let code = builder.code(
+        "libname",
+        "
+entity ent2 is
+  port (clk : in bit);
+end entity;
+
+architecture a of ent2 is
+begin
+  blk : block
+    signal clk : bit;
+  begin
+  end block;
+end architecture;
+",
+    );
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::warning(
+            code.s("clk", 2),
+            "Declaration of 'clk' hides port 'clk' : in",
+        )
+        .related(code.s1("clk"), "Hidden declaration")],
+    );
+}
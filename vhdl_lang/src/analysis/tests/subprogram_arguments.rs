@@ -195,6 +195,31 @@ signal bad : natural := subpgm(0);
     );
 }
 
+#[test]
+fn subprogram_argument_without_default_may_not_be_open() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+function subpgm(arg1: natural; arg2: character := 'c') return natural
+is begin
+end;
+
+signal good : natural := subpgm(0, open);
+signal bad : natural := subpgm(open);
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("subpgm(open)").s1("open"),
+            "parameter 'arg1' has no default value and may not be associated with open",
+        )
+        .related(code.s1("arg1"), "Defined here")],
+    );
+}
+
 #[test]
 fn subprogram_extra_argument_not_associated() {
     let mut builder = LibraryBuilder::new();
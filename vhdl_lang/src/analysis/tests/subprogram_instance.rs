@@ -0,0 +1,277 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+
+#[test]
+fn instantiated_generic_subprograms_can_be_called() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package gpkg is
+  function identity generic (type T) parameter (x : T) return T;
+  procedure swap generic (type T) parameter (variable a, b : inout T);
+end package;
+
+package body gpkg is
+  function identity generic (type T) parameter (x : T) return T is
+  begin
+    return x;
+  end function;
+
+  procedure swap generic (type T) parameter (variable a, b : inout T) is
+    variable tmp : T;
+  begin
+    tmp := a;
+    a := b;
+    b := tmp;
+  end procedure;
+end package body;
+
+use work.gpkg.all;
+
+package pkg is
+  function identity_int is new identity generic map (T => integer);
+  function identity_char is new work.gpkg.identity generic map (T => character);
+  procedure swap_bool is new swap generic map (T => boolean);
+
+  constant c0 : integer := identity_int(1);
+  constant c1 : character := identity_char('a');
+end package;
+
+package body pkg is
+  procedure test is
+    variable a, b : boolean;
+  begin
+    swap_bool(a, b);
+    swap_bool(a => a, b => b);
+  end procedure;
+end package body;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("new identity").end()),
+        Some(code.s1("identity").pos())
+    );
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("identity_int(1)").start()),
+        Some(code.s1("identity_int").pos())
+    );
+}
+
+#[test]
+fn instantiated_generic_subprograms_are_overloaded() {
+    let mut builder = LibraryBuilder::new();
+    builder.code(
+        "libname",
+        "
+package pkg is
+  function int_image(x : integer) return string;
+  function bool_image(x : boolean) return string;
+
+  function to_str generic (type T; function image(x : T) return string) parameter (x : T) return string;
+  function to_str is new to_str generic map (T => integer, image => int_image);
+  function to_str is new to_str generic map (T => boolean, image => bool_image);
+
+  constant c0 : string := to_str(0);
+  constant c1 : string := to_str(true);
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn uninstantiated_subprogram_cannot_be_called() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function identity generic (type T) parameter (x : T) return T;
+  constant c0 : integer := identity(0);
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s("identity", 2),
+            "Could not resolve call to 'identity'",
+        )
+        .related(
+            code.s1("identity"),
+            "Does not match function identity[T return T]",
+        )
+        .related(
+            code.s("identity", 2),
+            "Uninstantiated subprogram cannot be called",
+        )],
+    );
+}
+
+#[test]
+fn instantiated_subprogram_argument_must_match_mapped_type() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function identity generic (type T) parameter (x : T) return T;
+  function identity_bool is new identity generic map (T => boolean);
+  constant c0 : boolean := identity_bool('a');
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("'a'"),
+            "character literal does not match type 'BOOLEAN'",
+        )],
+    );
+}
+
+#[test]
+fn subprogram_instance_name_must_be_an_uninstantiated_subprogram() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function identity generic (type T) parameter (x : T) return T;
+  function plain(x : integer) return integer;
+  constant const : natural := 0;
+
+  function f0 is new plain;
+  procedure f1 is new identity generic map (T => integer);
+  function f2 is new const;
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("plain", 2),
+                "'plain' does not denote an uninstantiated function",
+            )
+            .related(
+                code.s1("plain"),
+                "Found function plain[INTEGER return INTEGER]",
+            ),
+            Diagnostic::error(
+                code.s("identity", 2),
+                "'identity' does not denote an uninstantiated procedure",
+            )
+            .related(code.s1("identity"), "Found function identity[T return T]"),
+            Diagnostic::error(
+                code.s("const", 3),
+                "constant 'const' is not an uninstantiated subprogram",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn generic_subprogram_body_replaces_its_declaration() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function identity generic (type T) parameter (x : T) return T;
+end package;
+
+package body pkg is
+  function identity generic (type T) parameter (x : T) return T is
+  begin
+    return x;
+  end function;
+
+  function identity_int is new identity generic map (T => integer);
+  constant c0 : integer := identity_int(0);
+end package body;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_no_diagnostics(&diagnostics);
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("return x").end()),
+        Some(code.s("x : T", 2).s1("x").pos())
+    );
+}
+
+#[test]
+fn subprogram_instance_requires_generic_map() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function identity generic (type T) parameter (x : T) return T;
+  function identity_int is new identity;
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("identity_int"),
+            "Missing generic map for function identity[T return T]",
+        )
+        .related(code.s1("identity"), "Defined here")],
+    );
+}
+
+#[test]
+fn subprogram_instance_must_associate_generics_without_default() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  function int_image(x : integer) return string;
+
+  function to_str generic (
+    type T;
+    function image(x : T) return string;
+    constant prefix : string := \"\")
+    parameter (x : T) return string;
+
+  function to_str_int is new to_str generic map (T => integer);
+  function to_str_default is new to_str generic map (T => integer, image => int_image);
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("to_str_int"),
+            "No association of function image[T return STRING]",
+        )
+        .related(code.s1("function image").s1("image"), "Defined here")],
+    );
+}
@@ -358,16 +358,109 @@ constant bad: integer := fun1;
             Diagnostic::error(code.s1(":= fun1").s1("fun1"), "Ambiguous call to 'fun1'")
                 .related(
                     code.s("fun1", 1),
-                    "Migth be function fun1[NATURAL return NATURAL]",
+                    "Might be function fun1[NATURAL return NATURAL]",
                 )
                 .related(
                     code.s("fun1", 2),
-                    "Migth be function fun1[BOOLEAN return NATURAL]",
+                    "Might be function fun1[BOOLEAN return NATURAL]",
                 ),
         ],
     );
 }
 
+#[test]
+fn ambiguous_call_explains_which_formals_the_actuals_could_match() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+function f1 return integer;
+function f1 return character;
+
+procedure proc(arg : integer);
+procedure proc(arg : character);
+
+procedure caller is
+begin
+  proc(f1);
+end procedure;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(code.s1("proc(f1)").s1("proc"), "Ambiguous call to 'proc'")
+                .related(
+                    code.s1("proc(arg : integer)").s1("proc"),
+                    "Might be procedure proc[INTEGER]",
+                )
+                .related(
+                    code.s1("proc(f1)").s1("f1"),
+                    "Could be integer type 'INTEGER' of parameter 'arg'",
+                )
+                .related(
+                    code.s1("proc(arg : character)").s1("proc"),
+                    "Might be procedure proc[CHARACTER]",
+                )
+                .related(
+                    code.s1("proc(f1)").s1("f1"),
+                    "Could be type 'CHARACTER' of parameter 'arg'",
+                ),
+        ],
+    );
+}
+
+#[test]
+fn operator_without_match_explains_which_operands_did_not_match() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_numeric_std();
+    let code = builder.code(
+        "libname",
+        "
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal u : unsigned(3 downto 0);
+  signal v : std_logic_vector(3 downto 0);
+  signal r : unsigned(3 downto 0);
+begin
+  r <= u + v;
+end architecture;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    assert_eq!(diagnostics.len(), 1);
+    let diagnostic = &diagnostics[0];
+    assert_eq!(diagnostic.pos, code.s1("u + v").s1("+").pos());
+    assert_eq!(diagnostic.message, "Found no match for operator \"+\"");
+
+    // Only the right operand does not match the unsigned addition
+    let idx = diagnostic
+        .related
+        .iter()
+        .position(|(_, message)| {
+            message
+                == "Does not match operator \"+\"[UNRESOLVED_UNSIGNED, UNRESOLVED_UNSIGNED return UNRESOLVED_UNSIGNED]"
+        })
+        .unwrap();
+    assert_eq!(
+        diagnostic.related[idx + 1],
+        (
+            code.s1("u + v").s1("v").pos(),
+            "expression with subtype 'STD_LOGIC_VECTOR' does not match array type 'UNRESOLVED_UNSIGNED'"
+                .to_owned()
+        )
+    );
+    assert!(diagnostic.related[idx + 2].1.starts_with("Does not match"));
+}
+
 #[test]
 fn test_name_can_be_indexed() {
     let mut builder = LibraryBuilder::new();
@@ -972,6 +1065,118 @@ constant bad3 : rec_t := ('a', 0, 0, others => 3);
     );
 }
 
+#[test]
+fn aggregate_positional_after_named() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+type rec_t is record
+    f1 : natural;
+    f2 : natural;
+end record;
+
+constant bad1 : rec_t := (f1 => 0, 1);
+constant bad2 : bit_vector(0 to 1) := (0 => '0', '1');
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("(f1 => 0, 1)").s1(", 1").s1("1"),
+                "Positional association cannot follow named association",
+            ),
+            Diagnostic::error(
+                code.s1("(0 => '0', '1')").s1("'1'"),
+                "Positional association cannot follow named association",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn array_aggregate_length_mismatch() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(7 downto 0);
+subtype nibble_t is bit_vector(0 to 2 * 2 - 1);
+
+constant good1 : word_t := ('0', '1', '0', '1', '0', '1', '0', '1');
+constant good2 : nibble_t := (0 to 1 => '0', 2 | 3 => '1');
+constant good3 : nibble_t := (others => '0');
+constant good4 : bit_vector(0 to 3) := (\"01\", \"10\");
+constant bad1 : word_t := ('0', '1');
+constant bad2 : nibble_t := (0 to 2 => '0');
+constant bad3 : bit_vector(1 to 3) := ('0', '1', '0', '1');
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("('0', '1');").s1("('0', '1')"),
+                "Aggregate of length 2 does not match target subtype of length 8",
+            ),
+            Diagnostic::error(
+                code.s1("(0 to 2 => '0')"),
+                "Aggregate of length 3 does not match target subtype of length 4",
+            ),
+            Diagnostic::error(
+                code.s1("('0', '1', '0', '1');").s1("('0', '1', '0', '1')"),
+                "Aggregate of length 4 does not match target subtype of length 3",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn string_literal_length_mismatch() {
+    let mut builder = LibraryBuilder::new();
+    let code = builder.in_declarative_region(
+        "
+subtype word_t is bit_vector(7 downto 0);
+
+constant good1 : word_t := x\"A5\";
+constant good2 : bit_vector(11 downto 0) := x\"FFF\";
+constant good3 : bit_vector(0 to 7) := 8d\"200\";
+constant good4 : bit_vector(0 to 3) := \"0101\";
+constant bad1 : bit_vector(7 downto 0) := x\"FFF\";
+constant bad2 : word_t := 6b\"10_1010\";
+constant bad3 : bit_vector(0 to 3) := \"01\";
+
+procedure proc(arg : bit_vector(3 downto 0) := o\"7\");
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s("x\"FFF\"", 2),
+                "Bit string literal of length 12 does not match target subtype of length 8",
+            ),
+            Diagnostic::error(
+                code.s1("6b\"10_1010\""),
+                "Bit string literal of length 6 does not match target subtype of length 8",
+            ),
+            Diagnostic::error(
+                code.s1("\"01\""),
+                "String literal of length 2 does not match target subtype of length 4",
+            ),
+            Diagnostic::error(
+                code.s1("o\"7\""),
+                "Bit string literal of length 3 does not match target subtype of length 4",
+            ),
+        ],
+    );
+}
+
 #[test]
 fn typecheck_aggregate_element_association_expr() {
     let mut builder = LibraryBuilder::new();
@@ -1126,7 +1331,7 @@ constant bad2 : character := - 'a';
     let (_, diagnostics) = builder.get_analyzed_root();
 
     check_diagnostics(
-        diagnostics,
+        without_releated(&diagnostics),
         vec![
             // Prefer to complain on return type when operator arguments are unambiguous
             Diagnostic::error(
@@ -1164,7 +1369,7 @@ constant bad2 : character := 'a' + 'b';
     let (_, diagnostics) = builder.get_analyzed_root();
 
     check_diagnostics(
-        diagnostics,
+        without_releated(&diagnostics),
         vec![
             // Prefer to complain on return type when operator arguments are unambiguous
             Diagnostic::error(
@@ -1299,10 +1504,10 @@ function bad_fun1 return natural;
 function bad_fun1 return character;
 function bad_fun2 return natural;
 
-attribute ram_style of good_fun1 : signal is 0;
-attribute ram_style of good_fun2[return natural] : signal is 0;
-attribute ram_style of bad_fun1 : signal is 0;
-attribute ram_style of bad_fun2[return boolean] : signal is 0;
+attribute ram_style of good_fun1 : function is 0;
+attribute ram_style of good_fun2[return natural] : function is 0;
+attribute ram_style of bad_fun1 : function is 0;
+attribute ram_style of bad_fun2[return boolean] : function is 0;
 
 ",
     );
@@ -1315,7 +1520,7 @@ attribute ram_style of bad_fun2[return boolean] : signal is 0;
             "Attribute specification should only have a signature for subprograms and enum literals",
         ),
         Diagnostic::error(
-            code.s1("bad_fun1 : signal").s1("bad_fun1"),
+            code.s1("bad_fun1 : function").s1("bad_fun1"),
             "Signature required for alias of subprogram and enum literals",
         ),
         Diagnostic::error(
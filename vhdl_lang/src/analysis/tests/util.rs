@@ -5,7 +5,9 @@
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
 use crate::analysis::DesignRoot;
+use crate::config::{Lint, LintConfig};
 use crate::data::*;
+use crate::standard::VHDLStandard;
 use crate::syntax::test::*;
 use crate::syntax::Symbols;
 use pretty_assertions::assert_eq;
@@ -15,6 +17,8 @@ use std::sync::Arc;
 pub struct LibraryBuilder {
     code_builder: CodeBuilder,
     libraries: HashMap<Symbol, Vec<Code>>,
+    lint_config: LintConfig,
+    third_party_libraries: Vec<Symbol>,
 }
 
 impl LibraryBuilder {
@@ -22,9 +26,29 @@ impl LibraryBuilder {
         LibraryBuilder {
             code_builder: CodeBuilder::new(),
             libraries: HashMap::default(),
+            lint_config: LintConfig::default(),
+            third_party_libraries: Vec::new(),
         }
     }
 
+    pub fn with_standard(standard: VHDLStandard) -> LibraryBuilder {
+        LibraryBuilder {
+            code_builder: CodeBuilder::with_standard(standard),
+            libraries: HashMap::default(),
+            lint_config: LintConfig::default(),
+            third_party_libraries: Vec::new(),
+        }
+    }
+
+    pub fn enable_lint(&mut self, lint: Lint) {
+        self.lint_config.set_enabled(lint, true);
+    }
+
+    pub fn set_third_party_library(&mut self, library_name: &str) {
+        let library_name = self.code_builder.symbol(library_name);
+        self.third_party_libraries.push(library_name);
+    }
+
     fn add_code(&mut self, library_name: &str, code: Code) {
         let library_name = self.code_builder.symbol(library_name);
         match self.libraries.entry(library_name) {
@@ -64,13 +88,42 @@ end architecture;"
         )
     }
 
+    /// The bundled ieee sources are VHDL-2008 regardless of the standard of the user code
+    fn bundled_code_builder(&self) -> CodeBuilder {
+        CodeBuilder {
+            symbols: self.code_builder.symbols.clone(),
+            standard: VHDLStandard::default(),
+        }
+    }
+
     pub fn add_std_logic_1164(&mut self) {
-        let std_logic_1164 = self.code_builder.code_from_source(std_logic_1164_package());
+        let std_logic_1164 = self
+            .bundled_code_builder()
+            .code_from_source(std_logic_1164_package());
         self.add_code("ieee", std_logic_1164);
     }
 
+    pub fn add_numeric_std(&mut self) {
+        self.add_std_logic_1164();
+        let numeric_std = self
+            .bundled_code_builder()
+            .code_from_source(numeric_std_package());
+        self.add_code("ieee", numeric_std);
+    }
+
     pub fn get_analyzed_root(&self) -> (DesignRoot, Vec<Diagnostic>) {
         let mut root = DesignRoot::new(self.code_builder.symbols.clone());
+        root.set_lint_config(self.lint_config.clone());
+        root.set_standard(self.code_builder.standard);
+        // The bundled std and ieee libraries are configured as third-party
+        for library_name in ["std", "ieee"] {
+            let library_name = self.code_builder.symbol(library_name);
+            root.set_library_standard(library_name.clone(), VHDLStandard::default());
+            root.set_third_party_library(library_name);
+        }
+        for library_name in self.third_party_libraries.iter() {
+            root.set_third_party_library(library_name.clone());
+        }
         let mut diagnostics = Vec::new();
 
         add_standard_library(self.symbols(), &mut root);
@@ -137,9 +190,20 @@ fn std_logic_1164_package() -> Source {
     )
 }
 
+fn numeric_std_package() -> Source {
+    Source::inline(
+        Path::new("numeric_std.vhd"),
+        &Latin1String::new(include_bytes!(
+            "../../../../vhdl_libraries/ieee2008/numeric_std.vhdl"
+        ))
+        .to_string(),
+    )
+}
+
 pub fn add_standard_library(symbols: Arc<Symbols>, root: &mut DesignRoot) {
     let builder = CodeBuilder {
         symbols: symbols.clone(),
+        standard: VHDLStandard::default(),
     };
     let std_standard = builder.code_from_source(standard_package());
     let std_textio = builder.code_from_source(textio_package());
@@ -186,17 +250,24 @@ pub fn duplicate_in_two_files(code1: &Code, code2: &Code, names: &[&str]) -> Vec
 }
 
 pub fn check_missing(contents: &str) {
+    check_missing_with_diagnostics(contents, |_| Vec::new());
+}
+
+/// Check that all occurences of missing are reported along with other expected diagnostics
+pub fn check_missing_with_diagnostics(
+    contents: &str,
+    other: impl FnOnce(&Code) -> Vec<Diagnostic>,
+) {
     let mut builder = LibraryBuilder::new();
     let code = builder.code("libname", contents);
     let diagnostics = builder.analyze();
     let occurences = contents.matches("missing").count();
     assert!(occurences > 0);
-    check_diagnostics(
-        diagnostics,
-        (1..=occurences)
-            .map(|idx| missing(&code, "missing", idx))
-            .collect(),
-    );
+    let mut expected: Vec<_> = (1..=occurences)
+        .map(|idx| missing(&code, "missing", idx))
+        .collect();
+    expected.extend(other(&code));
+    check_diagnostics(diagnostics, expected);
 }
 
 pub fn check_code_with_no_diagnostics(contents: &str) {
@@ -213,13 +284,22 @@ pub fn check_search_reference(contents: &str) {
 /// Check that all occurences of decl_name references the first occurence of if
 /// Also check that find all references returns all occurences of decl_name
 pub fn check_search_reference_with_name(decl_name: &str, contents: &str) {
+    check_search_reference_with_diagnostics(decl_name, contents, |_| Vec::new());
+}
+
+/// Like check_search_reference_with_name for code that has expected diagnostics
+pub fn check_search_reference_with_diagnostics(
+    decl_name: &str,
+    contents: &str,
+    expected: impl FnOnce(&Code) -> Vec<Diagnostic>,
+) {
     let mut builder = LibraryBuilder::new();
     let code = builder.code("libname", contents);
     let occurences = contents.matches(decl_name).count();
     assert!(occurences > 0);
 
     let (root, diagnostics) = builder.get_analyzed_root();
-    check_no_diagnostics(&diagnostics);
+    check_diagnostics(diagnostics, expected(&code));
 
     let mut references = Vec::new();
     for idx in 1..=occurences {
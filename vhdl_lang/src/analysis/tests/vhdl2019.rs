@@ -0,0 +1,182 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::standard::VHDLStandard;
+
+#[test]
+fn conditional_expressions() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  constant sel : boolean := true;
+  constant c0 : natural := 1 when sel else 2;
+  constant c1 : natural := 1 when sel else 2 when not sel else 3;
+  constant c2 : natural := 1 when 0 else 2;
+  constant c3 : natural := 1 when sel else false;
+
+  function fun(arg : boolean) return natural;
+  procedure proc(arg : boolean);
+end package;
+
+package body pkg is
+  function fun(arg : boolean) return natural is
+  begin
+    return 1 when arg;
+    return 2;
+  end function;
+
+  procedure proc(arg : boolean) is
+  begin
+    return when arg;
+    return when 0;
+  end procedure;
+end package body;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::error(
+                code.s1("1 when 0").s1("0"),
+                "type universal_integer cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+            Diagnostic::error(code.s1("false"), "'false' does not match subtype 'NATURAL'"),
+            Diagnostic::error(
+                code.s1("return when 0").s1("0"),
+                "type universal_integer cannot be implictly converted to type 'BOOLEAN'. Operator ?? is not defined for this type.",
+            ),
+        ],
+    );
+}
+
+#[test]
+fn sequential_block_has_own_declarative_region() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+entity ent is
+end entity;
+
+architecture a of ent is
+begin
+  main : process
+  begin
+    blk : block is
+      variable v : natural;
+    begin
+      v := 1;
+    end block blk;
+    v := 2;
+    wait;
+  end process;
+end architecture;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("v := 2").s1("v"),
+            "No declaration of 'v'",
+        )],
+    );
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("v := 1").start()),
+        Some(code.s1("v :").s1("v").pos())
+    );
+}
+
+#[test]
+fn private_variables_are_only_visible_in_protected_body() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    let code = builder.code(
+        "libname",
+        "
+package pkg is
+  type counter_t is protected
+    procedure increment;
+  end protected;
+
+  type wrapper_t is protected
+    private variable counter : counter_t;
+    procedure increment;
+  end protected;
+end package;
+
+package body pkg is
+  type counter_t is protected body
+    variable count : natural := 0;
+    procedure increment is
+    begin
+      count := count + 1;
+    end procedure;
+  end protected body;
+
+  type wrapper_t is protected body
+    procedure increment is
+    begin
+      counter.increment;
+    end procedure;
+  end protected body;
+
+  shared variable wrapper : wrapper_t;
+
+  procedure proc is
+  begin
+    wrapper.counter.increment;
+  end procedure;
+end package body;
+        ",
+    );
+
+    let (root, diagnostics) = builder.get_analyzed_root();
+    check_diagnostics(
+        diagnostics,
+        vec![Diagnostic::error(
+            code.s1("wrapper.counter").s1("counter"),
+            "Protected type selection must be a method, got variable 'counter'",
+        )],
+    );
+
+    assert_eq!(
+        root.search_reference_pos(code.source(), code.s1("counter.increment").start()),
+        Some(code.s1("counter :").s1("counter").pos())
+    );
+}
+
+#[test]
+fn vhdl2019_relaxed_syntax() {
+    let mut builder = LibraryBuilder::with_standard(VHDLStandard::VHDL2019);
+    builder.code(
+        "libname",
+        "
+package pkg is
+  type empty_t is record
+  end record;
+
+  component comp is
+    generic (
+      width : natural;
+    );
+    port (
+      clk : in boolean;
+    );
+  end comp;
+end package;
+        ",
+    );
+
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
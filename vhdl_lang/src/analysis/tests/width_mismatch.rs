@@ -0,0 +1,78 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::*;
+use crate::config::Lint;
+
+const CODE: &str = "
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity ent is
+end entity;
+
+architecture a of ent is
+  signal a, b : unsigned(7 downto 0);
+  signal sum : unsigned(8 downto 0);
+  signal vec : std_logic_vector(15 downto 0);
+  signal prod : unsigned(15 downto 0);
+begin
+  sum <= a + b;
+  sum <= resize(a, 9) + b;
+  sum <= '0' & a;
+  vec <= std_logic_vector(a & b);
+  prod <= a * b;
+  prod <= a * 3;
+  vec <= std_logic_vector(resize(a, 16));
+  vec(7 downto 0) <= std_logic_vector(a);
+  vec <= std_logic_vector(sum);
+
+  process
+    variable count : unsigned(3 downto 0);
+  begin
+    count := count + 1;
+    count := resize(a, 4);
+    count := resize(arg => a, new_size => 5);
+    wait;
+  end process;
+end architecture;
+";
+
+#[test]
+fn width_mismatch_is_disabled_by_default() {
+    let mut builder = LibraryBuilder::new();
+    builder.add_numeric_std();
+    builder.code("libname", CODE);
+    let diagnostics = builder.analyze();
+    check_no_diagnostics(&diagnostics);
+}
+
+#[test]
+fn width_mismatch_of_numeric_std_expressions() {
+    let mut builder = LibraryBuilder::new();
+    builder.enable_lint(Lint::WidthMismatch);
+    builder.add_numeric_std();
+    let code = builder.code("libname", CODE);
+    let diagnostics = builder.analyze();
+    check_diagnostics(
+        diagnostics,
+        vec![
+            Diagnostic::warning(
+                code.s1("a + b"),
+                "Expression of width 8 does not match target of width 9",
+            ),
+            Diagnostic::warning(
+                code.s1("std_logic_vector(sum)"),
+                "Expression of width 9 does not match target of width 16",
+            ),
+            Diagnostic::warning(
+                code.s1("resize(arg => a, new_size => 5)"),
+                "Expression of width 5 does not match target of width 4",
+            ),
+        ],
+    );
+}
@@ -0,0 +1,175 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use fnv::FnvHashMap;
+
+use super::analyze::*;
+use super::named_entity::*;
+use super::names::plural;
+use super::names::ResolvedName;
+use super::region::*;
+use crate::ast::*;
+use crate::data::*;
+
+impl<'a> AnalyzeContext<'a> {
+    /// Resolve the name of a mode view to the record type of the view
+    /// The converse of a mode view has the same type as the view itself
+    pub fn resolve_view_name(
+        &self,
+        scope: &Scope<'a>,
+        name: &mut WithPos<Name>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<TypeEnt<'a>> {
+        if let Name::Attribute(ref mut attr) = name.item {
+            if attr.attr.item == AttributeDesignator::Converse
+                && attr.signature.is_none()
+                && attr.expr.is_none()
+            {
+                return self.resolve_view_name(scope, &mut attr.name, diagnostics);
+            }
+        }
+
+        let resolved = self.name_resolve(scope, &name.pos, &mut name.item, diagnostics)?;
+        if let ResolvedName::Final(ent) = resolved {
+            if let AnyEntKind::View(typ) = ent.kind() {
+                return Ok(*typ);
+            }
+        }
+
+        diagnostics.error(
+            &name.pos,
+            format!("Expected mode view, got {}", resolved.describe()),
+        );
+        Err(EvalError::Unknown)
+    }
+
+    /// Check that the type of a mode view indication matches the type of the object
+    /// An array mode view applies to the elements of an array type
+    pub fn check_view_type(
+        &self,
+        view: &ModeViewIndication,
+        view_typ: TypeEnt<'a>,
+        typ: TypeEnt<'a>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        let matches = match view.kind {
+            ModeViewIndicationKind::Record => typ.base() == view_typ.base(),
+            ModeViewIndicationKind::Array => typ
+                .array_type()
+                .map(|(elem_typ, _)| elem_typ.base() == view_typ.base())
+                .unwrap_or(false),
+        };
+
+        if !matches {
+            let message = match view.kind {
+                ModeViewIndicationKind::Record => format!(
+                    "Mode view of {} does not match {}",
+                    view_typ.describe(),
+                    typ.describe()
+                ),
+                ModeViewIndicationKind::Array => format!(
+                    "Mode view of {} does not match the element type of {}",
+                    view_typ.describe(),
+                    typ.describe()
+                ),
+            };
+            diagnostics.error(&view.name.pos, message);
+        }
+    }
+
+    /// LRM 6.5.2 Each element of the record type of a mode view shall have exactly one mode
+    pub fn analyze_view_declaration(
+        &self,
+        scope: &Scope<'a>,
+        view: &mut ModeViewDeclaration,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> EvalResult<TypeEnt<'a>> {
+        let typ = match self.resolve_subtype_indication(scope, &mut view.typ, diagnostics) {
+            Ok(subtype) => subtype.type_mark(),
+            Err(err) => {
+                err.add_to(diagnostics)?;
+                return Err(EvalError::Unknown);
+            }
+        };
+
+        let region = if let Type::Record(ref region) = typ.base().kind() {
+            region
+        } else {
+            diagnostics.error(
+                &view.typ.type_mark.pos,
+                format!(
+                    "The type of a mode view must be a record type, got {}",
+                    typ.describe()
+                ),
+            );
+            return Err(EvalError::Unknown);
+        };
+
+        let mut defined: FnvHashMap<Designator, SrcPos> = FnvHashMap::default();
+
+        for element in view.elements.iter_mut() {
+            let ModeViewElementDefinition { names, mode } = element;
+
+            let element_view = if let ElementMode::View(ref mut element_view) = mode {
+                as_fatal(self.resolve_view_name(scope, &mut element_view.name, diagnostics))?
+                    .map(|view_typ| (&*element_view, view_typ))
+            } else {
+                None
+            };
+
+            for name in names.iter_mut() {
+                let designator = Designator::Identifier(name.item.item.clone());
+                let elem = if let Some(elem) = region.lookup(&designator) {
+                    elem
+                } else {
+                    diagnostics.push(Diagnostic::no_declaration_within(
+                        typ.into(),
+                        &name.item.pos,
+                        &designator,
+                    ));
+                    continue;
+                };
+                name.set_unique_reference(&elem);
+
+                if let Some(prev_pos) = defined.get(&designator) {
+                    diagnostics.push(
+                        Diagnostic::error(
+                            &name.item.pos,
+                            format!("Duplicate mode of record element '{designator}'"),
+                        )
+                        .related(prev_pos, "Previously defined here"),
+                    );
+                    continue;
+                }
+                defined.insert(designator, name.item.pos.clone());
+
+                if let Some((element_view, view_typ)) = element_view {
+                    self.check_view_type(element_view, view_typ, elem.type_mark(), diagnostics);
+                }
+            }
+        }
+
+        let missing: Vec<_> = region
+            .iter()
+            .filter(|elem| !defined.contains_key(elem.designator()))
+            .map(|elem| format!("'{}'", elem.designator()))
+            .collect();
+
+        if !missing.is_empty() {
+            diagnostics.error(
+                &view.ident.tree.pos,
+                format!(
+                    "Missing mode of record {} {} in mode view '{}'",
+                    plural("element", "elements", missing.len()),
+                    missing.join(", "),
+                    view.ident.tree.item
+                ),
+            );
+        }
+
+        Ok(typ)
+    }
+}
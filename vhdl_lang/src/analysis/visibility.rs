@@ -173,6 +173,21 @@ impl<'a> Visible<'a> {
         };
     }
 
+    pub fn entities(&self) -> impl Iterator<Item = EntRef<'a>> + '_ {
+        self.visible_entities.values().map(|visible| visible.entity)
+    }
+
+    /// The visible entity if exactly one non-overloaded entity is visible
+    pub fn single(&self) -> Option<EntRef<'a>> {
+        let mut entities = self.visible_entities.values();
+        let visible = entities.next()?;
+        if entities.next().is_none() && !visible.entity.is_overloaded() {
+            Some(visible.entity)
+        } else {
+            None
+        }
+    }
+
     pub fn into_unambiguous(
         self,
         pos: &SrcPos,
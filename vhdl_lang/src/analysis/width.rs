@@ -0,0 +1,265 @@
+//! This Source Code Form is subject to the terms of the Mozilla Public
+//! License, v. 2.0. If a copy of the MPL was not distributed with this file,
+//! You can obtain one at http://mozilla.org/MPL/2.0/.
+//!
+//! Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! Width mismatch lint for vector assignments
+//!
+//! The width of an expression is computed when it is statically known, following the
+//! result width rules of the numeric_std package for arithmetic operators and resize.
+
+use super::analyze::*;
+use super::named_entity::*;
+use crate::ast::*;
+use crate::config::Lint;
+use crate::data::*;
+
+impl<'a> AnalyzeContext<'a> {
+    pub fn check_expr_assignment_width(
+        &self,
+        target: &WithPos<Target>,
+        rhs: &AssignmentRightHand<WithPos<Expression>>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::WidthMismatch) {
+            return;
+        }
+
+        if let Some(target_width) = self.target_width(target) {
+            for expr in rhs_items(rhs) {
+                self.check_width(target_width, expr, diagnostics);
+            }
+        }
+    }
+
+    pub fn check_waveform_assignment_width(
+        &self,
+        target: &WithPos<Target>,
+        rhs: &AssignmentRightHand<Waveform>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::WidthMismatch) {
+            return;
+        }
+
+        if let Some(target_width) = self.target_width(target) {
+            for wavf in rhs_items(rhs) {
+                if let Waveform::Elements(ref elems) = wavf {
+                    for elem in elems.iter() {
+                        self.check_width(target_width, &elem.value, diagnostics);
+                    }
+                }
+            }
+        }
+    }
+
+    /// The initial value of an object declaration
+    /// Literals and aggregates are already checked against the subtype
+    pub fn check_initial_value_width(
+        &self,
+        subtype: &Subtype<'a>,
+        expr: &WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if !self.is_lint_enabled(Lint::WidthMismatch)
+            || matches!(expr.item, Expression::Literal(_) | Expression::Aggregate(_))
+        {
+            return;
+        }
+
+        if let Some(target_width) = subtype.static_length() {
+            self.check_width(target_width, expr, diagnostics);
+        }
+    }
+
+    fn check_width(
+        &self,
+        target_width: u64,
+        expr: &WithPos<Expression>,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) {
+        if let Some(width) = self.static_width(&expr.item) {
+            if width != target_width {
+                diagnostics.warning(
+                    &expr.pos,
+                    format!(
+                        "Expression of width {width} does not match target of width {target_width}"
+                    ),
+                );
+            }
+        }
+    }
+
+    fn target_width(&self, target: &WithPos<Target>) -> Option<u64> {
+        if let Target::Name(ref name) = target.item {
+            self.name_width(name)
+        } else {
+            None
+        }
+    }
+
+    fn name_width(&self, name: &Name) -> Option<u64> {
+        match name {
+            Name::Designator(des) => {
+                let ent = self.arena.get(des.reference?);
+                if let AnyEntKind::Object(obj) = ent.actual_kind() {
+                    obj.subtype.static_length()
+                } else {
+                    None
+                }
+            }
+            Name::Slice(_, drange) => self.static_length(drange),
+            _ => None,
+        }
+    }
+
+    /// The number of elements of a vector expression when it is statically known
+    fn static_width(&self, expr: &Expression) -> Option<u64> {
+        match expr {
+            Expression::Literal(Literal::BitString(bitstring)) => Some(bitstring.expanded_length()),
+            Expression::Literal(Literal::String(string)) => Some(string.len() as u64),
+            Expression::Name(name) => match name.as_ref() {
+                Name::CallOrIndexed(fcall) => self.call_width(fcall),
+                name => self.name_width(name),
+            },
+            Expression::Qualified(qexpr) => self.static_width(&qexpr.expr.item),
+            Expression::Binary(op, left, right) => {
+                let overloaded = OverloadedEnt::from_any(self.arena.get(op.item.reference?))?;
+                let left_is_array = is_array_formal(overloaded, 0);
+                let right_is_array = is_array_formal(overloaded, 1);
+
+                let operand_width = |is_array: bool, operand: &WithPos<Expression>| {
+                    if is_array {
+                        self.static_width(&operand.item)
+                    } else {
+                        Some(1)
+                    }
+                };
+
+                match op.item.item {
+                    Operator::Concat => operand_width(left_is_array, left)?
+                        .checked_add(operand_width(right_is_array, right)?),
+                    Operator::Plus | Operator::Minus | Operator::Times
+                        if self.is_numeric_std(overloaded.into()) =>
+                    {
+                        let (left, right) = match (left_is_array, right_is_array) {
+                            (true, true) => (
+                                self.static_width(&left.item)?,
+                                self.static_width(&right.item)?,
+                            ),
+                            // The natural or integer operand is converted to the width of the vector
+                            (true, false) => {
+                                let width = self.static_width(&left.item)?;
+                                (width, width)
+                            }
+                            (false, true) => {
+                                let width = self.static_width(&right.item)?;
+                                (width, width)
+                            }
+                            (false, false) => return None,
+                        };
+
+                        if op.item.item == Operator::Times {
+                            left.checked_add(right)
+                        } else {
+                            Some(left.max(right))
+                        }
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn call_width(&self, fcall: &CallOrIndexed) -> Option<u64> {
+        let ent = self.arena.get(fcall.name.item.get_suffix_reference()?);
+
+        match ent.actual_kind() {
+            // A type conversion keeps the number of elements
+            AnyEntKind::Type(_) => {
+                if let [AssociationElement {
+                    formal: None,
+                    actual:
+                        WithPos {
+                            item: ActualPart::Expression(expr),
+                            ..
+                        },
+                }] = fcall.parameters.as_slice()
+                {
+                    self.static_width(expr)
+                } else {
+                    None
+                }
+            }
+            AnyEntKind::Overloaded(_)
+                if self.is_numeric_std(ent) && self.is_named(ent, "resize") =>
+            {
+                let size = fcall
+                    .parameters
+                    .iter()
+                    .enumerate()
+                    .find(|(idx, assoc)| match assoc.formal {
+                        Some(ref formal) => !matches!(
+                            formal.item,
+                            Name::Designator(ref des) if self.is_designator(&des.item, "arg")
+                        ),
+                        None => *idx == 1,
+                    })
+                    .map(|(_, assoc)| assoc)?;
+
+                if let ActualPart::Expression(ref expr) = size.actual.item {
+                    // The size may be given as a vector whose width is used since VHDL-2008
+                    self.static_integer(expr)
+                        .and_then(|size| u64::try_from(size).ok())
+                        .or_else(|| self.static_width(expr))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    /// Subprograms declared in the numeric_std or numeric_bit packages
+    fn is_numeric_std(&self, ent: EntRef<'a>) -> bool {
+        ent.parent
+            .map(|package| {
+                self.is_named(package, "numeric_std") || self.is_named(package, "numeric_bit")
+            })
+            .unwrap_or(false)
+    }
+
+    fn is_named(&self, ent: EntRef<'a>, name: &str) -> bool {
+        self.is_designator(ent.designator(), name)
+    }
+
+    fn is_designator(&self, designator: &Designator, name: &str) -> bool {
+        matches!(designator, Designator::Identifier(sym) if sym == &self.root.symbol_utf8(name))
+    }
+}
+
+fn is_array_formal(overloaded: OverloadedEnt, idx: usize) -> bool {
+    overloaded
+        .nth_base(idx)
+        .map(|base| matches!(base.kind(), Type::Array { .. }))
+        .unwrap_or(false)
+}
+
+fn rhs_items<T>(rhs: &AssignmentRightHand<T>) -> Vec<&T> {
+    match rhs {
+        AssignmentRightHand::Simple(item) => vec![item],
+        AssignmentRightHand::Conditional(conditionals) => conditionals
+            .conditionals
+            .iter()
+            .map(|cond| &cond.item)
+            .chain(conditionals.else_item.iter())
+            .collect(),
+        AssignmentRightHand::Selected(selection) => selection
+            .alternatives
+            .iter()
+            .map(|alternative| &alternative.item)
+            .collect(),
+    }
+}
@@ -129,6 +129,7 @@ pub enum AttributeDesignator {
     SimpleName,
     InstanceName,
     PathName,
+    Converse,
 }
 
 #[derive(PartialEq, Debug, Copy, Clone, Eq)]
@@ -308,6 +309,10 @@ pub enum Expression {
 
     /// LRM 9.3.7 Allocators
     New(Box<WithPos<Allocator>>),
+
+    /// VHDL-2019 LRM 9.1 Conditional expressions
+    /// The else item is only missing for the value of a return statement
+    Conditional(Box<Conditionals<WithPos<Expression>>>),
 }
 
 /// An identifier together with the lexical source location it occurs in.
@@ -411,6 +416,8 @@ pub struct ElementDeclaration {
 #[derive(PartialEq, Debug, Clone)]
 pub enum ProtectedTypeDeclarativeItem {
     Subprogram(SubprogramDeclaration),
+    /// VHDL-2019 LRM 5.6.2 private variable declaration
+    PrivateVariable(ObjectDeclaration),
 }
 
 #[derive(PartialEq, Eq, Hash, Debug, Clone)]
@@ -496,21 +503,28 @@ pub enum EntityName {
 }
 
 /// LRM 7.2 Attribute specification
-// @TODO there are more classes
 #[derive(PartialEq, Eq, Debug, Clone, Copy)]
 pub enum EntityClass {
     Entity,
     Architecture,
     Configuration,
+    Procedure,
+    Function,
     Package,
+    Type,
+    Subtype,
+    Constant,
     Signal,
     Variable,
-    Procedure,
-    Function,
     Component,
-    Constant,
-    Type,
     Label,
+    Literal,
+    Units,
+    Group,
+    File,
+    Property,
+    Sequence,
+    View,
 }
 
 /// LRM 7.2 Attribute specification
@@ -529,6 +543,28 @@ pub enum Attribute {
     Declaration(AttributeDeclaration),
 }
 
+/// LRM 6.9 Group template declarations
+#[derive(PartialEq, Eq, Debug, Clone)]
+pub struct EntityClassEntry {
+    pub entity_class: EntityClass,
+    pub has_box: bool,
+}
+
+/// LRM 6.9 Group template declarations
+#[derive(PartialEq, Debug, Clone)]
+pub struct GroupTemplateDeclaration {
+    pub ident: WithDecl<Ident>,
+    pub entity_classes: Vec<EntityClassEntry>,
+}
+
+/// LRM 6.10 Group declarations
+#[derive(PartialEq, Debug, Clone)]
+pub struct GroupDeclaration {
+    pub ident: WithDecl<Ident>,
+    pub group_template_name: WithPos<SelectedName>,
+    pub constituents: Vec<WithPos<SelectedName>>,
+}
+
 /// LRM 5.6.2 Protected type declarations
 #[derive(PartialEq, Debug, Clone)]
 pub struct ProtectedTypeDeclaration {
@@ -567,7 +603,6 @@ pub enum TypeDefinition {
     Numeric(Range),
     /// LRM 5.2.4 Physical types
     Physical(PhysicalTypeDeclaration),
-    // @TODO floating
     /// LRM 5.3 Composite Types
     /// LRM 5.3.2 Array types
     Array(Vec<ArrayIndex>, SubtypeIndication),
@@ -610,11 +645,20 @@ pub enum InterfaceType {
     Parameter,
 }
 
+/// LRM 6.4.2.3 Signal declarations
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SignalKind {
+    Register,
+    Bus,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct ObjectDeclaration {
     pub class: ObjectClass,
     pub ident: WithDecl<Ident>,
     pub subtype_indication: SubtypeIndication,
+    /// Only signals may have a signal kind which makes them guarded signals
+    pub signal_kind: Option<SignalKind>,
     pub expression: Option<WithPos<Expression>>,
 }
 
@@ -632,10 +676,18 @@ pub enum SubprogramDesignator {
     OperatorSymbol(Operator),
 }
 
+/// LRM 4.2.1 Subprogram header
+#[derive(PartialEq, Debug, Clone)]
+pub struct SubprogramHeader {
+    pub generic_list: Vec<InterfaceDeclaration>,
+    pub generic_map: Option<Vec<AssociationElement>>,
+}
+
 /// LRM 4.2 Subprogram declaration
 #[derive(PartialEq, Debug, Clone)]
 pub struct ProcedureSpecification {
     pub designator: WithDecl<WithPos<SubprogramDesignator>>,
+    pub header: Option<SubprogramHeader>,
     pub parameter_list: Vec<InterfaceDeclaration>,
 }
 
@@ -644,6 +696,7 @@ pub struct ProcedureSpecification {
 pub struct FunctionSpecification {
     pub pure: bool,
     pub designator: WithDecl<WithPos<SubprogramDesignator>>,
+    pub header: Option<SubprogramHeader>,
     pub parameter_list: Vec<InterfaceDeclaration>,
     pub return_type: WithPos<TypeMark>,
 }
@@ -670,6 +723,22 @@ pub enum SubprogramDeclaration {
     Function(FunctionSpecification),
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum SubprogramKind {
+    Function,
+    Procedure,
+}
+
+/// LRM 4.4 Subprogram instantiation declaration
+#[derive(PartialEq, Debug, Clone)]
+pub struct SubprogramInstantiation {
+    pub kind: SubprogramKind,
+    pub ident: WithDecl<Ident>,
+    pub subprogram_name: WithPos<SelectedName>,
+    pub signature: Option<WithPos<Signature>>,
+    pub generic_map: Option<Vec<AssociationElement>>,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub struct InterfaceFileDeclaration {
     pub ident: WithDecl<Ident>,
@@ -683,10 +752,28 @@ pub struct InterfaceObjectDeclaration {
     pub class: ObjectClass,
     pub ident: WithDecl<Ident>,
     pub mode: Mode,
-    pub subtype_indication: SubtypeIndication,
+    /// The mode view when the mode is [`Mode::View`]
+    pub view: Option<ModeViewIndication>,
+    /// May only be omitted for a record mode view where the type is given by the view
+    pub subtype_indication: Option<SubtypeIndication>,
+    /// Only interface signals may be guarded signals of kind bus
+    pub signal_kind: Option<SignalKind>,
     pub expression: Option<WithPos<Expression>>,
 }
 
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum ModeViewIndicationKind {
+    Record,
+    Array,
+}
+
+/// LRM 6.5.2 Interface object declarations
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewIndication {
+    pub kind: ModeViewIndicationKind,
+    pub name: WithPos<Name>,
+}
+
 #[derive(PartialEq, Debug, Clone)]
 pub enum SubprogramDefault {
     Name(WithPos<SelectedName>),
@@ -726,6 +813,31 @@ pub enum Mode {
     InOut,
     Buffer,
     Linkage,
+    /// The modes of the elements are given by a mode view
+    View,
+}
+
+/// LRM 6.5.2 Interface object declarations
+#[derive(PartialEq, Debug, Clone)]
+pub enum ElementMode {
+    Simple(WithPos<Mode>),
+    View(ModeViewIndication),
+}
+
+/// LRM 6.5.2 Interface object declarations
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewElementDefinition {
+    pub names: Vec<WithRef<Ident>>,
+    pub mode: ElementMode,
+}
+
+/// LRM 6.5.2 Mode view declarations
+#[derive(PartialEq, Debug, Clone)]
+pub struct ModeViewDeclaration {
+    pub ident: WithDecl<Ident>,
+    pub typ: SubtypeIndication,
+    pub elements: Vec<ModeViewElementDefinition>,
+    pub end_ident_pos: Option<SrcPos>,
 }
 
 #[derive(PartialEq, Debug, Clone)]
@@ -752,9 +864,16 @@ pub enum Declaration {
     Alias(AliasDeclaration),
     SubprogramDeclaration(SubprogramDeclaration),
     SubprogramBody(SubprogramBody),
+    SubprogramInstantiation(SubprogramInstantiation),
+    View(ModeViewDeclaration),
     Use(WithPos<UseClause>),
     Package(PackageInstantiation),
     Configuration(ConfigurationSpecification),
+    Disconnection(DisconnectionSpecification),
+    PslProperty(PslPropertyDeclaration),
+    PslDefaultClock(WithPos<PslExpression>),
+    GroupTemplate(GroupTemplateDeclaration),
+    Group(GroupDeclaration),
 }
 
 /// LRM 10.2 Wait statement
@@ -906,6 +1025,14 @@ pub struct LoopStatement {
     pub end_label_pos: Option<SrcPos>,
 }
 
+/// VHDL-2019 LRM 10.16 Sequential block statement
+#[derive(PartialEq, Debug, Clone)]
+pub struct SequentialBlock {
+    pub decl: Vec<Declaration>,
+    pub statements: Vec<LabeledSequentialStatement>,
+    pub end_label_pos: Option<SrcPos>,
+}
+
 /// LRM 10.11 Next statement
 #[derive(PartialEq, Debug, Clone)]
 pub struct NextStatement {
@@ -924,6 +1051,8 @@ pub struct ExitStatement {
 #[derive(PartialEq, Debug, Clone)]
 pub struct ReturnStatement {
     pub expression: Option<WithPos<Expression>>,
+    /// VHDL-2019 return when condition
+    pub condition: Option<WithPos<Expression>>,
 }
 
 /// LRM 10. Sequential statements
@@ -943,6 +1072,7 @@ pub enum SequentialStatement {
     Next(NextStatement),
     Exit(ExitStatement),
     Return(ReturnStatement),
+    Block(SequentialBlock),
     Null,
 }
 
@@ -1070,6 +1200,10 @@ pub enum ConcurrentStatement {
     ForGenerate(ForGenerateStatement),
     IfGenerate(IfGenerateStatement),
     CaseGenerate(CaseGenerateStatement),
+    Psl(PslDirective),
+    /// PSL declarations embedded in comments may also be placed among the statements
+    PslProperty(PslPropertyDeclaration),
+    PslDefaultClock(WithPos<PslExpression>),
 }
 
 /// LRM 11. Concurrent statements
@@ -1079,6 +1213,185 @@ pub struct LabeledConcurrentStatement {
     pub statement: WithPos<ConcurrentStatement>,
 }
 
+/// PSL 6 A property, sequence or boolean expression
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslExpression {
+    /// A VHDL expression used as a boolean, or a named property or sequence instance
+    Hdl(WithPos<Expression>),
+    Unary(WithPos<PslUnaryOperator>, Box<WithPos<PslExpression>>),
+    Binary(
+        WithPos<PslBinaryOperator>,
+        Box<WithPos<PslExpression>>,
+        Box<WithPos<PslExpression>>,
+    ),
+    /// 6.1.1 A sequential extended regular expression within braces
+    Sere(Box<WithPos<PslExpression>>),
+    /// 6.1.2 A repeated sequence, the operand is omitted in for example `[*]`
+    Repetition(
+        Option<Box<WithPos<PslExpression>>>,
+        PslRepetition,
+        Option<PslCount>,
+    ),
+}
+
+/// A count such as `3` or a range of counts such as `1 to inf`
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslCount {
+    pub low: WithPos<Expression>,
+    pub high: Option<PslCountBound>,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslCountBound {
+    Expression(WithPos<Expression>),
+    Inf,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PslRepetition {
+    /// `[*]`
+    Consecutive,
+    /// `[+]`
+    Plus,
+    /// `[=]`
+    NonConsecutive,
+    /// `[->]`
+    GoTo,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PslNextKind {
+    Next,
+    /// `next_a`, `next_event_a`
+    All,
+    /// `next_e`, `next_event_e`
+    Exists,
+}
+
+#[derive(PartialEq, Debug, Clone)]
+pub enum PslUnaryOperator {
+    Always,
+    Never,
+    Eventually,
+    Not,
+    /// `next`, `next_a` and `next_e` with an optional count
+    Next {
+        kind: PslNextKind,
+        strong: bool,
+        count: Option<PslCount>,
+    },
+    /// `next_event`, `next_event_a` and `next_event_e` with an optional count
+    NextEvent {
+        kind: PslNextKind,
+        strong: bool,
+        event: Box<WithPos<PslExpression>>,
+        count: Option<PslCount>,
+    },
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PslBinaryOperator {
+    /// `->`
+    Implication,
+    /// `<->`
+    Equivalence,
+    /// `|->`
+    OverlappingImplication,
+    /// `|=>`
+    NonOverlappingImplication,
+    Until {
+        strong: bool,
+        inclusive: bool,
+    },
+    Before {
+        strong: bool,
+        inclusive: bool,
+    },
+    Abort,
+    AsyncAbort,
+    SyncAbort,
+    /// `@`
+    Clock,
+    And,
+    Or,
+    /// `;`
+    Concatenation,
+    /// `:`
+    Fusion,
+    /// `|`
+    Union,
+    /// `&`
+    NonLengthMatchingAnd,
+    /// `&&`
+    LengthMatchingAnd,
+    Within,
+    /// `,` between the two booleans of a fairness directive
+    Fairness,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PslPropertyKind {
+    Property,
+    Sequence,
+}
+
+/// PSL 6.2.4 Named properties and 6.1.1.6 Named sequences
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslPropertyDeclaration {
+    pub kind: PslPropertyKind,
+    pub ident: WithDecl<Ident>,
+    pub parameters: Vec<WithDecl<Ident>>,
+    pub body: WithPos<PslExpression>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum PslDirectiveKind {
+    Assert,
+    Assume,
+    AssumeGuarantee,
+    Restrict,
+    RestrictGuarantee,
+    Cover,
+    Fairness,
+    StrongFairness,
+}
+
+/// PSL 7.1 Verification directives
+#[derive(PartialEq, Debug, Clone)]
+pub struct PslDirective {
+    pub kind: PslDirectiveKind,
+    /// None when the property could not be parsed
+    pub property: Option<WithPos<PslExpression>>,
+    pub report: Option<WithPos<Expression>>,
+    pub severity: Option<WithPos<Expression>>,
+}
+
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum VerificationUnitKind {
+    Vunit,
+    Vmode,
+    Vprop,
+}
+
+/// PSL 7.2 The design unit that a verification unit is bound to
+#[derive(PartialEq, Debug, Clone)]
+pub struct VerificationUnitBinding {
+    pub entity_name: WithRef<Ident>,
+    pub architecture_name: Option<WithRef<Ident>>,
+}
+
+/// PSL 7.2 Verification units
+#[derive(PartialEq, Debug, Clone)]
+pub struct VerificationUnit {
+    pub context_clause: ContextClause,
+    pub kind: VerificationUnitKind,
+    pub ident: WithDecl<Ident>,
+    pub binding: Option<VerificationUnitBinding>,
+    pub inherits: Vec<WithRef<Ident>>,
+    pub decl: Vec<Declaration>,
+    pub statements: Vec<LabeledConcurrentStatement>,
+}
+
 /// LRM 13. Design units and their analysis
 #[derive(PartialEq, Debug, Clone)]
 pub struct LibraryClause {
@@ -1123,9 +1436,9 @@ pub struct PackageInstantiation {
 }
 
 /// LRM 7.3 Configuration specification
-#[derive(PartialEq, Eq, Debug, Clone)]
+#[derive(PartialEq, Debug, Clone)]
 pub enum InstantiationList {
-    Labels(Vec<Ident>),
+    Labels(Vec<WithRef<Ident>>),
     Others,
     All,
 }
@@ -1133,7 +1446,7 @@ pub enum InstantiationList {
 /// LRM 7.3.2 Binding indication
 #[derive(PartialEq, Debug, Clone)]
 pub enum EntityAspect {
-    Entity(WithPos<SelectedName>, Option<Ident>),
+    Entity(WithPos<SelectedName>, Option<WithRef<Ident>>),
     Configuration(WithPos<SelectedName>),
     Open,
 }
@@ -1167,6 +1480,22 @@ pub struct ConfigurationSpecification {
     pub vunit_bind_inds: Vec<VUnitBindingIndication>,
 }
 
+/// LRM 7.4 Disconnection specification
+#[derive(PartialEq, Debug, Clone)]
+pub enum GuardedSignalList {
+    Names(Vec<WithPos<Name>>),
+    Others,
+    All,
+}
+
+/// LRM 7.4 Disconnection specification
+#[derive(PartialEq, Debug, Clone)]
+pub struct DisconnectionSpecification {
+    pub signal_list: GuardedSignalList,
+    pub type_mark: WithPos<TypeMark>,
+    pub after: WithPos<Expression>,
+}
+
 /// LRM 3.4 Configuration declarations
 #[derive(PartialEq, Debug, Clone)]
 pub enum ConfigurationDeclarativeItem {
@@ -1269,6 +1598,9 @@ pub enum AnyPrimaryUnit {
 
     /// LRM 13.4 Context clauses
     Context(ContextDeclaration),
+
+    /// PSL 7.2 Verification units
+    VerificationUnit(VerificationUnit),
 }
 
 /// LRM 13.1 Design units
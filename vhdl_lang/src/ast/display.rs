@@ -120,6 +120,7 @@ impl Display for AttributeDesignator {
             AttributeDesignator::SimpleName => write!(f, "simple_name"),
             AttributeDesignator::InstanceName => write!(f, "instance_name"),
             AttributeDesignator::PathName => write!(f, "path_name"),
+            AttributeDesignator::Converse => write!(f, "converse"),
         }
     }
 }
@@ -241,6 +242,75 @@ impl Display for AttributeDeclaration {
     }
 }
 
+impl Display for EntityClass {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            EntityClass::Entity => write!(f, "entity"),
+            EntityClass::Architecture => write!(f, "architecture"),
+            EntityClass::Configuration => write!(f, "configuration"),
+            EntityClass::Procedure => write!(f, "procedure"),
+            EntityClass::Function => write!(f, "function"),
+            EntityClass::Package => write!(f, "package"),
+            EntityClass::Type => write!(f, "type"),
+            EntityClass::Subtype => write!(f, "subtype"),
+            EntityClass::Constant => write!(f, "constant"),
+            EntityClass::Signal => write!(f, "signal"),
+            EntityClass::Variable => write!(f, "variable"),
+            EntityClass::Component => write!(f, "component"),
+            EntityClass::Label => write!(f, "label"),
+            EntityClass::Literal => write!(f, "literal"),
+            EntityClass::Units => write!(f, "units"),
+            EntityClass::Group => write!(f, "group"),
+            EntityClass::File => write!(f, "file"),
+            EntityClass::Property => write!(f, "property"),
+            EntityClass::Sequence => write!(f, "sequence"),
+            EntityClass::View => write!(f, "view"),
+        }
+    }
+}
+
+impl Display for EntityClassEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        if self.has_box {
+            write!(f, "{} <>", self.entity_class)
+        } else {
+            write!(f, "{}", self.entity_class)
+        }
+    }
+}
+
+impl Display for GroupTemplateDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "group {} is (", self.ident)?;
+        let mut first = true;
+        for entry in self.entity_classes.iter() {
+            if first {
+                write!(f, "{entry}")?;
+            } else {
+                write!(f, ", {entry}")?;
+            }
+            first = false;
+        }
+        write!(f, ");")
+    }
+}
+
+impl Display for GroupDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "group {} : {} (", self.ident, self.group_template_name)?;
+        let mut first = true;
+        for constituent in self.constituents.iter() {
+            if first {
+                write!(f, "{constituent}")?;
+            } else {
+                write!(f, ", {constituent}")?;
+            }
+            first = false;
+        }
+        write!(f, ");")
+    }
+}
+
 impl Display for Choice {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -428,6 +498,21 @@ impl Display for Expression {
             Expression::Name(ref name) => write!(f, "{name}"),
             Expression::Literal(ref literal) => write!(f, "{literal}"),
             Expression::New(ref alloc) => write!(f, "new {alloc}"),
+            Expression::Conditional(ref conds) => {
+                let mut first = true;
+                for cond in conds.conditionals.iter() {
+                    if first {
+                        write!(f, "{} when {}", cond.item, cond.condition)?;
+                    } else {
+                        write!(f, " else {} when {}", cond.item, cond.condition)?;
+                    }
+                    first = false;
+                }
+                if let Some(ref else_item) = conds.else_item {
+                    write!(f, " else {else_item}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -739,6 +824,15 @@ impl Display for ObjectClass {
     }
 }
 
+impl Display for SignalKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            SignalKind::Register => write!(f, "register"),
+            SignalKind::Bus => write!(f, "bus"),
+        }
+    }
+}
+
 impl Display for ObjectDeclaration {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(
@@ -746,6 +840,9 @@ impl Display for ObjectDeclaration {
             "{} {} : {}",
             self.class, self.ident, self.subtype_indication,
         )?;
+        if let Some(ref signal_kind) = self.signal_kind {
+            write!(f, " {signal_kind}")?;
+        }
         match self.expression {
             Some(ref expr) => write!(f, " := {expr};"),
             None => write!(f, ";"),
@@ -775,9 +872,46 @@ impl Display for SubprogramDesignator {
     }
 }
 
+impl Display for SubprogramHeader {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, " generic (")?;
+        let mut first = true;
+        for generic in &self.generic_list {
+            if first {
+                write!(f, "\n  {generic}")?;
+            } else {
+                write!(f, ";\n  {generic}")?;
+            }
+            first = false;
+        }
+        write!(f, "\n)")?;
+        if let Some(assoc_list) = &self.generic_map {
+            let mut first = true;
+            for assoc in assoc_list {
+                if first {
+                    write!(f, " generic map (\n  {assoc}")?;
+                } else {
+                    write!(f, ",\n  {assoc}")?;
+                }
+                first = false;
+            }
+            if !first {
+                write!(f, "\n)")?;
+            }
+        }
+        Ok(())
+    }
+}
+
 impl Display for ProcedureSpecification {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         write!(f, "procedure {}", self.designator)?;
+        if let Some(header) = &self.header {
+            write!(f, "{header}")?;
+            if !self.parameter_list.is_empty() {
+                write!(f, " parameter ")?;
+            }
+        }
         let mut first = true;
         for param in &self.parameter_list {
             if first {
@@ -801,6 +935,12 @@ impl Display for FunctionSpecification {
             write!(f, "impure ")?;
         }
         write!(f, "function {}", self.designator)?;
+        if let Some(header) = &self.header {
+            write!(f, "{header}")?;
+            if !self.parameter_list.is_empty() {
+                write!(f, " parameter ")?;
+            }
+        }
         let mut first = true;
         for param in &self.parameter_list {
             if first {
@@ -873,21 +1013,29 @@ impl Display for InterfaceObjectDeclaration {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self.list_type {
             InterfaceType::Port => {
-                write!(
-                    f,
-                    "{} : {} {}",
-                    self.ident, self.mode, self.subtype_indication
-                )?;
+                write!(f, "{} : ", self.ident)?;
             }
             InterfaceType::Generic => {
-                write!(f, "{} : {}", self.ident, self.subtype_indication)?;
+                write!(f, "{} :", self.ident)?;
             }
             InterfaceType::Parameter => {
-                write!(
-                    f,
-                    "{} {} : {} {}",
-                    self.class, self.ident, self.mode, self.subtype_indication,
-                )?;
+                write!(f, "{} {} : ", self.class, self.ident)?;
+            }
+        }
+        if let Some(ref view) = self.view {
+            write!(f, "{view}")?;
+            if let Some(ref subtype_indication) = self.subtype_indication {
+                write!(f, " of {subtype_indication}")?;
+            }
+        } else {
+            if self.list_type != InterfaceType::Generic {
+                write!(f, "{}", self.mode)?;
+            }
+            if let Some(ref subtype_indication) = self.subtype_indication {
+                write!(f, " {subtype_indication}")?;
+            }
+            if let Some(ref signal_kind) = self.signal_kind {
+                write!(f, " {signal_kind}")?;
             }
         }
         match self.expression {
@@ -897,6 +1045,41 @@ impl Display for InterfaceObjectDeclaration {
     }
 }
 
+impl Display for ModeViewIndication {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.kind {
+            ModeViewIndicationKind::Record => write!(f, "view {}", self.name),
+            ModeViewIndicationKind::Array => write!(f, "view ({})", self.name),
+        }
+    }
+}
+
+impl Display for ElementMode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self {
+            ElementMode::Simple(mode) => write!(f, "{mode}"),
+            ElementMode::View(view) => write!(f, "{view}"),
+        }
+    }
+}
+
+impl Display for ModeViewDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        write!(f, "view {} of {} is", self.ident, self.typ)?;
+        for element in &self.elements {
+            write!(f, "\n  ")?;
+            for (i, name) in element.names.iter().enumerate() {
+                if i > 0 {
+                    write!(f, ", ")?;
+                }
+                write!(f, "{name}")?;
+            }
+            write!(f, " : {};", element.mode)?;
+        }
+        write!(f, "\nend view;")
+    }
+}
+
 impl Display for SubprogramDefault {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         match self {
@@ -958,6 +1141,7 @@ impl Display for Mode {
             Mode::InOut => write!(f, "inout"),
             Mode::Buffer => write!(f, "buffer"),
             Mode::Linkage => write!(f, "linkage"),
+            Mode::View => write!(f, "view"),
         }
     }
 }
@@ -1036,6 +1220,34 @@ impl Display for PackageInstantiation {
     }
 }
 
+impl Display for SubprogramInstantiation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        match self.kind {
+            SubprogramKind::Function => write!(f, "function ")?,
+            SubprogramKind::Procedure => write!(f, "procedure ")?,
+        }
+        write!(f, "{} is new {}", self.ident, self.subprogram_name)?;
+        if let Some(signature) = &self.signature {
+            write!(f, " {signature}")?;
+        }
+        if let Some(assoc_list) = &self.generic_map {
+            let mut first = true;
+            for assoc in assoc_list {
+                if first {
+                    write!(f, "\n  generic map (\n    {assoc}")?;
+                } else {
+                    write!(f, ",\n    {assoc}")?;
+                }
+                first = false;
+            }
+            if !first {
+                write!(f, "\n  )")?;
+            }
+        }
+        write!(f, ";")
+    }
+}
+
 impl Display for ConfigurationDeclaration {
     fn fmt(&self, f: &mut Formatter<'_>) -> Result {
         // Not used: context_clause, decl, block_config, vunit_bind_inds
@@ -1108,9 +1320,52 @@ impl Display for PackageDeclaration {
     }
 }
 
+impl Display for PslPropertyDeclaration {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        // Not used: body
+        match self.kind {
+            PslPropertyKind::Property => write!(f, "property {}", self.ident)?,
+            PslPropertyKind::Sequence => write!(f, "sequence {}", self.ident)?,
+        }
+        let mut first = true;
+        for parameter in self.parameters.iter() {
+            if first {
+                write!(f, "({parameter}")?;
+            } else {
+                write!(f, ", {parameter}")?;
+            }
+            first = false;
+        }
+        if !first {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl Display for VerificationUnit {
+    fn fmt(&self, f: &mut Formatter<'_>) -> Result {
+        // Not used: context_clause, inherits, decl, statements
+        match self.kind {
+            VerificationUnitKind::Vunit => write!(f, "vunit {}", self.ident)?,
+            VerificationUnitKind::Vmode => write!(f, "vmode {}", self.ident)?,
+            VerificationUnitKind::Vprop => write!(f, "vprop {}", self.ident)?,
+        }
+        if let Some(binding) = &self.binding {
+            write!(f, "({}", binding.entity_name)?;
+            if let Some(architecture_name) = &binding.architecture_name {
+                write!(f, "({architecture_name})")?;
+            }
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::standard::VHDLStandard;
     use crate::syntax::test::Code;
     use assert_matches::assert_matches;
 
@@ -1384,9 +1639,9 @@ mod tests {
         assert_format("3ub\"0110\"", Code::expr);
         assert_format("10uo\"1377\"", Code::expr);
         assert_format("15ux\"5FaA\"", Code::expr);
-        assert_format("3sb\"0110\"", Code::expr);
-        assert_format("10so\"1377\"", Code::expr);
-        assert_format("15sx\"5FaA\"", Code::expr);
+        assert_format("3sb\"1110\"", Code::expr);
+        assert_format("10so\"7377\"", Code::expr);
+        assert_format("15sx\"DFaA\"", Code::expr);
         assert_format("12d\"1234\"", Code::expr);
     }
 
@@ -1735,6 +1990,15 @@ end units;",
         assert_format("shared variable foo : natural;", Code::object_decl);
     }
 
+    #[test]
+    fn test_object_declaration_signal_kind() {
+        assert_format("signal foo : resolved_bit bus;", Code::object_decl);
+        assert_format(
+            "signal foo : resolved_bit register := '0';",
+            Code::object_decl,
+        );
+    }
+
     #[test]
     fn test_object_declaration_optional_expression() {
         assert_format("constant foo : natural := 0;", Code::object_decl);
@@ -1829,6 +2093,18 @@ end units;",
         );
     }
 
+    #[test]
+    pub fn test_generic_function_specification() {
+        assert_format(
+            "function foo generic (
+  type T
+) parameter (
+  constant foo : in T
+) return T",
+            Code::subprogram_decl,
+        );
+    }
+
     #[test]
     pub fn test_interface_declaration_object() {
         assert_format("signal foo : in std_logic", Code::parameter);
@@ -1861,6 +2137,23 @@ end units;",
         assert_format("foo : in natural := bar(0)", Code::port);
     }
 
+    #[test]
+    fn test_interface_declaration_object_mode_view() {
+        for code in [
+            "foo : view master",
+            "foo : view master'converse of rec_t",
+            "foo : view (master) of rec_vec_t",
+        ] {
+            assert_eq!(
+                format!(
+                    "{}",
+                    Code::with_standard(code, VHDLStandard::VHDL2019).port()
+                ),
+                code
+            );
+        }
+    }
+
     #[test]
     fn test_interface_declaration_file() {
         assert_format("file foo : text", Code::parameter);
@@ -2093,6 +2386,26 @@ end context;",
         );
     }
 
+    #[test]
+    fn test_group_template_declaration() {
+        assert_format("group path is (signal, label <>);", |code| {
+            assert_matches!(
+                code.declarative_part().remove(0),
+                Declaration::GroupTemplate(template) => template
+            )
+        });
+    }
+
+    #[test]
+    fn test_group_declaration() {
+        assert_format("group g : pkg.path (sig, 'a', lbl);", |code| {
+            assert_matches!(
+                code.declarative_part().remove(0),
+                Declaration::Group(group) => group
+            )
+        });
+    }
+
     #[test]
     fn test_configuration_declaration() {
         assert_format_eq(
@@ -63,10 +63,17 @@ pub enum FoundDeclaration<'a> {
     Package(&'a mut PackageDeclaration),
     PackageBody(&'a mut PackageBody),
     PackageInstance(&'a mut PackageInstantiation),
+    SubprogramInstance(&'a mut SubprogramInstantiation),
+    View(&'a mut ModeViewDeclaration),
+    PslProperty(&'a mut PslPropertyDeclaration),
+    PslParameter(&'a mut WithDecl<Ident>),
+    GroupTemplate(&'a mut GroupTemplateDeclaration),
+    Group(&'a mut GroupDeclaration),
     Configuration(&'a mut ConfigurationDeclaration),
     Entity(&'a mut EntityDeclaration),
     Architecture(&'a mut ArchitectureBody),
     Context(&'a mut ContextDeclaration),
+    VerificationUnit(&'a mut VerificationUnit),
     ForIndex(&'a mut WithDecl<Ident>, &'a mut DiscreteRange),
     ForGenerateIndex(Option<&'a Ident>, &'a mut ForGenerateStatement),
     GenerateBody(&'a mut WithDecl<Ident>),
@@ -275,8 +282,12 @@ impl Search for LabeledSequentialStatement {
         }
         match self.statement.item {
             SequentialStatement::Return(ref mut ret) => {
-                let ReturnStatement { ref mut expression } = ret;
+                let ReturnStatement {
+                    ref mut expression,
+                    ref mut condition,
+                } = ret;
                 return_if_found!(expression.search(searcher));
+                return_if_found!(condition.search(searcher));
             }
             SequentialStatement::ProcedureCall(ref mut pcall) => {
                 return_if_finished!(searcher.search_with_pos(&pcall.pos));
@@ -337,6 +348,15 @@ impl Search for LabeledSequentialStatement {
             SequentialStatement::Case(ref mut case_stmt) => {
                 return_if_found!(case_stmt.search(searcher));
             }
+            SequentialStatement::Block(ref mut block) => {
+                let SequentialBlock {
+                    decl,
+                    statements,
+                    end_label_pos: _,
+                } = block;
+                return_if_found!(decl.search(searcher));
+                return_if_found!(statements.search(searcher));
+            }
             SequentialStatement::Loop(ref mut loop_stmt) => {
                 let LoopStatement {
                     iteration_scheme,
@@ -474,7 +494,7 @@ impl Search for LabeledConcurrentStatement {
         }
         match self.statement.item {
             ConcurrentStatement::Block(ref mut block) => {
-                // @TODO guard condition
+                return_if_found!(block.guard_condition.search(searcher));
                 return_if_found!(block.decl.search(searcher));
                 return_if_found!(block.statements.search(searcher));
             }
@@ -541,6 +561,31 @@ impl Search for LabeledConcurrentStatement {
                 return_if_found!(report.search(searcher));
                 return_if_found!(severity.search(searcher));
             }
+            ConcurrentStatement::Psl(ref mut directive) => {
+                let PslDirective {
+                    kind: _,
+                    property,
+                    report,
+                    severity,
+                } = directive;
+                return_if_found!(property.search(searcher));
+                return_if_found!(report.search(searcher));
+                return_if_found!(severity.search(searcher));
+            }
+            ConcurrentStatement::PslProperty(ref mut property) => {
+                return_if_found!(searcher
+                    .search_decl(FoundDeclaration::PslProperty(property))
+                    .or_not_found());
+                for parameter in property.parameters.iter_mut() {
+                    return_if_found!(searcher
+                        .search_decl(FoundDeclaration::PslParameter(parameter))
+                        .or_not_found());
+                }
+                return_if_found!(property.body.search(searcher));
+            }
+            ConcurrentStatement::PslDefaultClock(ref mut clock) => {
+                return_if_found!(clock.search(searcher));
+            }
         };
 
         if let Some(end_label_pos) = self.statement.item.end_label_pos() {
@@ -562,6 +607,55 @@ impl Search for WithPos<WithRef<Designator>> {
     }
 }
 
+impl Search for WithPos<PslExpression> {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        match self.item {
+            PslExpression::Hdl(ref mut expr) => expr.search(searcher),
+            PslExpression::Unary(ref mut op, ref mut operand) => {
+                match op.item {
+                    PslUnaryOperator::Next { ref mut count, .. } => {
+                        return_if_found!(count.search(searcher));
+                    }
+                    PslUnaryOperator::NextEvent {
+                        ref mut event,
+                        ref mut count,
+                        ..
+                    } => {
+                        return_if_found!(event.search(searcher));
+                        return_if_found!(count.search(searcher));
+                    }
+                    PslUnaryOperator::Always
+                    | PslUnaryOperator::Never
+                    | PslUnaryOperator::Eventually
+                    | PslUnaryOperator::Not => {}
+                }
+                operand.search(searcher)
+            }
+            PslExpression::Binary(_, ref mut lhs, ref mut rhs) => {
+                return_if_found!(lhs.search(searcher));
+                rhs.search(searcher)
+            }
+            PslExpression::Sere(ref mut sere) => sere.search(searcher),
+            PslExpression::Repetition(ref mut operand, _, ref mut count) => {
+                if let Some(ref mut operand) = operand {
+                    return_if_found!(operand.search(searcher));
+                }
+                count.search(searcher)
+            }
+        }
+    }
+}
+
+impl Search for PslCount {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        return_if_found!(self.low.search(searcher));
+        if let Some(PslCountBound::Expression(ref mut high)) = self.high {
+            return_if_found!(high.search(searcher));
+        }
+        NotFound
+    }
+}
+
 impl Search for WithPos<SelectedName> {
     fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
         return_if_finished!(searcher.search_with_pos(&self.pos));
@@ -612,7 +706,13 @@ fn search_pos_name(
             NotFound
         }
         Name::External(ref mut ename) => {
-            let ExternalName { subtype, .. } = ename.as_mut();
+            let ExternalName { path, subtype, .. } = ename.as_mut();
+            let path_name = match path.item {
+                ExternalPath::Package(ref mut name)
+                | ExternalPath::Absolute(ref mut name)
+                | ExternalPath::Relative(ref mut name, _) => name,
+            };
+            return_if_found!(path_name.search(searcher));
             return_if_found!(subtype.search(searcher));
             NotFound
         }
@@ -746,6 +846,9 @@ impl Search for TypeDeclaration {
                         ProtectedTypeDeclarativeItem::Subprogram(ref mut subprogram) => {
                             return_if_found!(subprogram.search(searcher));
                         }
+                        ProtectedTypeDeclarativeItem::PrivateVariable(ref mut object_decl) => {
+                            return_if_found!(object_decl.search(searcher));
+                        }
                     }
                 }
             }
@@ -843,6 +946,7 @@ fn search_pos_expr(
         Expression::Name(ref mut name) => search_pos_name(pos, name, searcher),
         Expression::Aggregate(ref mut assocs) => assocs.search(searcher),
         Expression::Qualified(ref mut qexpr) => qexpr.search(searcher),
+        Expression::Conditional(ref mut conds) => search_conditionals(conds, true, searcher),
         Expression::New(ref mut alloc) => {
             return_if_finished!(searcher.search_with_pos(&alloc.pos));
             match alloc.item {
@@ -1063,8 +1167,90 @@ impl Search for Declaration {
                 return_if_found!(package_instance.search(searcher));
             }
 
-            Declaration::Configuration(_) => {
-                // @TODO
+            Declaration::SubprogramInstantiation(ref mut subprogram_instance) => {
+                return_if_found!(searcher
+                    .search_decl(FoundDeclaration::SubprogramInstance(subprogram_instance))
+                    .or_not_found());
+                let SubprogramInstantiation {
+                    kind: _,
+                    ident: _,
+                    subprogram_name,
+                    signature,
+                    generic_map,
+                } = subprogram_instance;
+                return_if_found!(subprogram_name.search(searcher));
+                if let Some(signature) = signature {
+                    return_if_found!(signature.item.search(searcher));
+                }
+                return_if_found!(generic_map.search(searcher));
+            }
+
+            Declaration::View(ref mut view) => {
+                return_if_found!(searcher
+                    .search_decl(FoundDeclaration::View(view))
+                    .or_not_found());
+                return_if_found!(view.typ.search(searcher));
+                for element in view.elements.iter_mut() {
+                    for name in element.names.iter_mut() {
+                        return_if_found!(searcher.search_ident_ref(name).or_not_found());
+                    }
+                    if let ElementMode::View(ref mut view) = element.mode {
+                        return_if_found!(view.name.search(searcher));
+                    }
+                }
+            }
+
+            Declaration::PslProperty(ref mut property) => {
+                return_if_found!(searcher
+                    .search_decl(FoundDeclaration::PslProperty(property))
+                    .or_not_found());
+                for parameter in property.parameters.iter_mut() {
+                    return_if_found!(searcher
+                        .search_decl(FoundDeclaration::PslParameter(parameter))
+                        .or_not_found());
+                }
+                return_if_found!(property.body.search(searcher));
+            }
+
+            Declaration::PslDefaultClock(ref mut clock) => {
+                return_if_found!(clock.search(searcher));
+            }
+
+            Declaration::GroupTemplate(ref mut template) => {
+                return_if_found!(searcher
+                    .search_decl(FoundDeclaration::GroupTemplate(template))
+                    .or_not_found());
+            }
+
+            Declaration::Group(ref mut group) => {
+                return_if_found!(searcher
+                    .search_decl(FoundDeclaration::Group(group))
+                    .or_not_found());
+                return_if_found!(group.group_template_name.search(searcher));
+                return_if_found!(group.constituents.search(searcher));
+            }
+
+            Declaration::Configuration(ref mut configuration) => {
+                let ConfigurationSpecification {
+                    spec,
+                    bind_ind,
+                    vunit_bind_inds: _,
+                } = configuration;
+                return_if_found!(spec.search(searcher));
+                return_if_found!(bind_ind.search(searcher));
+            }
+
+            Declaration::Disconnection(ref mut disconnection) => {
+                let DisconnectionSpecification {
+                    signal_list,
+                    type_mark,
+                    after,
+                } = disconnection;
+                if let GuardedSignalList::Names(ref mut names) = signal_list {
+                    return_if_found!(names.search(searcher));
+                }
+                return_if_found!(type_mark.search(searcher));
+                return_if_found!(after.search(searcher));
             }
         }
         NotFound
@@ -1078,6 +1264,9 @@ impl Search for InterfaceDeclaration {
                 return_if_found!(searcher
                     .search_decl(FoundDeclaration::InterfaceObject(decl))
                     .or_not_found());
+                if let Some(ref mut view) = decl.view {
+                    return_if_found!(view.name.search(searcher));
+                }
                 return_if_found!(decl.subtype_indication.search(searcher));
                 return_if_found!(decl.expression.search(searcher));
             }
@@ -1151,6 +1340,7 @@ impl Search for ProcedureSpecification {
         return_if_found!(searcher
             .search_decl(FoundDeclaration::Procedure(self))
             .or_not_found());
+        return_if_found!(self.header.search(searcher));
         self.parameter_list.search(searcher)
     }
 }
@@ -1160,11 +1350,19 @@ impl Search for FunctionSpecification {
         return_if_found!(searcher
             .search_decl(FoundDeclaration::Function(self))
             .or_not_found());
+        return_if_found!(self.header.search(searcher));
         return_if_found!(self.parameter_list.search(searcher));
         self.return_type.search(searcher)
     }
 }
 
+impl Search for SubprogramHeader {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        return_if_found!(self.generic_list.search(searcher));
+        self.generic_map.search(searcher)
+    }
+}
+
 impl Search for LibraryClause {
     fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
         for name in self.name_list.iter_mut() {
@@ -1283,7 +1481,106 @@ impl Search for ConfigurationDeclaration {
         return_if_found!(searcher
             .search_decl(FoundDeclaration::Configuration(self))
             .or_not_found());
-        self.entity_name.search(searcher)
+        return_if_found!(self.entity_name.search(searcher));
+        for item in self.decl.iter_mut() {
+            match item {
+                ConfigurationDeclarativeItem::Use(ref mut use_clause) => {
+                    return_if_found!(searcher
+                        .search_with_pos(&use_clause.pos)
+                        .or_else(|| use_clause.item.name_list.search(searcher)));
+                }
+            }
+        }
+        self.block_config.search(searcher)
+    }
+}
+
+impl Search for BlockConfiguration {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        return_if_found!(self.block_spec.search(searcher));
+        for use_clause in self.use_clauses.iter_mut() {
+            return_if_found!(use_clause.name_list.search(searcher));
+        }
+        self.items.search(searcher)
+    }
+}
+
+impl Search for ConfigurationItem {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        match self {
+            ConfigurationItem::Block(ref mut block_config) => block_config.search(searcher),
+            ConfigurationItem::Component(ref mut component_config) => {
+                let ComponentConfiguration {
+                    spec,
+                    bind_ind,
+                    vunit_bind_inds: _,
+                    block_config,
+                } = component_config;
+                return_if_found!(spec.search(searcher));
+                return_if_found!(bind_ind.search(searcher));
+                block_config.search(searcher)
+            }
+        }
+    }
+}
+
+impl Search for ComponentSpecification {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        if let InstantiationList::Labels(ref mut labels) = self.instantiation_list {
+            for label in labels.iter_mut() {
+                return_if_found!(searcher
+                    .search_pos_with_ref(&label.item.pos, &mut label.reference)
+                    .or_not_found());
+            }
+        }
+        self.component_name.search(searcher)
+    }
+}
+
+impl Search for BindingIndication {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        match self.entity_aspect {
+            Some(EntityAspect::Entity(ref mut entity_name, ref mut architecture_name)) => {
+                return_if_found!(entity_name.search(searcher));
+                if let Some(ref mut architecture_name) = architecture_name {
+                    return_if_found!(searcher
+                        .search_pos_with_ref(
+                            &architecture_name.item.pos,
+                            &mut architecture_name.reference
+                        )
+                        .or_not_found());
+                }
+            }
+            Some(EntityAspect::Configuration(ref mut config_name)) => {
+                return_if_found!(config_name.search(searcher));
+            }
+            Some(EntityAspect::Open) | None => {}
+        }
+        return_if_found!(self.generic_map.search(searcher));
+        self.port_map.search(searcher)
+    }
+}
+
+impl Search for VerificationUnit {
+    fn search(&mut self, searcher: &mut impl Searcher) -> SearchResult {
+        return_if_finished!(searcher.search_source(self.source()));
+        return_if_found!(self.context_clause.search(searcher));
+        return_if_found!(searcher
+            .search_decl(FoundDeclaration::VerificationUnit(self))
+            .or_not_found());
+        if let Some(ref mut binding) = self.binding {
+            return_if_found!(searcher
+                .search_ident_ref(&mut binding.entity_name)
+                .or_not_found());
+            if let Some(ref mut architecture_name) = binding.architecture_name {
+                return_if_found!(searcher.search_ident_ref(architecture_name).or_not_found());
+            }
+        }
+        for inherit in self.inherits.iter_mut() {
+            return_if_found!(searcher.search_ident_ref(inherit).or_not_found());
+        }
+        return_if_found!(self.decl.search(searcher));
+        self.statements.search(searcher)
     }
 }
 
@@ -1606,10 +1903,17 @@ impl<'a> FoundDeclaration<'a> {
             FoundDeclaration::Package(value) => value.end_ident_pos.as_ref(),
             FoundDeclaration::PackageBody(value) => value.end_ident_pos.as_ref(),
             FoundDeclaration::PackageInstance(..) => None,
+            FoundDeclaration::SubprogramInstance(..) => None,
+            FoundDeclaration::View(value) => value.end_ident_pos.as_ref(),
+            FoundDeclaration::PslProperty(..) => None,
+            FoundDeclaration::PslParameter(..) => None,
+            FoundDeclaration::GroupTemplate(..) => None,
+            FoundDeclaration::Group(..) => None,
             FoundDeclaration::Configuration(value) => value.end_ident_pos.as_ref(),
             FoundDeclaration::Entity(value) => value.end_ident_pos.as_ref(),
             FoundDeclaration::Architecture(value) => value.end_ident_pos.as_ref(),
             FoundDeclaration::Context(value) => value.end_ident_pos.as_ref(),
+            FoundDeclaration::VerificationUnit(..) => None,
             FoundDeclaration::GenerateBody(..) => None,
             FoundDeclaration::ConcurrentStatement(..) => None,
             FoundDeclaration::SequentialStatement(..) => None,
@@ -1641,10 +1945,17 @@ impl<'a> HasEntityId for FoundDeclaration<'a> {
             FoundDeclaration::Package(value) => value.ident.decl,
             FoundDeclaration::PackageBody(value) => value.ident.decl,
             FoundDeclaration::PackageInstance(value) => value.ident.decl,
+            FoundDeclaration::SubprogramInstance(value) => value.ident.decl,
+            FoundDeclaration::View(value) => value.ident.decl,
+            FoundDeclaration::PslProperty(value) => value.ident.decl,
+            FoundDeclaration::PslParameter(value) => value.decl,
+            FoundDeclaration::GroupTemplate(value) => value.ident.decl,
+            FoundDeclaration::Group(value) => value.ident.decl,
             FoundDeclaration::Configuration(value) => value.ident.decl,
             FoundDeclaration::Entity(value) => value.ident.decl,
             FoundDeclaration::Architecture(value) => value.ident.decl,
             FoundDeclaration::Context(value) => value.ident.decl,
+            FoundDeclaration::VerificationUnit(value) => value.ident.decl,
             FoundDeclaration::GenerateBody(value) => value.decl,
             FoundDeclaration::ConcurrentStatement(_, value) => **value,
             FoundDeclaration::SequentialStatement(_, value) => **value,
@@ -1676,10 +1987,17 @@ impl<'a> HasSrcPos for FoundDeclaration<'a> {
             FoundDeclaration::Package(value) => value.ident.pos(),
             FoundDeclaration::PackageBody(value) => value.ident.pos(),
             FoundDeclaration::PackageInstance(value) => value.ident.pos(),
+            FoundDeclaration::SubprogramInstance(value) => value.ident.pos(),
+            FoundDeclaration::View(value) => value.ident.pos(),
+            FoundDeclaration::PslProperty(value) => value.ident.pos(),
+            FoundDeclaration::PslParameter(value) => value.pos(),
+            FoundDeclaration::GroupTemplate(value) => value.ident.pos(),
+            FoundDeclaration::Group(value) => value.ident.pos(),
             FoundDeclaration::Configuration(value) => value.ident.pos(),
             FoundDeclaration::Entity(value) => value.ident.pos(),
             FoundDeclaration::Architecture(value) => value.ident.pos(),
             FoundDeclaration::Context(value) => value.ident.pos(),
+            FoundDeclaration::VerificationUnit(value) => value.ident.pos(),
             FoundDeclaration::GenerateBody(value) => value.pos(),
             FoundDeclaration::ConcurrentStatement(value, _) => value.pos(),
             FoundDeclaration::SequentialStatement(value, _) => value.pos(),
@@ -1757,6 +2075,24 @@ impl std::fmt::Display for FoundDeclaration<'_> {
             FoundDeclaration::PackageInstance(ref value) => {
                 write!(f, "{value}")
             }
+            FoundDeclaration::SubprogramInstance(ref value) => {
+                write!(f, "{value}")
+            }
+            FoundDeclaration::View(ref value) => {
+                write!(f, "{value}")
+            }
+            FoundDeclaration::PslProperty(ref value) => {
+                write!(f, "{value}")
+            }
+            FoundDeclaration::PslParameter(ref value) => {
+                write!(f, "{value}")
+            }
+            FoundDeclaration::GroupTemplate(ref value) => {
+                write!(f, "{value}")
+            }
+            FoundDeclaration::Group(ref value) => {
+                write!(f, "{value}")
+            }
             FoundDeclaration::Configuration(ref value) => {
                 write!(f, "{value}")
             }
@@ -1769,6 +2105,9 @@ impl std::fmt::Display for FoundDeclaration<'_> {
             FoundDeclaration::Context(ref value) => {
                 write!(f, "{value}")
             }
+            FoundDeclaration::VerificationUnit(ref value) => {
+                write!(f, "{value}")
+            }
             FoundDeclaration::GenerateBody(value) => {
                 write!(f, "{value}")
             }
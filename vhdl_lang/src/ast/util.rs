@@ -36,6 +36,16 @@ impl WithPos<SelectedName> {
     }
 }
 
+impl SelectedName {
+    // Get the reference of the suffix once the name has been resolved
+    pub fn get_suffix_reference(&self) -> Option<EntityId> {
+        match self {
+            SelectedName::Designator(suffix) => suffix.reference,
+            SelectedName::Selected(_, suffix) => suffix.item.reference,
+        }
+    }
+}
+
 impl WithPos<Name> {
     pub fn suffix_pos(&self) -> &SrcPos {
         match self.item {
@@ -178,6 +188,12 @@ impl HasIdent for PackageInstantiation {
     }
 }
 
+impl HasIdent for SubprogramInstantiation {
+    fn ident(&self) -> &Ident {
+        self.ident.ident()
+    }
+}
+
 impl HasIdent for ContextDeclaration {
     fn ident(&self) -> &Ident {
         self.ident.ident()
@@ -190,6 +206,12 @@ impl HasIdent for ConfigurationDeclaration {
     }
 }
 
+impl HasIdent for VerificationUnit {
+    fn ident(&self) -> &Ident {
+        self.ident.ident()
+    }
+}
+
 impl HasIdent for AnyPrimaryUnit {
     fn ident(&self) -> &Ident {
         match self {
@@ -198,6 +220,7 @@ impl HasIdent for AnyPrimaryUnit {
             AnyPrimaryUnit::Package(ref unit) => unit.ident(),
             AnyPrimaryUnit::PackageInstance(ref unit) => unit.ident(),
             AnyPrimaryUnit::Context(ref unit) => unit.ident(),
+            AnyPrimaryUnit::VerificationUnit(ref unit) => unit.ident(),
         }
     }
 }
@@ -468,6 +491,86 @@ impl DiscreteRange {
     }
 }
 
+impl BaseSpecifier {
+    /// The number of bits each extended digit expands to, None for decimal
+    pub fn bits_per_digit(&self) -> Option<u32> {
+        match self {
+            BaseSpecifier::B | BaseSpecifier::UB | BaseSpecifier::SB => Some(1),
+            BaseSpecifier::O | BaseSpecifier::UO | BaseSpecifier::SO => Some(3),
+            BaseSpecifier::X | BaseSpecifier::UX | BaseSpecifier::SX => Some(4),
+            BaseSpecifier::D => None,
+        }
+    }
+
+    pub fn is_signed(&self) -> bool {
+        matches!(
+            self,
+            BaseSpecifier::SB | BaseSpecifier::SO | BaseSpecifier::SX
+        )
+    }
+}
+
+impl BitString {
+    /// LRM 15.8 The bit string value with underscores removed and each extended digit
+    /// expanded to its bits, before any explicit length is applied
+    pub fn expanded_value(&self) -> Vec<u8> {
+        let digits = self.value.bytes.iter().copied().filter(|chr| *chr != b'_');
+
+        if let Some(bits) = self.base.bits_per_digit() {
+            let mut result = Vec::new();
+            for chr in digits {
+                if let Some(value) = (chr as char).to_digit(16) {
+                    for bit in (0..bits).rev() {
+                        result.push(if (value >> bit) & 1 == 1 { b'1' } else { b'0' });
+                    }
+                } else {
+                    // Other graphic characters are replicated
+                    result.resize(result.len() + bits as usize, chr);
+                }
+            }
+            result
+        } else {
+            let mut decimal: Vec<u32> = digits
+                .filter_map(|chr| (chr as char).to_digit(10))
+                .skip_while(|digit| *digit == 0)
+                .collect();
+
+            if decimal.is_empty() {
+                return if self.value.bytes.is_empty() {
+                    Vec::new()
+                } else {
+                    vec![b'0']
+                };
+            }
+
+            // Repeated division by two yields the bits from least significant
+            let mut result = Vec::new();
+            while !decimal.is_empty() {
+                let mut remainder = 0;
+                for digit in decimal.iter_mut() {
+                    let value = remainder * 10 + *digit;
+                    *digit = value / 2;
+                    remainder = value % 2;
+                }
+                result.push(if remainder == 1 { b'1' } else { b'0' });
+                let leading_zeros = decimal.iter().take_while(|digit| **digit == 0).count();
+                decimal.drain(..leading_zeros);
+            }
+            result.reverse();
+            result
+        }
+    }
+
+    /// The number of elements of the bit string literal value
+    pub fn expanded_length(&self) -> u64 {
+        if let Some(length) = self.length {
+            u64::from(length)
+        } else {
+            self.expanded_value().len() as u64
+        }
+    }
+}
+
 impl SubprogramDeclaration {
     pub fn subpgm_designator(&self) -> &WithPos<SubprogramDesignator> {
         match self {
@@ -475,6 +578,20 @@ impl SubprogramDeclaration {
             SubprogramDeclaration::Function(s) => &s.designator.tree,
         }
     }
+
+    pub fn header(&self) -> Option<&SubprogramHeader> {
+        match self {
+            SubprogramDeclaration::Procedure(s) => s.header.as_ref(),
+            SubprogramDeclaration::Function(s) => s.header.as_ref(),
+        }
+    }
+
+    pub fn header_mut(&mut self) -> Option<&mut SubprogramHeader> {
+        match self {
+            SubprogramDeclaration::Procedure(s) => s.header.as_mut(),
+            SubprogramDeclaration::Function(s) => s.header.as_mut(),
+        }
+    }
 }
 
 impl SubprogramDeclaration {
@@ -495,6 +612,7 @@ impl ConcurrentStatement {
             Process(_) => Some(Concurrent::Process),
             Assert(_) => None,
             Assignment(_) => None,
+            Psl(_) | PslProperty(_) | PslDefaultClock(_) => None,
             Instance(_) => Some(Concurrent::Instance),
             ForGenerate(_) | IfGenerate(_) | CaseGenerate(_) => Some(Concurrent::Generate),
         }
@@ -509,6 +627,7 @@ impl ConcurrentStatement {
             Process(value) => value.end_label_pos.as_ref(),
             Assert(_) => None,
             Assignment(_) => None,
+            Psl(_) | PslProperty(_) | PslDefaultClock(_) => None,
             Instance(_) => None,
             ForGenerate(value) => value.end_label_pos.as_ref(),
             IfGenerate(value) => value.end_label_pos.as_ref(),
@@ -521,6 +640,20 @@ impl ConcurrentStatement {
     }
 }
 
+impl ReturnStatement {
+    /// Returns false when a VHDL-2019 condition may let control continue after the statement
+    pub fn is_unconditional(&self) -> bool {
+        let has_missing_else = matches!(
+            self.expression,
+            Some(WithPos {
+                item: Expression::Conditional(ref conds),
+                ..
+            }) if conds.else_item.is_none()
+        );
+        self.condition.is_none() && !has_missing_else
+    }
+}
+
 impl SequentialStatement {
     pub fn label_typ(&self) -> Option<Sequential> {
         use SequentialStatement::*;
@@ -536,6 +669,7 @@ impl SequentialStatement {
             If(_) => Some(Sequential::If),
             Case(_) => Some(Sequential::Case),
             Loop(_) => Some(Sequential::Loop),
+            Block(_) => Some(Sequential::Block),
             Next(_) => None,
             Exit(_) => None,
             Return(_) => None,
@@ -557,6 +691,7 @@ impl SequentialStatement {
             If(value) => value.end_label_pos.as_ref(),
             Case(value) => value.end_label_pos.as_ref(),
             Loop(value) => value.end_label_pos.as_ref(),
+            Block(value) => value.end_label_pos.as_ref(),
             Next(_) => None,
             Exit(_) => None,
             Return(_) => None,
@@ -7,6 +7,7 @@
 //! Configuration of the design hierarchy and other settings
 
 use crate::data::*;
+use crate::standard::VHDLStandard;
 use fnv::FnvHashMap;
 use std::env;
 use std::fs::File;
@@ -19,12 +20,94 @@ use toml::Value;
 pub struct Config {
     // A map from library name to file name
     libraries: FnvHashMap<String, LibraryConfig>,
+    lints: LintConfig,
+    // Conditional analysis identifiers of all libraries
+    conditions: FnvHashMap<String, String>,
+    // The language standard of libraries that do not set their own
+    standard: Option<VHDLStandard>,
+    // Lint names that are not known, these are ignored with a warning
+    unknown_lints: Vec<String>,
+}
+
+/// Lints that are not enabled by default
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Lint {
+    /// Variables that are read before they are assigned and assignments that are never read
+    UnassignedVariables,
+    /// Declarations that hide a declaration of an enclosing region or a use clause
+    Shadowing,
+    /// Vector assignments where the statically known widths of target and expression differ
+    WidthMismatch,
+    /// Signals of combinational processes that are not assigned on every path through the process
+    InferredLatches,
+    /// Functions that can reach the end of their body without a return statement
+    MissingReturn,
+    /// Statements that follow a return, exit or next statement
+    UnreachableCode,
+    /// Processes without a sensitivity list that do not contain a wait statement
+    MissingWait,
+}
+
+impl Lint {
+    /// The name of the lint in the configuration file
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lint::UnassignedVariables => "unassigned_variables",
+            Lint::Shadowing => "shadowing",
+            Lint::WidthMismatch => "width_mismatch",
+            Lint::InferredLatches => "inferred_latches",
+            Lint::MissingReturn => "missing_return",
+            Lint::UnreachableCode => "unreachable_code",
+            Lint::MissingWait => "missing_wait",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Lint> {
+        [
+            Lint::UnassignedVariables,
+            Lint::Shadowing,
+            Lint::WidthMismatch,
+            Lint::InferredLatches,
+            Lint::MissingReturn,
+            Lint::UnreachableCode,
+            Lint::MissingWait,
+        ]
+        .into_iter()
+        .find(|lint| lint.name() == name)
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, Default, Debug)]
+pub struct LintConfig {
+    // Lints that have been explicitly enabled or disabled
+    lints: FnvHashMap<Lint, bool>,
+}
+
+impl LintConfig {
+    pub fn is_enabled(&self, lint: Lint) -> bool {
+        self.lints.get(&lint).copied().unwrap_or(false)
+    }
+
+    pub fn set_enabled(&mut self, lint: Lint, enabled: bool) {
+        self.lints.insert(lint, enabled);
+    }
+
+    /// Lints set by the appended config take precedence
+    fn append(&mut self, other: &LintConfig) {
+        self.lints.extend(other.lints.iter());
+    }
 }
 
 #[derive(Clone, PartialEq, Eq, Default, Debug)]
 pub struct LibraryConfig {
     name: String,
     patterns: Vec<String>,
+    // Conditional analysis identifiers of this library
+    conditions: FnvHashMap<String, String>,
+    // The language standard of this library
+    standard: Option<VHDLStandard>,
+    // Lints are never reported for the design units of this library
+    is_third_party: bool,
 }
 
 impl LibraryConfig {
@@ -122,6 +205,44 @@ impl LibraryConfig {
     pub fn name(&self) -> &str {
         self.name.as_str()
     }
+
+    /// Conditional analysis identifiers that are only defined for this library
+    pub fn conditions(&self) -> &FnvHashMap<String, String> {
+        &self.conditions
+    }
+
+    /// The language standard of this library if it overrides the global standard
+    pub fn standard(&self) -> Option<VHDLStandard> {
+        self.standard
+    }
+
+    /// Whether this library contains third-party code that lints are not reported for
+    pub fn is_third_party(&self) -> bool {
+        self.is_third_party
+    }
+}
+
+/// Parse a table of conditional analysis identifiers and their string values
+fn parse_conditions(value: &Value) -> Result<FnvHashMap<String, String>, String> {
+    let table = value.as_table().ok_or("conditions must be a table")?;
+
+    let mut conditions = FnvHashMap::default();
+    for (name, value) in table.iter() {
+        let value = value
+            .as_str()
+            .ok_or_else(|| format!("condition {name} must be a string"))?;
+        conditions.insert(name.to_owned(), value.to_owned());
+    }
+    Ok(conditions)
+}
+
+fn parse_standard(value: &Value) -> Result<VHDLStandard, String> {
+    value
+        .as_str()
+        .and_then(VHDLStandard::from_name)
+        .ok_or_else(|| {
+            "standard must be one of \"1993\", \"2002\", \"2008\" or \"2019\"".to_owned()
+        })
 }
 
 impl Config {
@@ -156,16 +277,66 @@ impl Config {
                 patterns.push(path);
             }
 
+            let conditions = if let Some(conditions) = lib.get("conditions") {
+                parse_conditions(conditions)?
+            } else {
+                FnvHashMap::default()
+            };
+
+            let standard = lib.get("standard").map(parse_standard).transpose()?;
+
+            let is_third_party = if let Some(is_third_party) = lib.get("is_third_party") {
+                is_third_party
+                    .as_bool()
+                    .ok_or_else(|| format!("is_third_party for library {name} must be a boolean"))?
+            } else {
+                false
+            };
+
             libraries.insert(
                 name.to_owned(),
                 LibraryConfig {
                     name: name.to_owned(),
                     patterns,
+                    conditions,
+                    standard,
+                    is_third_party,
                 },
             );
         }
 
-        Ok(Config { libraries })
+        let mut lints = LintConfig::default();
+        let mut unknown_lints = Vec::new();
+        if let Some(lint_table) = config.get("lint") {
+            let lint_table = lint_table.as_table().ok_or("lint must be a table")?;
+
+            for (name, enabled) in lint_table.iter() {
+                let enabled = enabled
+                    .as_bool()
+                    .ok_or_else(|| format!("lint {name} must be a boolean"))?;
+                if let Some(lint) = Lint::from_name(name) {
+                    lints.set_enabled(lint, enabled);
+                } else {
+                    unknown_lints.push(name.to_owned());
+                }
+            }
+        }
+
+        let conditions = if let Some(conditions) = config.get("conditions") {
+            parse_conditions(conditions)?
+        } else {
+            FnvHashMap::default()
+        };
+
+        let standard = config.get("standard").map(parse_standard).transpose()?;
+
+        Ok(Config {
+            libraries,
+            lints,
+            conditions,
+            standard,
+            unknown_lints,
+        })
     }
 
     pub fn read_file_path(file_name: &Path) -> io::Result<Config> {
@@ -186,28 +357,57 @@ impl Config {
         self.libraries.values()
     }
 
+    pub fn lints(&self) -> &LintConfig {
+        &self.lints
+    }
+
+    /// Conditional analysis identifiers that are defined for all libraries
+    pub fn conditions(&self) -> &FnvHashMap<String, String> {
+        &self.conditions
+    }
+
+    /// The language standard of libraries that do not set their own
+    pub fn standard(&self) -> VHDLStandard {
+        self.standard.unwrap_or_default()
+    }
+
+    /// The language standard of a library
+    pub fn library_standard(&self, library: &LibraryConfig) -> VHDLStandard {
+        library.standard().unwrap_or_else(|| self.standard())
+    }
+
     /// Append another config to self
     ///
     /// In case of conflict the appended config takes precedence
     pub fn append(&mut self, config: &Config, messages: &mut dyn MessageHandler) {
         for library in config.iter_libraries() {
             if let Some(parent_library) = self.libraries.get_mut(&library.name) {
+                // A re-defined third-party library such as ieee remains third-party
+                let is_third_party = parent_library.is_third_party || library.is_third_party;
                 *parent_library = library.clone();
+                parent_library.is_third_party = is_third_party;
 
                 messages.push(Message::warning(format!(
                     "Re-defined library {}",
                     &library.name
                 )));
             } else {
-                self.libraries.insert(
-                    library.name.clone(),
-                    LibraryConfig {
-                        name: library.name.clone(),
-                        patterns: library.patterns.clone(),
-                    },
-                );
+                self.libraries.insert(library.name.clone(), library.clone());
             }
         }
+        self.lints.append(&config.lints);
+        for name in config.unknown_lints.iter() {
+            messages.push(Message::warning(format!("Unknown lint {name}")));
+        }
+        self.conditions.extend(
+            config
+                .conditions
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone())),
+        );
+        if config.standard.is_some() {
+            self.standard = config.standard;
+        }
     }
 
     /// Load configuration file from installation folder
@@ -437,6 +637,243 @@ lib3.files = [
         assert_eq!(merged_config, expected_config);
     }
 
+    #[test]
+    fn config_lints() {
+        let parent = Path::new("parent_folder");
+        let config0 = Config::from_str(
+            "
+[libraries]
+
+[lint]
+unassigned_variables = true
+shadowing = true
+width_mismatch = true
+inferred_latches = true
+missing_return = true
+unreachable_code = true
+missing_wait = true
+",
+            parent,
+        )
+        .unwrap();
+        assert!(config0.lints().is_enabled(Lint::UnassignedVariables));
+        assert!(config0.lints().is_enabled(Lint::Shadowing));
+        assert!(config0.lints().is_enabled(Lint::WidthMismatch));
+        assert!(config0.lints().is_enabled(Lint::InferredLatches));
+        assert!(config0.lints().is_enabled(Lint::MissingReturn));
+        assert!(config0.lints().is_enabled(Lint::UnreachableCode));
+        assert!(config0.lints().is_enabled(Lint::MissingWait));
+
+        let config1 = Config::from_str(
+            "
+[libraries]
+
+[lint]
+unassigned_variables = false
+",
+            parent,
+        )
+        .unwrap();
+
+        let mut merged_config = config0;
+        merged_config.append(&config1, &mut Vec::new());
+        assert!(!merged_config.lints().is_enabled(Lint::UnassignedVariables));
+        assert!(merged_config.lints().is_enabled(Lint::Shadowing));
+        assert!(!Config::default()
+            .lints()
+            .is_enabled(Lint::UnassignedVariables));
+
+        let config2 = Config::from_str(
+            "
+[libraries]
+
+[lint]
+missing = true
+shadowing = false
+",
+            parent,
+        )
+        .unwrap();
+        let mut messages = Vec::new();
+        merged_config.append(&config2, &mut messages);
+        assert_eq!(messages, vec![Message::warning("Unknown lint missing")]);
+        assert!(!merged_config.lints().is_enabled(Lint::Shadowing));
+    }
+
+    #[test]
+    fn config_conditions() {
+        let parent = Path::new("parent_folder");
+        let config0 = Config::from_str(
+            "
+[conditions]
+TOOL_TYPE = \"SIMULATION\"
+TOOL_VENDOR = \"vendor\"
+
+[libraries]
+lib1.files = []
+lib1.conditions = { TOOL_EDITION = \"pro\" }
+lib2.files = []
+",
+            parent,
+        )
+        .unwrap();
+        assert_eq!(
+            config0.conditions().get("TOOL_TYPE").map(String::as_str),
+            Some("SIMULATION")
+        );
+        assert_eq!(
+            config0
+                .get_library("lib1")
+                .unwrap()
+                .conditions()
+                .get("TOOL_EDITION")
+                .map(String::as_str),
+            Some("pro")
+        );
+        assert!(config0.get_library("lib2").unwrap().conditions().is_empty());
+
+        let config1 = Config::from_str(
+            "
+[conditions]
+TOOL_TYPE = \"SYNTHESIS\"
+
+[libraries]
+",
+            parent,
+        )
+        .unwrap();
+
+        let mut merged_config = config0;
+        merged_config.append(&config1, &mut Vec::new());
+        assert_eq!(
+            merged_config
+                .conditions()
+                .get("TOOL_TYPE")
+                .map(String::as_str),
+            Some("SYNTHESIS")
+        );
+        assert_eq!(
+            merged_config
+                .conditions()
+                .get("TOOL_VENDOR")
+                .map(String::as_str),
+            Some("vendor")
+        );
+
+        assert_eq!(
+            Config::from_str(
+                "
+[libraries]
+
+[conditions]
+TOOL_TYPE = 1
+",
+                parent,
+            ),
+            Err("condition TOOL_TYPE must be a string".to_owned())
+        );
+    }
+
+    #[test]
+    fn config_standard() {
+        let parent = Path::new("parent_folder");
+        let config0 = Config::from_str(
+            "
+standard = \"1993\"
+
+[libraries]
+lib1.files = []
+lib2.files = []
+lib2.standard = \"2019\"
+",
+            parent,
+        )
+        .unwrap();
+        assert_eq!(config0.standard(), VHDLStandard::VHDL1993);
+        assert_eq!(
+            config0.library_standard(config0.get_library("lib1").unwrap()),
+            VHDLStandard::VHDL1993
+        );
+        assert_eq!(
+            config0.library_standard(config0.get_library("lib2").unwrap()),
+            VHDLStandard::VHDL2019
+        );
+
+        let config1 = Config::from_str(
+            "
+standard = \"2002\"
+
+[libraries]
+",
+            parent,
+        )
+        .unwrap();
+
+        let mut merged_config = config0;
+        merged_config.append(&config1, &mut Vec::new());
+        assert_eq!(merged_config.standard(), VHDLStandard::VHDL2002);
+
+        let mut merged_config = config1;
+        merged_config.append(&Config::default(), &mut Vec::new());
+        assert_eq!(merged_config.standard(), VHDLStandard::VHDL2002);
+
+        assert_eq!(Config::default().standard(), VHDLStandard::VHDL2008);
+
+        assert_eq!(
+            Config::from_str(
+                "
+[libraries]
+lib.files = []
+lib.standard = \"2000\"
+",
+                parent,
+            ),
+            Err("standard must be one of \"1993\", \"2002\", \"2008\" or \"2019\"".to_owned())
+        );
+    }
+
+    #[test]
+    fn config_third_party() {
+        let parent = Path::new("parent_folder");
+        let config0 = Config::from_str(
+            "
+[libraries]
+lib1.files = []
+lib2.files = []
+lib2.is_third_party = true
+",
+            parent,
+        )
+        .unwrap();
+        assert!(!config0.get_library("lib1").unwrap().is_third_party());
+        assert!(config0.get_library("lib2").unwrap().is_third_party());
+
+        // A re-defined third-party library remains third-party
+        let config1 = Config::from_str(
+            "
+[libraries]
+lib2.files = []
+",
+            parent,
+        )
+        .unwrap();
+        let mut merged_config = config0;
+        merged_config.append(&config1, &mut Vec::new());
+        assert!(merged_config.get_library("lib2").unwrap().is_third_party());
+
+        assert_eq!(
+            Config::from_str(
+                "
+[libraries]
+lib.files = []
+lib.is_third_party = \"yes\"
+",
+                parent,
+            ),
+            Err("is_third_party for library lib must be a boolean".to_owned())
+        );
+    }
+
     #[test]
     fn test_warning_on_missing_file() {
         let parent = Path::new("parent_folder");
@@ -15,9 +15,10 @@ mod analysis;
 mod config;
 mod data;
 mod project;
+mod standard;
 mod syntax;
 
-pub use crate::config::Config;
+pub use crate::config::{Config, Lint, LintConfig};
 pub use crate::data::{
     Diagnostic, Latin1String, Message, MessageHandler, MessagePrinter, MessageType,
     NullDiagnostics, NullMessages, Position, Range, Severity, Source, SrcPos,
@@ -25,7 +26,8 @@ pub use crate::data::{
 
 pub use crate::analysis::{
     AnyEnt, AnyEntKind, Concurrent, Design, EntHierarchy, EntRef, EntityId, Object, Overloaded,
-    Type,
+    Psl, Type,
 };
 pub use crate::project::{Project, SourceFile};
+pub use crate::standard::VHDLStandard;
 pub use crate::syntax::{ParserResult, VHDLParser};
@@ -7,7 +7,8 @@
 use crate::analysis::{AnyEnt, DesignRoot, EntRef};
 use crate::ast::DesignFile;
 use crate::config::Config;
-use crate::syntax::VHDLParser;
+use crate::standard::VHDLStandard;
+use crate::syntax::{Conditions, VHDLParser};
 use crate::{data::*, EntHierarchy};
 use fnv::{FnvHashMap, FnvHashSet};
 use std::collections::hash_map::Entry;
@@ -18,6 +19,10 @@ pub struct Project {
     root: DesignRoot,
     files: FnvHashMap<PathBuf, SourceFile>,
     empty_libraries: FnvHashSet<Symbol>,
+    // Conditional analysis identifiers of files that are not mapped to a library
+    default_conditions: Conditions,
+    // Conditional analysis identifiers per library
+    library_conditions: FnvHashMap<Symbol, Conditions>,
 }
 
 impl Project {
@@ -27,6 +32,8 @@ impl Project {
             root: DesignRoot::new(parser.symbols.clone()),
             files: FnvHashMap::default(),
             empty_libraries: FnvHashSet::default(),
+            default_conditions: Conditions::default(),
+            library_conditions: FnvHashMap::default(),
             parser,
         }
     }
@@ -35,6 +42,7 @@ impl Project {
     /// Files referred by configuration are parsed into corresponding libraries.
     pub fn from_config(config: &Config, messages: &mut dyn MessageHandler) -> Project {
         let mut project = Project::new();
+        project.root.set_lint_config(config.lints().clone());
 
         let files = project.load_files_from_config(config, messages);
         project.parse_and_add_files(files, messages);
@@ -48,6 +56,7 @@ impl Project {
     pub fn update_config(&mut self, config: &Config, messages: &mut dyn MessageHandler) {
         self.parser = VHDLParser::default();
         self.root = DesignRoot::new(self.parser.symbols.clone());
+        self.root.set_lint_config(config.lints().clone());
 
         // Reset library associations for known files,
         // all project files are added to the corresponding libraries later on.
@@ -63,12 +72,18 @@ impl Project {
             .partition(|(file_name, _library_names)| self.files.contains_key(file_name));
 
         for (file_name, library_names) in known_files {
+            let conditions = self.conditions_of(&library_names).clone();
+            let standard = self.standard_of(&library_names);
             if let Some(source_file) = self.files.get_mut(&file_name) {
                 source_file.parser_diagnostics.clear();
                 source_file.library_names = library_names;
-                source_file.design_file = self
-                    .parser
-                    .parse_design_source(&source_file.source, &mut source_file.parser_diagnostics);
+                (source_file.design_file, source_file.inactive_regions) =
+                    self.parser.parse_design_source(
+                        &source_file.source,
+                        standard,
+                        &conditions,
+                        &mut source_file.parser_diagnostics,
+                    );
             }
         }
 
@@ -82,11 +97,32 @@ impl Project {
     ) -> FnvHashMap<PathBuf, FnvHashSet<Symbol>> {
         let mut files: FnvHashMap<PathBuf, FnvHashSet<Symbol>> = FnvHashMap::default();
         self.empty_libraries.clear();
+        self.library_conditions.clear();
+
+        self.default_conditions = Conditions::default();
+        for (name, value) in config.conditions() {
+            self.default_conditions.set(name, value);
+        }
+
+        self.root.set_standard(config.standard());
 
         for library in config.iter_libraries() {
             let library_name =
                 Latin1String::from_utf8(library.name()).expect("Library name not latin-1 encoded");
             let library_name = self.parser.symbol(&library_name);
+            self.root
+                .set_library_standard(library_name.clone(), config.library_standard(library));
+            if library.is_third_party() {
+                self.root.set_third_party_library(library_name.clone());
+            }
+
+            // Library conditions take precedence over global conditions
+            let mut conditions = self.default_conditions.clone();
+            for (name, value) in library.conditions() {
+                conditions.set(name, value);
+            }
+            self.library_conditions
+                .insert(library_name.clone(), conditions);
 
             let mut empty_library = true;
             for file_name in library.file_names(messages) {
@@ -111,6 +147,29 @@ impl Project {
         files
     }
 
+    /// The library whose settings are used to parse a file
+    /// Files that are mapped to several libraries use the first library by name
+    fn parse_library_of(library_names: &FnvHashSet<Symbol>) -> Option<&Symbol> {
+        library_names
+            .iter()
+            .min_by_key(|library_name| library_name.name_utf8())
+    }
+
+    /// The conditional analysis identifiers used to parse a file
+    fn conditions_of(&self, library_names: &FnvHashSet<Symbol>) -> &Conditions {
+        Self::parse_library_of(library_names)
+            .and_then(|library_name| self.library_conditions.get(library_name))
+            .unwrap_or(&self.default_conditions)
+    }
+
+    /// The language standard used to parse a file
+    fn standard_of(&self, library_names: &FnvHashSet<Symbol>) -> VHDLStandard {
+        match Self::parse_library_of(library_names) {
+            Some(library_name) => self.root.standard_of(library_name),
+            None => self.root.standard(),
+        }
+    }
+
     fn parse_and_add_files(
         &mut self,
         files_to_parse: FnvHashMap<PathBuf, FnvHashSet<Symbol>>,
@@ -121,17 +180,22 @@ impl Project {
         let parsed: Vec<_> = files_to_parse
             .into_par_iter()
             .map_init(
-                || &self.parser,
-                |parser, (file_name, library_names)| {
+                || &*self,
+                |project, (file_name, library_names)| {
                     let mut diagnostics = Vec::new();
-                    let result = parser.parse_design_file(&file_name, &mut diagnostics);
+                    let result = project.parser.parse_design_file(
+                        &file_name,
+                        project.standard_of(&library_names),
+                        project.conditions_of(&library_names),
+                        &mut diagnostics,
+                    );
                     (file_name, library_names, diagnostics, result)
                 },
             )
             .collect();
 
         for (file_name, library_names, parser_diagnostics, result) in parsed.into_iter() {
-            let (source, design_file) = match result {
+            let (source, design_file, inactive_regions) = match result {
                 Ok(result) => result,
                 Err(err) => {
                     messages.push(Message::file_error(err.to_string(), &file_name));
@@ -146,6 +210,7 @@ impl Project {
                     library_names,
                     parser_diagnostics,
                     design_file,
+                    inactive_regions,
                 },
             );
         }
@@ -189,13 +254,17 @@ impl Project {
                     library_names,
                     parser_diagnostics: vec![],
                     design_file: DesignFile::default(),
+                    inactive_regions: vec![],
                 }
             }
         };
         source_file.parser_diagnostics.clear();
-        source_file.design_file = self
-            .parser
-            .parse_design_source(source, &mut source_file.parser_diagnostics);
+        (source_file.design_file, source_file.inactive_regions) = self.parser.parse_design_source(
+            source,
+            self.standard_of(&source_file.library_names),
+            self.conditions_of(&source_file.library_names),
+            &mut source_file.parser_diagnostics,
+        );
         self.files
             .insert(source.file_name().to_owned(), source_file);
     }
@@ -292,6 +361,14 @@ impl Project {
     pub fn files(&self) -> impl Iterator<Item = &SourceFile> {
         self.files.values()
     }
+
+    /// Source ranges that were skipped by conditional analysis
+    pub fn inactive_regions(&self, source: &Source) -> &[SrcPos] {
+        self.files
+            .get(source.file_name())
+            .map(|file| file.inactive_regions.as_slice())
+            .unwrap_or(&[])
+    }
 }
 
 /// Multiply clonable value by cloning
@@ -322,6 +399,7 @@ pub struct SourceFile {
     source: Source,
     design_file: DesignFile,
     parser_diagnostics: Vec<Diagnostic>,
+    inactive_regions: Vec<SrcPos>,
 }
 
 impl SourceFile {
@@ -405,6 +483,122 @@ end architecture;
         assert_eq!(diag.message, "Duplicate architecture 'rtl' of entity 'ent'")
     }
 
+    #[test]
+    fn unmapped_files_are_parsed_with_the_configured_standard() {
+        let root = tempfile::tempdir().unwrap();
+        let config_str = "
+standard = '2019'
+
+[libraries]
+        ";
+        let config = Config::from_str(config_str, root.path()).unwrap();
+        let mut messages = Vec::new();
+        let mut project = Project::from_config(&config, &mut messages);
+        assert_eq!(messages, vec![]);
+
+        let vhdl_file_path = root.path().join("file.vhd");
+        std::fs::write(
+            &vhdl_file_path,
+            "
+entity ent is
+end entity;
+
+architecture rtl of ent is
+  component comp is
+  end comp;
+begin
+end architecture;
+",
+        )
+        .unwrap();
+        let source = Source::from_latin1_file(&vhdl_file_path).unwrap();
+
+        project.update_source(&source);
+        check_no_diagnostics(&project.analyse());
+    }
+
+    #[test]
+    fn lints_are_not_reported_for_the_bundled_libraries() {
+        let root = tempfile::tempdir().unwrap();
+        let mut config = Config::read_file_path(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../vhdl_libraries/vhdl_ls.toml"),
+        )
+        .unwrap();
+        let lint_config = Config::from_str(
+            "
+[lint]
+unassigned_variables = true
+shadowing = true
+width_mismatch = true
+inferred_latches = true
+missing_return = true
+unreachable_code = true
+missing_wait = true
+
+[libraries]
+        ",
+            root.path(),
+        )
+        .unwrap();
+        let mut messages = Vec::new();
+        config.append(&lint_config, &mut messages);
+        let mut project = Project::from_config(&config, &mut messages);
+        assert_eq!(messages, vec![]);
+        check_no_diagnostics(&project.analyse());
+    }
+
+    /// The bundled std and ieee libraries only exist in their VHDL-2008 revision and are
+    /// analyzed as such whatever the standard of the other libraries
+    #[test]
+    fn bundled_libraries_are_vhdl_2008_for_any_standard() {
+        let root = tempfile::tempdir().unwrap();
+        let vhdl_file_path = root.path().join("legacy.vhd");
+        std::fs::write(
+            &vhdl_file_path,
+            "
+library ieee;
+use ieee.std_logic_1164.all;
+use ieee.numeric_std.all;
+
+entity legacy is
+  port (
+    force : in std_logic_vector(3 downto 0);
+    q : out unsigned(3 downto 0));
+end entity;
+
+architecture rtl of legacy is
+begin
+  q <= unsigned(force) + 1;
+end architecture;
+        ",
+        )
+        .unwrap();
+
+        let mut config = Config::read_file_path(
+            &Path::new(env!("CARGO_MANIFEST_DIR")).join("../vhdl_libraries/vhdl_ls.toml"),
+        )
+        .unwrap();
+        let legacy_config = Config::from_str(
+            "
+standard = \"1993\"
+
+[libraries]
+legacy.files = ['legacy.vhd']
+        ",
+            root.path(),
+        )
+        .unwrap();
+        let mut messages = Vec::new();
+        config.append(&legacy_config, &mut messages);
+        assert_eq!(
+            config.library_standard(config.get_library("ieee").unwrap()),
+            VHDLStandard::VHDL2008
+        );
+        let mut project = Project::from_config(&config, &mut messages);
+        assert_eq!(messages, vec![]);
+        check_no_diagnostics(&project.analyse());
+    }
+
     /// Test that the same file can be added to several libraries
     #[test]
     fn test_same_file_in_multiple_libraries() {
@@ -0,0 +1,46 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use std::fmt;
+
+/// The revision of the VHDL language standard
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Default)]
+pub enum VHDLStandard {
+    VHDL1993,
+    VHDL2002,
+    #[default]
+    VHDL2008,
+    VHDL2019,
+}
+
+impl VHDLStandard {
+    /// The year of the revision as used by the VHDL_VERSION conditional analysis identifier
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VHDLStandard::VHDL1993 => "1993",
+            VHDLStandard::VHDL2002 => "2002",
+            VHDLStandard::VHDL2008 => "2008",
+            VHDLStandard::VHDL2019 => "2019",
+        }
+    }
+
+    /// Parse the year of the revision as written in the configuration file
+    pub fn from_name(name: &str) -> Option<VHDLStandard> {
+        match name {
+            "1993" => Some(VHDLStandard::VHDL1993),
+            "2002" => Some(VHDLStandard::VHDL2002),
+            "2008" => Some(VHDLStandard::VHDL2008),
+            "2019" => Some(VHDLStandard::VHDL2019),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for VHDLStandard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "VHDL-{}", self.as_str())
+    }
+}
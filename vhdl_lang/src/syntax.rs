@@ -16,20 +16,24 @@ mod configuration;
 mod context;
 mod declarative_part;
 mod design_unit;
+mod disconnection;
 mod expression;
+mod group;
 mod interface_declaration;
 mod names;
 mod object_declaration;
 mod parser;
+mod psl;
 mod range;
 mod sequential_statement;
 mod subprogram;
 mod subtype_indication;
 mod type_declaration;
+mod view_declaration;
 mod waveform;
 
 #[cfg(test)]
 pub mod test;
 
 pub use parser::{ParserResult, VHDLParser};
-pub use tokens::Symbols;
+pub use tokens::{Conditions, Symbols};
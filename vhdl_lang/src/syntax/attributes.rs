@@ -14,26 +14,34 @@ use crate::ast::{
     EntityTag, WithRef,
 };
 
-fn parse_entity_class(stream: &TokenStream) -> ParseResult<EntityClass> {
+pub fn parse_entity_class(stream: &TokenStream) -> ParseResult<EntityClass> {
     Ok(expect_token!(stream, token,
         Entity => EntityClass::Entity,
         Architecture => EntityClass::Architecture,
         Configuration => EntityClass::Configuration,
+        Procedure => EntityClass::Procedure,
+        Function => EntityClass::Function,
         Package => EntityClass::Package,
+        Type => EntityClass::Type,
+        Subtype => EntityClass::Subtype,
+        Constant => EntityClass::Constant,
         Signal => EntityClass::Signal,
         Variable => EntityClass::Variable,
-        Procedure => EntityClass::Procedure,
-        Function => EntityClass::Function,
         Component => EntityClass::Component,
-        Constant => EntityClass::Constant,
-        Type => EntityClass::Type,
-        Label => EntityClass::Label
+        Label => EntityClass::Label,
+        Literal => EntityClass::Literal,
+        Units => EntityClass::Units,
+        Group => EntityClass::Group,
+        File => EntityClass::File,
+        Property => EntityClass::Property,
+        Sequence => EntityClass::Sequence,
+        View => EntityClass::View
     ))
 }
 
 pub fn parse_entity_name_list(stream: &TokenStream) -> ParseResult<Vec<EntityName>> {
     Ok(expect_token!(stream, token,
-        Identifier | StringLiteral => {
+        Identifier | StringLiteral | Character => {
             let mut entity_name_list = Vec::new();
             let mut token = token;
             loop {
@@ -41,6 +49,7 @@ pub fn parse_entity_name_list(stream: &TokenStream) -> ParseResult<Vec<EntityNam
                 let designator = match token.kind {
                     Identifier => token.to_identifier_value()?.map_into(Designator::Identifier),
                     StringLiteral => token.to_operator_symbol()?.map_into(Designator::OperatorSymbol),
+                    Character => token.to_character_value()?.map_into(Designator::Character),
                     _ => unreachable!(""),
                 };
 
@@ -58,7 +67,7 @@ pub fn parse_entity_name_list(stream: &TokenStream) -> ParseResult<Vec<EntityNam
                 }));
 
                 if stream.skip_if_kind(Comma) {
-                    token = expect_token!(stream, token, Identifier | StringLiteral => token);
+                    token = expect_token!(stream, token, Identifier | StringLiteral | Character => token);
                 } else {
                     break entity_name_list;
                 }
@@ -158,6 +167,23 @@ mod tests {
         )
     }
 
+    #[test]
+    fn parse_attribute_specification_character_literal() {
+        let code = Code::new("attribute attr_name of 'a' : literal is 0+1;");
+        assert_eq!(
+            code.with_stream(parse_attribute),
+            vec![Attribute::Specification(AttributeSpecification {
+                ident: WithRef::new(code.s1("attr_name").ident()),
+                entity_name: EntityName::Name(EntityTag {
+                    designator: code.s1("'a'").ref_designator(),
+                    signature: None
+                }),
+                entity_class: EntityClass::Literal,
+                expr: code.s1("0+1").expr()
+            })]
+        )
+    }
+
     #[test]
     fn parse_attribute_specification_list() {
         let code = Code::new("attribute attr_name of foo, bar : signal is 0+1;");
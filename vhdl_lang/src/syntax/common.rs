@@ -9,6 +9,7 @@ use crate::ast::Ident;
 use crate::data::Diagnostic;
 use crate::data::DiagnosticHandler;
 use crate::data::WithPos;
+use crate::standard::VHDLStandard;
 use crate::SrcPos;
 
 /// Parse optional part followed by optional keyword
@@ -31,6 +32,35 @@ where
     Ok(optional)
 }
 
+/// Check that a construct is allowed by the language standard that is parsed
+pub fn check_standard(
+    stream: &TokenStream,
+    pos: &SrcPos,
+    required: VHDLStandard,
+    construct: &str,
+) -> ParseResult<()> {
+    if stream.standard() < required {
+        Err(Diagnostic::error(
+            pos,
+            format!("{construct} requires {required} or later"),
+        ))
+    } else {
+        Ok(())
+    }
+}
+
+/// Parse the reserved word that is repeated after end
+/// The repetition is optional in VHDL-2019
+/// Returns true if the reserved word was present
+pub fn parse_end_keyword(stream: &TokenStream, keyword: Kind) -> ParseResult<bool> {
+    if stream.standard() < VHDLStandard::VHDL2019 {
+        stream.expect_kind(keyword)?;
+        Ok(true)
+    } else {
+        Ok(stream.skip_if_kind(keyword))
+    }
+}
+
 pub fn check_end_identifier_mismatch<T: std::fmt::Display + std::cmp::PartialEq>(
     ident: &WithPos<T>,
     end_ident: Option<WithPos<T>>,
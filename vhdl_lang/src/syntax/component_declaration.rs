@@ -5,6 +5,7 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use super::common::check_end_identifier_mismatch;
+use super::common::parse_end_keyword;
 use super::common::ParseResult;
 use super::interface_declaration::{parse_generic_interface_list, parse_port_interface_list};
 use super::tokens::{Kind::*, TokenStream};
@@ -82,7 +83,7 @@ pub fn parse_component_declaration(
     let generic_list = parse_optional_generic_list(stream, diagnostics)?;
     let port_list = parse_optional_port_list(stream, diagnostics)?;
     stream.expect_kind(End)?;
-    stream.expect_kind(Component)?;
+    parse_end_keyword(stream, Component)?;
     let end_ident = stream.pop_optional_ident();
     stream.expect_kind(SemiColon)?;
 
@@ -99,6 +100,7 @@ mod tests {
     use super::*;
 
     use crate::ast::Ident;
+    use crate::standard::VHDLStandard;
     use crate::syntax::test::Code;
     use crate::SrcPos;
 
@@ -160,6 +162,27 @@ end component foo;
         );
     }
 
+    #[test]
+    fn test_component_end_without_component_vhdl2019() {
+        let code = Code::with_standard(
+            "\
+component foo is
+end foo;
+",
+            VHDLStandard::VHDL2019,
+        );
+        let component = code.with_stream_no_diagnostics(parse_component_declaration);
+        assert_eq!(
+            component,
+            to_component(
+                code.s1("foo").decl_ident(),
+                vec![],
+                vec![],
+                Some(code.s("foo", 2).pos())
+            )
+        );
+    }
+
     #[test]
     fn test_component_with_generic() {
         let code = Code::new(
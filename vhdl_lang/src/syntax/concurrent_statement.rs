@@ -5,7 +5,7 @@
 // Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 
 use super::common::check_label_identifier_mismatch;
-use super::common::ParseResult;
+use super::common::{check_standard, ParseResult};
 use super::declarative_part::{is_declarative_part, parse_declarative_part};
 use super::expression::parse_aggregate;
 use super::expression::{parse_choices, parse_expression};
@@ -14,6 +14,9 @@ use super::names::parse_name;
 use super::names::{
     expression_to_ident, into_selected_name, parse_association_list, parse_selected_name,
 };
+use super::psl::{
+    is_psl_assert, parse_psl_default_clock, parse_psl_directive, parse_psl_property_declaration,
+};
 use super::range::parse_discrete_range;
 use super::sequential_statement::{
     parse_assert_statement, parse_labeled_sequential_statements, parse_selection,
@@ -23,6 +26,7 @@ use super::tokens::{Kind::*, TokenStream};
 use super::waveform::{parse_delay_mechanism, parse_waveform};
 use crate::ast::*;
 use crate::data::*;
+use crate::standard::VHDLStandard;
 
 /// LRM 11.2 Block statement
 pub fn parse_block_statement(
@@ -166,6 +170,7 @@ pub fn parse_process_statement(
         peek_token!(stream, token,
         All => {
             stream.skip();
+            check_standard(stream, &token.pos, VHDLStandard::VHDL2008, "Sensitivity list all")?;
             stream.expect_kind(RightPar)?;
             Some(SensitivityList::All)
         },
@@ -257,8 +262,7 @@ fn parse_assignment_known_target(
 ) -> ParseResult<ConcurrentStatement> {
     // @TODO postponed
     let postponed = false;
-    // @TODO guarded
-    let guarded = false;
+    let guarded = stream.skip_if_kind(Guarded);
     let delay_mechanism = parse_delay_mechanism(stream)?;
     Ok(ConcurrentStatement::Assignment(
         ConcurrentSignalAssignment {
@@ -293,8 +297,7 @@ fn parse_selected_signal_assignment(
     stream.expect_kind(Select)?;
     let target = parse_target(stream)?;
     stream.expect_kind(LTE)?;
-    // @TODO guarded
-    let guarded = false;
+    let guarded = stream.skip_if_kind(Guarded);
     let delay_mechanism = parse_delay_mechanism(stream)?;
     let rhs = AssignmentRightHand::Selected(parse_selection(stream, expression, parse_waveform)?);
     Ok(ConcurrentSignalAssignment {
@@ -596,7 +599,20 @@ pub fn parse_concurrent_statement(
             For => ConcurrentStatement::ForGenerate(parse_for_generate_statement(stream, label, diagnostics)?),
             If => ConcurrentStatement::IfGenerate(parse_if_generate_statement(stream, label, diagnostics)?),
             Case => ConcurrentStatement::CaseGenerate(parse_case_generate_statement(stream, label, diagnostics)?),
-            Assert => ConcurrentStatement::Assert(parse_concurrent_assert_statement(stream, false)?),
+            Assert => {
+                if is_psl_assert(stream) {
+                    ConcurrentStatement::Psl(parse_psl_directive(stream, diagnostics)?)
+                } else {
+                    ConcurrentStatement::Assert(parse_concurrent_assert_statement(stream, false)?)
+                }
+            },
+            Assume | AssumeGuarantee | Restrict | RestrictGuarantee | Cover | Fairness | Strong => {
+                ConcurrentStatement::Psl(parse_psl_directive(stream, diagnostics)?)
+            },
+            Property | Sequence => {
+                ConcurrentStatement::PslProperty(parse_psl_property_declaration(stream)?)
+            },
+            Default => ConcurrentStatement::PslDefaultClock(parse_psl_default_clock(stream)?),
             Postponed => {
                 stream.skip();
                 let token = stream.peek_expect()?;
@@ -651,7 +667,21 @@ pub fn parse_labeled_concurrent_statements(
                 break Ok(statements);
             }
             _ => {
-                statements.push(parse_labeled_concurrent_statement(stream, diagnostics)?);
+                let psl_comment_region = stream.psl_comment_region(token).cloned();
+                match parse_labeled_concurrent_statement(stream, diagnostics) {
+                    Ok(statement) => statements.push(statement),
+                    Err(err) => {
+                        // A PSL statement embedded in comments is skipped until its semi colon
+                        let region = psl_comment_region.ok_or_else(|| err.clone())?;
+                        diagnostics.push(err);
+                        while let Some(token) = stream.peek() {
+                            if token.pos.start() >= region.end() {
+                                break;
+                            }
+                            stream.skip();
+                        }
+                    }
+                }
             }
         }
     }
@@ -1131,6 +1161,25 @@ end process;",
         );
     }
 
+    #[test]
+    fn test_process_sensitivity_list_all_requires_vhdl2008() {
+        let code = Code::with_standard(
+            "\
+process (all) is
+begin
+end process;",
+            VHDLStandard::VHDL1993,
+        );
+        let (result, _) = code.with_partial_stream_diagnostics(parse_labeled_concurrent_statement);
+        assert_eq!(
+            result,
+            Err(Diagnostic::error(
+                code.s1("all"),
+                "Sensitivity list all requires VHDL-2008 or later"
+            ))
+        );
+    }
+
     #[test]
     fn test_concurrent_assert() {
         let code = Code::new("assert cond = true;");
@@ -1187,6 +1236,24 @@ end process;",
         );
     }
 
+    #[test]
+    fn test_guarded_concurrent_signal_assignment() {
+        let code = Code::new("foo <= guarded transport bar after 1 ns;");
+        let assign = ConcurrentSignalAssignment {
+            postponed: false,
+            guarded: true,
+            target: code.s1("foo").name().map_into(Target::Name),
+            delay_mechanism: Some(DelayMechanism::Transport),
+            rhs: AssignmentRightHand::Simple(code.s1("bar after 1 ns").waveform()),
+        };
+        let stmt = code.with_stream_no_diagnostics(parse_labeled_concurrent_statement);
+        assert_eq!(stmt.label.tree, None);
+        assert_eq!(
+            stmt.statement,
+            WithPos::new(ConcurrentStatement::Assignment(assign), code.pos())
+        );
+    }
+
     #[test]
     fn test_concurrent_signal_assignment_external_name() {
         let code = Code::new("<< signal dut.foo : std_logic >> <= bar(2 to 3);");
@@ -1239,6 +1306,41 @@ with x(0) + 1 select
         assert_eq!(stmt.statement.pos, code.pos());
     }
 
+    #[test]
+    fn parse_guarded_selected_signal_assignment() {
+        let code = Code::new(
+            "\
+with sel select
+   foo <= guarded bar when '0', baz when others;",
+        );
+
+        let selection = Selection {
+            expression: code.s1("sel").expr(),
+            alternatives: vec![
+                Alternative {
+                    choices: code.s1("'0'").choices(),
+                    item: code.s1("bar").waveform(),
+                },
+                Alternative {
+                    choices: code.s1("others").choices(),
+                    item: code.s1("baz").waveform(),
+                },
+            ],
+        };
+
+        let stmt = code.with_stream_no_diagnostics(parse_labeled_concurrent_statement);
+        assert_eq!(
+            stmt.statement.item,
+            ConcurrentStatement::Assignment(ConcurrentSignalAssignment {
+                postponed: false,
+                guarded: true,
+                target: code.s1("foo").name().map_into(Target::Name),
+                delay_mechanism: None,
+                rhs: AssignmentRightHand::Selected(selection)
+            })
+        );
+    }
+
     #[test]
     fn test_component_instantiation() {
         let code = Code::new("inst: component lib.foo.bar;");
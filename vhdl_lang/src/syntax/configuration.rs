@@ -26,7 +26,7 @@ fn parse_entity_aspect(stream: &TokenStream) -> ParseResult<EntityAspect> {
                 if stream.skip_if_kind(LeftPar) {
                     let ident = stream.expect_ident()?;
                     stream.expect_kind(RightPar)?;
-                    Some(ident)
+                    Some(ident.into_ref())
                 } else {
                     None
                 }
@@ -148,15 +148,15 @@ fn parse_component_specification_or_name(
                     let ident = to_simple_name(name)?;
                     let component_name = parse_selected_name(stream)?;
                     Ok(ComponentSpecificationOrName::ComponentSpec(ComponentSpecification {
-                        instantiation_list: InstantiationList::Labels(vec![ident]),
+                        instantiation_list: InstantiationList::Labels(vec![ident.into_ref()]),
                         component_name,
                     }))
                 }
                 Comma => {
                     stream.skip();
-                    let mut idents = vec![to_simple_name(name)?];
+                    let mut idents = vec![to_simple_name(name)?.into_ref()];
                     loop {
-                        idents.push(stream.expect_ident()?);
+                        idents.push(stream.expect_ident()?.into_ref());
                         expect_token!(
                             stream,
                             next_token,
@@ -198,8 +198,10 @@ fn parse_block_configuration_known_name(
     diagnostics: &mut dyn DiagnosticHandler,
 ) -> ParseResult<BlockConfiguration> {
     let block_spec = name;
-    // @TODO use clauses
-    let use_clauses = Vec::new();
+    let mut use_clauses = Vec::new();
+    while stream.peek_kind() == Some(Use) {
+        use_clauses.push(parse_use_clause(stream)?.item);
+    }
     let mut items = Vec::new();
 
     loop {
@@ -500,6 +502,35 @@ end configuration cfg;
         );
     }
 
+    #[test]
+    fn configuration_block_configuration_use_clause() {
+        let code = Code::new(
+            "\
+configuration cfg of entity_name is
+  for rtl
+    use lib.foo.bar;
+  end for;
+end configuration cfg;
+",
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_configuration_declaration),
+            ConfigurationDeclaration {
+                context_clause: ContextClause::default(),
+                ident: code.s1("cfg").decl_ident(),
+                entity_name: code.s1("entity_name").selected_name(),
+                decl: vec![],
+                vunit_bind_inds: Vec::new(),
+                block_config: BlockConfiguration {
+                    block_spec: code.s1("rtl").name(),
+                    use_clauses: vec![code.s1("use lib.foo.bar;").use_clause().item],
+                    items: vec![],
+                },
+                end_ident_pos: Some(code.s("cfg", 2).pos())
+            }
+        );
+    }
+
     #[test]
     fn configuration_nested_block_configuration() {
         let code = Code::new(
@@ -572,7 +603,8 @@ end configuration cfg;
                         spec: ComponentSpecification {
                             instantiation_list: InstantiationList::Labels(vec![code
                                 .s1("inst")
-                                .ident()]),
+                                .ident()
+                                .into_ref()]),
                             component_name: code.s1("lib.pkg.comp").selected_name()
                         },
                         bind_ind: None,
@@ -620,7 +652,8 @@ end configuration cfg;
                         spec: ComponentSpecification {
                             instantiation_list: InstantiationList::Labels(vec![code
                                 .s1("inst")
-                                .ident()]),
+                                .ident()
+                                .into_ref()]),
                             component_name: code.s1("lib.pkg.comp").selected_name()
                         },
                         bind_ind: Some(BindingIndication {
@@ -674,7 +707,8 @@ end configuration cfg;
                         spec: ComponentSpecification {
                             instantiation_list: InstantiationList::Labels(vec![code
                                 .s1("inst")
-                                .ident()]),
+                                .ident()
+                                .into_ref()]),
                             component_name: code.s1("lib.pkg.comp").selected_name()
                         },
                         bind_ind: Some(BindingIndication {
@@ -728,7 +762,8 @@ end configuration cfg;
                             spec: ComponentSpecification {
                                 instantiation_list: InstantiationList::Labels(vec![code
                                     .s1("inst")
-                                    .ident()]),
+                                    .ident()
+                                    .into_ref()]),
                                 component_name: code.s1("lib.pkg.comp").selected_name()
                             },
                             bind_ind: None,
@@ -738,9 +773,9 @@ end configuration cfg;
                         ConfigurationItem::Component(ComponentConfiguration {
                             spec: ComponentSpecification {
                                 instantiation_list: InstantiationList::Labels(vec![
-                                    code.s1("inst1").ident(),
-                                    code.s1("inst2").ident(),
-                                    code.s1("inst3").ident()
+                                    code.s1("inst1").ident().into_ref(),
+                                    code.s1("inst2").ident().into_ref(),
+                                    code.s1("inst3").ident().into_ref()
                                 ]),
                                 component_name: code.s1("lib2.pkg.comp").selected_name()
                             },
@@ -789,7 +824,7 @@ end configuration cfg;
             code.with_stream(parse_entity_aspect),
             EntityAspect::Entity(
                 code.s1("lib.foo.name").selected_name(),
-                Some(code.s1("arch").ident())
+                Some(code.s1("arch").ident().into_ref())
             )
         );
     }
@@ -823,7 +858,7 @@ end configuration cfg;
                 bind_ind: BindingIndication {
                     entity_aspect: Some(EntityAspect::Entity(
                         code.s1("work.foo").selected_name(),
-                        Some(code.s1("rtl").ident())
+                        Some(code.s1("rtl").ident().into_ref())
                     )),
                     generic_map: None,
                     port_map: None
@@ -847,7 +882,7 @@ end configuration cfg;
                 bind_ind: BindingIndication {
                     entity_aspect: Some(EntityAspect::Entity(
                         code.s1("work.foo").selected_name(),
-                        Some(code.s1("rtl").ident())
+                        Some(code.s1("rtl").ident().into_ref())
                     )),
                     generic_map: None,
                     port_map: None
@@ -873,7 +908,7 @@ end configuration cfg;
                 bind_ind: BindingIndication {
                     entity_aspect: Some(EntityAspect::Entity(
                         code.s1("work.foo").selected_name(),
-                        Some(code.s1("rtl").ident())
+                        Some(code.s1("rtl").ident().into_ref())
                     )),
                     generic_map: None,
                     port_map: None
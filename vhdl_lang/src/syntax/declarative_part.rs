@@ -10,11 +10,15 @@ use super::common::ParseResult;
 use super::component_declaration::parse_component_declaration;
 use super::configuration::parse_configuration_specification;
 use super::context::parse_use_clause;
+use super::disconnection::parse_disconnection_specification;
+use super::group::parse_group;
 use super::names::{parse_association_list, parse_selected_name};
 use super::object_declaration::{parse_file_declaration, parse_object_declaration};
+use super::psl::{parse_psl_default_clock, parse_psl_property_declaration};
 use super::subprogram::parse_subprogram;
 use super::tokens::{Kind::*, *};
 use super::type_declaration::parse_type_declaration;
+use super::view_declaration::parse_mode_view_declaration;
 use crate::ast::{ContextClause, Declaration, PackageInstantiation};
 use crate::data::DiagnosticHandler;
 
@@ -47,10 +51,38 @@ pub fn is_declarative_part(stream: &TokenStream, begin_is_end: bool) -> ParseRes
     Ok(check_declarative_part(stream.peek_expect()?, !begin_is_end, begin_is_end).is_ok())
 }
 
+/// True if the token kind starts a declaration within a declarative part
+pub fn is_declaration_start(kind: Kind) -> bool {
+    matches!(
+        kind,
+        Use | Type
+            | Subtype
+            | Shared
+            | Constant
+            | Signal
+            | Variable
+            | File
+            | Component
+            | Attribute
+            | Alias
+            | Impure
+            | Pure
+            | Function
+            | Procedure
+            | Package
+            | For
+            | View
+            | Property
+            | Sequence
+            | Default
+            | Group
+            | Disconnect
+    )
+}
+
 fn check_declarative_part(token: &Token, may_end: bool, may_begin: bool) -> ParseResult<()> {
     match token.kind {
-        Use | Type | Subtype | Shared | Constant | Signal | Variable | File | Component
-        | Attribute | Alias | Impure | Pure | Function | Procedure | Package | For => Ok(()),
+        kind if is_declaration_start(kind) => Ok(()),
         Begin if may_begin => Ok(()),
         End if may_end => Ok(()),
         _ => {
@@ -64,9 +96,22 @@ fn check_declarative_part(token: &Token, may_end: bool, may_begin: bool) -> Pars
     }
 }
 
+fn is_begin_or_end(kind: Kind) -> bool {
+    matches!(kind, Begin | End)
+}
+
 pub fn parse_declarative_part(
     stream: &TokenStream,
     diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<Vec<Declaration>> {
+    parse_declarative_part_until(stream, diagnostics, is_begin_or_end)
+}
+
+/// Parse declarations until a token for which `is_end` is true
+pub fn parse_declarative_part_until(
+    stream: &TokenStream,
+    diagnostics: &mut dyn DiagnosticHandler,
+    is_end: fn(Kind) -> bool,
 ) -> ParseResult<Vec<Declaration>> {
     let mut declarations: Vec<Declaration> = Vec::new();
 
@@ -89,15 +134,25 @@ pub fn parse_declarative_part(
                 | Attribute
                 | Use
                 | Alias
+                | View
+                | Property
+                | Sequence
+                | Default
+                | Group
+                | Disconnect
                 | Begin
                 | End
+                | RightCurly
         )
     }
 
     while let Some(token) = stream.peek() {
+        if is_end(token.kind) {
+            break;
+        }
         match token.kind {
-            Begin | End => break,
-            Type | Subtype | Component | Impure | Pure | Function | Procedure | Package | For => {
+            Type | Subtype | Component | Impure | Pure | Function | Procedure | Package | For
+            | View => {
                 let decl = match token.kind {
                     Type | Subtype => {
                         parse_type_declaration(stream, diagnostics).map(Declaration::Type)?
@@ -109,6 +164,9 @@ pub fn parse_declarative_part(
                     For => {
                         parse_configuration_specification(stream).map(Declaration::Configuration)?
                     }
+                    View => {
+                        parse_mode_view_declaration(stream, diagnostics).map(Declaration::View)?
+                    }
                     _ => unreachable!(),
                 };
                 declarations.push(decl);
@@ -133,10 +191,18 @@ pub fn parse_declarative_part(
                 }
             }
 
-            Use | Alias => {
+            Use | Alias | Group | Disconnect | Property | Sequence | Default => {
                 let decl: ParseResult<Declaration> = match token.kind {
                     Use => parse_use_clause(stream).map(Declaration::Use),
                     Alias => parse_alias_declaration(stream).map(Declaration::Alias),
+                    Group => parse_group(stream),
+                    Disconnect => {
+                        parse_disconnection_specification(stream).map(Declaration::Disconnection)
+                    }
+                    Property | Sequence => {
+                        parse_psl_property_declaration(stream).map(Declaration::PslProperty)
+                    }
+                    Default => parse_psl_default_clock(stream).map(Declaration::PslDefaultClock),
                     _ => unreachable!(),
                 };
                 match decl.or_recover_until(stream, diagnostics, is_recover_token) {
@@ -228,6 +294,7 @@ constant x: natural := 5;
                 class: ObjectClass::Constant,
                 ident: code.s1("x").decl_ident(),
                 subtype_indication: code.s1("natural").subtype_indication(),
+                signal_kind: None,
                 expression: Some(code.s1("5").expr())
             })])
         );
@@ -16,6 +16,7 @@ use super::context::{
 };
 use super::declarative_part::{parse_declarative_part, parse_package_instantiation};
 use super::interface_declaration::parse_generic_interface_list;
+use super::psl::parse_verification_unit;
 use crate::ast::*;
 use crate::data::*;
 
@@ -227,6 +228,13 @@ pub fn parse_design_file(
                 }
                 Err(diagnostic) => diagnostics.push(diagnostic),
             },
+            Vunit | Vmode | Vprop => match parse_verification_unit(stream, diagnostics) {
+                Ok(mut unit) => {
+                    unit.context_clause = take_context_clause(&mut context_clause);
+                    design_units.push(AnyDesignUnit::Primary(AnyPrimaryUnit::VerificationUnit(unit)));
+                }
+                Err(diagnostic) => diagnostics.push(diagnostic),
+            },
             Package => {
                 if stream.next_kinds_are(&[Package, Body]) {
                     match parse_package_body(stream, diagnostics) {
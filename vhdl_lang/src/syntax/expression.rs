@@ -4,13 +4,14 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use super::common::ParseResult;
+use super::common::{check_standard, ParseResult};
 use super::names::{parse_name, parse_type_mark};
 use super::subtype_indication::parse_subtype_constraint;
 use super::tokens::{Kind, Kind::*, TokenStream};
 use crate::ast;
 use crate::ast::*;
 use crate::data::{Diagnostic, WithPos};
+use crate::standard::VHDLStandard;
 
 fn name_to_expression(name: WithPos<Name>) -> WithPos<Expression> {
     WithPos {
@@ -129,6 +130,42 @@ fn kind_to_operator(kind: Kind) -> Option<Operator> {
     })
 }
 
+/// The condition operator, the matching relational operators and the unary logical
+/// operators were added in VHDL-2008
+fn check_operator_standard(
+    stream: &TokenStream,
+    op: &WithPos<WithRef<Operator>>,
+    unary: bool,
+) -> ParseResult<()> {
+    let added_in_vhdl2008 = match op.item.item {
+        Operator::QueQue
+        | Operator::QueEQ
+        | Operator::QueNE
+        | Operator::QueLT
+        | Operator::QueLTE
+        | Operator::QueGT
+        | Operator::QueGTE => true,
+        Operator::And
+        | Operator::Or
+        | Operator::Nand
+        | Operator::Nor
+        | Operator::Xor
+        | Operator::Xnor => unary,
+        _ => false,
+    };
+
+    if added_in_vhdl2008 {
+        let construct = if unary && op.item.item != Operator::QueQue {
+            format!("Unary operator {}", op.item.item)
+        } else {
+            format!("Operator {}", op.item.item)
+        };
+        check_standard(stream, &op.pos, VHDLStandard::VHDL2008, &construct)
+    } else {
+        Ok(())
+    }
+}
+
 fn kind_to_prefix_unary_op(kind: Kind) -> Option<(Operator, usize)> {
     let op = kind_to_operator(kind)?;
     let prec = op.unary_precedence()?;
@@ -393,13 +430,13 @@ fn parse_primary(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
             stream.skip();
             Ok(token
                 .to_bit_string()?
-                .map_into(|bs| Expression::Literal(Literal::BitString(bs))))
+                .map_into(|bs| Expression::Literal(ast::Literal::BitString(bs))))
         }
         Character => {
             stream.skip();
             Ok(token
                 .to_character_value()?
-                .map_into(|chr| Expression::Literal(Literal::Character(chr))))
+                .map_into(|chr| Expression::Literal(ast::Literal::Character(chr))))
         }
         StringLiteral => {
             if stream.next_kinds_are(&[StringLiteral, LeftPar]) {
@@ -410,13 +447,13 @@ fn parse_primary(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
                 stream.skip();
                 Ok(token
                     .to_string_value()?
-                    .map_into(|string| Expression::Literal(Literal::String(string))))
+                    .map_into(|string| Expression::Literal(ast::Literal::String(string))))
             }
         }
         Null => {
             stream.skip();
             Ok(WithPos {
-                item: Expression::Literal(Literal::Null),
+                item: Expression::Literal(ast::Literal::Null),
                 pos: token.pos.clone(),
             })
         }
@@ -441,11 +478,12 @@ fn parse_primary(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
                     unit: WithRef::new(unit),
                 };
                 Ok(WithPos {
-                    item: Expression::Literal(Literal::Physical(physical)),
+                    item: Expression::Literal(ast::Literal::Physical(physical)),
                     pos,
                 })
             } else {
-                Ok(value.map_into(|value| Expression::Literal(Literal::AbstractLiteral(value))))
+                Ok(value
+                    .map_into(|value| Expression::Literal(ast::Literal::AbstractLiteral(value))))
             }
         }
 
@@ -458,15 +496,14 @@ fn parse_primary(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
             // Prefix unary operation
             if let Some((unary_op, op_precedence)) = kind_to_prefix_unary_op(kind) {
                 stream.skip();
+                let unary_op = WithPos::new(WithRef::new(unary_op), token.pos.clone());
+                check_operator_standard(stream, &unary_op, true)?;
 
                 let expr = parse_expr(stream, op_precedence)?;
                 let pos = token.pos.combine(&expr);
 
                 Ok(WithPos {
-                    item: Expression::Unary(
-                        WithPos::new(WithRef::new(unary_op), token.pos.clone()),
-                        Box::new(expr),
-                    ),
+                    item: Expression::Unary(unary_op, Box::new(expr)),
                     pos,
                 })
             } else {
@@ -479,10 +516,15 @@ fn parse_primary(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
     }
 }
 
+/// The PSL operators `->` and `<->` are not VHDL operators, although they start with one
+fn is_psl_implication(stream: &TokenStream) -> bool {
+    stream.next_kinds_are(&[Minus, GT]) || stream.next_kinds_are(&[LT, Minus, GT])
+}
+
 fn parse_expr(stream: &TokenStream, min_precedence: usize) -> ParseResult<WithPos<Expression>> {
     let mut lhs = parse_primary(stream)?;
     while let Some(token) = stream.peek() {
-        if token.kind == RightPar {
+        if token.kind == RightPar || is_psl_implication(stream) {
             return Ok(lhs);
         };
 
@@ -490,14 +532,12 @@ fn parse_expr(stream: &TokenStream, min_precedence: usize) -> ParseResult<WithPo
             // Binary operation
             if op_precedence > min_precedence {
                 stream.skip();
+                let binary_op = WithPos::new(WithRef::new(binary_op), token.pos.clone());
+                check_operator_standard(stream, &binary_op, false)?;
                 let rhs = parse_expr(stream, op_precedence)?;
                 let pos = lhs.pos.combine(&rhs);
                 lhs = WithPos {
-                    item: Expression::Binary(
-                        WithPos::new(WithRef::new(binary_op), token.pos.clone()),
-                        Box::new(lhs),
-                        Box::new(rhs),
-                    ),
+                    item: Expression::Binary(binary_op, Box::new(lhs), Box::new(rhs)),
                     pos,
                 };
             } else {
@@ -532,10 +572,88 @@ pub fn parse_expression(stream: &TokenStream) -> ParseResult<WithPos<Expression>
     })
 }
 
+/// Parse an expression without the binary logical operators
+/// Used by PSL where the logical operators may have PSL operands
+pub fn parse_relation(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
+    let state = stream.state();
+    parse_expr(stream, 2).inspect_err(|_| stream.set_state(state))
+}
+
+/// Parse the {when condition else expression} part of a conditional expression
+/// The last else may be omitted when allow_missing_else is set
+fn parse_conditional_tail(
+    stream: &TokenStream,
+    initial_item: WithPos<Expression>,
+    allow_missing_else: bool,
+) -> ParseResult<WithPos<Expression>> {
+    if stream.peek_kind() != Some(When) {
+        return Ok(initial_item);
+    }
+
+    let start = initial_item.pos.clone();
+    let mut conditionals = Vec::new();
+    let mut item = initial_item;
+    let mut else_item = None;
+
+    loop {
+        let when = stream.expect_kind(When)?;
+        check_standard(
+            stream,
+            &when.pos,
+            VHDLStandard::VHDL2019,
+            "Conditional expression",
+        )?;
+        let condition = parse_expression(stream)?;
+        conditionals.push(Conditional { condition, item });
+
+        if !stream.skip_if_kind(Else) {
+            if !allow_missing_else {
+                stream.expect_kind(Else)?;
+            }
+            break;
+        }
+
+        let next_item = parse_expression(stream)?;
+        if stream.peek_kind() == Some(When) {
+            item = next_item;
+        } else {
+            else_item = Some(next_item);
+            break;
+        }
+    }
+
+    let end = else_item
+        .as_ref()
+        .map(|item| item.pos.clone())
+        .unwrap_or_else(|| conditionals.last().unwrap().condition.pos.clone());
+
+    Ok(WithPos::new(
+        Expression::Conditional(Box::new(Conditionals {
+            conditionals,
+            else_item,
+        })),
+        start.combine(&end),
+    ))
+}
+
+/// VHDL-2019 LRM 9.1 conditional_expression ::= expression { when condition else expression }
+pub fn parse_conditional_expression(stream: &TokenStream) -> ParseResult<WithPos<Expression>> {
+    let expr = parse_expression(stream)?;
+    parse_conditional_tail(stream, expr, false)
+}
+
+/// VHDL-2019 LRM 10.13 The value of a return statement may omit the last else
+pub fn parse_conditional_or_unaffected_expression(
+    stream: &TokenStream,
+) -> ParseResult<WithPos<Expression>> {
+    let expr = parse_expression(stream)?;
+    parse_conditional_tail(stream, expr, true)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ast::{AbstractLiteral, Name};
+    use crate::ast::{AbstractLiteral, Literal, Name};
     use crate::data::Latin1String;
     use crate::syntax::test::Code;
 
@@ -1340,4 +1458,92 @@ mod tests {
 
         assert_expression_is("and 1 + 2", "((And Integer(1)) Plus Integer(2))");
     }
+
+    #[test]
+    fn parses_conditional_expression() {
+        let code = Code::with_standard(
+            "val1 when cond1 else val2 when cond2 else val3",
+            VHDLStandard::VHDL2019,
+        );
+        assert_eq!(
+            code.with_stream(parse_conditional_expression),
+            WithPos {
+                item: Expression::Conditional(Box::new(Conditionals {
+                    conditionals: vec![
+                        Conditional {
+                            condition: code.s1("cond1").expr(),
+                            item: code.s1("val1").expr(),
+                        },
+                        Conditional {
+                            condition: code.s1("cond2").expr(),
+                            item: code.s1("val2").expr(),
+                        },
+                    ],
+                    else_item: Some(code.s1("val3").expr()),
+                })),
+                pos: code.pos()
+            }
+        );
+    }
+
+    #[test]
+    fn conditional_expression_requires_else() {
+        let code = Code::with_standard("val1 when cond1;", VHDLStandard::VHDL2019);
+        assert_eq!(
+            code.with_partial_stream(parse_conditional_expression),
+            Err(Diagnostic::error(code.s1(";"), "Expected 'else'"))
+        );
+    }
+
+    #[test]
+    fn conditional_expression_requires_vhdl2019() {
+        let code = Code::new("val1 when cond1 else val2");
+        assert_eq!(
+            code.with_partial_stream(parse_conditional_expression),
+            Err(Diagnostic::error(
+                code.s1("when"),
+                "Conditional expression requires VHDL-2019 or later"
+            ))
+        );
+    }
+
+    #[test]
+    fn vhdl2008_operators_require_vhdl2008() {
+        let code = Code::with_standard("a ?= b", VHDLStandard::VHDL2002);
+        assert_eq!(
+            code.with_partial_stream(parse_expression),
+            Err(Diagnostic::error(
+                code.s1("?="),
+                "Operator ?= requires VHDL-2008 or later"
+            ))
+        );
+
+        let code = Code::with_standard("?? a", VHDLStandard::VHDL1993);
+        assert_eq!(
+            code.with_partial_stream(parse_expression),
+            Err(Diagnostic::error(
+                code.s1("??"),
+                "Operator ?? requires VHDL-2008 or later"
+            ))
+        );
+
+        let code = Code::with_standard("or a", VHDLStandard::VHDL1993);
+        assert_eq!(
+            code.with_partial_stream(parse_expression),
+            Err(Diagnostic::error(
+                code.s1("or"),
+                "Unary operator or requires VHDL-2008 or later"
+            ))
+        );
+
+        let code = Code::with_standard("a or not b", VHDLStandard::VHDL1993);
+        assert_eq!(
+            code.with_stream(parse_expression).item,
+            Expression::Binary(
+                WithPos::new(WithRef::new(Operator::Or), code.s1("or").pos()),
+                Box::new(code.s1("a").expr()),
+                Box::new(code.s1("not b").expr()),
+            )
+        );
+    }
 }
@@ -0,0 +1,153 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::attributes::parse_entity_class;
+use super::common::ParseResult;
+use super::names::parse_selected_name;
+use super::tokens::{Kind::*, TokenStream};
+use crate::ast::*;
+use crate::data::WithPos;
+
+/// LRM 6.9 Group template declarations
+fn parse_entity_class_entry_list(stream: &TokenStream) -> ParseResult<Vec<EntityClassEntry>> {
+    stream.expect_kind(LeftPar)?;
+    let mut entity_classes = Vec::new();
+    loop {
+        let entity_class = parse_entity_class(stream)?;
+        let has_box = stream.skip_if_kind(BOX);
+        entity_classes.push(EntityClassEntry {
+            entity_class,
+            has_box,
+        });
+
+        if has_box {
+            // The box may only appear on the last entry
+            stream.expect_kind(RightPar)?;
+            break;
+        }
+
+        expect_token!(stream, token,
+            Comma => {},
+            RightPar => break
+        );
+    }
+    Ok(entity_classes)
+}
+
+/// LRM 6.10 Group declarations
+fn parse_group_constituent_list(stream: &TokenStream) -> ParseResult<Vec<WithPos<SelectedName>>> {
+    stream.expect_kind(LeftPar)?;
+    let mut constituents = Vec::new();
+    loop {
+        constituents.push(parse_selected_name(stream)?);
+        expect_token!(stream, token,
+            Comma => {},
+            RightPar => break
+        );
+    }
+    Ok(constituents)
+}
+
+/// Parse either a group template declaration or a group declaration
+pub fn parse_group(stream: &TokenStream) -> ParseResult<Declaration> {
+    stream.expect_kind(Group)?;
+    let ident = stream.expect_ident()?;
+    Ok(expect_token!(stream, token,
+        Is => {
+            let entity_classes = parse_entity_class_entry_list(stream)?;
+            stream.expect_kind(SemiColon)?;
+            Declaration::GroupTemplate(GroupTemplateDeclaration {
+                ident: ident.into(),
+                entity_classes,
+            })
+        },
+        Colon => {
+            let group_template_name = parse_selected_name(stream)?;
+            let constituents = parse_group_constituent_list(stream)?;
+            stream.expect_kind(SemiColon)?;
+            Declaration::Group(GroupDeclaration {
+                ident: ident.into(),
+                group_template_name,
+                constituents,
+            })
+        }
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Diagnostic;
+    use crate::syntax::test::Code;
+
+    #[test]
+    fn parse_group_template_declaration() {
+        let code = Code::new("group pin2pin is (signal, signal);");
+        assert_eq!(
+            code.with_stream(parse_group),
+            Declaration::GroupTemplate(GroupTemplateDeclaration {
+                ident: code.s1("pin2pin").decl_ident(),
+                entity_classes: vec![
+                    EntityClassEntry {
+                        entity_class: EntityClass::Signal,
+                        has_box: false,
+                    },
+                    EntityClassEntry {
+                        entity_class: EntityClass::Signal,
+                        has_box: false,
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn parse_group_template_declaration_with_box() {
+        let code = Code::new("group path is (label, literal <>);");
+        assert_eq!(
+            code.with_stream(parse_group),
+            Declaration::GroupTemplate(GroupTemplateDeclaration {
+                ident: code.s1("path").decl_ident(),
+                entity_classes: vec![
+                    EntityClassEntry {
+                        entity_class: EntityClass::Label,
+                        has_box: false,
+                    },
+                    EntityClassEntry {
+                        entity_class: EntityClass::Literal,
+                        has_box: true,
+                    },
+                ],
+            })
+        );
+    }
+
+    #[test]
+    fn box_must_be_on_last_entity_class() {
+        let code = Code::new("group path is (label <>, signal);");
+        assert_eq!(
+            code.with_partial_stream(parse_group),
+            Err(Diagnostic::error(code.s1(","), "Expected ')'"))
+        );
+    }
+
+    #[test]
+    fn parse_group_declaration() {
+        let code = Code::new("group g : lib.pkg.pin2pin (foo, pkg.bar, 'a');");
+        assert_eq!(
+            code.with_stream(parse_group),
+            Declaration::Group(GroupDeclaration {
+                ident: code.s1("g :").s1("g").decl_ident(),
+                group_template_name: code.s1("lib.pkg.pin2pin").selected_name(),
+                constituents: vec![
+                    code.s1("foo").selected_name(),
+                    code.s1("pkg.bar").selected_name(),
+                    code.s1("'a'").selected_name(),
+                ],
+            })
+        );
+    }
+}
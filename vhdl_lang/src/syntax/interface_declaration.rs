@@ -4,17 +4,19 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use super::common::ParseResult;
+use super::common::{check_standard, ParseResult};
 use super::names::{parse_association_list_no_leftpar, parse_identifier_list, parse_selected_name};
 use super::object_declaration::{parse_file_declaration_no_semi, parse_optional_assignment};
 use super::subprogram::parse_subprogram_declaration_no_semi;
 use super::subtype_indication::parse_subtype_indication;
 use super::tokens::{Kind::*, *};
+use super::view_declaration::parse_mode_view_indication;
 /// LRM 6.5 Interface declarations
 use crate::ast::*;
 use crate::data::*;
+use crate::standard::VHDLStandard;
 
-fn parse_optional_mode(stream: &TokenStream) -> ParseResult<Option<WithPos<Mode>>> {
+pub fn parse_optional_mode(stream: &TokenStream) -> ParseResult<Option<WithPos<Mode>>> {
     let token = stream.peek_expect()?;
     let mode = match token.kind {
         In => Mode::In,
@@ -94,7 +96,18 @@ fn parse_interface_object_declaration(
 
     stream.expect_kind(Colon)?;
 
-    let mode_with_pos = parse_optional_mode(stream)?;
+    let view = if stream.peek_kind() == Some(View) {
+        let view_pos = stream.peek_expect()?.pos.clone();
+        Some((parse_mode_view_indication(stream)?, view_pos))
+    } else {
+        None
+    };
+
+    let mode_with_pos = if let Some((_, ref view_pos)) = view {
+        Some(WithPos::new(Mode::View, view_pos.clone()))
+    } else {
+        parse_optional_mode(stream)?
+    };
     let mode = mode_with_pos
         .as_ref()
         .map(|mode| mode.item)
@@ -110,11 +123,42 @@ fn parse_interface_object_declaration(
         (InterfaceType::Port, None, _) => ObjectClass::Signal,
         (InterfaceType::Generic, None, _) => ObjectClass::Constant,
         (InterfaceType::Parameter, None, Mode::In) => ObjectClass::Constant,
+        (InterfaceType::Parameter, None, Mode::View) => ObjectClass::Signal,
         (InterfaceType::Parameter, None, _) => ObjectClass::Variable,
     };
 
-    let subtype = parse_subtype_indication(stream)?;
-    let expr = parse_optional_assignment(stream)?;
+    let view = view.map(|(view, _)| view);
+    let mut signal_kind = None;
+    let (subtype, expr) = match view {
+        // The subtype of an array mode view is required, a record mode view provides its own type
+        Some(ModeViewIndication {
+            kind: ModeViewIndicationKind::Array,
+            ..
+        }) => {
+            stream.expect_kind(Of)?;
+            (Some(parse_subtype_indication(stream)?), None)
+        }
+        Some(_) => {
+            if stream.skip_if_kind(Of) {
+                (Some(parse_subtype_indication(stream)?), None)
+            } else {
+                (None, None)
+            }
+        }
+        None => {
+            let subtype = parse_subtype_indication(stream)?;
+            if let Some(token) = stream.pop_if_kind(Bus) {
+                if object_class != ObjectClass::Signal {
+                    return Err(Diagnostic::error(
+                        &token.pos,
+                        "Only interface signals may be of kind bus",
+                    ));
+                }
+                signal_kind = Some(SignalKind::Bus);
+            }
+            (Some(subtype), parse_optional_assignment(stream)?)
+        }
+    };
 
     // @TODO maybe move this to a semantic check?
     for ident in idents.iter() {
@@ -151,7 +195,9 @@ fn parse_interface_object_declaration(
                 mode,
                 class: object_class,
                 ident: ident.into(),
+                view: view.clone(),
                 subtype_indication: subtype.clone(),
+                signal_kind,
                 expression: expr.clone(),
             })
         })
@@ -223,17 +269,20 @@ fn parse_interface_declaration(
         },
         File => parse_interface_file_declaration(stream),
         Type => {
+            check_standard(stream, &token.pos, VHDLStandard::VHDL2008, "Interface type declaration")?;
             stream.skip();
             let ident = stream.expect_ident()?;
             Ok(vec![InterfaceDeclaration::Type(WithDecl::new(ident))])
         },
         Function | Procedure | Impure | Pure => {
+            check_standard(stream, &token.pos, VHDLStandard::VHDL2008, "Interface subprogram declaration")?;
             let decl = parse_subprogram_declaration_no_semi(stream, diagnostics)?;
             let default = parse_subprogram_default(stream)?;
 
             Ok(vec![InterfaceDeclaration::Subprogram(decl, default)])
         },
         Package => {
+            check_standard(stream, &token.pos, VHDLStandard::VHDL2008, "Interface package declaration")?;
             Ok(vec![InterfaceDeclaration::Package (parse_interface_package(stream)?)])
         }
     )
@@ -246,7 +295,8 @@ fn parse_semicolon_separator(stream: &TokenStream) -> ParseResult<()> {
         stream, token,
         SemiColon => {
             stream.skip();
-            if stream.next_kind_is(RightPar) {
+            // VHDL-2019 allows a trailing semicolon after the last interface element
+            if stream.next_kind_is(RightPar) && stream.standard() < VHDLStandard::VHDL2019 {
                 return Err(Diagnostic::error(&token.pos,
                         format!("Last interface element may not end with {}",
                         kinds_str(&[SemiColon]))));
@@ -401,7 +451,9 @@ mod tests {
                     mode: Mode::In,
                     class: ObjectClass::Constant,
                     ident: code.s1("foo").decl_ident(),
-                    subtype_indication: code.s1("natural").subtype_indication(),
+                    view: None,
+                    subtype_indication: Some(code.s1("natural").subtype_indication()),
+                    signal_kind: None,
                     expression: None
                 }),
                 InterfaceDeclaration::Object(InterfaceObjectDeclaration {
@@ -409,7 +461,9 @@ mod tests {
                     mode: Mode::In,
                     class: ObjectClass::Constant,
                     ident: code.s1("bar").decl_ident(),
-                    subtype_indication: code.s1("natural").subtype_indication(),
+                    view: None,
+                    subtype_indication: Some(code.s1("natural").subtype_indication()),
+                    signal_kind: None,
                     expression: None
                 })
             ]
@@ -426,7 +480,9 @@ mod tests {
                 mode: Mode::In,
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").decl_ident(),
-                subtype_indication: code.s1("std_logic").subtype_indication(),
+                view: None,
+                subtype_indication: Some(code.s1("std_logic").subtype_indication()),
+                signal_kind: None,
                 expression: None
             })
         );
@@ -478,7 +534,9 @@ mod tests {
                 mode: Mode::In,
                 class: ObjectClass::Signal,
                 ident: code.s1("foo").decl_ident(),
-                subtype_indication: code.s1("std_logic").subtype_indication(),
+                view: None,
+                subtype_indication: Some(code.s1("std_logic").subtype_indication()),
+                signal_kind: None,
                 expression: None
             })
         );
@@ -546,7 +604,9 @@ mod tests {
                 mode: Mode::In,
                 class: ObjectClass::Constant,
                 ident: code.s1("foo").decl_ident(),
-                subtype_indication: code.s1("std_logic").subtype_indication(),
+                view: None,
+                subtype_indication: Some(code.s1("std_logic").subtype_indication()),
+                signal_kind: None,
                 expression: None
             })
         );
@@ -562,10 +622,114 @@ mod tests {
                 mode: Mode::In,
                 class: ObjectClass::Signal,
                 ident: code.s1("foo").decl_ident(),
-                subtype_indication: code.s1("std_logic").subtype_indication(),
+                view: None,
+                subtype_indication: Some(code.s1("std_logic").subtype_indication()),
+                signal_kind: None,
+                expression: None
+            })
+        );
+    }
+
+    #[test]
+    fn parses_port_with_record_mode_view() {
+        let code = Code::with_standard("foo : view master", VHDLStandard::VHDL2019);
+        assert_eq!(
+            code.with_stream(parse_port),
+            InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                list_type: InterfaceType::Port,
+                mode: Mode::View,
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").decl_ident(),
+                view: Some(ModeViewIndication {
+                    kind: ModeViewIndicationKind::Record,
+                    name: code.s1("master").name(),
+                }),
+                subtype_indication: None,
+                signal_kind: None,
+                expression: None
+            })
+        );
+
+        let code = Code::with_standard(
+            "foo : view master'converse of rec_t",
+            VHDLStandard::VHDL2019,
+        );
+        assert_eq!(
+            code.with_stream(parse_port),
+            InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                list_type: InterfaceType::Port,
+                mode: Mode::View,
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").decl_ident(),
+                view: Some(ModeViewIndication {
+                    kind: ModeViewIndicationKind::Record,
+                    name: code.s1("master'converse").name(),
+                }),
+                subtype_indication: Some(code.s1("rec_t").subtype_indication()),
+                signal_kind: None,
+                expression: None
+            })
+        );
+    }
+
+    #[test]
+    fn parses_port_with_array_mode_view() {
+        let code = Code::with_standard(
+            "foo : view (master) of rec_vec_t(0 to 1)",
+            VHDLStandard::VHDL2019,
+        );
+        assert_eq!(
+            code.with_stream(parse_port),
+            InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                list_type: InterfaceType::Port,
+                mode: Mode::View,
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").decl_ident(),
+                view: Some(ModeViewIndication {
+                    kind: ModeViewIndicationKind::Array,
+                    name: code.s1("master").name(),
+                }),
+                subtype_indication: Some(code.s1("rec_vec_t(0 to 1)").subtype_indication()),
+                signal_kind: None,
                 expression: None
             })
         );
+
+        let code = Code::with_standard("foo : view (master)", VHDLStandard::VHDL2019);
+        assert_eq!(
+            code.with_partial_stream(parse_port),
+            Err(Diagnostic::error(
+                code.eof_pos(),
+                "Unexpected EOF, when expecting 'of'"
+            ))
+        );
+    }
+
+    #[test]
+    fn parses_port_of_kind_bus() {
+        let code = Code::new("foo : inout std_logic bus := '0'");
+        assert_eq!(
+            code.with_stream(parse_port),
+            InterfaceDeclaration::Object(InterfaceObjectDeclaration {
+                list_type: InterfaceType::Port,
+                mode: Mode::InOut,
+                class: ObjectClass::Signal,
+                ident: code.s1("foo").decl_ident(),
+                view: None,
+                subtype_indication: Some(code.s1("std_logic").subtype_indication()),
+                signal_kind: Some(SignalKind::Bus),
+                expression: Some(code.s1("'0'").expr())
+            })
+        );
+
+        let code = Code::new("variable foo : inout std_logic bus");
+        assert_eq!(
+            code.with_partial_stream(parse_parameter),
+            Err(Diagnostic::error(
+                code.s1("bus"),
+                "Only interface signals may be of kind bus"
+            ))
+        );
     }
 
     #[test]
@@ -623,6 +787,25 @@ bar : natural)",
         );
     }
 
+    #[test]
+    fn test_parse_generic_interface_list_last_semi_colon_vhdl2019() {
+        let code = Code::with_standard(
+            "\
+(constant foo : std_logic;
+ bar : natural;
+)",
+            VHDLStandard::VHDL2019,
+        );
+
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_generic_interface_list),
+            vec![
+                code.s1("constant foo : std_logic").generic(),
+                code.s1("bar : natural").generic()
+            ]
+        );
+    }
+
     #[test]
     fn test_parse_port_interface_list() {
         let code = Code::new(
@@ -745,6 +928,27 @@ bar : natural)",
         );
     }
 
+    #[test]
+    fn interface_type_and_subprogram_require_vhdl2008() {
+        let code = Code::with_standard("type name", VHDLStandard::VHDL2002);
+        assert_eq!(
+            code.with_partial_stream(parse_generic),
+            Err(Diagnostic::error(
+                code.s1("type"),
+                "Interface type declaration requires VHDL-2008 or later"
+            ))
+        );
+
+        let code = Code::with_standard("function foo return bar", VHDLStandard::VHDL2002);
+        assert_eq!(
+            code.with_partial_stream(parse_generic),
+            Err(Diagnostic::error(
+                code.s1("function"),
+                "Interface subprogram declaration requires VHDL-2008 or later"
+            ))
+        );
+    }
+
     #[test]
     fn parses_interface_subprogram() {
         let code = Code::new("function foo return bar");
@@ -113,7 +113,7 @@ fn expression_to_name(expr: WithPos<Expression>) -> ParseResult<WithPos<Name>> {
             item: *name,
             pos: expr.pos,
         }),
-        Expression::Literal(Literal::String(val)) => {
+        Expression::Literal(ast::Literal::String(val)) => {
             if let Some(op) = Operator::from_latin1(val) {
                 Ok(WithPos {
                     item: Name::Designator(Designator::OperatorSymbol(op).into_ref()),
@@ -123,7 +123,7 @@ fn expression_to_name(expr: WithPos<Expression>) -> ParseResult<WithPos<Name>> {
                 Err(Diagnostic::error(expr.pos, "Invalid operator symbol"))
             }
         }
-        Expression::Literal(Literal::Character(val)) => Ok(WithPos {
+        Expression::Literal(ast::Literal::Character(val)) => Ok(WithPos {
             item: Name::Designator(Designator::Character(val).into_ref()),
             pos: expr.pos,
         }),
@@ -375,6 +375,14 @@ fn _parse_name(stream: &TokenStream) -> ParseResult<WithPos<Name>> {
                 }
             }
             LeftSquare => {
+                // A PSL repetition such as req[*3] is not a signature
+                if stream.nth_kind_is(1, Times)
+                    || stream.nth_kind_is(1, Plus)
+                    || stream.nth_kind_is(1, EQ)
+                    || stream.nth_kind_is(1, Minus)
+                {
+                    break;
+                }
                 let state = stream.state();
                 let signature = Some(parse_signature(stream)?);
                 if !stream.skip_if_kind(Tick) {
@@ -5,9 +5,10 @@
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
 use super::design_unit::parse_design_file;
-use super::tokens::{Symbols, TokenStream, Tokenizer};
+use super::tokens::{Conditions, Symbols, TokenStream, Tokenizer};
 use crate::ast::DesignFile;
 use crate::data::*;
+use crate::standard::VHDLStandard;
 use std::io;
 use std::sync::Arc;
 
@@ -16,38 +17,51 @@ pub struct VHDLParser {
     pub symbols: Arc<Symbols>,
 }
 
-pub type ParserResult = Result<(Source, DesignFile), io::Error>;
+/// The parsed source, design file and the source ranges skipped by conditional analysis
+pub type ParserResult = Result<(Source, DesignFile, Vec<SrcPos>), io::Error>;
 
 impl VHDLParser {
     pub fn symbol(&self, name: &Latin1String) -> Symbol {
         self.symbols.symtab().insert(name)
     }
 
+    /// Parse a design file, returns the source ranges skipped by conditional analysis
     pub fn parse_design_source(
         &self,
         source: &Source,
+        standard: VHDLStandard,
+        conditions: &Conditions,
         diagnostics: &mut dyn DiagnosticHandler,
-    ) -> DesignFile {
+    ) -> (DesignFile, Vec<SrcPos>) {
         let contents = source.contents();
-        let tokenizer = Tokenizer::new(&self.symbols, source, ContentReader::new(&contents));
-        let stream = TokenStream::new(tokenizer, diagnostics);
+        let tokenizer = Tokenizer::new(
+            &self.symbols,
+            source,
+            ContentReader::new(&contents),
+            standard,
+        );
+        let stream = TokenStream::new(tokenizer, conditions, diagnostics);
 
-        match parse_design_file(&stream, diagnostics) {
+        let design_file = match parse_design_file(&stream, diagnostics) {
             Ok(design_file) => design_file,
             Err(diagnostic) => {
                 diagnostics.push(diagnostic);
                 DesignFile::default()
             }
-        }
+        };
+        (design_file, stream.inactive_regions().to_vec())
     }
 
     pub fn parse_design_file(
         &self,
         file_name: &Path,
+        standard: VHDLStandard,
+        conditions: &Conditions,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> ParserResult {
         let source = Source::from_latin1_file(file_name)?;
-        let design_file = self.parse_design_source(&source, diagnostics);
-        Ok((source, design_file))
+        let (design_file, inactive_regions) =
+            self.parse_design_source(&source, standard, conditions, diagnostics);
+        Ok((source, design_file, inactive_regions))
     }
 }
@@ -0,0 +1,1055 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::common::{parse_optional, ParseResult};
+use super::concurrent_statement::parse_labeled_concurrent_statement;
+use super::declarative_part::{is_declaration_start, parse_declarative_part_until};
+use super::expression::{parse_expression, parse_relation};
+use super::tokens::{Kind::*, *};
+use crate::ast::*;
+use crate::data::*;
+
+/// True if the token is the PSL identifier `word`
+/// PSL keywords which are not VHDL reserved words are tokenized as identifiers
+fn is_psl_word(token: &Token, word: &str) -> bool {
+    if let Value::Identifier(ref sym) = token.value {
+        token.kind == Identifier && sym.name_utf8().eq_ignore_ascii_case(word)
+    } else {
+        false
+    }
+}
+
+fn expect_psl_word(stream: &TokenStream, word: &str) -> ParseResult<()> {
+    let token = stream.peek_expect()?;
+    if is_psl_word(token, word) {
+        stream.skip();
+        Ok(())
+    } else {
+        Err(Diagnostic::error(token, format!("Expected '{word}'")))
+    }
+}
+
+/// True if an assert statement is a PSL assertion rather than a VHDL assertion
+/// The next token is the assert keyword
+///
+/// An assertion within a PSL comment is always a PSL assertion,
+/// otherwise an assertion which is a valid VHDL assertion is a VHDL assertion
+pub fn is_psl_assert(stream: &TokenStream) -> bool {
+    if let Some(token) = stream.peek() {
+        if stream.psl_comment_region(token).is_some() {
+            return true;
+        }
+    }
+
+    let state = stream.state();
+    stream.skip();
+    let is_vhdl = parse_expression(stream).is_ok()
+        && matches!(stream.peek_kind(), Some(Report | Severity | SemiColon));
+    stream.set_state(state);
+    !is_vhdl
+}
+
+fn psl_binary(
+    op: PslBinaryOperator,
+    pos: SrcPos,
+    lhs: WithPos<PslExpression>,
+    rhs: WithPos<PslExpression>,
+) -> WithPos<PslExpression> {
+    let expr_pos = lhs.pos.combine(&rhs.pos);
+    WithPos::new(
+        PslExpression::Binary(WithPos::new(op, pos), Box::new(lhs), Box::new(rhs)),
+        expr_pos,
+    )
+}
+
+fn psl_unary(
+    op: PslUnaryOperator,
+    pos: SrcPos,
+    operand: WithPos<PslExpression>,
+) -> WithPos<PslExpression> {
+    let expr_pos = pos.combine(&operand.pos);
+    WithPos::new(
+        PslExpression::Unary(WithPos::new(op, pos), Box::new(operand)),
+        expr_pos,
+    )
+}
+
+/// Pop a PSL operator consisting of several tokens, returns its position
+fn pop_kinds(stream: &TokenStream, kinds: &[Kind]) -> Option<SrcPos> {
+    if stream.next_kinds_are(kinds) {
+        let start = stream.peek()?.pos.clone();
+        for _ in 1..kinds.len() {
+            stream.skip();
+        }
+        let end = stream.peek()?.pos.clone();
+        stream.skip();
+        Some(start.combine(&end))
+    } else {
+        None
+    }
+}
+
+/// Pop the PSL word and an optional strong suffix `!`, returns its position and strength
+fn pop_psl_word(stream: &TokenStream, word: &str) -> Option<(SrcPos, bool)> {
+    let token = stream.peek()?;
+    if !is_psl_word(token, word) {
+        return None;
+    }
+    stream.skip();
+    if let Some(strong) = stream.pop_if_kind(Exclamation) {
+        Some((token.pos.combine(&strong.pos), true))
+    } else {
+        Some((token.pos.clone(), false))
+    }
+}
+
+/// PSL 6.2.1 Parse a property, sequence or boolean
+///
+/// The operands are VHDL expressions without the binary logical operators,
+/// the logical operators are parsed as PSL operators
+pub fn parse_psl_property(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    // 6.2.1.1 FL invariance operators
+    for (word, op) in [
+        ("always", PslUnaryOperator::Always),
+        ("never", PslUnaryOperator::Never),
+        ("eventually", PslUnaryOperator::Eventually),
+    ] {
+        if let Some((pos, _)) = pop_psl_word(stream, word) {
+            let operand = parse_psl_property(stream)?;
+            return Ok(psl_unary(op, pos, operand));
+        }
+    }
+
+    let lhs = parse_psl_suffix_implication(stream)?;
+
+    // 6.2.1.7 Logical implication and equivalence operators
+    if let Some(pos) = pop_kinds(stream, &[Minus, GT]) {
+        let rhs = parse_psl_property(stream)?;
+        Ok(psl_binary(PslBinaryOperator::Implication, pos, lhs, rhs))
+    } else if let Some(pos) = pop_kinds(stream, &[LT, Minus, GT]) {
+        let rhs = parse_psl_property(stream)?;
+        Ok(psl_binary(PslBinaryOperator::Equivalence, pos, lhs, rhs))
+    } else {
+        Ok(lhs)
+    }
+}
+
+/// 6.2.1.6 Suffix implication operators
+fn parse_psl_suffix_implication(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let lhs = parse_psl_bounding(stream)?;
+
+    if let Some(pos) = pop_kinds(stream, &[Bar, Minus, GT]) {
+        let rhs = parse_psl_property(stream)?;
+        Ok(psl_binary(
+            PslBinaryOperator::OverlappingImplication,
+            pos,
+            lhs,
+            rhs,
+        ))
+    } else if let Some(pos) = pop_kinds(stream, &[Bar, RightArrow]) {
+        let rhs = parse_psl_property(stream)?;
+        Ok(psl_binary(
+            PslBinaryOperator::NonOverlappingImplication,
+            pos,
+            lhs,
+            rhs,
+        ))
+    } else {
+        Ok(lhs)
+    }
+}
+
+/// 6.2.1.5 Bounding operators
+fn parse_psl_bounding(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let mut lhs = parse_psl_occurrence(stream)?;
+
+    loop {
+        let (op, pos) = if let Some(token) = stream.pop_if_kind(Until) {
+            if let Some(strong) = stream.pop_if_kind(Exclamation) {
+                (
+                    PslBinaryOperator::Until {
+                        strong: true,
+                        inclusive: false,
+                    },
+                    token.pos.combine(&strong.pos),
+                )
+            } else {
+                (
+                    PslBinaryOperator::Until {
+                        strong: false,
+                        inclusive: false,
+                    },
+                    token.pos.clone(),
+                )
+            }
+        } else if let Some((pos, strong)) = pop_psl_word(stream, "until_") {
+            (
+                PslBinaryOperator::Until {
+                    strong,
+                    inclusive: true,
+                },
+                pos,
+            )
+        } else if let Some((pos, strong)) = pop_psl_word(stream, "before") {
+            (
+                PslBinaryOperator::Before {
+                    strong,
+                    inclusive: false,
+                },
+                pos,
+            )
+        } else if let Some((pos, strong)) = pop_psl_word(stream, "before_") {
+            (
+                PslBinaryOperator::Before {
+                    strong,
+                    inclusive: true,
+                },
+                pos,
+            )
+        } else {
+            return Ok(lhs);
+        };
+
+        let rhs = parse_psl_occurrence(stream)?;
+        lhs = psl_binary(op, pos, lhs, rhs);
+    }
+}
+
+/// 6.2.1.3 Occurrence operators
+fn parse_psl_occurrence(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let (kind, is_event, pos, strong) = if let Some(token) = stream.pop_if_kind(Next) {
+        if let Some(strong) = stream.pop_if_kind(Exclamation) {
+            (
+                PslNextKind::Next,
+                false,
+                token.pos.combine(&strong.pos),
+                true,
+            )
+        } else {
+            (PslNextKind::Next, false, token.pos.clone(), false)
+        }
+    } else if let Some((pos, strong)) = pop_psl_word(stream, "next_a") {
+        (PslNextKind::All, false, pos, strong)
+    } else if let Some((pos, strong)) = pop_psl_word(stream, "next_e") {
+        (PslNextKind::Exists, false, pos, strong)
+    } else if let Some((pos, strong)) = pop_psl_word(stream, "next_event") {
+        (PslNextKind::Next, true, pos, strong)
+    } else if let Some((pos, strong)) = pop_psl_word(stream, "next_event_a") {
+        (PslNextKind::All, true, pos, strong)
+    } else if let Some((pos, strong)) = pop_psl_word(stream, "next_event_e") {
+        (PslNextKind::Exists, true, pos, strong)
+    } else {
+        return parse_psl_termination(stream);
+    };
+
+    let event = if is_event {
+        stream.expect_kind(LeftPar)?;
+        let event = parse_psl_or(stream)?;
+        stream.expect_kind(RightPar)?;
+        Some(Box::new(event))
+    } else {
+        None
+    };
+
+    let count = if stream.skip_if_kind(LeftSquare) {
+        let count = parse_psl_count(stream)?;
+        stream.expect_kind(RightSquare)?;
+        Some(count)
+    } else {
+        None
+    };
+
+    let op = if let Some(event) = event {
+        PslUnaryOperator::NextEvent {
+            kind,
+            strong,
+            event,
+            count,
+        }
+    } else {
+        PslUnaryOperator::Next {
+            kind,
+            strong,
+            count,
+        }
+    };
+
+    let operand = parse_psl_occurrence(stream)?;
+    Ok(psl_unary(op, pos, operand))
+}
+
+/// 6.2.1.4 Termination operators
+fn parse_psl_termination(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let mut lhs = parse_psl_or(stream)?;
+
+    loop {
+        let (op, pos) = if let Some((pos, _)) = pop_psl_word(stream, "abort") {
+            (PslBinaryOperator::Abort, pos)
+        } else if let Some((pos, _)) = pop_psl_word(stream, "async_abort") {
+            (PslBinaryOperator::AsyncAbort, pos)
+        } else if let Some((pos, _)) = pop_psl_word(stream, "sync_abort") {
+            (PslBinaryOperator::SyncAbort, pos)
+        } else {
+            return Ok(lhs);
+        };
+
+        let rhs = parse_psl_or(stream)?;
+        lhs = psl_binary(op, pos, lhs, rhs);
+    }
+}
+
+/// 5.1 The logical operators of HDL expressions which may have PSL operands
+fn parse_psl_or(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let mut lhs = parse_psl_and(stream)?;
+    while let Some(token) = stream.pop_if_kind(Or) {
+        let rhs = parse_psl_and(stream)?;
+        lhs = psl_binary(PslBinaryOperator::Or, token.pos.clone(), lhs, rhs);
+    }
+    Ok(lhs)
+}
+
+fn parse_psl_and(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let mut lhs = parse_psl_clocked(stream)?;
+    while let Some(token) = stream.pop_if_kind(And) {
+        let rhs = parse_psl_clocked(stream)?;
+        lhs = psl_binary(PslBinaryOperator::And, token.pos.clone(), lhs, rhs);
+    }
+    Ok(lhs)
+}
+
+/// 6.1.1.1 Clocked sequences and 6.2.1.2 clocked properties
+fn parse_psl_clocked(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let mut lhs = parse_psl_repetition(stream)?;
+    while let Some(token) = stream.pop_if_kind(CommAt) {
+        let clock = parse_psl_repetition(stream)?;
+        lhs = psl_binary(PslBinaryOperator::Clock, token.pos.clone(), lhs, clock);
+    }
+    Ok(lhs)
+}
+
+/// Pop the start of a repetition operator such as `[*`
+fn pop_repetition(stream: &TokenStream) -> Option<(PslRepetition, SrcPos)> {
+    let (repetition, pos) = if let Some(pos) = pop_kinds(stream, &[LeftSquare, Times]) {
+        (PslRepetition::Consecutive, pos)
+    } else if let Some(pos) = pop_kinds(stream, &[LeftSquare, Plus]) {
+        (PslRepetition::Plus, pos)
+    } else if let Some(pos) = pop_kinds(stream, &[LeftSquare, EQ]) {
+        (PslRepetition::NonConsecutive, pos)
+    } else if let Some(pos) = pop_kinds(stream, &[LeftSquare, Minus, GT]) {
+        (PslRepetition::GoTo, pos)
+    } else {
+        return None;
+    };
+    Some((repetition, pos))
+}
+
+/// 6.1.2 Sequences with repetition operators
+fn parse_psl_repetition(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let mut operand = if let Some((repetition, pos)) = pop_repetition(stream) {
+        parse_psl_repetition_count(stream, None, repetition, pos)?
+    } else {
+        parse_psl_primary(stream)?
+    };
+
+    while let Some((repetition, pos)) = pop_repetition(stream) {
+        let pos = operand.pos.combine(&pos);
+        operand = parse_psl_repetition_count(stream, Some(operand), repetition, pos)?;
+    }
+    Ok(operand)
+}
+
+fn parse_psl_repetition_count(
+    stream: &TokenStream,
+    operand: Option<WithPos<PslExpression>>,
+    repetition: PslRepetition,
+    pos: SrcPos,
+) -> ParseResult<WithPos<PslExpression>> {
+    let count = if stream.next_kind_is(RightSquare) || repetition == PslRepetition::Plus {
+        None
+    } else {
+        Some(parse_psl_count(stream)?)
+    };
+    let end = stream.expect_kind(RightSquare)?;
+    Ok(WithPos::new(
+        PslExpression::Repetition(operand.map(Box::new), repetition, count),
+        pos.combine(&end.pos),
+    ))
+}
+
+/// A count or range of counts such as `3`, `1 to 3`, `1:3` or `1 to inf`
+fn parse_psl_count(stream: &TokenStream) -> ParseResult<PslCount> {
+    let low = parse_expression(stream)?;
+    let high = if stream.skip_if_kind(To) || stream.skip_if_kind(Colon) {
+        let token = stream.peek_expect()?;
+        if is_psl_word(token, "inf") {
+            stream.skip();
+            Some(PslCountBound::Inf)
+        } else {
+            Some(PslCountBound::Expression(parse_expression(stream)?))
+        }
+    } else {
+        None
+    };
+    Ok(PslCount { low, high })
+}
+
+/// 6.1.1 The operators of a sequential extended regular expression in order of increasing precedence
+fn parse_psl_sere(stream: &TokenStream, level: usize) -> ParseResult<WithPos<PslExpression>> {
+    if level == 5 {
+        return parse_psl_property(stream);
+    }
+
+    let mut lhs = parse_psl_sere(stream, level + 1)?;
+    loop {
+        let (op, pos) = match level {
+            0 => {
+                if let Some(token) = stream.pop_if_kind(SemiColon) {
+                    (PslBinaryOperator::Concatenation, token.pos.clone())
+                } else {
+                    return Ok(lhs);
+                }
+            }
+            1 => {
+                if let Some(token) = stream.pop_if_kind(Colon) {
+                    (PslBinaryOperator::Fusion, token.pos.clone())
+                } else {
+                    return Ok(lhs);
+                }
+            }
+            2 => {
+                if let Some(token) = stream.pop_if_kind(Bar) {
+                    (PslBinaryOperator::Union, token.pos.clone())
+                } else {
+                    return Ok(lhs);
+                }
+            }
+            3 => {
+                if let Some(pos) = pop_kinds(stream, &[Concat, Concat]) {
+                    (PslBinaryOperator::LengthMatchingAnd, pos)
+                } else if let Some(token) = stream.pop_if_kind(Concat) {
+                    (PslBinaryOperator::NonLengthMatchingAnd, token.pos.clone())
+                } else {
+                    return Ok(lhs);
+                }
+            }
+            _ => {
+                if let Some((pos, _)) = pop_psl_word(stream, "within") {
+                    (PslBinaryOperator::Within, pos)
+                } else {
+                    return Ok(lhs);
+                }
+            }
+        };
+        let rhs = parse_psl_sere(stream, level + 1)?;
+        lhs = psl_binary(op, pos, lhs, rhs);
+    }
+}
+
+fn parse_psl_primary(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    let token = stream.peek_expect()?;
+    match token.kind {
+        LeftCurly => {
+            stream.skip();
+            let sere = parse_psl_sere(stream, 0)?;
+            let end = stream.expect_kind(RightCurly)?;
+            // A strong sequence
+            let end = stream.pop_if_kind(Exclamation).unwrap_or(end);
+            Ok(WithPos::new(
+                PslExpression::Sere(Box::new(sere)),
+                token.pos.combine(&end.pos),
+            ))
+        }
+        Exclamation | Not => {
+            stream.skip();
+            let operand = parse_psl_repetition(stream)?;
+            Ok(psl_unary(PslUnaryOperator::Not, token.pos.clone(), operand))
+        }
+        LeftPar => {
+            // A parenthesized VHDL expression is parsed as such
+            if let Ok(expr) = parse_relation(stream) {
+                let pos = expr.pos.clone();
+                return Ok(WithPos::new(PslExpression::Hdl(expr), pos));
+            }
+            stream.skip();
+            let expr = parse_psl_property(stream)?;
+            let end = stream.expect_kind(RightPar)?;
+            Ok(WithPos::new(expr.item, token.pos.combine(&end.pos)))
+        }
+        Identifier
+            if ["always", "never", "eventually"]
+                .iter()
+                .any(|word| is_psl_word(token, word)) =>
+        {
+            parse_psl_property(stream)
+        }
+        _ => {
+            let expr = parse_relation(stream)?;
+            let pos = expr.pos.clone();
+            Ok(WithPos::new(PslExpression::Hdl(expr), pos))
+        }
+    }
+}
+
+/// Skip the remaining tokens of a PSL statement that could not be parsed
+fn skip_psl_statement(stream: &TokenStream) -> ParseResult<()> {
+    let mut depth = 0;
+    loop {
+        let token = stream.peek_expect()?;
+        match token.kind {
+            SemiColon if depth == 0 => return Ok(()),
+            LeftPar | LeftSquare | LeftCurly => depth += 1,
+            RightPar | RightSquare | RightCurly if depth > 0 => depth -= 1,
+            _ => {}
+        }
+        stream.skip();
+    }
+}
+
+/// PSL 6.2.4 Named properties and 6.1.1.6 Named sequences
+pub fn parse_psl_property_declaration(stream: &TokenStream) -> ParseResult<PslPropertyDeclaration> {
+    let kind = expect_token!(
+        stream,
+        token,
+        Property => PslPropertyKind::Property,
+        Sequence => PslPropertyKind::Sequence
+    );
+    let ident = WithDecl::new(stream.expect_ident()?);
+
+    let mut parameters = Vec::new();
+    if stream.skip_if_kind(LeftPar) {
+        // Each parameter specification is a parameter kind followed by a list of names
+        // The names are the identifiers directly followed by a comma, semi colon or right parenthesis
+        let mut depth = 0;
+        loop {
+            let token = stream.peek_expect()?;
+            match token.kind {
+                LeftPar => depth += 1,
+                RightPar if depth == 0 => {
+                    stream.skip();
+                    break;
+                }
+                RightPar => depth -= 1,
+                Identifier
+                    if depth == 0
+                        && (stream.nth_kind_is(1, Comma)
+                            || stream.nth_kind_is(1, SemiColon)
+                            || stream.nth_kind_is(1, RightPar)) =>
+                {
+                    parameters.push(WithDecl::new(token.to_identifier_value()?));
+                }
+                _ => {}
+            }
+            stream.skip();
+        }
+    }
+
+    stream.expect_kind(Is)?;
+    let body = parse_psl_property(stream)?;
+    stream.expect_kind(SemiColon)?;
+
+    Ok(PslPropertyDeclaration {
+        kind,
+        ident,
+        parameters,
+        body,
+    })
+}
+
+/// PSL 5.3 Default clock declaration
+pub fn parse_psl_default_clock(stream: &TokenStream) -> ParseResult<WithPos<PslExpression>> {
+    stream.expect_kind(Default)?;
+    expect_psl_word(stream, "clock")?;
+    stream.expect_kind(Is)?;
+    let clock = parse_psl_property(stream)?;
+    stream.expect_kind(SemiColon)?;
+    Ok(clock)
+}
+
+/// The property of a directive, or the booleans of a fairness directive
+fn parse_psl_directive_property(
+    stream: &TokenStream,
+    kind: PslDirectiveKind,
+) -> ParseResult<WithPos<PslExpression>> {
+    let property = parse_psl_property(stream)?;
+    if matches!(
+        kind,
+        PslDirectiveKind::Fairness | PslDirectiveKind::StrongFairness
+    ) {
+        if let Some(token) = stream.pop_if_kind(Comma) {
+            let rhs = parse_psl_property(stream)?;
+            return Ok(psl_binary(
+                PslBinaryOperator::Fairness,
+                token.pos.clone(),
+                property,
+                rhs,
+            ));
+        }
+    }
+    Ok(property)
+}
+
+/// PSL 7.1 Verification directives
+/// A property which cannot be parsed is skipped so that the rest of the design unit is parsed
+pub fn parse_psl_directive(
+    stream: &TokenStream,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<PslDirective> {
+    let kind = expect_token!(
+        stream,
+        token,
+        Assert => PslDirectiveKind::Assert,
+        Assume => PslDirectiveKind::Assume,
+        AssumeGuarantee => PslDirectiveKind::AssumeGuarantee,
+        Restrict => PslDirectiveKind::Restrict,
+        RestrictGuarantee => PslDirectiveKind::RestrictGuarantee,
+        Cover => PslDirectiveKind::Cover,
+        Fairness => PslDirectiveKind::Fairness,
+        Strong => {
+            stream.expect_kind(Fairness)?;
+            PslDirectiveKind::StrongFairness
+        }
+    );
+
+    let property = match parse_psl_directive_property(stream, kind) {
+        Ok(property) => Some(property),
+        Err(err) => {
+            diagnostics.push(err);
+            skip_psl_statement(stream)?;
+            None
+        }
+    };
+    let report = parse_optional(stream, Report, parse_expression)?;
+    let severity = parse_optional(stream, Severity, parse_expression)?;
+    stream.expect_kind(SemiColon)?;
+
+    Ok(PslDirective {
+        kind,
+        property,
+        report,
+        severity,
+    })
+}
+
+fn is_vunit_item_end(kind: Kind) -> bool {
+    !is_declaration_start(kind)
+}
+
+/// PSL 7.2 Verification units
+pub fn parse_verification_unit(
+    stream: &TokenStream,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<VerificationUnit> {
+    let kind = expect_token!(
+        stream,
+        token,
+        Vunit => VerificationUnitKind::Vunit,
+        Vmode => VerificationUnitKind::Vmode,
+        Vprop => VerificationUnitKind::Vprop
+    );
+    let ident = WithDecl::new(stream.expect_ident()?);
+
+    let binding = if stream.skip_if_kind(LeftPar) {
+        let entity_name = stream.expect_ident()?.into_ref();
+        let architecture_name = if stream.skip_if_kind(LeftPar) {
+            let architecture_name = stream.expect_ident()?.into_ref();
+            stream.expect_kind(RightPar)?;
+            Some(architecture_name)
+        } else {
+            None
+        };
+        stream.expect_kind(RightPar)?;
+        Some(VerificationUnitBinding {
+            entity_name,
+            architecture_name,
+        })
+    } else {
+        None
+    };
+
+    stream.expect_kind(LeftCurly)?;
+
+    let mut inherits = Vec::new();
+    let mut decl = Vec::new();
+    let mut statements = Vec::new();
+
+    loop {
+        let token = stream.peek_expect()?;
+        if token.kind == RightCurly {
+            stream.skip();
+            break;
+        } else if is_psl_word(token, "inherit") {
+            stream.skip();
+            loop {
+                inherits.push(stream.expect_ident()?.into_ref());
+                expect_token!(
+                    stream,
+                    token,
+                    Comma => continue,
+                    SemiColon => break
+                );
+            }
+        } else if is_declaration_start(token.kind) {
+            decl.append(&mut parse_declarative_part_until(
+                stream,
+                diagnostics,
+                is_vunit_item_end,
+            )?);
+        } else {
+            statements.push(parse_labeled_concurrent_statement(stream, diagnostics)?);
+        }
+    }
+
+    Ok(VerificationUnit {
+        context_clause: ContextClause::default(),
+        kind,
+        ident,
+        binding,
+        inherits,
+        decl,
+        statements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::Diagnostic;
+    use crate::syntax::design_unit::parse_design_file;
+    use crate::syntax::test::Code;
+
+    fn hdl(code: &Code) -> WithPos<PslExpression> {
+        WithPos::new(PslExpression::Hdl(code.expr()), code.pos())
+    }
+
+    fn count(code: &Code) -> Option<PslCount> {
+        Some(PslCount {
+            low: code.expr(),
+            high: None,
+        })
+    }
+
+    #[test]
+    fn parse_property_declaration() {
+        let code = Code::new(
+            "property p(boolean a, b; const n) is always (a -> next[n] b) @ rising_edge(clk);",
+        );
+        let implication = psl_binary(
+            PslBinaryOperator::Implication,
+            code.s1("->").pos(),
+            hdl(&code.s1("(a").s1("a")),
+            psl_unary(
+                PslUnaryOperator::Next {
+                    kind: PslNextKind::Next,
+                    strong: false,
+                    count: count(&code.s1("[n]").s1("n")),
+                },
+                code.s1("next").pos(),
+                hdl(&code.s1("b)").s1("b")),
+            ),
+        );
+        assert_eq!(
+            code.with_stream(parse_psl_property_declaration),
+            PslPropertyDeclaration {
+                kind: PslPropertyKind::Property,
+                ident: code.s1("p(").s1("p").decl_ident(),
+                parameters: vec![
+                    code.s1("a,").s1("a").decl_ident(),
+                    code.s1("b;").s1("b").decl_ident(),
+                    code.s1("n)").s1("n").decl_ident(),
+                ],
+                body: psl_unary(
+                    PslUnaryOperator::Always,
+                    code.s1("always").pos(),
+                    psl_binary(
+                        PslBinaryOperator::Clock,
+                        code.s1("@").pos(),
+                        WithPos::new(implication.item, code.s1("(a -> next[n] b)").pos()),
+                        hdl(&code.s1("rising_edge(clk)")),
+                    ),
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_sequence_declaration() {
+        let code = Code::new("sequence seq is {req; ack[*1 to 3]; done};");
+        let repetition = WithPos::new(
+            PslExpression::Repetition(
+                Some(Box::new(hdl(&code.s1("ack")))),
+                PslRepetition::Consecutive,
+                Some(PslCount {
+                    low: code.s1("1").expr(),
+                    high: Some(PslCountBound::Expression(code.s1("3").expr())),
+                }),
+            ),
+            code.s1("ack[*1 to 3]").pos(),
+        );
+        let sere = psl_binary(
+            PslBinaryOperator::Concatenation,
+            code.s1("; done").s1(";").pos(),
+            psl_binary(
+                PslBinaryOperator::Concatenation,
+                code.s1("; ack").s1(";").pos(),
+                hdl(&code.s1("req")),
+                repetition,
+            ),
+            hdl(&code.s1("done")),
+        );
+        assert_eq!(
+            code.with_stream(parse_psl_property_declaration),
+            PslPropertyDeclaration {
+                kind: PslPropertyKind::Sequence,
+                ident: code.s("seq", 2).decl_ident(),
+                parameters: vec![],
+                body: WithPos::new(
+                    PslExpression::Sere(Box::new(sere)),
+                    code.s1("{req; ack[*1 to 3]; done}").pos()
+                ),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_default_clock() {
+        let code = Code::new("default clock is clk'event and clk = '1';");
+        assert_eq!(
+            code.with_stream(parse_psl_default_clock),
+            psl_binary(
+                PslBinaryOperator::And,
+                code.s1("and").pos(),
+                hdl(&code.s1("clk'event")),
+                hdl(&code.s1("clk = '1'")),
+            )
+        );
+    }
+
+    #[test]
+    fn parse_directives() {
+        let code = Code::new("cover {req; ack} report \"covered\";");
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_psl_directive),
+            PslDirective {
+                kind: PslDirectiveKind::Cover,
+                property: Some(WithPos::new(
+                    PslExpression::Sere(Box::new(psl_binary(
+                        PslBinaryOperator::Concatenation,
+                        code.s1(";").pos(),
+                        hdl(&code.s1("req")),
+                        hdl(&code.s1("ack")),
+                    ))),
+                    code.s1("{req; ack}").pos()
+                )),
+                report: Some(code.s1("\"covered\"").expr()),
+                severity: None,
+            }
+        );
+
+        let code = Code::new("strong fairness req, ack;");
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_psl_directive),
+            PslDirective {
+                kind: PslDirectiveKind::StrongFairness,
+                property: Some(psl_binary(
+                    PslBinaryOperator::Fairness,
+                    code.s1(",").pos(),
+                    hdl(&code.s1("req")),
+                    hdl(&code.s1("ack")),
+                )),
+                report: None,
+                severity: None,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_sequence_operators() {
+        let code = Code::new("{req[->2] : busy[*]; done} |=> ack until! rdy");
+        let property = code.with_stream(parse_psl_property);
+        let (op, lhs, rhs) = if let PslExpression::Binary(op, lhs, rhs) = property.item {
+            (op, lhs, rhs)
+        } else {
+            panic!("Expected binary operator");
+        };
+        assert_eq!(op.item, PslBinaryOperator::NonOverlappingImplication);
+        assert_eq!(op.pos, code.s1("|=>").pos());
+        assert_eq!(lhs.pos, code.s1("{req[->2] : busy[*]; done}").pos());
+        assert_eq!(
+            rhs.item,
+            PslExpression::Binary(
+                WithPos::new(
+                    PslBinaryOperator::Until {
+                        strong: true,
+                        inclusive: false
+                    },
+                    code.s1("until!").pos()
+                ),
+                Box::new(hdl(&code.s1("ack"))),
+                Box::new(hdl(&code.s1("rdy"))),
+            )
+        );
+    }
+
+    #[test]
+    fn parse_psl_assert_is_distinguished_from_vhdl_assert() {
+        let code = Code::new("assert always req -> eventually! ack;");
+        let stmt = code.with_stream_no_diagnostics(parse_labeled_concurrent_statement);
+        assert_eq!(
+            stmt.statement.item,
+            ConcurrentStatement::Psl(PslDirective {
+                kind: PslDirectiveKind::Assert,
+                property: Some(psl_unary(
+                    PslUnaryOperator::Always,
+                    code.s1("always").pos(),
+                    psl_binary(
+                        PslBinaryOperator::Implication,
+                        code.s1("->").pos(),
+                        hdl(&code.s1("req")),
+                        psl_unary(
+                            PslUnaryOperator::Eventually,
+                            code.s1("eventually!").pos(),
+                            hdl(&code.s1("ack")),
+                        ),
+                    ),
+                )),
+                report: None,
+                severity: None,
+            })
+        );
+
+        let code = Code::new("assert req -> next ack;");
+        let stmt = code.with_stream_no_diagnostics(parse_labeled_concurrent_statement);
+        assert!(matches!(
+            stmt.statement.item,
+            ConcurrentStatement::Psl(PslDirective {
+                kind: PslDirectiveKind::Assert,
+                property: Some(..),
+                ..
+            })
+        ));
+
+        let code = Code::new("assert req report \"msg\";");
+        let stmt = code.with_stream_no_diagnostics(parse_labeled_concurrent_statement);
+        assert!(matches!(
+            stmt.statement.item,
+            ConcurrentStatement::Assert(..)
+        ));
+    }
+
+    #[test]
+    fn unparsable_psl_directive_is_skipped() {
+        let code = Code::new(
+            "\
+vunit checker {
+  assert always {req; ack |-> ;
+  cover {req};
+}",
+        );
+        let (unit, diagnostics) = code.with_stream_diagnostics(parse_verification_unit);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                code.s1("|-> ;").s1(";").pos(),
+                "Expected {expression}"
+            )]
+        );
+        assert_eq!(unit.statements.len(), 2);
+        assert!(matches!(
+            unit.statements[0].statement.item,
+            ConcurrentStatement::Psl(PslDirective { property: None, .. })
+        ));
+        assert!(matches!(
+            unit.statements[1].statement.item,
+            ConcurrentStatement::Psl(PslDirective {
+                kind: PslDirectiveKind::Cover,
+                property: Some(..),
+                ..
+            })
+        ));
+    }
+    #[test]
+    fn unparsable_psl_comment_is_skipped_until_its_semi_colon() {
+        let code = Code::new(
+            "\
+architecture rtl of ent is
+begin
+  -- psl endpoint e_done is {req;
+  --   ack};
+  -- psl assert always req -> next ack;
+end architecture;
+
+entity ent2 is
+end entity;",
+        );
+        let (design_file, diagnostics) = code.with_stream_diagnostics(parse_design_file);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::error(
+                code.s1("e_done").pos(),
+                "Expected '<=' or ';'"
+            )]
+        );
+        assert_eq!(design_file.design_units.len(), 2);
+        if let AnyDesignUnit::Secondary(AnySecondaryUnit::Architecture(ref arch)) =
+            design_file.design_units[0]
+        {
+            assert_eq!(arch.statements.len(), 1);
+            assert!(matches!(
+                arch.statements[0].statement.item,
+                ConcurrentStatement::Psl(PslDirective {
+                    kind: PslDirectiveKind::Assert,
+                    property: Some(..),
+                    ..
+                })
+            ));
+        } else {
+            panic!("Expected architecture");
+        }
+    }
+
+    #[test]
+    fn parse_vunit() {
+        let code = Code::new(
+            "\
+vunit checker(ent(rtl)) {
+  inherit common, other;
+  default clock is rising_edge(clk);
+  signal s : bit;
+  assert always req -> next ack;
+  lbl: cover {req};
+}",
+        );
+        let unit = code.with_stream_no_diagnostics(parse_verification_unit);
+        assert_eq!(unit.kind, VerificationUnitKind::Vunit);
+        assert_eq!(unit.ident, code.s1("checker").decl_ident());
+        assert_eq!(
+            unit.binding,
+            Some(VerificationUnitBinding {
+                entity_name: code.s1("ent").ident().into_ref(),
+                architecture_name: Some(code.s1("rtl").ident().into_ref()),
+            })
+        );
+        assert_eq!(
+            unit.inherits,
+            vec![
+                code.s1("common").ident().into_ref(),
+                code.s1("other").ident().into_ref()
+            ]
+        );
+        assert_eq!(unit.decl.len(), 2);
+        assert!(matches!(unit.decl[0], Declaration::PslDefaultClock(..)));
+        assert!(matches!(unit.decl[1], Declaration::Object(..)));
+        assert_eq!(unit.statements.len(), 2);
+        assert_eq!(unit.statements[1].label.tree, Some(code.s1("lbl").ident()));
+        assert!(matches!(
+            unit.statements[0].statement.item,
+            ConcurrentStatement::Psl(PslDirective {
+                kind: PslDirectiveKind::Assert,
+                ..
+            })
+        ));
+    }
+}
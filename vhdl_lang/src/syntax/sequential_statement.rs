@@ -5,15 +5,19 @@
 // Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
 
 use super::common::parse_optional;
-use super::common::ParseResult;
+use super::common::{check_standard, ParseResult};
+use super::declarative_part::parse_declarative_part;
 use super::expression::parse_aggregate;
-use super::expression::{parse_choices, parse_expression};
+use super::expression::{
+    parse_choices, parse_conditional_or_unaffected_expression, parse_expression,
+};
 use super::names::parse_name;
 use super::range::parse_discrete_range;
 use super::tokens::{Kind::*, TokenStream};
 use super::waveform::{parse_delay_mechanism, parse_waveform};
 use crate::ast::*;
 use crate::data::*;
+use crate::standard::VHDLStandard;
 use crate::syntax::common::check_label_identifier_mismatch;
 
 /// LRM 10.2 Wait statement
@@ -235,6 +239,34 @@ fn parse_loop_statement(
     )
 }
 
+/// VHDL-2019 LRM 10.16 Sequential block statement
+fn parse_sequential_block(
+    stream: &TokenStream,
+    label: Option<&Ident>,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<SequentialBlock> {
+    let block = stream.expect_kind(Block)?;
+    check_standard(
+        stream,
+        &block.pos,
+        VHDLStandard::VHDL2019,
+        "Sequential block statement",
+    )?;
+    stream.pop_if_kind(Is);
+    let decl = parse_declarative_part(stream, diagnostics)?;
+    stream.expect_kind(Begin)?;
+    let statements = parse_labeled_sequential_statements(stream, diagnostics)?;
+    stream.expect_kind(End)?;
+    stream.pop_if_kind(Block);
+    let end_ident = stream.pop_optional_ident();
+    stream.expect_kind(SemiColon)?;
+    Ok(SequentialBlock {
+        decl,
+        statements,
+        end_label_pos: check_label_identifier_mismatch(label, end_ident, diagnostics),
+    })
+}
+
 /// LRM 10.11 Next statement
 fn parse_next_statement(stream: &TokenStream) -> ParseResult<NextStatement> {
     stream.expect_kind(Next)?;
@@ -263,33 +295,55 @@ fn parse_exit_statement(stream: &TokenStream) -> ParseResult<ExitStatement> {
 fn parse_return_statement(stream: &TokenStream) -> ParseResult<ReturnStatement> {
     stream.expect_kind(Return)?;
     let expression = {
-        if stream.peek_kind() == Some(SemiColon) {
+        if matches!(stream.peek_kind(), Some(SemiColon) | Some(When)) {
             None
         } else {
-            Some(parse_expression(stream)?)
+            Some(parse_conditional_or_unaffected_expression(stream)?)
         }
     };
+    let condition = if let Some(when) = stream.pop_if_kind(When) {
+        check_standard(
+            stream,
+            &when.pos,
+            VHDLStandard::VHDL2019,
+            "Conditional return",
+        )?;
+        Some(parse_expression(stream)?)
+    } else {
+        None
+    };
     stream.expect_kind(SemiColon)?;
-    Ok(ReturnStatement { expression })
+    Ok(ReturnStatement {
+        expression,
+        condition,
+    })
 }
 
-/// LRM 10.5 Signal assignment statement
+/// LRM 11.6 Concurrent signal assignment statement
 pub fn parse_signal_assignment_right_hand(
     stream: &TokenStream,
 ) -> ParseResult<AssignmentRightHand<Waveform>> {
-    parse_assignment_right_hand(stream, parse_waveform)
+    parse_assignment_right_hand(stream, parse_waveform, false)
+}
+
+/// LRM 10.5 Signal assignment statement
+fn parse_sequential_signal_assignment_right_hand(
+    stream: &TokenStream,
+) -> ParseResult<AssignmentRightHand<Waveform>> {
+    parse_assignment_right_hand(stream, parse_waveform, true)
 }
 
 /// LRM 10.6 Variable assignment statement
 fn parse_variable_assignment_right_hand(
     stream: &TokenStream,
 ) -> ParseResult<AssignmentRightHand<WithPos<Expression>>> {
-    parse_assignment_right_hand(stream, parse_expression)
+    parse_assignment_right_hand(stream, parse_expression, true)
 }
 
 fn parse_assignment_right_hand<T, F>(
     stream: &TokenStream,
     parse_item: F,
+    is_sequential: bool,
 ) -> ParseResult<AssignmentRightHand<T>>
 where
     F: Fn(&TokenStream) -> ParseResult<T>,
@@ -300,6 +354,9 @@ where
         stream,
         token,
         When => {
+            if is_sequential {
+                check_standard(stream, &token.pos, VHDLStandard::VHDL2008, "Sequential conditional assignment")?;
+            }
             Ok(AssignmentRightHand::Conditional(parse_conditonals(stream, item, parse_item)?))
 
         },
@@ -447,7 +504,7 @@ fn parse_assignment_or_procedure_call(
                     SequentialStatement::SignalAssignment(SignalAssignment {
                         target,
                         delay_mechanism,
-                        rhs: parse_signal_assignment_right_hand(stream)?
+                        rhs: parse_sequential_signal_assignment_right_hand(stream)?
                     })
                 }
             }
@@ -532,6 +589,9 @@ fn parse_unlabeled_sequential_statement(
             Next => SequentialStatement::Next(parse_next_statement(stream)?),
             Exit => SequentialStatement::Exit(parse_exit_statement(stream)?),
             Return => SequentialStatement::Return(parse_return_statement(stream)?),
+            Block => {
+                SequentialStatement::Block(parse_sequential_block(stream, label, diagnostics)?)
+            },
             Null => {
                 stream.skip();
                 stream.expect_kind(SemiColon)?;
@@ -539,6 +599,7 @@ fn parse_unlabeled_sequential_statement(
             },
             With => {
                 stream.skip();
+                check_standard(stream, &token.pos, VHDLStandard::VHDL2008, "Sequential selected assignment")?;
                 parse_selected_assignment(stream)?
             },
             Identifier|LeftPar|LtLt => {
@@ -1820,7 +1881,10 @@ end loop;",
             with_label(
                 None,
                 WithPos::new(
-                    SequentialStatement::Return(ReturnStatement { expression: None }),
+                    SequentialStatement::Return(ReturnStatement {
+                        expression: None,
+                        condition: None,
+                    }),
                     code.pos()
                 )
             )
@@ -1838,6 +1902,54 @@ end loop;",
                 WithPos::new(
                     SequentialStatement::Return(ReturnStatement {
                         expression: Some(code.s1("1 + 2").expr()),
+                        condition: None,
+                    }),
+                    code.pos()
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn parse_return_statement_condition() {
+        let code = Code::with_standard("return when cond;", VHDLStandard::VHDL2019);
+        let statement = parse_stmt(&code);
+        assert_eq!(
+            statement,
+            with_label(
+                None,
+                WithPos::new(
+                    SequentialStatement::Return(ReturnStatement {
+                        expression: None,
+                        condition: Some(code.s1("cond").expr()),
+                    }),
+                    code.pos()
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn parse_return_statement_conditional_expression() {
+        let code = Code::with_standard("return val1 when cond;", VHDLStandard::VHDL2019);
+        let statement = parse_stmt(&code);
+        assert_eq!(
+            statement,
+            with_label(
+                None,
+                WithPos::new(
+                    SequentialStatement::Return(ReturnStatement {
+                        expression: Some(WithPos::new(
+                            Expression::Conditional(Box::new(Conditionals {
+                                conditionals: vec![Conditional {
+                                    condition: code.s1("cond").expr(),
+                                    item: code.s1("val1").expr(),
+                                }],
+                                else_item: None,
+                            })),
+                            code.s1("val1 when cond").pos()
+                        )),
+                        condition: None,
                     }),
                     code.pos()
                 )
@@ -1845,6 +1957,102 @@ end loop;",
         );
     }
 
+    #[test]
+    fn parse_return_statement_condition_requires_vhdl2019() {
+        let code = Code::new("return when cond;");
+        let (result, _) = code.with_partial_stream_diagnostics(parse_sequential_statement);
+        assert_eq!(
+            result,
+            Err(Diagnostic::error(
+                code.s1("when"),
+                "Conditional return requires VHDL-2019 or later"
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_sequential_block() {
+        let code = Code::with_standard(
+            "\
+blk: block is
+  variable v : natural;
+begin
+  v := 0;
+end block blk;",
+            VHDLStandard::VHDL2019,
+        );
+        let statement = parse_stmt(&code);
+        assert_eq!(
+            statement,
+            with_label(
+                Some(code.s1("blk").ident()),
+                WithPos::new(
+                    SequentialStatement::Block(SequentialBlock {
+                        decl: code.s1("variable v : natural;").declarative_part(),
+                        statements: vec![code.s1("v := 0;").sequential_statement()],
+                        end_label_pos: Some(code.s("blk", 2).pos()),
+                    }),
+                    code.pos_after("blk: ")
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn parse_sequential_block_requires_vhdl2019() {
+        let code = Code::new(
+            "\
+block
+begin
+end block;",
+        );
+        let (result, _) = code.with_partial_stream_diagnostics(parse_sequential_statement);
+        assert_eq!(
+            result,
+            Err(Diagnostic::error(
+                code.s1("block"),
+                "Sequential block statement requires VHDL-2019 or later"
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_sequential_conditional_assignment_requires_vhdl2008() {
+        for code in [
+            "foo := bar when cond else baz;",
+            "foo <= bar when cond else baz;",
+        ] {
+            let code = Code::with_standard(code, VHDLStandard::VHDL2002);
+            let (result, _) = code.with_partial_stream_diagnostics(parse_sequential_statement);
+            assert_eq!(
+                result,
+                Err(Diagnostic::error(
+                    code.s1("when"),
+                    "Sequential conditional assignment requires VHDL-2008 or later"
+                ))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_sequential_selected_assignment_requires_vhdl2008() {
+        let code = Code::with_standard(
+            "\
+with sel select
+  foo <= bar when '0',
+         baz when others;",
+            VHDLStandard::VHDL1993,
+        );
+        let (result, _) = code.with_partial_stream_diagnostics(parse_sequential_statement);
+        assert_eq!(
+            result,
+            Err(Diagnostic::error(
+                code.s1("with"),
+                "Sequential selected assignment requires VHDL-2008 or later"
+            ))
+        );
+    }
+
     #[test]
     fn parse_null_statement() {
         let (code, statement) = parse("null;");
@@ -6,8 +6,8 @@
 
 use super::common::{check_end_identifier_mismatch, ParseResult};
 use super::declarative_part::parse_declarative_part;
-use super::interface_declaration::parse_parameter_interface_list;
-use super::names::parse_type_mark;
+use super::interface_declaration::{parse_generic_interface_list, parse_parameter_interface_list};
+use super::names::{parse_association_list, parse_selected_name, parse_type_mark};
 use super::sequential_statement::parse_labeled_sequential_statements;
 use super::tokens::{kinds_error, Kind::*, TokenStream};
 use crate::ast::*;
@@ -98,9 +98,10 @@ pub fn parse_subprogram_declaration_no_semi(
     };
 
     let designator = parse_designator(stream)?;
+    let header = parse_subprogram_header(stream, diagnostics)?;
 
     let parameter_list = {
-        if stream.peek_kind() == Some(LeftPar) {
+        if stream.skip_if_kind(Parameter) || stream.peek_kind() == Some(LeftPar) {
             parse_parameter_interface_list(stream, diagnostics)?
         } else {
             Vec::new()
@@ -113,17 +114,81 @@ pub fn parse_subprogram_declaration_no_semi(
         Ok(SubprogramDeclaration::Function(FunctionSpecification {
             pure: is_pure,
             designator: designator.into(),
+            header,
             parameter_list,
             return_type,
         }))
     } else {
         Ok(SubprogramDeclaration::Procedure(ProcedureSpecification {
             designator: designator.into(),
+            header,
             parameter_list,
         }))
     }
 }
 
+/// LRM 4.2.1 Subprogram header
+fn parse_subprogram_header(
+    stream: &TokenStream,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<Option<SubprogramHeader>> {
+    if !stream.skip_if_kind(Generic) {
+        return Ok(None);
+    }
+
+    let generic_list = parse_generic_interface_list(stream, diagnostics)?;
+    let generic_map = if stream.next_kinds_are(&[Generic, Map]) {
+        stream.skip();
+        stream.skip();
+        Some(parse_association_list(stream)?)
+    } else {
+        None
+    };
+
+    Ok(Some(SubprogramHeader {
+        generic_list,
+        generic_map,
+    }))
+}
+
+/// LRM 4.4 Subprogram instantiation declaration
+pub fn parse_subprogram_instantiation(
+    stream: &TokenStream,
+) -> ParseResult<SubprogramInstantiation> {
+    let kind = expect_token!(
+        stream,
+        token,
+        Function => SubprogramKind::Function,
+        Procedure => SubprogramKind::Procedure
+    );
+    let ident = stream.expect_ident()?;
+    stream.expect_kind(Is)?;
+    stream.expect_kind(New)?;
+    let subprogram_name = parse_selected_name(stream)?;
+
+    let signature = if stream.peek_kind() == Some(LeftSquare) {
+        Some(parse_signature(stream)?)
+    } else {
+        None
+    };
+
+    let generic_map = if stream.skip_if_kind(Generic) {
+        stream.expect_kind(Map)?;
+        Some(parse_association_list(stream)?)
+    } else {
+        None
+    };
+    stream.expect_kind(SemiColon)?;
+
+    Ok(SubprogramInstantiation {
+        kind,
+        ident: ident.into(),
+        subprogram_name,
+        signature,
+        generic_map,
+    })
+}
+
 pub fn parse_subprogram_declaration(
     stream: &TokenStream,
     diagnostics: &mut dyn DiagnosticHandler,
@@ -176,6 +241,14 @@ pub fn parse_subprogram(
     stream: &TokenStream,
     diagnostics: &mut dyn DiagnosticHandler,
 ) -> ParseResult<Declaration> {
+    if stream.next_kinds_are(&[Function, Identifier, Is, New])
+        || stream.next_kinds_are(&[Procedure, Identifier, Is, New])
+    {
+        return Ok(Declaration::SubprogramInstantiation(
+            parse_subprogram_instantiation(stream)?,
+        ));
+    }
+
     let specification = parse_subprogram_declaration_no_semi(stream, diagnostics)?;
     expect_token!(
         stream,
@@ -210,6 +283,7 @@ procedure foo;
                     .ident()
                     .map_into(SubprogramDesignator::Identifier)
                     .into(),
+                header: None,
                 parameter_list: Vec::new(),
             })
         );
@@ -231,6 +305,7 @@ function foo return lib.foo.natural;
                     .ident()
                     .map_into(SubprogramDesignator::Identifier)
                     .into(),
+                header: None,
                 parameter_list: Vec::new(),
                 return_type: code.s1("lib.foo.natural").type_mark()
             })
@@ -253,6 +328,7 @@ function \"+\" return lib.foo.natural;
                     pos: code.s1("\"+\"").pos()
                 }
                 .into(),
+                header: None,
                 parameter_list: Vec::new(),
                 return_type: code.s1("lib.foo.natural").type_mark()
             })
@@ -275,6 +351,7 @@ impure function foo return lib.foo.natural;
                     .ident()
                     .map_into(SubprogramDesignator::Identifier)
                     .into(),
+                header: None,
                 parameter_list: Vec::new(),
                 return_type: code.s1("lib.foo.natural").type_mark()
             })
@@ -296,6 +373,7 @@ pure function foo return lib.foo.natural;
                     .ident()
                     .map_into(SubprogramDesignator::Identifier)
                     .into(),
+                header: None,
                 parameter_list: Vec::new(),
                 return_type: code.s1("lib.foo.natural").type_mark()
             })
@@ -316,6 +394,7 @@ procedure foo(foo : natural);
                     .ident()
                     .map_into(SubprogramDesignator::Identifier)
                     .into(),
+                header: None,
                 parameter_list: vec![code.s1("foo : natural").parameter()],
             })
         );
@@ -337,6 +416,7 @@ function foo(foo : natural) return lib.foo.natural;
                     .ident()
                     .map_into(SubprogramDesignator::Identifier)
                     .into(),
+                header: None,
                 parameter_list: vec![code.s1("foo : natural").parameter()],
                 return_type: code.s1("lib.foo.natural").type_mark()
             })
@@ -468,6 +548,97 @@ function foo(arg : natural) return natural;
         );
     }
 
+    #[test]
+    pub fn parses_generic_function_specification() {
+        let code = Code::new(
+            "\
+function foo generic (type T; constant c : natural) parameter (arg : T) return T;
+",
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_subprogram_declaration),
+            SubprogramDeclaration::Function(FunctionSpecification {
+                pure: true,
+                designator: code
+                    .s1("foo")
+                    .ident()
+                    .map_into(SubprogramDesignator::Identifier)
+                    .into(),
+                header: Some(SubprogramHeader {
+                    generic_list: vec![
+                        code.s1("type T").generic(),
+                        code.s1("constant c : natural").generic()
+                    ],
+                    generic_map: None,
+                }),
+                parameter_list: vec![code.s1("arg : T").parameter()],
+                return_type: code.s("T", 3).type_mark()
+            })
+        );
+    }
+
+    #[test]
+    pub fn parses_generic_procedure_specification_with_generic_map() {
+        let code = Code::new(
+            "\
+procedure foo generic (type T) generic map (T => natural) (arg : T);
+",
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_subprogram_declaration),
+            SubprogramDeclaration::Procedure(ProcedureSpecification {
+                designator: code
+                    .s1("foo")
+                    .ident()
+                    .map_into(SubprogramDesignator::Identifier)
+                    .into(),
+                header: Some(SubprogramHeader {
+                    generic_list: vec![code.s1("type T").generic()],
+                    generic_map: Some(code.s1("(T => natural)").association_list()),
+                }),
+                parameter_list: vec![code.s1("arg : T").parameter()],
+            })
+        );
+    }
+
+    #[test]
+    pub fn parses_subprogram_instantiation() {
+        let code = Code::new(
+            "\
+function foo is new lib.pkg.bar;
+",
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_subprogram),
+            Declaration::SubprogramInstantiation(SubprogramInstantiation {
+                kind: SubprogramKind::Function,
+                ident: code.s1("foo").decl_ident(),
+                subprogram_name: code.s1("lib.pkg.bar").selected_name(),
+                signature: None,
+                generic_map: None,
+            })
+        );
+    }
+
+    #[test]
+    pub fn parses_subprogram_instantiation_with_signature_and_generic_map() {
+        let code = Code::new(
+            "\
+procedure foo is new bar [natural] generic map (T => natural);
+",
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_subprogram),
+            Declaration::SubprogramInstantiation(SubprogramInstantiation {
+                kind: SubprogramKind::Procedure,
+                ident: code.s1("foo").decl_ident(),
+                subprogram_name: code.s1("bar").selected_name(),
+                signature: Some(code.s1("[natural]").signature()),
+                generic_map: Some(code.s1("(T => natural)").association_list()),
+            })
+        );
+    }
+
     #[test]
     pub fn parses_subprogram_body_end_ident() {
         let code = Code::new(
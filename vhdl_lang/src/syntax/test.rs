@@ -21,13 +21,14 @@ use super::range::{parse_discrete_range, parse_range};
 use super::sequential_statement::parse_sequential_statement;
 use super::subprogram::{parse_signature, parse_subprogram_declaration_no_semi};
 use super::subtype_indication::parse_subtype_indication;
-use super::tokens::{Comment, Kind, Symbols, Token, TokenStream, Tokenizer};
+use super::tokens::{Comment, Conditions, Kind, Symbols, Token, TokenStream, Tokenizer};
 use super::type_declaration::parse_type_declaration;
 use super::waveform::parse_waveform;
 use crate::ast;
 use crate::ast::*;
 use crate::data::Range;
 use crate::data::*;
+use crate::standard::VHDLStandard;
 use std::collections::hash_map::DefaultHasher;
 use std::collections::hash_map::Entry;
 use std::collections::HashMap;
@@ -37,12 +38,21 @@ use std::sync::Arc;
 
 pub struct CodeBuilder {
     pub symbols: Arc<Symbols>,
+    pub standard: VHDLStandard,
 }
 
 impl CodeBuilder {
     pub fn new() -> CodeBuilder {
         CodeBuilder {
             symbols: Arc::new(Symbols::default()),
+            standard: VHDLStandard::default(),
+        }
+    }
+
+    pub fn with_standard(standard: VHDLStandard) -> CodeBuilder {
+        CodeBuilder {
+            symbols: Arc::new(Symbols::default()),
+            standard,
         }
     }
 
@@ -53,6 +63,7 @@ impl CodeBuilder {
 
         let code = Code {
             symbols: self.symbols.clone(),
+            standard: self.standard,
             pos,
         };
 
@@ -80,6 +91,7 @@ impl CodeBuilder {
 #[derive(Clone)]
 pub struct Code {
     pub symbols: Arc<Symbols>,
+    standard: VHDLStandard,
     pos: SrcPos,
 }
 
@@ -88,6 +100,10 @@ impl Code {
         CodeBuilder::new().code(code)
     }
 
+    pub fn with_standard(code: &str, standard: VHDLStandard) -> Code {
+        CodeBuilder::with_standard(standard).code(code)
+    }
+
     pub fn new_with_file_name(file_name: &Path, code: &str) -> Code {
         CodeBuilder::new().code_with_file_name(file_name, code)
     }
@@ -95,6 +111,7 @@ impl Code {
     fn in_range(&self, range: Range) -> Code {
         Code {
             symbols: self.symbols.clone(),
+            standard: self.standard,
             pos: SrcPos::new(self.pos.source.clone(), range),
         }
     }
@@ -170,7 +187,8 @@ impl Code {
         {
             let contents = self.pos.source.contents();
             let reader = ContentReader::new(&contents);
-            let mut tokenizer = Tokenizer::new(&self.symbols, &self.pos.source, reader);
+            let mut tokenizer =
+                Tokenizer::new(&self.symbols, &self.pos.source, reader, self.standard);
             loop {
                 let token = tokenizer.pop();
 
@@ -206,8 +224,8 @@ impl Code {
         );
         let contents = source.contents();
         let reader = ContentReader::new(&contents);
-        let tokenizer = Tokenizer::new(&self.symbols, &source, reader);
-        let mut stream = TokenStream::new(tokenizer, &mut NoDiagnostics);
+        let tokenizer = Tokenizer::new(&self.symbols, &source, reader, self.standard);
+        let mut stream = TokenStream::new(tokenizer, &Conditions::default(), &mut NoDiagnostics);
         forward(&stream, self.pos.start());
         parse_fun(&mut stream)
     }
@@ -231,8 +249,8 @@ impl Code {
     {
         let contents = self.pos.source.contents();
         let reader = ContentReader::new(&contents);
-        let tokenizer = Tokenizer::new(&self.symbols, &self.pos.source, reader);
-        let mut stream = TokenStream::new(tokenizer, &mut NoDiagnostics);
+        let tokenizer = Tokenizer::new(&self.symbols, &self.pos.source, reader, self.standard);
+        let mut stream = TokenStream::new(tokenizer, &Conditions::default(), &mut NoDiagnostics);
         parse_fun(&mut stream)
     }
 
@@ -4,9 +4,11 @@
 //
 // Copyright (c) 2019, Olof Kraigher olof.kraigher@gmail.com
 
+mod conditional;
 #[macro_use]
 mod tokenizer;
 mod tokenstream;
 
+pub use conditional::Conditions;
 pub use tokenizer::*;
 pub use tokenstream::*;
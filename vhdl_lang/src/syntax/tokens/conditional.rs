@@ -0,0 +1,527 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+//! LRM 24.2 Conditional analysis
+
+use super::tokenizer::Kind::*;
+use super::tokenizer::*;
+use crate::data::{
+    Diagnostic, DiagnosticHandler, DiagnosticResult, NullDiagnostics, Position, Severity, SrcPos,
+};
+use crate::standard::VHDLStandard;
+use fnv::FnvHashMap;
+
+/// The values of the conditional analysis identifiers
+/// VHDL_VERSION is defined by the standard unless set explicitly
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Conditions {
+    // Identifiers are case insensitive and stored in upper case
+    values: FnvHashMap<String, String>,
+}
+
+// Kind::Default shadows the prelude
+impl std::default::Default for Conditions {
+    /// The predefined conditional analysis identifiers
+    fn default() -> Self {
+        let mut conditions = Conditions {
+            values: FnvHashMap::default(),
+        };
+        conditions.set("TOOL_NAME", "vhdl_ls");
+        conditions.set("TOOL_VENDOR", "rust_hdl");
+        conditions.set("TOOL_VERSION", env!("CARGO_PKG_VERSION"));
+        conditions
+    }
+}
+
+impl Conditions {
+    pub fn set(&mut self, name: &str, value: &str) {
+        self.values.insert(name.to_uppercase(), value.to_owned());
+    }
+
+    pub fn get(&self, name: &str) -> Option<&str> {
+        self.values
+            .get(&name.to_uppercase())
+            .map(|value| value.as_str())
+    }
+}
+
+/// An open `if directive
+struct Branch {
+    // The position of the `if directive
+    pos: SrcPos,
+    // The enclosing region is active
+    parent_active: bool,
+    // The current branch is active
+    active: bool,
+    // Any branch so far was active
+    taken: bool,
+    // The `else directive has been seen
+    has_else: bool,
+}
+
+struct ConditionalAnalysis<'a> {
+    standard: VHDLStandard,
+    conditions: &'a Conditions,
+    tokens: std::iter::Peekable<std::vec::IntoIter<Token>>,
+    branches: Vec<Branch>,
+    // The end of the last token that was consumed
+    last_end: Position,
+    // The start of the current inactive region
+    inactive_start: Option<Position>,
+    inactive_regions: Vec<SrcPos>,
+}
+
+impl<'a> ConditionalAnalysis<'a> {
+    fn is_active(&self) -> bool {
+        self.branches
+            .last()
+            .map(|branch| branch.parent_active && branch.active)
+            .unwrap_or(true)
+    }
+
+    fn next_token(&mut self) -> Option<Token> {
+        let token = self.tokens.next()?;
+        self.last_end = token.pos.end();
+        Some(token)
+    }
+
+    /// Open or close an inactive region after a directive changed the active state
+    /// The region starts after the directive and ends at the start of the directive
+    fn update_region(&mut self, was_active: bool, directive: &SrcPos) {
+        let is_active = self.is_active();
+        if was_active && !is_active {
+            self.inactive_start = Some(self.last_end);
+        } else if !was_active && is_active {
+            if let Some(start) = self.inactive_start.take() {
+                self.inactive_regions
+                    .push(directive.source.pos(start, directive.start()));
+            }
+        }
+    }
+
+    fn peek_kind(&mut self) -> Option<Kind> {
+        self.tokens.peek().map(|token| token.kind)
+    }
+
+    fn expect(&mut self, kind: Kind, after: &SrcPos) -> DiagnosticResult<Token> {
+        match self.tokens.peek() {
+            Some(token) if token.kind == kind => Ok(self.next_token().unwrap()),
+            Some(token) => Err(token.kinds_error(&[kind])),
+            None => Err(kinds_error(after.pos_at_end(), &[kind])),
+        }
+    }
+
+    /// Skip the remaining tokens on the line of the directive
+    fn skip_line(&mut self, directive: &SrcPos) {
+        while let Some(token) = self.tokens.peek() {
+            if token.pos.range.start.line != directive.range.start.line {
+                break;
+            }
+            self.next_token();
+        }
+    }
+
+    fn relation(
+        &mut self,
+        after: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> DiagnosticResult<bool> {
+        if self.peek_kind() == Some(Not) {
+            let not_token = self.next_token().unwrap();
+            let lpar = self.expect(LeftPar, &not_token.pos)?;
+            let value = self.expression(&lpar.pos, diagnostics)?;
+            self.expect(RightPar, after)?;
+            return Ok(!value);
+        } else if self.peek_kind() == Some(LeftPar) {
+            let lpar = self.next_token().unwrap();
+            let value = self.expression(&lpar.pos, diagnostics)?;
+            self.expect(RightPar, after)?;
+            return Ok(value);
+        }
+
+        let ident = self.expect(Identifier, after)?.to_identifier_value()?;
+        let op = match self.next_token() {
+            Some(token) if matches!(token.kind, EQ | NE | LT | LTE | GT | GTE) => token.kind,
+            Some(token) => return Err(token.kinds_error(&[EQ, NE, LT, LTE, GT, GTE])),
+            None => return Err(kinds_error(ident.pos.pos_at_end(), &[EQ])),
+        };
+        let string = self.expect(StringLiteral, &ident.pos)?.to_string_value()?;
+        let string = string.item.to_string();
+
+        let name = ident.item.name_utf8();
+        let value = if let Some(value) = self.conditions.get(&name) {
+            value
+        } else if name.eq_ignore_ascii_case("VHDL_VERSION") {
+            self.standard.as_str()
+        } else {
+            diagnostics.error(
+                &ident.pos,
+                format!("Undefined conditional analysis identifier '{name}'"),
+            );
+            return Ok(false);
+        };
+
+        Ok(match op {
+            EQ => value == string,
+            NE => value != string,
+            LT => value < string.as_str(),
+            LTE => value <= string.as_str(),
+            GT => value > string.as_str(),
+            _ => value >= string.as_str(),
+        })
+    }
+
+    fn expression(
+        &mut self,
+        after: &SrcPos,
+        diagnostics: &mut dyn DiagnosticHandler,
+    ) -> DiagnosticResult<bool> {
+        let mut value = self.relation(after, diagnostics)?;
+
+        while let Some(op) = self.peek_kind() {
+            if !matches!(op, And | Or | Xor | Xnor) {
+                break;
+            }
+            let op_token = self.next_token().unwrap();
+            let rhs = self.relation(&op_token.pos, diagnostics)?;
+            value = match op {
+                And => value && rhs,
+                Or => value || rhs,
+                Xor => value != rhs,
+                _ => value == rhs,
+            };
+        }
+
+        Ok(value)
+    }
+
+    /// Parse the condition of an `if or `elsif directive including the then keyword
+    /// Errors are only reported within active regions
+    fn condition(&mut self, directive: &SrcPos, diagnostics: &mut dyn DiagnosticHandler) -> bool {
+        let mut null_diagnostics = NullDiagnostics;
+        let diagnostics: &mut dyn DiagnosticHandler = if self.is_active() {
+            diagnostics
+        } else {
+            &mut null_diagnostics
+        };
+
+        let result = self.expression(directive, diagnostics).and_then(|value| {
+            self.expect(Then, directive)?;
+            Ok(value)
+        });
+
+        match result {
+            Ok(value) => value,
+            Err(err) => {
+                diagnostics.push(err);
+                self.skip_line(directive);
+                false
+            }
+        }
+    }
+
+    /// Handle a directive starting after the grave accent
+    fn directive(&mut self, grave: &Token, diagnostics: &mut dyn DiagnosticHandler) {
+        let was_active = self.is_active();
+
+        let token = if let Some(token) = self.next_token() {
+            token
+        } else {
+            diagnostics.error(&grave.pos, "Expected tool directive");
+            return;
+        };
+        let pos = grave.pos.combine(&token.pos);
+
+        match token.kind {
+            If => {
+                let parent_active = self.is_active();
+                let value = self.condition(&pos, diagnostics);
+                self.branches.push(Branch {
+                    pos: pos.clone(),
+                    parent_active,
+                    active: value,
+                    taken: value,
+                    has_else: false,
+                });
+            }
+            Elsif | Else => {
+                let is_else = token.kind == Else;
+                let value = if is_else {
+                    true
+                } else {
+                    // The condition is evaluated as if within the enclosing region
+                    let branch = self.branches.pop();
+                    let value = self.condition(&pos, diagnostics);
+                    self.branches.extend(branch);
+                    value
+                };
+
+                if let Some(branch) = self.branches.last_mut() {
+                    if branch.has_else && branch.parent_active {
+                        diagnostics.error(
+                            &pos,
+                            format!("Conditional analysis `{} after `else", kind_str(token.kind)),
+                        );
+                    }
+                    branch.active = !branch.taken && value;
+                    branch.taken |= value;
+                    branch.has_else |= is_else;
+                } else if was_active {
+                    diagnostics.error(
+                        &pos,
+                        format!(
+                            "Conditional analysis `{} without matching `if",
+                            kind_str(token.kind)
+                        ),
+                    );
+                }
+            }
+            End => {
+                if self.peek_kind() == Some(If) {
+                    self.next_token();
+                }
+                if self.branches.pop().is_none() {
+                    diagnostics.error(&pos, "Conditional analysis `end without matching `if");
+                }
+            }
+            Identifier => {
+                let ident = token.to_identifier_value().unwrap();
+                let name = ident.item.name_utf8().to_lowercase();
+
+                if name == "warning" || name == "error" {
+                    match self.expect(StringLiteral, &pos) {
+                        Ok(string) => {
+                            if was_active {
+                                let message = string.to_string_value().unwrap().item.to_string();
+                                let severity = if name == "warning" {
+                                    Severity::Warning
+                                } else {
+                                    Severity::Error
+                                };
+                                diagnostics.push(Diagnostic::new(&pos, message, severity));
+                            }
+                        }
+                        Err(err) => {
+                            if was_active {
+                                diagnostics.push(err);
+                            }
+                            self.skip_line(&pos);
+                        }
+                    }
+                } else {
+                    // LRM 15.11 Other tool directives extend to the end of the line and are ignored
+                    self.skip_line(&pos);
+                }
+            }
+            _ => {
+                if was_active {
+                    diagnostics.error(&pos, "Expected tool directive");
+                }
+                self.skip_line(&pos);
+            }
+        }
+
+        self.update_region(was_active, &pos);
+    }
+}
+
+/// Apply the conditional analysis directives of a token sequence
+/// Returns the tokens of the active regions and the source ranges of the inactive regions
+pub fn conditional_analysis(
+    tokens: Vec<Token>,
+    standard: VHDLStandard,
+    conditions: &Conditions,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> (Vec<Token>, Vec<SrcPos>) {
+    if !tokens.iter().any(|token| token.kind == GraveAccent) {
+        return (tokens, Vec::new());
+    }
+
+    let mut result = Vec::with_capacity(tokens.len());
+    let mut analysis = ConditionalAnalysis {
+        standard,
+        conditions,
+        tokens: tokens.into_iter().peekable(),
+        branches: Vec::new(),
+        last_end: Position::default(),
+        inactive_start: None,
+        inactive_regions: Vec::new(),
+    };
+
+    while let Some(token) = analysis.next_token() {
+        if token.kind == GraveAccent {
+            analysis.directive(&token, diagnostics);
+        } else if analysis.is_active() {
+            result.push(token);
+        }
+    }
+
+    if let Some(branch) = analysis.branches.first() {
+        diagnostics.error(
+            &branch.pos,
+            "Conditional analysis `if without matching `end",
+        );
+        if let Some(start) = analysis.inactive_start {
+            let end = branch.pos.source.contents().end();
+            analysis
+                .inactive_regions
+                .push(branch.pos.source.pos(start, end));
+        }
+    }
+
+    (result, analysis.inactive_regions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::syntax::test::Code;
+
+    fn analyze(code: &Code, conditions: &Conditions) -> (Vec<Kind>, Vec<SrcPos>, Vec<Diagnostic>) {
+        let mut diagnostics = Vec::new();
+        let (tokens, regions) = conditional_analysis(
+            code.tokenize(),
+            VHDLStandard::VHDL2019,
+            conditions,
+            &mut diagnostics,
+        );
+        (
+            tokens.iter().map(|token| token.kind).collect(),
+            regions,
+            diagnostics,
+        )
+    }
+
+    #[test]
+    fn selects_active_branch() {
+        let code = Code::new(
+            "\
+`if TOOL_NAME = \"other\" then
+  signal
+`elsif TOOL_NAME = \"vhdl_ls\" then
+  constant
+`else
+  variable
+`end if
+begin",
+        );
+        let (kinds, regions, diagnostics) = analyze(&code, &Conditions::default());
+        assert_eq!(kinds, vec![Constant, Begin]);
+        assert_eq!(diagnostics, vec![]);
+        assert_eq!(
+            regions,
+            vec![
+                code.source()
+                    .pos(code.s1("then").end(), code.s1("`elsif").start()),
+                code.source().pos(
+                    code.s1("`else\n").s1("`else").end(),
+                    code.s1("`end").start()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn evaluates_logical_operators() {
+        let code = Code::new(
+            "\
+`if (TOOL_NAME /= \"x\" and not (vendor = \"y\")) or vendor < \"a\" then
+  signal
+`end
+`if vendor = \"y\" xor tool_name = \"vhdl_ls\" then
+  constant
+`end",
+        );
+        let mut conditions = Conditions::default();
+        conditions.set("VENDOR", "y");
+        let (kinds, _, diagnostics) = analyze(&code, &conditions);
+        assert_eq!(kinds, vec![]);
+        assert_eq!(diagnostics, vec![]);
+    }
+
+    #[test]
+    fn vhdl_version_follows_the_standard() {
+        let code = Code::new(
+            "\
+`if VHDL_VERSION = \"2008\" then
+  signal
+`else
+  constant
+`end",
+        );
+        let mut diagnostics = Vec::new();
+        let (tokens, _) = conditional_analysis(
+            code.tokenize(),
+            VHDLStandard::VHDL2008,
+            &Conditions::default(),
+            &mut diagnostics,
+        );
+        assert_eq!(
+            tokens.iter().map(|token| token.kind).collect::<Vec<_>>(),
+            vec![Signal]
+        );
+        assert_eq!(diagnostics, vec![]);
+
+        let (kinds, _, diagnostics) = analyze(&code, &Conditions::default());
+        assert_eq!(kinds, vec![Constant]);
+        assert_eq!(diagnostics, vec![]);
+    }
+
+    #[test]
+    fn nested_directives_in_inactive_region_are_skipped() {
+        let code = Code::new(
+            "\
+`if TOOL_NAME = \"other\" then
+  `if MISSING = \"x\" then
+    signal
+  `else
+    `error \"not reported\"
+  `end
+`else
+  `warning \"reported\"
+  constant
+`end",
+        );
+        let (kinds, regions, diagnostics) = analyze(&code, &Conditions::default());
+        assert_eq!(kinds, vec![Constant]);
+        assert_eq!(regions.len(), 1);
+        assert_eq!(
+            diagnostics,
+            vec![Diagnostic::warning(code.s1("`warning"), "reported")]
+        );
+    }
+
+    #[test]
+    fn error_on_undefined_identifier_and_unbalanced_directives() {
+        let code = Code::new(
+            "\
+`if MISSING = \"x\" then
+`end
+`else
+`if TOOL_NAME = \"vhdl_ls\" then
+  signal",
+        );
+        let (kinds, _, diagnostics) = analyze(&code, &Conditions::default());
+        assert_eq!(kinds, vec![Signal]);
+        assert_eq!(
+            diagnostics,
+            vec![
+                Diagnostic::error(
+                    code.s1("MISSING"),
+                    "Undefined conditional analysis identifier 'MISSING'"
+                ),
+                Diagnostic::error(
+                    code.s1("`else"),
+                    "Conditional analysis `else without matching `if"
+                ),
+                Diagnostic::error(
+                    code.s("`if", 2),
+                    "Conditional analysis `if without matching `end"
+                ),
+            ]
+        );
+    }
+}
@@ -9,6 +9,7 @@ use fnv::FnvHashMap;
 use crate::ast::{self, AttributeDesignator, Operator};
 use crate::ast::{BaseSpecifier, Ident};
 use crate::data::*;
+use crate::standard::VHDLStandard;
 
 /// The kind of a Token
 #[derive(PartialEq, Eq, Clone, Copy, Debug)]
@@ -94,7 +95,27 @@ pub enum Kind {
     Impure,
     Function,
     Procedure,
+    Parameter,
+    Group,
+    Literal,
+    Guarded,
+    Bus,
+    Register,
+    Disconnect,
+    View,
+    Private,
     Vunit,
+    Vmode,
+    Vprop,
+    Property,
+    Sequence,
+    Assume,
+    AssumeGuarantee,
+    Restrict,
+    RestrictGuarantee,
+    Cover,
+    Fairness,
+    Strong,
 
     // Unary operators
     Abs,
@@ -151,6 +172,8 @@ pub enum Kind {
     RightPar,
     LeftSquare,
     RightSquare,
+    LeftCurly,
+    RightCurly,
     SemiColon,
     Colon,
     Bar,
@@ -160,6 +183,8 @@ pub enum Kind {
     GtGt,
     Circ,
     CommAt,
+    Exclamation,
+    GraveAccent,
     Concat,
     Comma,
     ColonEq,
@@ -343,7 +368,27 @@ pub fn kind_str(kind: Kind) -> &'static str {
         Impure => "impure",
         Function => "function",
         Procedure => "procedure",
+        Parameter => "parameter",
+        Group => "group",
+        Literal => "literal",
+        Guarded => "guarded",
+        Bus => "bus",
+        Register => "register",
+        Disconnect => "disconnect",
+        View => "view",
+        Private => "private",
         Vunit => "vunit",
+        Vmode => "vmode",
+        Vprop => "vprop",
+        Property => "property",
+        Sequence => "sequence",
+        Assume => "assume",
+        AssumeGuarantee => "assume_guarantee",
+        Restrict => "restrict",
+        RestrictGuarantee => "restrict_guarantee",
+        Cover => "cover",
+        Fairness => "fairness",
+        Strong => "strong",
 
         // Unary operators
         Abs => "abs",
@@ -400,6 +445,8 @@ pub fn kind_str(kind: Kind) -> &'static str {
         RightPar => ")",
         LeftSquare => "[",
         RightSquare => "]",
+        LeftCurly => "{",
+        RightCurly => "}",
         SemiColon => ";",
         Colon => ":",
         Bar => "|",
@@ -409,6 +456,8 @@ pub fn kind_str(kind: Kind) -> &'static str {
         GtGt => ">>",
         Circ => "^",
         CommAt => "@",
+        Exclamation => "!",
+        GraveAccent => "`",
         Concat => "&",
         Comma => ",",
         ColonEq => ":=",
@@ -764,6 +813,10 @@ fn parse_exponent(reader: &mut ContentReader) -> Result<i32, TokenError> {
 pub struct TokenState {
     last_token_kind: Option<Kind>,
     start: ReaderState,
+    // Within a PSL statement that is embedded in comments
+    psl_comment: bool,
+    // The nesting of braces within such a PSL statement, a SERE may contain semi colons
+    psl_braces: usize,
 }
 
 impl TokenState {
@@ -771,6 +824,8 @@ impl TokenState {
         TokenState {
             last_token_kind: None,
             start,
+            psl_comment: false,
+            psl_braces: 0,
         }
     }
 }
@@ -1022,8 +1077,15 @@ fn parse_abstract_literal(
             let integer = initial?;
 
             if let Some(base_spec) = parse_base_specifier(reader)? {
-                // @TODO check overflow
-                parse_bit_string(buffer, reader, base_spec, Some(integer as u32))
+                if let Ok(length) = u32::try_from(integer) {
+                    parse_bit_string(buffer, reader, state.pos(), base_spec, Some(length))
+                } else {
+                    Err(TokenError::range(
+                        state.pos(),
+                        reader.pos(),
+                        "Bit string literal length is too large",
+                    ))
+                }
             } else {
                 Err(TokenError::range(
                     state.pos(),
@@ -1087,6 +1149,7 @@ fn maybe_base_specifier(reader: &mut ContentReader) -> Result<Option<BaseSpecifi
 fn parse_bit_string(
     buffer: &mut Latin1String,
     reader: &mut ContentReader,
+    start: Position,
     base_specifier: BaseSpecifier,
     bit_string_length: Option<u32>,
 ) -> Result<(Kind, Value), TokenError> {
@@ -1098,14 +1161,68 @@ fn parse_bit_string(
         }
     };
 
-    Ok((
-        BitString,
-        Value::BitString(ast::BitString {
-            length: bit_string_length,
-            base: base_specifier,
-            value,
-        }),
-    ))
+    let bit_string = ast::BitString {
+        length: bit_string_length,
+        base: base_specifier,
+        value,
+    };
+
+    if let Err(message) = check_bit_string(&bit_string) {
+        return Err(TokenError::range(start, reader.pos(), message));
+    }
+
+    Ok((BitString, Value::BitString(bit_string)))
+}
+
+/// LRM 15.8 The digits must be valid for the base and truncating the value
+/// to an explicit length may only remove leading zeros or sign bits
+fn check_bit_string(bit_string: &ast::BitString) -> Result<(), String> {
+    for &chr in bit_string.value.bytes.iter() {
+        if chr == b'_' {
+            continue;
+        }
+
+        let is_valid = if let Some(bits) = bit_string.base.bits_per_digit() {
+            // Graphic characters other than digits are allowed since VHDL-2008
+            (chr as char)
+                .to_digit(16)
+                .map(|value| value < (1 << bits))
+                .unwrap_or(true)
+        } else {
+            chr.is_ascii_digit()
+        };
+
+        if !is_valid {
+            return Err(format!(
+                "Invalid digit '{}' for base specifier {}",
+                chr as char, bit_string.base
+            ));
+        }
+    }
+
+    if let Some(length) = bit_string.length {
+        let expanded = bit_string.expanded_value();
+        let length = length as usize;
+
+        if expanded.len() > length {
+            let (removed, kept) = expanded.split_at(expanded.len() - length);
+            let fill = if bit_string.base.is_signed() {
+                kept.first().copied().unwrap_or(b'0')
+            } else {
+                b'0'
+            };
+
+            if removed.iter().any(|chr| *chr != fill) {
+                return Err(format!(
+                    "Bit string literal value of length {} does not fit in length {}",
+                    expanded.len(),
+                    length
+                ));
+            }
+        }
+    }
+
+    Ok(())
 }
 
 /// LRM 15.4 Identifiers
@@ -1113,6 +1230,7 @@ fn parse_basic_identifier_or_keyword(
     buffer: &mut Latin1String,
     reader: &mut ContentReader,
     symbols: &Symbols,
+    standard: VHDLStandard,
 ) -> Result<(Kind, Value), TokenError> {
     buffer.bytes.clear();
     while let Some(b) = reader.peek()? {
@@ -1127,7 +1245,7 @@ fn parse_basic_identifier_or_keyword(
         }
     }
 
-    Ok(symbols.insert_or_keyword(buffer))
+    Ok(symbols.insert_or_keyword(buffer, standard))
 }
 
 /// Assumes leading ' has already been consumed
@@ -1149,7 +1267,94 @@ fn parse_character_literal(
     }
 }
 
-fn get_leading_comments(reader: &mut ContentReader) -> Result<Vec<Comment>, TokenError> {
+/// Assume -- has already been consumed
+/// Consume the psl prefix of a comment that contains an embedded PSL statement
+fn skip_psl_comment_prefix(reader: &mut ContentReader) -> Result<bool, TokenError> {
+    let state = reader.state();
+    skip_whitespace_in_line(reader);
+    for expected in b"psl" {
+        match reader.pop()? {
+            Some(byte) if byte.to_ascii_lowercase() == *expected => {}
+            _ => {
+                reader.set_state(state);
+                return Ok(false);
+            }
+        }
+    }
+
+    if matches!(reader.peek()?, None | Some(b' ' | b'\t' | b'\r' | b'\n'))
+        && starts_psl_statement(&mut reader.clone())?
+    {
+        Ok(true)
+    } else {
+        reader.set_state(state);
+        Ok(false)
+    }
+}
+
+/// The words that may start a PSL statement after an optional label
+const PSL_STATEMENT_KEYWORDS: &[&[u8]] = &[
+    b"assert",
+    b"assume",
+    b"assume_guarantee",
+    b"cover",
+    b"restrict",
+    b"restrict_guarantee",
+    b"fairness",
+    b"strong",
+    b"property",
+    b"sequence",
+    b"endpoint",
+    b"vunit",
+    b"vmode",
+    b"vprop",
+];
+
+/// Read a lower case word of identifier characters after whitespace within the line
+fn read_psl_word(reader: &mut ContentReader) -> Result<Vec<u8>, TokenError> {
+    skip_whitespace_in_line(reader);
+    let mut word = Vec::new();
+    while let Some(byte) = reader.peek()? {
+        if byte.is_ascii_alphanumeric() || byte == b'_' {
+            word.push(byte.to_ascii_lowercase());
+            reader.skip();
+        } else {
+            break;
+        }
+    }
+    Ok(word)
+}
+
+/// A comment such as `-- psl is not used` is prose and not a PSL statement
+fn starts_psl_statement(reader: &mut ContentReader) -> Result<bool, TokenError> {
+    let word = read_psl_word(reader)?;
+
+    if word == b"default" {
+        return Ok(read_psl_word(reader)? == b"clock");
+    } else if PSL_STATEMENT_KEYWORDS.contains(&word.as_slice()) {
+        return Ok(true);
+    } else if word.is_empty() {
+        return Ok(false);
+    }
+
+    // A labeled verification directive
+    skip_whitespace_in_line(reader);
+    if reader.peek()? == Some(b':') {
+        reader.skip();
+        let directive = read_psl_word(reader)?;
+        Ok(PSL_STATEMENT_KEYWORDS.contains(&directive.as_slice()))
+    } else {
+        Ok(false)
+    }
+}
+
+/// Comments starting with psl contain PSL statements that are tokenized as code.
+/// Leading comments continue such a statement until it is terminated by a semicolon
+/// PSL comments are not recognized when `psl_comment` is None
+fn get_leading_comments(
+    reader: &mut ContentReader,
+    mut psl_comment: Option<&mut bool>,
+) -> Result<Vec<Comment>, TokenError> {
     let mut comments: Vec<Comment> = Vec::new();
 
     loop {
@@ -1173,6 +1378,12 @@ fn get_leading_comments(reader: &mut ContentReader) -> Result<Vec<Comment>, Toke
             }
             b'-' => {
                 if reader.pop()? == Some(b'-') {
+                    if let Some(ref mut psl_comment) = psl_comment {
+                        if **psl_comment || skip_psl_comment_prefix(reader)? {
+                            **psl_comment = true;
+                            continue;
+                        }
+                    }
                     comments.push(parse_comment(reader));
                 } else {
                     reader.set_state(state);
@@ -1237,6 +1448,18 @@ fn get_trailing_comment(reader: &mut ContentReader) -> Result<Option<Comment>, T
     }
 }
 
+/// The first language standard where the keyword is reserved
+fn keyword_standard(kind: Kind) -> VHDLStandard {
+    match kind {
+        Protected => VHDLStandard::VHDL2002,
+        Context | Default | Force | Release | Parameter => VHDLStandard::VHDL2008,
+        Vunit | Vmode | Vprop | Property | Sequence | Assume | AssumeGuarantee | Restrict
+        | RestrictGuarantee | Cover | Fairness | Strong => VHDLStandard::VHDL2008,
+        View | Private => VHDLStandard::VHDL2019,
+        _ => VHDLStandard::VHDL1993,
+    }
+}
+
 /// Static tokenizer data
 pub struct Symbols {
     symtab: SymbolTable,
@@ -1249,12 +1472,11 @@ impl Symbols {
         &self.symtab
     }
 
-    fn insert_or_keyword(&self, name: &Latin1String) -> (Kind, Value) {
+    fn insert_or_keyword(&self, name: &Latin1String, standard: VHDLStandard) -> (Kind, Value) {
         let symbol = self.symtab.insert(name);
-        if let Some(kind) = self.keywords.get(symbol.id) {
-            (*kind, Value::NoValue)
-        } else {
-            (Identifier, Value::Identifier(symbol))
+        match self.keywords.get(symbol.id) {
+            Some(kind) if keyword_standard(*kind) <= standard => (*kind, Value::NoValue),
+            _ => (Identifier, Value::Identifier(symbol)),
         }
     }
 }
@@ -1342,6 +1564,15 @@ impl std::default::Default for Symbols {
             ("impure", Impure),
             ("function", Function),
             ("procedure", Procedure),
+            ("parameter", Parameter),
+            ("group", Group),
+            ("literal", Literal),
+            ("guarded", Guarded),
+            ("bus", Bus),
+            ("register", Register),
+            ("disconnect", Disconnect),
+            ("view", View),
+            ("private", Private),
             ("abs", Abs),
             ("not", Not),
             ("and", And),
@@ -1359,6 +1590,17 @@ impl std::default::Default for Symbols {
             ("mod", Mod),
             ("rem", Rem),
             ("vunit", Vunit),
+            ("vmode", Vmode),
+            ("vprop", Vprop),
+            ("property", Property),
+            ("sequence", Sequence),
+            ("assume", Assume),
+            ("assume_guarantee", AssumeGuarantee),
+            ("restrict", Restrict),
+            ("restrict_guarantee", RestrictGuarantee),
+            ("cover", Cover),
+            ("fairness", Fairness),
+            ("strong", Strong),
         ];
 
         let attributes = [
@@ -1432,6 +1674,7 @@ impl std::default::Default for Symbols {
             ("simple_name", AttributeDesignator::SimpleName),
             ("instance_name", AttributeDesignator::InstanceName),
             ("path_name", AttributeDesignator::PathName),
+            ("converse", AttributeDesignator::Converse),
         ];
 
         let symtab = SymbolTable::default();
@@ -1460,12 +1703,15 @@ impl std::default::Default for Symbols {
 }
 
 pub struct Tokenizer<'a> {
+    pub standard: VHDLStandard,
     symbols: &'a Symbols,
     buffer: Latin1String,
     state: TokenState,
     pub source: &'a Source,
     reader: ContentReader<'a>,
     final_comments: Option<Vec<Comment>>,
+    // The source ranges of the PSL statements that are embedded in comments
+    psl_comment_regions: Vec<SrcPos>,
 }
 
 impl<'a> Tokenizer<'a> {
@@ -1473,17 +1719,25 @@ impl<'a> Tokenizer<'a> {
         symbols: &'a Symbols,
         source: &'a Source,
         reader: ContentReader<'a>,
+        standard: VHDLStandard,
     ) -> Tokenizer<'a> {
         Tokenizer {
+            standard,
             symbols,
             state: TokenState::new(reader.state()),
             buffer: Latin1String::empty(),
             source,
             reader,
             final_comments: None,
+            psl_comment_regions: Vec::new(),
         }
     }
 
+    /// The source ranges of the PSL statements that are embedded in comments
+    pub fn psl_comment_regions(&self) -> &[SrcPos] {
+        &self.psl_comment_regions
+    }
+
     pub fn attribute(&self, sym: Symbol) -> AttributeDesignator {
         self.symbols
             .attributes
@@ -1512,13 +1766,15 @@ impl<'a> Tokenizer<'a> {
 
         let (kind, value) = match byte {
             b'a'..=b'z' | b'A'..=b'Z' => {
+                let start = self.reader.pos();
                 if let Some(base_spec) = maybe_base_specifier(&mut self.reader)? {
-                    parse_bit_string(&mut self.buffer, &mut self.reader, base_spec, None)?
+                    parse_bit_string(&mut self.buffer, &mut self.reader, start, base_spec, None)?
                 } else {
                     parse_basic_identifier_or_keyword(
                         &mut self.buffer,
                         &mut self.reader,
                         self.symbols,
+                        self.standard,
                     )?
                 }
             }
@@ -1688,6 +1944,11 @@ impl<'a> Tokenizer<'a> {
                 self.reader.skip();
                 (Bar, Value::NoValue)
             }
+            b'`' => {
+                // LRM 24.2 Conditional analysis directives start with a grave accent
+                self.reader.skip();
+                (GraveAccent, Value::NoValue)
+            }
             b'[' => {
                 self.reader.skip();
                 (LeftSquare, Value::NoValue)
@@ -1696,6 +1957,19 @@ impl<'a> Tokenizer<'a> {
                 self.reader.skip();
                 (RightSquare, Value::NoValue)
             }
+            // PSL sequences and strong operators
+            b'{' => {
+                self.reader.skip();
+                (LeftCurly, Value::NoValue)
+            }
+            b'}' => {
+                self.reader.skip();
+                (RightCurly, Value::NoValue)
+            }
+            b'!' => {
+                self.reader.skip();
+                (Exclamation, Value::NoValue)
+            }
             b'\\' => {
                 self.reader.skip();
                 // LRM 15.4.3 Extended identifers
@@ -1712,7 +1986,13 @@ impl<'a> Tokenizer<'a> {
     }
 
     fn pop_raw(&mut self) -> Result<Option<Token>, TokenError> {
-        let leading_comments = get_leading_comments(&mut self.reader)?;
+        let within_psl_comment = self.state.psl_comment;
+        let psl_comment = if self.standard >= VHDLStandard::VHDL2008 {
+            Some(&mut self.state.psl_comment)
+        } else {
+            None
+        };
+        let leading_comments = get_leading_comments(&mut self.reader, psl_comment)?;
         self.state.start = self.reader.state();
 
         match self.parse_token()? {
@@ -1737,6 +2017,25 @@ impl<'a> Tokenizer<'a> {
                     comments: token_comments,
                 };
                 self.state.last_token_kind = Some(token.kind);
+                if self.state.psl_comment {
+                    match token.kind {
+                        LeftCurly => self.state.psl_braces += 1,
+                        RightCurly => {
+                            self.state.psl_braces = self.state.psl_braces.saturating_sub(1)
+                        }
+                        _ => {}
+                    }
+                    if within_psl_comment {
+                        if let Some(region) = self.psl_comment_regions.last_mut() {
+                            *region = region.combine(&token.pos);
+                        }
+                    } else {
+                        self.psl_comment_regions.push(token.pos.clone());
+                    }
+                }
+                if token.kind == SemiColon && self.state.psl_braces == 0 {
+                    self.state.psl_comment = false;
+                }
                 Ok(Some(token))
             }
             None => {
@@ -1804,6 +2103,60 @@ mod tests {
         assert_eq!(kinds_tokenize("not"), vec![Not]);
     }
 
+    #[test]
+    fn tokenize_keywords_of_later_standard_as_identifiers() {
+        assert_eq!(kinds_tokenize("private view"), vec![Identifier, Identifier]);
+        assert_eq!(
+            kinds(&Code::with_standard("private view", VHDLStandard::VHDL2019).tokenize()),
+            vec![Private, View]
+        );
+        assert_eq!(
+            kinds(&Code::with_standard("context force", VHDLStandard::VHDL2002).tokenize()),
+            vec![Identifier, Identifier]
+        );
+        assert_eq!(
+            kinds(&Code::with_standard("protected", VHDLStandard::VHDL1993).tokenize()),
+            vec![Identifier]
+        );
+    }
+
+    #[test]
+    fn tokenize_psl_comments() {
+        assert_eq!(
+            kinds_tokenize("-- psl default clock is clk;\n-- not psl\nbegin"),
+            vec![Default, Identifier, Is, Identifier, SemiColon, Begin]
+        );
+        // A PSL statement continues in later comments until the semi colon
+        assert_eq!(
+            kinds_tokenize("--psl assert always\n--   req;\nbegin"),
+            vec![Assert, Identifier, Identifier, SemiColon, Begin]
+        );
+        assert_eq!(kinds_tokenize("-- psl_foo\nbegin"), vec![Begin]);
+        assert_eq!(
+            kinds_tokenize("-- psl is not used in this design, see notes\nbegin"),
+            vec![Begin]
+        );
+        assert_eq!(
+            kinds_tokenize("-- psl my_label: cover {req};\nbegin"),
+            vec![Identifier, Colon, Cover, LeftCurly, Identifier, RightCurly, SemiColon, Begin]
+        );
+        assert_eq!(
+            kinds(
+                &Code::with_standard("-- psl cover {req};\nbegin", VHDLStandard::VHDL2002)
+                    .tokenize()
+            ),
+            vec![Begin]
+        );
+    }
+
+    #[test]
+    fn tokenize_psl_delimiters() {
+        assert_eq!(
+            kinds_tokenize("{ } !"),
+            vec![LeftCurly, RightCurly, Exclamation]
+        );
+    }
+
     #[test]
     fn tokenize_newline() {
         assert_eq!(
@@ -2150,8 +2503,6 @@ my_other_ident",
         );
     }
 
-    // @TODO test incorrect value for base, ex: 2x"ff"
-    // @TODO test incorrect value for length ex: 2x"1111"
     #[test]
     fn tokenize_bit_string_literal() {
         use BaseSpecifier::{B, D, O, SB, SO, SX, UB, UO, UX, X};
@@ -2238,6 +2589,112 @@ my_other_ident",
         );
     }
 
+    #[test]
+    fn tokenize_bit_string_with_invalid_digit() {
+        let code = Code::new("b\"1021\"");
+        let (tokens, _) = code.tokenize_result();
+        assert_eq!(
+            tokens,
+            vec![Err(Diagnostic::error(
+                code.pos(),
+                "Invalid digit '2' for base specifier b"
+            ))]
+        );
+
+        let code = Code::new("12o\"7781\"");
+        let (tokens, _) = code.tokenize_result();
+        assert_eq!(
+            tokens,
+            vec![Err(Diagnostic::error(
+                code.pos(),
+                "Invalid digit '8' for base specifier o"
+            ))]
+        );
+
+        let code = Code::new("d\"12a\"");
+        let (tokens, _) = code.tokenize_result();
+        assert_eq!(
+            tokens,
+            vec![Err(Diagnostic::error(
+                code.pos(),
+                "Invalid digit 'a' for base specifier d"
+            ))]
+        );
+    }
+
+    #[test]
+    fn tokenize_bit_string_with_extended_digits() {
+        let code = Code::new("b\"1-Z_X\"");
+        let tokens = code.tokenize();
+        assert_eq!(
+            tokens,
+            vec![Token {
+                kind: BitString,
+                value: Value::BitString(ast::BitString {
+                    length: None,
+                    base: BaseSpecifier::B,
+                    value: Latin1String::from_utf8_unchecked("1-Z_X")
+                }),
+                pos: code.pos(),
+                comments: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn tokenize_bit_string_that_does_not_fit_length() {
+        let code = Code::new("2x\"f\"");
+        let (tokens, _) = code.tokenize_result();
+        assert_eq!(
+            tokens,
+            vec![Err(Diagnostic::error(
+                code.pos(),
+                "Bit string literal value of length 4 does not fit in length 2"
+            ))]
+        );
+
+        let code = Code::new("3d\"8\"");
+        let (tokens, _) = code.tokenize_result();
+        assert_eq!(
+            tokens,
+            vec![Err(Diagnostic::error(
+                code.pos(),
+                "Bit string literal value of length 4 does not fit in length 3"
+            ))]
+        );
+
+        let code = Code::new("3sx\"7\"");
+        let (tokens, _) = code.tokenize_result();
+        assert_eq!(
+            tokens,
+            vec![Err(Diagnostic::error(
+                code.pos(),
+                "Bit string literal value of length 4 does not fit in length 3"
+            ))]
+        );
+
+        // Leading zeros and sign bits may be removed
+        for code in ["2x\"3\"", "3ux\"7\"", "3sx\"f\"", "2sb\"1110\""] {
+            let (tokens, _) = Code::new(code).tokenize_result();
+            assert!(tokens.iter().all(|token| token.is_ok()), "{code}");
+        }
+    }
+
+    #[test]
+    fn bit_string_expanded_length() {
+        let length = |code: &str| match Code::new(code).tokenize().pop().unwrap().value {
+            Value::BitString(bit_string) => bit_string.expanded_length(),
+            _ => panic!("Expected bit string"),
+        };
+
+        assert_eq!(length("x\"F_F\""), 8);
+        assert_eq!(length("o\"7\""), 3);
+        assert_eq!(length("12x\"FF\""), 12);
+        assert_eq!(length("d\"255\""), 8);
+        assert_eq!(length("d\"256\""), 9);
+        assert_eq!(length("d\"0\""), 1);
+    }
+
     #[test]
     fn tokenize_based_integer() {
         assert_eq!(
@@ -2440,6 +2897,11 @@ my_other_ident",
         assert_eq!(kinds_tokenize("[]"), vec![LeftSquare, RightSquare]);
     }
 
+    #[test]
+    fn tokenize_grave_accent() {
+        assert_eq!(kinds_tokenize("`if"), vec![GraveAccent, If]);
+    }
+
     #[test]
     fn tokenize_ignores_comments() {
         assert_eq!(
@@ -2559,7 +3021,7 @@ comment
 
     #[test]
     fn tokenize_illegal() {
-        let code = Code::new("begin!end");
+        let code = Code::new("begin$end");
         let (tokens, _) = code.tokenize_result();
         assert_eq!(
             tokens,
@@ -2570,7 +3032,7 @@ comment
                     pos: code.s1("begin").pos(),
                     comments: None,
                 }),
-                Err(Diagnostic::error(&code.s1("!"), "Illegal token")),
+                Err(Diagnostic::error(&code.s1("$"), "Illegal token")),
                 Ok(Token {
                     kind: End,
                     value: Value::NoValue,
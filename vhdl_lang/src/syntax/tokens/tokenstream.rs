@@ -6,38 +6,76 @@
 
 use std::cell::Cell;
 
+use super::conditional::*;
 use super::tokenizer::Kind::*;
 use super::tokenizer::*;
 use crate::ast::{AttributeDesignator, Ident, RangeAttribute, TypeAttribute};
 use crate::data::{DiagnosticHandler, DiagnosticResult, WithPos};
+use crate::standard::VHDLStandard;
 use crate::{Diagnostic, SrcPos};
 
 pub struct TokenStream<'a> {
     tokenizer: Tokenizer<'a>,
     idx: Cell<usize>,
     tokens: Vec<Token>,
+    inactive_regions: Vec<SrcPos>,
 }
 
 impl<'a> TokenStream<'a> {
     pub fn new(
         mut tokenizer: Tokenizer<'a>,
+        conditions: &Conditions,
         diagnostics: &mut dyn DiagnosticHandler,
     ) -> TokenStream<'a> {
         let mut tokens = Vec::new();
+        let mut errors = Vec::new();
         loop {
             match tokenizer.pop() {
                 Ok(Some(token)) => tokens.push(token),
                 Ok(None) => break,
-                Err(err) => diagnostics.push(err),
+                Err(err) => errors.push(err),
             }
         }
+
+        let (tokens, inactive_regions) =
+            conditional_analysis(tokens, tokenizer.standard, conditions, diagnostics);
+
+        // Errors within inactive regions are ignored
+        for err in errors {
+            let is_inactive = inactive_regions
+                .iter()
+                .any(|region| region.start() <= err.pos.start() && err.pos.start() < region.end());
+            if !is_inactive {
+                diagnostics.push(err);
+            }
+        }
+
         TokenStream {
             tokenizer,
             idx: Cell::new(0),
             tokens,
+            inactive_regions,
         }
     }
 
+    /// The revision of the language standard that is parsed
+    pub fn standard(&self) -> VHDLStandard {
+        self.tokenizer.standard
+    }
+
+    /// The source ranges skipped by conditional analysis
+    pub fn inactive_regions(&self) -> &[SrcPos] {
+        &self.inactive_regions
+    }
+
+    /// The PSL statement embedded in comments that contains the token
+    pub fn psl_comment_region(&self, token: &Token) -> Option<&SrcPos> {
+        self.tokenizer
+            .psl_comment_regions()
+            .iter()
+            .find(|region| region.start() <= token.pos.start() && token.pos.start() < region.end())
+    }
+
     pub fn state(&self) -> usize {
         self.get_idx()
     }
@@ -142,8 +180,12 @@ impl<'a> TokenStream<'a> {
         self.nth_kind_is(0, kind)
     }
 
+    pub fn nth(&self, idx: usize) -> Option<&Token> {
+        self.tokens.get(self.get_idx() + idx)
+    }
+
     pub fn nth_kind_is(&self, idx: usize, kind: Kind) -> bool {
-        if let Some(token) = self.tokens.get(self.get_idx() + idx) {
+        if let Some(token) = self.nth(idx) {
             token.kind == kind
         } else {
             false
@@ -256,8 +298,13 @@ mod tests {
         ($code:ident, $stream:ident) => {
             let source = $code.source();
             let contents = source.contents();
-            let tokenizer = Tokenizer::new(&$code.symbols, source, ContentReader::new(&contents));
-            let $stream = TokenStream::new(tokenizer, &mut NoDiagnostics);
+            let tokenizer = Tokenizer::new(
+                &$code.symbols,
+                source,
+                ContentReader::new(&contents),
+                VHDLStandard::default(),
+            );
+            let $stream = TokenStream::new(tokenizer, &Conditions::default(), &mut NoDiagnostics);
         };
     }
 
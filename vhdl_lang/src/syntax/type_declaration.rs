@@ -4,10 +4,11 @@
 //
 // Copyright (c) 2018, Olof Kraigher olof.kraigher@gmail.com
 
-use super::common::check_end_identifier_mismatch;
 use super::common::ParseResult;
+use super::common::{check_end_identifier_mismatch, check_standard, parse_end_keyword};
 use super::declarative_part::parse_declarative_part;
 use super::names::parse_identifier_list;
+use super::object_declaration::parse_object_declaration;
 use super::range::{parse_array_index_constraint, parse_range};
 use super::subprogram::parse_subprogram_declaration;
 use super::subtype_indication::parse_subtype_indication;
@@ -15,6 +16,7 @@ use super::tokens::{Kind::*, TokenStream};
 use crate::ast::*;
 use crate::ast::{AbstractLiteral, Range};
 use crate::data::DiagnosticHandler;
+use crate::standard::VHDLStandard;
 use crate::syntax::names::parse_type_mark;
 
 /// LRM 5.2.2 Enumeration types
@@ -76,7 +78,15 @@ fn parse_record_type_definition(
     let mut elem_decls = Vec::new();
 
     loop {
-        if stream.skip_if_kind(End) {
+        if let Some(end) = stream.pop_if_kind(End) {
+            if elem_decls.is_empty() {
+                check_standard(
+                    stream,
+                    &end.pos,
+                    VHDLStandard::VHDL2019,
+                    "Empty record type",
+                )?;
+            }
             stream.pop_if_kind(Record);
             let end_ident = stream.pop_optional_ident();
             stream.expect_kind(SemiColon)?;
@@ -124,13 +134,23 @@ pub fn parse_protected_type_declaration(
             Impure | Function | Procedure => items.push(ProtectedTypeDeclarativeItem::Subprogram(
                 parse_subprogram_declaration(stream, diagnostics)?,
             )),
+            Private => {
+                stream.skip();
+                let variable = stream.peek_expect()?;
+                if variable.kind != Variable {
+                    return Err(variable.kinds_error(&[Variable]));
+                }
+                for object_decl in parse_object_declaration(stream)? {
+                    items.push(ProtectedTypeDeclarativeItem::PrivateVariable(object_decl));
+                }
+            },
             End => {
                 stream.skip();
                 break;
             }
         );
     }
-    stream.expect_kind(Protected)?;
+    parse_end_keyword(stream, Protected)?;
     let end_ident = stream.pop_optional_ident();
     Ok((ProtectedTypeDeclaration { items }, end_ident))
 }
@@ -177,7 +197,7 @@ fn parse_physical_type_definition(
     }
 
     stream.expect_kind(End)?;
-    stream.expect_kind(Units)?;
+    parse_end_keyword(stream, Units)?;
     let end_ident = stream.pop_optional_ident();
     stream.expect_kind(SemiColon)?;
 
@@ -247,8 +267,9 @@ pub fn parse_type_declaration(
             if stream.skip_if_kind(Body) {
                 let decl = parse_declarative_part(stream, diagnostics)?;
                 stream.expect_kind(End)?;
-                stream.expect_kind(Protected)?;
-                stream.expect_kind(Body)?;
+                if parse_end_keyword(stream, Protected)? {
+                    stream.expect_kind(Body)?;
+                }
                 let end_ident = stream.pop_optional_ident();
                 stream.expect_kind(SemiColon)?;
                 end_ident_pos = check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics);
@@ -289,6 +310,7 @@ mod tests {
     use super::*;
 
     use crate::ast::{DiscreteRange, Ident};
+    use crate::data::Diagnostic;
     use crate::syntax::test::Code;
     use crate::SrcPos;
 
@@ -532,6 +554,39 @@ end record;",
         );
     }
 
+    #[test]
+    fn parse_empty_record_type_definition() {
+        let code = Code::with_standard(
+            "\
+type foo is record
+end record;",
+            VHDLStandard::VHDL2019,
+        );
+
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_type_declaration),
+            TypeDeclaration {
+                ident: code.s1("foo").decl_ident(),
+                def: TypeDefinition::Record(vec![]),
+                end_ident_pos: None,
+            }
+        );
+
+        let code = Code::new(
+            "\
+type foo is record
+end record;",
+        );
+        let (result, _) = code.with_partial_stream_diagnostics(parse_type_declaration);
+        assert_eq!(
+            result,
+            Err(Diagnostic::error(
+                code.s1("end"),
+                "Empty record type requires VHDL-2019 or later"
+            ))
+        );
+    }
+
     #[test]
     fn parse_record_type_definition_many() {
         let code = Code::new(
@@ -692,6 +747,30 @@ end protected;
         )
     }
 
+    #[test]
+    fn test_protected_type_declaration_with_private_variable() {
+        let code = Code::with_standard(
+            "\
+type foo is protected
+  procedure proc;
+  private variable bar : natural;
+end protected;
+",
+            VHDLStandard::VHDL2019,
+        );
+        let items = vec![
+            ProtectedTypeDeclarativeItem::Subprogram(code.s1("procedure proc").subprogram_decl()),
+            ProtectedTypeDeclarativeItem::PrivateVariable(
+                code.s1("variable bar : natural;").object_decl(),
+            ),
+        ];
+
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_type_declaration),
+            protected_decl(code.s1("foo").ident(), items, None)
+        )
+    }
+
     #[test]
     fn test_protected_type_body() {
         let code = Code::new(
@@ -724,6 +803,37 @@ end protected body;
         )
     }
 
+    #[test]
+    fn test_protected_type_end_without_protected_vhdl2019() {
+        let code = Code::with_standard(
+            "\
+type foo is protected
+end foo;
+",
+            VHDLStandard::VHDL2019,
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_type_declaration),
+            protected_decl(code.s1("foo").ident(), vec![], Some(code.s("foo", 2).pos()))
+        );
+
+        let code = Code::with_standard(
+            "\
+type foo is protected body
+end foo;
+",
+            VHDLStandard::VHDL2019,
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_type_declaration),
+            TypeDeclaration {
+                ident: code.s1("foo").decl_ident(),
+                def: TypeDefinition::ProtectedBody(ProtectedTypeBody { decl: vec![] }),
+                end_ident_pos: Some(code.s("foo", 2).pos()),
+            }
+        );
+    }
+
     #[test]
     fn test_physical_type_declaration() {
         let code = Code::new(
@@ -748,6 +858,44 @@ end units phys;
         )
     }
 
+    #[test]
+    fn test_physical_type_end_without_units_vhdl2019() {
+        let code = Code::with_standard(
+            "\
+type phys is range 0 to 15 units
+   primary_unit;
+end phys;
+",
+            VHDLStandard::VHDL2019,
+        );
+
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_type_declaration),
+            TypeDeclaration {
+                ident: code.s1("phys").decl_ident(),
+                def: TypeDefinition::Physical(PhysicalTypeDeclaration {
+                    range: code.s1("0 to 15").range(),
+                    primary_unit: code.s1("primary_unit").decl_ident(),
+                    secondary_units: vec![]
+                }),
+                end_ident_pos: Some(code.s("phys", 2).pos()),
+            }
+        );
+
+        let code = Code::new(
+            "\
+type phys is range 0 to 15 units
+   primary_unit;
+end phys;
+",
+        );
+        let (result, _) = code.with_partial_stream_diagnostics(parse_type_declaration);
+        assert_eq!(
+            result,
+            Err(Diagnostic::error(code.s("phys", 2), "Expected 'units'"))
+        );
+    }
+
     #[test]
     fn test_physical_type_declaration_secondary_units() {
         let code = Code::new(
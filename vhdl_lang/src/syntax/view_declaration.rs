@@ -0,0 +1,159 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this file,
+// You can obtain one at http://mozilla.org/MPL/2.0/.
+//
+// Copyright (c) 2023, Olof Kraigher olof.kraigher@gmail.com
+
+use super::common::check_end_identifier_mismatch;
+use super::common::parse_end_keyword;
+use super::common::ParseResult;
+use super::interface_declaration::parse_optional_mode;
+use super::names::{parse_identifier_list, parse_name};
+use super::subtype_indication::parse_subtype_indication;
+use super::tokens::{Kind::*, TokenStream};
+/// LRM 6.5.2 Mode view declarations
+use crate::ast::*;
+use crate::data::DiagnosticHandler;
+
+/// Parse a mode view indication starting at the view keyword
+/// The array form has the view name within parenthesis
+pub fn parse_mode_view_indication(stream: &TokenStream) -> ParseResult<ModeViewIndication> {
+    stream.expect_kind(View)?;
+    if stream.skip_if_kind(LeftPar) {
+        let name = parse_name(stream)?;
+        stream.expect_kind(RightPar)?;
+        Ok(ModeViewIndication {
+            kind: ModeViewIndicationKind::Array,
+            name,
+        })
+    } else {
+        Ok(ModeViewIndication {
+            kind: ModeViewIndicationKind::Record,
+            name: parse_name(stream)?,
+        })
+    }
+}
+
+fn parse_element_mode(stream: &TokenStream) -> ParseResult<ElementMode> {
+    if stream.peek_kind() == Some(View) {
+        Ok(ElementMode::View(parse_mode_view_indication(stream)?))
+    } else if let Some(mode) = parse_optional_mode(stream)? {
+        Ok(ElementMode::Simple(mode))
+    } else {
+        let token = stream.peek_expect()?;
+        Err(token.kinds_error(&[In, Out, InOut, Buffer, Linkage, View]))
+    }
+}
+
+pub fn parse_mode_view_declaration(
+    stream: &TokenStream,
+    diagnostics: &mut dyn DiagnosticHandler,
+) -> ParseResult<ModeViewDeclaration> {
+    stream.expect_kind(View)?;
+    let ident = WithDecl::new(stream.expect_ident()?);
+    stream.expect_kind(Of)?;
+    let typ = parse_subtype_indication(stream)?;
+    stream.expect_kind(Is)?;
+
+    let mut elements = Vec::new();
+    while stream.peek_kind() != Some(End) {
+        let names = parse_identifier_list(stream)?
+            .into_iter()
+            .map(WithRef::new)
+            .collect();
+        stream.expect_kind(Colon)?;
+        let mode = parse_element_mode(stream)?;
+        stream.expect_kind(SemiColon)?;
+        elements.push(ModeViewElementDefinition { names, mode });
+    }
+
+    stream.expect_kind(End)?;
+    parse_end_keyword(stream, View)?;
+    let end_ident = stream.pop_optional_ident();
+    stream.expect_kind(SemiColon)?;
+
+    Ok(ModeViewDeclaration {
+        end_ident_pos: check_end_identifier_mismatch(&ident.tree, end_ident, diagnostics),
+        ident,
+        typ,
+        elements,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{Diagnostic, WithPos};
+    use crate::standard::VHDLStandard;
+    use crate::syntax::test::Code;
+
+    #[test]
+    fn parse_view_declaration() {
+        let code = Code::with_standard(
+            "\
+view master of bus_t is
+  addr, wdata : out;
+  rdata : in;
+  sub : view sub_master;
+  subs : view (sub_master'converse);
+end view master;
+",
+            VHDLStandard::VHDL2019,
+        );
+        assert_eq!(
+            code.with_stream_no_diagnostics(parse_mode_view_declaration),
+            ModeViewDeclaration {
+                ident: code.s1("master").decl_ident(),
+                typ: code.s1("bus_t").subtype_indication(),
+                elements: vec![
+                    ModeViewElementDefinition {
+                        names: vec![
+                            WithRef::new(code.s1("addr").ident()),
+                            WithRef::new(code.s1("wdata").ident()),
+                        ],
+                        mode: ElementMode::Simple(WithPos::new(Mode::Out, code.s1("out").pos())),
+                    },
+                    ModeViewElementDefinition {
+                        names: vec![WithRef::new(code.s1("rdata").ident())],
+                        mode: ElementMode::Simple(WithPos::new(Mode::In, code.s1("in").pos())),
+                    },
+                    ModeViewElementDefinition {
+                        names: vec![WithRef::new(code.s1("sub").ident())],
+                        mode: ElementMode::View(ModeViewIndication {
+                            kind: ModeViewIndicationKind::Record,
+                            name: code.s1("sub_master").name(),
+                        }),
+                    },
+                    ModeViewElementDefinition {
+                        names: vec![WithRef::new(code.s1("subs").ident())],
+                        mode: ElementMode::View(ModeViewIndication {
+                            kind: ModeViewIndicationKind::Array,
+                            name: code.s1("sub_master'converse").name(),
+                        }),
+                    },
+                ],
+                end_ident_pos: Some(code.s1("end view master").s1("master").pos()),
+            }
+        );
+    }
+
+    #[test]
+    fn parse_view_declaration_requires_element_mode() {
+        let code = Code::with_standard(
+            "\
+view master of bus_t is
+  addr : bus_t;
+end view;
+",
+            VHDLStandard::VHDL2019,
+        );
+        let (result, _) = code.with_partial_stream_diagnostics(parse_mode_view_declaration);
+        assert_eq!(
+            result,
+            Err(Diagnostic::error(
+                code.s("bus_t", 2),
+                "Expected 'in', 'out', 'inout', 'buffer', 'linkage' or 'view'"
+            ))
+        );
+    }
+}
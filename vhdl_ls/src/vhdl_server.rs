@@ -128,6 +128,7 @@ impl VHDLServer {
             })),
             workspace_symbol_provider: Some(OneOf::Left(true)),
             document_symbol_provider: Some(OneOf::Left(true)),
+            folding_range_provider: Some(FoldingRangeProviderCapability::Simple(true)),
             ..Default::default()
         };
 
@@ -551,6 +552,36 @@ impl VHDLServer {
         }
     }
 
+    /// Regions that are skipped by conditional analysis can be folded
+    pub fn folding_range(&self, params: &FoldingRangeParams) -> Option<Vec<FoldingRange>> {
+        let source = self
+            .project
+            .get_source(&uri_to_file_name(&params.text_document.uri))?;
+
+        Some(
+            self.project
+                .inactive_regions(&source)
+                .iter()
+                .filter_map(|region| {
+                    // The directive that ends the region is kept visible
+                    let end_line = region.end().line.checked_sub(1)?;
+                    if end_line > region.start().line {
+                        Some(FoldingRange {
+                            start_line: region.start().line,
+                            start_character: None,
+                            end_line,
+                            end_character: None,
+                            kind: Some(FoldingRangeKind::Region),
+                            collapsed_text: None,
+                        })
+                    } else {
+                        None
+                    }
+                })
+                .collect(),
+        )
+    }
+
     pub fn text_document_hover(&mut self, params: &TextDocumentPositionParams) -> Option<Hover> {
         let source = self
             .project
@@ -759,7 +790,9 @@ fn overloaded_kind(overloaded: &Overloaded) -> SymbolKind {
     match overloaded {
         Overloaded::SubprogramDecl(_) => SymbolKind::FUNCTION,
         Overloaded::Subprogram(_) => SymbolKind::FUNCTION,
-        Overloaded::InterfaceSubprogram(_) => SymbolKind::FUNCTION,
+        Overloaded::UninstSubprogramDecl(..) => SymbolKind::FUNCTION,
+        Overloaded::UninstSubprogram(..) => SymbolKind::FUNCTION,
+        Overloaded::InterfaceSubprogram(..) => SymbolKind::FUNCTION,
         Overloaded::EnumLiteral(_) => SymbolKind::ENUM_MEMBER,
         Overloaded::Alias(o) => overloaded_kind(o.kind()),
     }
@@ -813,7 +846,11 @@ fn to_symbol_kind(kind: &AnyEntKind) -> SymbolKind {
         AnyEntKind::File { .. } => SymbolKind::FILE,
         AnyEntKind::InterfaceFile { .. } => SymbolKind::INTERFACE,
         AnyEntKind::Component(_) => SymbolKind::CLASS,
+        AnyEntKind::View(_) => SymbolKind::INTERFACE,
+        AnyEntKind::Psl(_) => SymbolKind::PROPERTY,
         AnyEntKind::Attribute(_) => SymbolKind::PROPERTY,
+        AnyEntKind::GroupTemplate(_) => SymbolKind::STRUCT,
+        AnyEntKind::Group => SymbolKind::STRUCT,
         AnyEntKind::Overloaded(o) => overloaded_kind(o),
         AnyEntKind::Type(t) => type_kind(t),
         AnyEntKind::ElementDeclaration(_) => SymbolKind::FIELD,
@@ -823,13 +860,14 @@ fn to_symbol_kind(kind: &AnyEntKind) -> SymbolKind {
         AnyEntKind::Library => SymbolKind::NAMESPACE,
         AnyEntKind::Design(d) => match d {
             vhdl_lang::Design::Entity(_, _) => SymbolKind::MODULE,
-            vhdl_lang::Design::Architecture(_) => SymbolKind::MODULE,
+            vhdl_lang::Design::Architecture(..) => SymbolKind::MODULE,
             vhdl_lang::Design::Configuration => SymbolKind::MODULE,
             vhdl_lang::Design::Package(_, _) => SymbolKind::PACKAGE,
             vhdl_lang::Design::PackageBody => SymbolKind::PACKAGE,
             vhdl_lang::Design::UninstPackage(_, _) => SymbolKind::PACKAGE,
             vhdl_lang::Design::PackageInstance(_) => SymbolKind::PACKAGE,
             vhdl_lang::Design::Context(_) => SymbolKind::NAMESPACE,
+            vhdl_lang::Design::VerificationUnit(_) => SymbolKind::MODULE,
         },
     }
 }
@@ -1188,6 +1226,59 @@ lib.files = [
         assert_eq!(response, Some(expected));
     }
 
+    #[test]
+    fn inactive_regions_are_folding_ranges() {
+        let (mock, mut server) = setup_server();
+        let (_tempdir, root_uri) = temp_root_uri();
+        let file_uri = write_file(
+            &root_uri,
+            "file.vhd",
+            "\
+entity ent is
+end entity;
+
+architecture rtl of ent is
+`if TOOL_TYPE = \"SYNTHESIS\" then
+  component comp0 end component;
+  this is not vhdl !
+`else
+  component comp1 end component;
+`end if
+begin
+end architecture;
+",
+        );
+        let config_uri = write_config(
+            &root_uri,
+            "
+[libraries]
+lib.files = ['file.vhd']
+lib.conditions = { TOOL_TYPE = \"SIMULATION\" }
+",
+        );
+
+        expect_loaded_config_messages(&mock, &config_uri);
+        initialize_server(&mut server, root_uri);
+
+        let response = server.folding_range(&FoldingRangeParams {
+            text_document: TextDocumentIdentifier { uri: file_uri },
+            work_done_progress_params: WorkDoneProgressParams::default(),
+            partial_result_params: PartialResultParams::default(),
+        });
+
+        assert_eq!(
+            response,
+            Some(vec![FoldingRange {
+                start_line: 4,
+                start_character: None,
+                end_line: 6,
+                end_character: None,
+                kind: Some(FoldingRangeKind::Region),
+                collapsed_text: None,
+            }])
+        );
+    }
+
     #[test]
     fn client_register_capability() {
         let (mock, mut server) = setup_server();